@@ -49,6 +49,7 @@ pub enum TerminatedReason {
     Stopped = 0,
     AddressTerminated = 1,
     NotFound = 2,
+    Failed = 3,
 }
 impl TerminatedReason {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -60,6 +61,7 @@ impl TerminatedReason {
             Self::Stopped => "Stopped",
             Self::AddressTerminated => "AddressTerminated",
             Self::NotFound => "NotFound",
+            Self::Failed => "Failed",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -68,6 +70,7 @@ impl TerminatedReason {
             "Stopped" => Some(Self::Stopped),
             "AddressTerminated" => Some(Self::AddressTerminated),
             "NotFound" => Some(Self::NotFound),
+            "Failed" => Some(Self::Failed),
             _ => None,
         }
     }
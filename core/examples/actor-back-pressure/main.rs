@@ -5,7 +5,6 @@ use nexus_actor_core_rs::actor::context::{ContextHandle, InfoPart, MessagePart,
 use nexus_actor_core_rs::actor::dispatch::{
   unbounded_mailbox_creator_with_opts, MailboxMiddleware, MailboxMiddlewareHandle, MailboxProducer,
 };
-use nexus_actor_core_rs::actor::message::Message;
 use nexus_actor_core_rs::actor::message::MessageHandle;
 use nexus_actor_message_derive_rs::Message;
 use nexus_actor_utils_rs::concurrent::WaitGroup;
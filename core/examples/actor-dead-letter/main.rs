@@ -2,7 +2,7 @@ use clap::Parser;
 use governor::{Quota, RateLimiter};
 use nexus_actor_core_rs::actor::actor_system::ActorSystem;
 use nexus_actor_core_rs::actor::context::SenderPart;
-use nexus_actor_core_rs::actor::message::{Message, MessageHandle};
+use nexus_actor_core_rs::actor::message::MessageHandle;
 use nexus_actor_core_rs::actor::{Config, ConfigOption};
 use nexus_actor_core_rs::Message;
 use std::env;
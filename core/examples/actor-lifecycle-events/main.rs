@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use nexus_actor_core_rs::actor::actor::{Actor, ActorError, ErrorReason, Props};
 use nexus_actor_core_rs::actor::actor_system::ActorSystem;
 use nexus_actor_core_rs::actor::context::{ContextHandle, MessagePart, SenderPart, SpawnerPart, StopperPart};
-use nexus_actor_core_rs::actor::message::{Message, MessageHandle};
+use nexus_actor_core_rs::actor::message::MessageHandle;
 use nexus_actor_message_derive_rs::Message;
 use nexus_actor_utils_rs::concurrent::WaitGroup;
 use std::env;
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use nexus_actor_core_rs::actor::actor::{Props, TypedProps};
 use nexus_actor_core_rs::actor::actor_system::ActorSystem;
 use nexus_actor_core_rs::actor::dispatch::{
-  unbounded_mailbox_creator_with_opts, MailboxMiddleware, MailboxMiddlewareHandle,
+  unbounded_mailbox_creator_with_opts, MailboxMiddleware, MailboxMiddlewareHandle, MessageCounterMiddleware,
 };
 use nexus_actor_core_rs::actor::message::MessageHandle;
 use nexus_actor_core_rs::actor::typed_context::{TypedSenderPart, TypedSpawnerPart};
@@ -49,10 +49,12 @@ async fn main() {
 
   let system = ActorSystem::new().await.unwrap();
   let mut root_context = system.get_root_context().await.to_typed();
+  let counter = MessageCounterMiddleware::new();
   let props = TypedProps::from_async_actor_receiver_with_opts(
     move |_| async move { Ok(()) },
     [Props::with_mailbox_producer(unbounded_mailbox_creator_with_opts([
       MailboxMiddlewareHandle::new(MailboxLogger::new()),
+      MailboxMiddlewareHandle::new(counter.clone()),
     ]))],
   )
   .await;
@@ -62,4 +64,9 @@ async fn main() {
   sleep(std::time::Duration::from_secs(1)).await;
   root_context.send(pid, "Hello".to_string()).await;
   sleep(std::time::Duration::from_secs(5)).await;
+  tracing::info!(
+    "Messages posted: {}, received: {}",
+    counter.get_posted_count(),
+    counter.get_received_count()
+  );
 }
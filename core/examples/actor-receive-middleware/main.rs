@@ -1,7 +1,7 @@
 use nexus_actor_core_rs::actor::actor::{Logger, Props};
 use nexus_actor_core_rs::actor::actor_system::ActorSystem;
 use nexus_actor_core_rs::actor::context::{MessagePart, SenderPart, SpawnerPart};
-use nexus_actor_core_rs::actor::message::{Message, MessageHandle};
+use nexus_actor_core_rs::actor::message::MessageHandle;
 use nexus_actor_core_rs::Message;
 use std::env;
 use std::time::Duration;
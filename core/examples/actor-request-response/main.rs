@@ -1,7 +1,6 @@
 use nexus_actor_core_rs::actor::actor::Props;
 use nexus_actor_core_rs::actor::actor_system::ActorSystem;
 use nexus_actor_core_rs::actor::context::{BasePart, MessagePart, SenderPart, SpawnerPart};
-use nexus_actor_core_rs::actor::message::Message;
 use nexus_actor_core_rs::actor::message::MessageHandle;
 use nexus_actor_core_rs::actor::message::ResponseHandle;
 use nexus_actor_core_rs::Message;
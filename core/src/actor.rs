@@ -10,8 +10,13 @@ pub mod guardian;
 pub mod interaction_test;
 pub mod message;
 pub mod metrics;
+pub mod persistence;
 pub mod process;
+pub mod router;
+mod scheduler;
+mod scheduler_test;
+mod shutdown_hook;
 pub mod supervisor;
 pub mod typed_context;
 
-pub use {self::config::*, self::config_option::*};
+pub use {self::config::*, self::config_option::*, self::scheduler::*, self::shutdown_hook::*};
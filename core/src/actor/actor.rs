@@ -2,30 +2,48 @@ mod actor;
 mod actor_behavior;
 mod actor_behavior_test;
 mod actor_error;
+mod actor_error_test;
 mod actor_example_test;
 mod actor_handle;
 mod actor_inner_error;
 mod actor_process;
 mod actor_producer;
 mod actor_receiver;
+mod actor_ref;
+mod actor_ref_test;
 mod child_test;
 mod context_decorator;
 mod context_decorator_chain;
+mod context_decorator_test;
 mod context_handler;
 mod continuer;
+mod continuer_test;
+mod error_reason_summary;
+mod error_reason_summary_test;
+mod idempotency_check;
+mod lifecycle_event;
+mod lifecycle_event_test;
 mod middleware;
 mod middleware_chain;
+mod name_generator;
 mod pid;
 mod pid_set;
 mod pid_set_test;
 mod props;
+mod receive_guard;
+mod receive_guard_test;
 mod receive_timeout_test;
 mod receiver_middleware;
 mod receiver_middleware_chain;
+mod receiver_middleware_test;
+mod request_response_actor;
+mod request_response_actor_test;
 mod restart_statistics;
 mod sender_middleware;
 mod sender_middleware_chain;
+mod snapshotable;
 mod spawn_example_test;
+mod spawn_interceptor;
 mod spawn_middleware;
 mod spawn_named_example_test;
 mod spawn_test;
@@ -40,10 +58,13 @@ mod typed_props;
 
 pub use {
   self::actor::*, self::actor_behavior::*, self::actor_error::*, self::actor_handle::*, self::actor_inner_error::*,
-  self::actor_process::*, self::actor_producer::*, self::actor_receiver::*, self::context_decorator::*,
-  self::context_decorator_chain::*, self::context_handler::*, self::continuer::*, self::middleware::*,
-  self::middleware_chain::*, self::pid::*, self::pid_set::*, self::props::*, self::receiver_middleware::*,
-  self::receiver_middleware_chain::*, self::restart_statistics::*, self::sender_middleware::*,
-  self::sender_middleware_chain::*, self::spawn_middleware::*, self::spawner::*, self::taks::*, self::typed_actor::*,
-  self::typed_actor_producer::*, self::typed_actor_receiver::*, self::typed_pid::*, self::typed_props::*,
+  self::actor_process::*, self::actor_producer::*, self::actor_receiver::*, self::actor_ref::*,
+  self::context_decorator::*, self::context_decorator_chain::*, self::context_handler::*, self::continuer::*,
+  self::error_reason_summary::*, self::idempotency_check::*, self::lifecycle_event::*, self::middleware::*,
+  self::middleware_chain::*, self::name_generator::*, self::pid::*, self::pid_set::*, self::props::*,
+  self::receive_guard::*, self::receiver_middleware::*, self::receiver_middleware_chain::*,
+  self::request_response_actor::*, self::restart_statistics::*, self::sender_middleware::*,
+  self::sender_middleware_chain::*, self::snapshotable::*, self::spawn_interceptor::*, self::spawn_middleware::*,
+  self::spawner::*, self::taks::*, self::typed_actor::*, self::typed_actor_producer::*, self::typed_actor_receiver::*,
+  self::typed_pid::*, self::typed_props::*,
 };
@@ -1,10 +1,13 @@
 use crate::actor::actor::actor_error::ActorError;
+use crate::actor::actor::pid::ExtendedPid;
+use crate::actor::actor::ErrorReason;
+use crate::actor::actor::Snapshotable;
 use crate::actor::context::ContextHandle;
 use crate::actor::context::MessagePart;
 use crate::actor::message::AutoReceiveMessage;
 use crate::actor::supervisor::SupervisorStrategyHandle;
 use crate::actor::Config;
-use crate::generated::actor::Terminated;
+use crate::generated::actor::{Terminated, TerminatedReason};
 use async_trait::async_trait;
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -17,6 +20,11 @@ pub trait Actor: Debug + Send + Sync + 'static {
     std::any::type_name_of_val(self).to_string()
   }
 
+  // AsSnapshotable returns the actor as a Snapshotable, for actors spawned with Props::with_snapshot
+  fn as_snapshotable(&self) -> Option<&dyn Snapshotable> {
+    None
+  }
+
   #[instrument(skip_all)]
   async fn handle(&mut self, context_handle: ContextHandle) -> Result<(), ActorError> {
     let message_handle = context_handle.get_message_handle().await;
@@ -26,7 +34,10 @@ pub trait Actor: Debug + Send + Sync + 'static {
         AutoReceiveMessage::PreStart => self.pre_start(context_handle).await,
         AutoReceiveMessage::PostStart => self.post_start(context_handle).await,
         AutoReceiveMessage::PreRestart => self.pre_restart(context_handle).await,
-        AutoReceiveMessage::PostRestart => self.post_restart(context_handle).await,
+        AutoReceiveMessage::PostRestart => {
+          let reason = context_handle.get_restart_reason().await;
+          self.post_restart_with_reason(context_handle, reason).await
+        }
         AutoReceiveMessage::PreStop => self.pre_stop(context_handle).await,
         AutoReceiveMessage::PostStop => self.post_stop(context_handle).await,
         AutoReceiveMessage::Terminated(t) => self.post_child_terminate(context_handle, &t).await,
@@ -61,6 +72,16 @@ pub trait Actor: Debug + Send + Sync + 'static {
     self.pre_start(context_handle).await
   }
 
+  // PostRestartWithReason receives the failure that caused the restart, None when the actor did not fail
+  // itself. It defaults to post_restart
+  async fn post_restart_with_reason(
+    &mut self,
+    context_handle: ContextHandle,
+    _: Option<ErrorReason>,
+  ) -> Result<(), ActorError> {
+    self.post_restart(context_handle).await
+  }
+
   //#[instrument]
   async fn pre_stop(&mut self, _: ContextHandle) -> Result<(), ActorError> {
     tracing::debug!("Actor::pre_stop");
@@ -74,8 +95,30 @@ pub trait Actor: Debug + Send + Sync + 'static {
   }
 
   //#[instrument]
-  async fn post_child_terminate(&mut self, _: ContextHandle, _: &Terminated) -> Result<(), ActorError> {
+  async fn post_child_terminate(
+    &mut self,
+    context_handle: ContextHandle,
+    terminated: &Terminated,
+  ) -> Result<(), ActorError> {
     tracing::debug!("Actor::post_child_terminate");
+    match &terminated.who {
+      Some(who) => {
+        self
+          .on_child_terminated(context_handle, &ExtendedPid::new(who.clone()), terminated.why())
+          .await
+      }
+      None => Ok(()),
+    }
+  }
+
+  //#[instrument]
+  async fn on_child_terminated(
+    &mut self,
+    _: ContextHandle,
+    _: &ExtendedPid,
+    _: TerminatedReason,
+  ) -> Result<(), ActorError> {
+    tracing::debug!("Actor::on_child_terminated");
     Ok(())
   }
 
@@ -8,12 +8,16 @@ use tokio::sync::RwLock;
 #[derive(Debug, Clone)]
 pub struct ActorBehavior {
   stack: Arc<RwLock<Stack<ActorReceiver>>>,
+  on_success: Arc<RwLock<Option<ActorReceiver>>>,
+  on_failure: Arc<RwLock<Option<ActorReceiver>>>,
 }
 
 impl ActorBehavior {
   pub fn new() -> Self {
     Self {
       stack: Arc::new(RwLock::new(Stack::new())),
+      on_success: Arc::new(RwLock::new(None)),
+      on_failure: Arc::new(RwLock::new(None)),
     }
   }
 
@@ -37,6 +41,27 @@ impl ActorBehavior {
     let mut mg = self.stack.write().await;
     mg.clear();
   }
+
+  // Defers a reset to the given receiver until the current message has been handled successfully
+  pub async fn become_on_success(&mut self, receiver: ActorReceiver) {
+    let mut mg = self.on_success.write().await;
+    *mg = Some(receiver);
+  }
+
+  // Defers a reset to the given receiver until the current message has failed
+  pub async fn become_on_failure(&mut self, receiver: ActorReceiver) {
+    let mut mg = self.on_failure.write().await;
+    *mg = Some(receiver);
+  }
+
+  async fn apply_pending(&mut self, succeeded: bool) {
+    let on_success = self.on_success.write().await.take();
+    let on_failure = self.on_failure.write().await.take();
+    let next = if succeeded { on_success } else { on_failure };
+    if let Some(receiver) = next {
+      self.reset(receiver).await;
+    }
+  }
 }
 
 impl Default for ActorBehavior {
@@ -52,7 +77,9 @@ impl Actor for ActorBehavior {
       let mg = self.stack.read().await;
       mg.peek()
     } {
-      behavior.run(context_handle.clone()).await?;
+      let result = behavior.run(context_handle.clone()).await;
+      self.apply_pending(result.is_ok()).await;
+      result?;
     } else {
       tracing::error!("empty behavior called: pid = {}", context_handle.get_self().await);
     }
@@ -2,10 +2,11 @@
 mod tests {
 
   use crate::actor::actor::actor_behavior::ActorBehavior;
-  use crate::actor::actor::{Actor, ActorError, ActorReceiver, Props};
+  use crate::actor::actor::{Actor, ActorError, ActorReceiver, ErrorReason, ExtendedPid, Props};
   use crate::actor::actor_system::ActorSystem;
-  use crate::actor::context::{BasePart, ContextHandle, MessagePart, SenderPart, SpawnerPart};
-  use crate::actor::message::{Message, MessageHandle, ResponseHandle};
+  use crate::actor::context::{BasePart, ContextHandle, MessagePart, RootContext, SenderPart, SpawnerPart};
+  use crate::actor::message::{MessageHandle, ResponseHandle};
+  use crate::actor::supervisor::{Directive, OneForOneStrategy, SupervisorStrategyHandle};
   use async_trait::async_trait;
   use nexus_actor_message_derive_rs::Message;
   use std::env;
@@ -106,4 +107,102 @@ mod tests {
       .await;
     assert!(response.is_ok());
   }
+
+  #[derive(Debug, Clone, PartialEq, Message)]
+  struct Attempt(bool);
+
+  #[derive(Debug, Clone, PartialEq, Message)]
+  struct GetState;
+
+  #[derive(Debug, Clone)]
+  struct RetryActor {
+    behavior: ActorBehavior,
+  }
+
+  impl RetryActor {
+    async fn new() -> Self {
+      let mut actor = Self {
+        behavior: ActorBehavior::new(),
+      };
+      let cloned_self = actor.clone();
+      actor
+        .behavior
+        .reset(ActorReceiver::new(move |ctx| {
+          let mut cloned_self = cloned_self.clone();
+          async move { cloned_self.waiting(ctx).await }
+        }))
+        .await;
+      actor
+    }
+
+    async fn waiting(&mut self, context: ContextHandle) -> Result<(), ActorError> {
+      let message_handle = context.get_message_handle().await;
+      if let Some(Attempt(succeeded)) = message_handle.to_typed::<Attempt>() {
+        let cloned_self = self.clone();
+        self
+          .behavior
+          .become_on_success(ActorReceiver::new(move |ctx| {
+            let mut cloned_self = cloned_self.clone();
+            async move { cloned_self.done(ctx).await }
+          }))
+          .await;
+        if !succeeded {
+          return Err(ActorError::ReceiveError(ErrorReason::new("attempt failed", 0)));
+        }
+      } else if message_handle.to_typed::<GetState>().is_some() {
+        context.respond(ResponseHandle::new("waiting".to_string())).await;
+      }
+      Ok(())
+    }
+
+    async fn done(&mut self, context: ContextHandle) -> Result<(), ActorError> {
+      if context.get_message_handle().await.to_typed::<GetState>().is_some() {
+        context.respond(ResponseHandle::new("done".to_string())).await;
+      }
+      Ok(())
+    }
+  }
+
+  #[async_trait]
+  impl Actor for RetryActor {
+    async fn receive(&mut self, context_handle: ContextHandle) -> Result<(), ActorError> {
+      self.behavior.receive(context_handle).await
+    }
+  }
+
+  #[tokio::test]
+  async fn test_become_on_success_only_transitions_on_success() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    // Resume keeps the failed actor instance, so a restart can't mask the behavior left in place
+    let strategy = OneForOneStrategy::new(10, Duration::from_secs(10)).with_decider(|_| async { Directive::Resume });
+    let mut root_context = system
+      .get_root_context()
+      .await
+      .with_guardian(SupervisorStrategyHandle::new(strategy));
+    let pid = root_context
+      .spawn(Props::from_async_actor_producer(|_| async { RetryActor::new().await }).await)
+      .await;
+
+    let get_state = |root_context: RootContext, pid: ExtendedPid| async move {
+      root_context
+        .request_future(pid, MessageHandle::new(GetState), Duration::from_secs(1))
+        .await
+        .result()
+        .await
+        .unwrap()
+        .to_typed::<String>()
+        .unwrap()
+    };
+
+    root_context.send(pid.clone(), MessageHandle::new(Attempt(false))).await;
+    assert_eq!(get_state(root_context.clone(), pid.clone()).await, "waiting");
+
+    root_context.send(pid.clone(), MessageHandle::new(Attempt(true))).await;
+    assert_eq!(get_state(root_context.clone(), pid.clone()).await, "done");
+  }
 }
@@ -1,20 +1,26 @@
 use crate::actor::actor::actor_inner_error::ErrorReason;
+use crate::actor::message::MessageHandle;
+use nexus_actor_message_derive_rs::Message;
 use thiserror::Error;
 
-#[derive(Debug, Clone, PartialEq, Eq, Error)]
+// ActorError tells at which stage of the actor lifecycle a failure happened. The reason is also reported as the
+// error's source, see ErrorReason::from_error to keep the underlying error in the chain.
+#[derive(Debug, Clone, PartialEq, Eq, Message, Error)]
 pub enum ActorError {
   #[error("Receive error: {0}")]
-  ReceiveError(ErrorReason),
+  ReceiveError(#[source] ErrorReason),
   #[error("Restart error: {0}")]
-  RestartError(ErrorReason),
+  RestartError(#[source] ErrorReason),
+  #[error("Start error: {0}")]
+  StartError(#[source] ErrorReason),
   #[error("Stop error: {0}")]
-  StopError(ErrorReason),
+  StopError(#[source] ErrorReason),
   #[error("Initialization error: {0}")]
-  InitializationError(ErrorReason),
+  InitializationError(#[source] ErrorReason),
   #[error("Communication error: {0}")]
-  CommunicationError(ErrorReason),
+  CommunicationError(#[source] ErrorReason),
   #[error("Behavior not initialized: {0}")]
-  BehaviorNotInitialized(ErrorReason),
+  BehaviorNotInitialized(#[source] ErrorReason),
 }
 
 impl ActorError {
@@ -22,12 +28,23 @@ impl ActorError {
     match self {
       ActorError::ReceiveError(e)
       | ActorError::RestartError(e)
+      | ActorError::StartError(e)
       | ActorError::StopError(e)
       | ActorError::InitializationError(e)
       | ActorError::CommunicationError(e)
       | ActorError::BehaviorNotInitialized(e) => Some(e),
     }
   }
+
+  // Builds the reason escalated to the supervisor: the actor's reason, with this error and the failed message
+  // attached so that deciders can match on the variant.
+  pub(crate) fn to_escalated_reason(&self, message_handle: &MessageHandle) -> Option<ErrorReason> {
+    let reason = self.reason()?.clone().with_actor_error(self.clone());
+    Some(match reason.message_snapshot() {
+      Some(_) => reason,
+      None => reason.with_message_snapshot(message_handle.clone()),
+    })
+  }
 }
 
 static_assertions::assert_impl_all!(ActorError: Send, Sync);
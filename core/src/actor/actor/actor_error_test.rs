@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+  use crate::actor::actor::{ActorError, ErrorReason, TakeError};
+  use crate::actor::message::MessageHandle;
+  use std::env;
+  use std::error::Error;
+  use tracing_subscriber::EnvFilter;
+
+  #[test]
+  fn test_actor_error_source_chain() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let error = ActorError::ReceiveError(ErrorReason::from_error(std::io::Error::other("disk full"), 7));
+
+    let reason = error.source().expect("actor error has no source");
+    assert!(reason.is::<ErrorReason>());
+    let io_error = reason.source().expect("reason has no source");
+    assert_eq!(
+      io_error.downcast_ref::<std::io::Error>().unwrap().to_string(),
+      "disk full"
+    );
+    assert!(io_error.source().is_none());
+
+    assert!(ErrorReason::new("plain", 0).source().is_none());
+  }
+
+  #[test]
+  fn test_error_reason_from_error_can_be_taken() {
+    let mut reason = ErrorReason::from_error(std::io::Error::other("disk full"), 0);
+    assert!(reason.is_type::<std::io::Error>());
+    assert_eq!(reason.to_summary().message, "disk full");
+
+    let io_error = reason.take::<std::io::Error>().unwrap();
+    assert_eq!(io_error.to_string(), "disk full");
+    assert!(reason.source().is_none());
+  }
+
+  #[test]
+  fn test_shared_error_reason_keeps_its_source_when_take_fails() {
+    let mut reason = ErrorReason::from_error(std::io::Error::other("disk full"), 0);
+    let clone = reason.clone();
+
+    assert!(matches!(reason.take::<std::io::Error>(), Err(TakeError::StillShared)));
+    assert!(reason.source().is_some());
+    assert!(reason.is_type::<std::io::Error>());
+
+    drop(clone);
+    assert_eq!(reason.take::<std::io::Error>().unwrap().to_string(), "disk full");
+  }
+
+  #[test]
+  fn test_actor_error_display_includes_the_reason() {
+    let error = ActorError::ReceiveError(ErrorReason::from_error(std::io::Error::other("disk full"), 0));
+    assert!(error.to_string().starts_with("Receive error: "));
+    assert!(error.to_string().contains("disk full"));
+    assert_eq!(error.source().unwrap().source().unwrap().to_string(), "disk full");
+  }
+
+  #[test]
+  fn test_escalated_reason_keeps_actor_error_and_message() {
+    let message_handle = MessageHandle::new("hello".to_string());
+    let error = ActorError::StopError(ErrorReason::new("stop failed", 0));
+
+    let reason = error.to_escalated_reason(&message_handle).unwrap();
+    assert!(reason.is_type::<&str>());
+    assert_eq!(reason.actor_error(), Some(&error));
+    assert_eq!(reason.message_snapshot(), Some(&message_handle));
+
+    let other = MessageHandle::new("other".to_string());
+    let error = ActorError::ReceiveError(ErrorReason::new("receive failed", 0).with_message_snapshot(other.clone()));
+    let reason = error.to_escalated_reason(&message_handle).unwrap();
+    assert_eq!(reason.message_snapshot(), Some(&other));
+  }
+}
@@ -7,7 +7,6 @@ mod tests {
   use crate::actor::actor_system::ActorSystem;
   use crate::actor::context::{BasePart, MessagePart, SenderPart, SpawnerPart, StopperPart};
   use crate::actor::message::AutoReceiveMessage;
-  use crate::actor::message::Message;
   use crate::actor::message::MessageHandle;
   use crate::actor::message::ResponseHandle;
   use nexus_actor_message_derive_rs::Message;
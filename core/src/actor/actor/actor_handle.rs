@@ -6,10 +6,12 @@ use tokio::sync::RwLock;
 use crate::actor::actor::actor::Actor;
 use crate::actor::actor::actor_error::ActorError;
 use crate::actor::context::ContextHandle;
+use crate::actor::message::Message;
 use crate::actor::supervisor::SupervisorStrategyHandle;
 
+// The actor's type name is captured up front, so it can be reported while the actor is busy
 #[derive(Debug, Clone)]
-pub struct ActorHandle(Arc<RwLock<dyn Actor>>);
+pub struct ActorHandle(Arc<RwLock<dyn Actor>>, Option<&'static str>, Arc<str>);
 
 impl PartialEq for ActorHandle {
   fn eq(&self, other: &Self) -> bool {
@@ -27,16 +29,44 @@ impl std::hash::Hash for ActorHandle {
 
 impl ActorHandle {
   pub fn new_arc(actor: Arc<RwLock<dyn Actor>>) -> Self {
-    ActorHandle(actor)
+    let type_name = actor
+      .try_read()
+      .map(|actor| actor.get_type_name())
+      .unwrap_or_else(|_| "unknown".to_string());
+    ActorHandle(actor, None, type_name.into())
   }
 
   pub fn new(actor: impl Actor + 'static) -> Self {
-    ActorHandle(Arc::new(RwLock::new(actor)))
+    let type_name = actor.get_type_name();
+    ActorHandle(Arc::new(RwLock::new(actor)), None, type_name.into())
+  }
+
+  pub fn new_typed<M: Message>(actor: impl Actor + 'static) -> Self {
+    let type_name = actor.get_type_name();
+    ActorHandle(
+      Arc::new(RwLock::new(actor)),
+      Some(std::any::type_name::<M>()),
+      type_name.into(),
+    )
+  }
+
+  // MessageTypeName returns the message type accepted by a typed actor, or None for untyped actors
+  pub fn get_message_type_name(&self) -> Option<&'static str> {
+    self.1
+  }
+
+  pub(crate) async fn take_snapshot(&self) -> Option<serde_json::Value> {
+    let mg = self.0.read().await;
+    mg.as_snapshotable().map(|snapshotable| snapshotable.snapshot())
   }
 }
 
 #[async_trait]
 impl Actor for ActorHandle {
+  fn get_type_name(&self) -> String {
+    self.2.to_string()
+  }
+
   async fn handle(&mut self, c: ContextHandle) -> Result<(), ActorError> {
     let mut mg = self.0.write().await;
     mg.handle(c).await
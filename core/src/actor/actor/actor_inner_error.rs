@@ -3,33 +3,181 @@ use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 use std::sync::Arc;
 
+use crate::actor::actor::actor_error::ActorError;
+use crate::actor::actor::error_reason_summary::ErrorReasonSummary;
+use crate::actor::message::MessageHandle;
 use backtrace::Backtrace;
 
 #[derive(Clone)]
 pub struct ErrorReason {
-  reason: Option<Arc<dyn Any + Send + Sync>>,
+  reason: Option<Reason>,
   pub code: i32,
+  kind: String,
+  message: Option<String>,
+  failure: Option<Arc<FailureContext>>,
   backtrace: Backtrace,
 }
 
+trait ErrorValue: Error + Any + Send + Sync {}
+
+impl<E: Error + Send + Sync + 'static> ErrorValue for E {}
+
+// Reason holds the reason value once. A reason built from an error is also the error's source
+#[derive(Clone)]
+enum Reason {
+  Value(Arc<dyn Any + Send + Sync>),
+  Error(Arc<dyn ErrorValue>),
+}
+
+impl Reason {
+  fn as_any(&self) -> &(dyn Any + Send + Sync) {
+    match self {
+      Reason::Value(value) => value.as_ref(),
+      Reason::Error(error) => error.as_ref(),
+    }
+  }
+
+  fn into_any(self) -> Arc<dyn Any + Send + Sync> {
+    match self {
+      Reason::Value(value) => value,
+      Reason::Error(error) => error,
+    }
+  }
+
+  fn is_unique(&mut self) -> bool {
+    match self {
+      Reason::Value(value) => Arc::get_mut(value).is_some(),
+      Reason::Error(error) => Arc::get_mut(error).is_some(),
+    }
+  }
+}
+
+impl Debug for Reason {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Reason::Error(error) => Debug::fmt(error.as_ref(), f),
+      Reason::Value(value) => {
+        if let Some(value) = value.downcast_ref::<&str>() {
+          Debug::fmt(value, f)
+        } else if let Some(value) = value.downcast_ref::<String>() {
+          Debug::fmt(value, f)
+        } else {
+          value.fmt(f)
+        }
+      }
+    }
+  }
+}
+
+// FailureContext is kept behind a single pointer so that Result<_, ActorError> stays small
+#[derive(Debug, Clone, Default)]
+struct FailureContext {
+  actor_error: Option<ActorError>,
+  message_snapshot: Option<MessageHandle>,
+}
+
 impl ErrorReason {
   pub fn new<T>(reason: T, code: i32) -> Self
   where
     T: Send + Sync + 'static, {
     Self {
-      reason: Some(Arc::new(reason)),
+      reason: Some(Reason::Value(Arc::new(reason))),
+      code,
+      kind: std::any::type_name::<T>().to_string(),
+      message: None,
+      failure: None,
+      backtrace: Backtrace::new(),
+    }
+  }
+
+  // new_serializable captures the reason's Display output so that it survives crossing a system boundary.
+  pub fn new_serializable<T>(reason: T, code: i32) -> Self
+  where
+    T: Display + Send + Sync + 'static, {
+    let message = reason.to_string();
+    Self {
+      message: Some(message),
+      ..Self::new(reason, code)
+    }
+  }
+
+  // from_error keeps the error as the reason and exposes it through Error::source, so that tooling can walk
+  // the chain of causes.
+  pub fn from_error<E>(error: E, code: i32) -> Self
+  where
+    E: Error + Send + Sync + 'static, {
+    let message = error.to_string();
+    Self {
+      reason: Some(Reason::Error(Arc::new(error))),
       code,
+      kind: std::any::type_name::<E>().to_string(),
+      message: Some(message),
+      failure: None,
+      backtrace: Backtrace::new(),
+    }
+  }
+
+  // from_summary rebuilds a reason received from a remote system. The summary itself becomes the reason value.
+  pub fn from_summary(summary: ErrorReasonSummary) -> Self {
+    Self {
+      reason: Some(Reason::Value(Arc::new(summary.clone()))),
+      code: summary.code,
+      kind: summary.kind,
+      message: Some(summary.message),
+      failure: None,
       backtrace: Backtrace::new(),
     }
   }
 
+  pub fn kind(&self) -> &str {
+    &self.kind
+  }
+
+  // actor_error is the error returned by the actor when this reason was escalated by its mailbox. Deciders can
+  // match on its variant to tell a failure in receive from a failure while starting, restarting or stopping.
+  pub fn actor_error(&self) -> Option<&ActorError> {
+    self.failure.as_ref()?.actor_error.as_ref()
+  }
+
+  // message_snapshot is the message that was being processed when the failure happened
+  pub fn message_snapshot(&self) -> Option<&MessageHandle> {
+    self.failure.as_ref()?.message_snapshot.as_ref()
+  }
+
+  pub fn with_message_snapshot(mut self, message_handle: MessageHandle) -> Self {
+    Arc::make_mut(self.failure.get_or_insert_default()).message_snapshot = Some(message_handle);
+    self
+  }
+
+  pub(crate) fn with_actor_error(mut self, actor_error: ActorError) -> Self {
+    Arc::make_mut(self.failure.get_or_insert_default()).actor_error = Some(actor_error);
+    self
+  }
+
+  pub fn to_summary(&self) -> ErrorReasonSummary {
+    let message = match (&self.message, self.reason.as_ref()) {
+      (Some(message), _) => message.clone(),
+      (None, Some(reason)) => {
+        if let Some(s) = reason.as_any().downcast_ref::<String>() {
+          s.clone()
+        } else if let Some(s) = reason.as_any().downcast_ref::<&'static str>() {
+          s.to_string()
+        } else {
+          format!("non-serializable reason: {}", self.kind)
+        }
+      }
+      (None, None) => "error has been taken".to_string(),
+    };
+    ErrorReasonSummary::new(self.kind.clone(), message, self.code)
+  }
+
   pub fn backtrace(&self) -> &Backtrace {
     &self.backtrace
   }
 
   pub fn is_type<T: Send + Sync + 'static>(&self) -> bool {
     match self.reason.as_ref() {
-      Some(m) => m.is::<T>(),
+      Some(m) => m.as_any().is::<T>(),
       None => false,
     }
   }
@@ -37,24 +185,24 @@ impl ErrorReason {
   pub fn take<T>(&mut self) -> Result<T, TakeError>
   where
     T: Send + Sync + 'static, {
-    match self.reason.take() {
-      Some(v) => match v.downcast::<T>() {
-        Ok(arc_v) => match Arc::try_unwrap(arc_v) {
-          Ok(v) => Ok(v),
-          Err(arc_v) => {
-            self.reason = Some(arc_v);
-            Err(TakeError::StillShared)
-          }
-        },
-        Err(original) => {
-          self.reason = Some(original.clone());
-          Err(TakeError::TypeMismatch {
-            expected: std::any::type_name::<T>(),
-            found: original.type_id(),
-          })
-        }
-      },
-      None => Err(TakeError::AlreadyTaken),
+    let Some(mut reason) = self.reason.take() else {
+      return Err(TakeError::AlreadyTaken);
+    };
+    if !reason.as_any().is::<T>() {
+      let found = reason.as_any().type_id();
+      self.reason = Some(reason);
+      return Err(TakeError::TypeMismatch {
+        expected: std::any::type_name::<T>(),
+        found,
+      });
+    }
+    if !reason.is_unique() {
+      self.reason = Some(reason);
+      return Err(TakeError::StillShared);
+    }
+    match reason.into_any().downcast::<T>().map(Arc::try_unwrap) {
+      Ok(Ok(v)) => Ok(v),
+      _ => unreachable!("the reason was checked to be an unshared T"),
     }
   }
 
@@ -95,12 +243,19 @@ impl Debug for ErrorReason {
   }
 }
 
-impl Error for ErrorReason {}
+impl Error for ErrorReason {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match self.reason.as_ref() {
+      Some(Reason::Error(error)) => Some(error.as_ref()),
+      _ => None,
+    }
+  }
+}
 
 impl PartialEq for ErrorReason {
   fn eq(&self, other: &Self) -> bool {
     match (&self.reason, &other.reason) {
-      (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+      (Some(a), Some(b)) => std::ptr::addr_eq(a.as_any(), b.as_any()),
       (None, None) => true,
       _ => false,
     }
@@ -113,7 +268,7 @@ impl std::hash::Hash for ErrorReason {
   fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
     self.reason.is_some().hash(state);
     if let Some(error) = &self.reason {
-      error.type_id().hash(state);
+      error.as_any().type_id().hash(state);
     }
     std::ptr::addr_of!(self.backtrace).hash(state);
   }
@@ -121,23 +276,18 @@ impl std::hash::Hash for ErrorReason {
 
 impl From<std::io::Error> for ErrorReason {
   fn from(error: std::io::Error) -> Self {
-    let error_arc = Arc::new(error);
-    ErrorReason {
-      reason: Some(error_arc.clone()),
-      code: 0,
-      backtrace: Backtrace::new(),
-    }
+    Self::from_error(error, 0)
   }
 }
 
 impl From<String> for ErrorReason {
   fn from(s: String) -> Self {
-    Self::new(s, 0)
+    Self::new_serializable(s, 0)
   }
 }
 
 impl From<&str> for ErrorReason {
   fn from(s: &str) -> Self {
-    Self::new(s.to_string(), 0)
+    Self::new_serializable(s.to_string(), 0)
   }
 }
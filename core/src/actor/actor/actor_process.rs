@@ -3,18 +3,22 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use tokio::sync::Notify;
 
 use crate::actor::actor::pid::ExtendedPid;
 use crate::actor::dispatch::Mailbox;
 use crate::actor::dispatch::MailboxHandle;
 use crate::actor::message::MessageHandle;
 use crate::actor::message::SystemMessage;
-use crate::actor::process::Process;
+use crate::actor::process::{Diagnostics, Process};
 
 #[derive(Debug, Clone)]
 pub struct ActorProcess {
   mailbox: MailboxHandle,
   dead: Arc<AtomicBool>,
+  dead_notify: Arc<Notify>,
+  started: Arc<AtomicBool>,
+  diagnostics: Option<Arc<dyn Diagnostics>>,
 }
 
 impl PartialEq for ActorProcess {
@@ -37,12 +41,51 @@ impl ActorProcess {
     Self {
       mailbox,
       dead: Arc::new(AtomicBool::new(false)),
+      dead_notify: Arc::new(Notify::new()),
+      started: Arc::new(AtomicBool::new(false)),
+      diagnostics: None,
     }
   }
 
+  pub fn with_diagnostics(mut self, diagnostics: impl Diagnostics) -> Self {
+    self.diagnostics = Some(Arc::new(diagnostics));
+    self
+  }
+
+  pub async fn get_diagnostics(&self) -> Option<String> {
+    match &self.diagnostics {
+      Some(diagnostics) => Some(diagnostics.get_diagnostics().await),
+      None => None,
+    }
+  }
+
+  pub fn get_mailbox(&self) -> MailboxHandle {
+    self.mailbox.clone()
+  }
+
   pub fn is_dead(&self) -> bool {
     self.dead.load(Ordering::SeqCst)
   }
+
+  // IsStarted reports whether the actor has finished handling PostStart
+  pub fn is_started(&self) -> bool {
+    self.started.load(Ordering::SeqCst)
+  }
+
+  pub(crate) fn set_started(&self) {
+    self.started.store(true, Ordering::SeqCst);
+  }
+
+  // Resolves once the process has been marked dead, e.g. by a stop request
+  pub async fn wait_for_dead(&self) {
+    let notified = self.dead_notify.notified();
+    tokio::pin!(notified);
+    notified.as_mut().enable();
+    if self.is_dead() {
+      return;
+    }
+    notified.await;
+  }
 }
 
 #[async_trait]
@@ -54,6 +97,11 @@ impl Process for ActorProcess {
 
   async fn send_system_message(&self, _: &ExtendedPid, message_handle: MessageHandle) {
     tracing::debug!("ActorProcess::send_system_message: {:?}", message_handle);
+    // A Stop posted directly (supervisor directives, stop_children) marks the process dead just like
+    // `stop` does, so an `ask` blocking the mailbox gives up and lets the Stop be processed
+    if let Some(SystemMessage::Stop) = message_handle.to_typed::<SystemMessage>() {
+      self.set_dead();
+    }
     self.mailbox.post_system_message(message_handle).await;
   }
 
@@ -66,6 +114,7 @@ impl Process for ActorProcess {
 
   fn set_dead(&self) {
     self.dead.store(true, Ordering::SeqCst);
+    self.dead_notify.notify_waiters();
   }
 
   fn as_any(&self) -> &dyn Any {
@@ -0,0 +1,66 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use crate::actor::actor::ExtendedPid;
+use crate::actor::actor_system::ActorSystem;
+use crate::actor::context::SenderPart;
+use crate::actor::dispatch::future::ActorFutureError;
+use crate::actor::message::MessageHandle;
+
+// ActorRef addresses an actor of a given system without exposing the pid and process machinery behind it
+#[derive(Debug, Clone)]
+pub struct ActorRef {
+  pid: ExtendedPid,
+  actor_system: ActorSystem,
+}
+
+impl PartialEq for ActorRef {
+  fn eq(&self, other: &Self) -> bool {
+    self.pid == other.pid
+  }
+}
+
+impl Eq for ActorRef {}
+
+impl Display for ActorRef {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.path())
+  }
+}
+
+impl ActorRef {
+  pub fn new(pid: ExtendedPid, actor_system: ActorSystem) -> Self {
+    Self { pid, actor_system }
+  }
+
+  // Path is the address of the actor followed by its id, e.g. `nonhost/parent/child`
+  pub fn path(&self) -> String {
+    format!("{}/{}", self.pid.address(), self.pid.id())
+  }
+
+  pub async fn tell(&self, message_handle: MessageHandle) {
+    self
+      .actor_system
+      .get_root_context()
+      .await
+      .send(self.pid.clone(), message_handle)
+      .await
+  }
+
+  pub async fn request(
+    &self,
+    message_handle: MessageHandle,
+    timeout: Duration,
+  ) -> Result<MessageHandle, ActorFutureError> {
+    self
+      .actor_system
+      .get_root_context()
+      .await
+      .request_future(self.pid.clone(), message_handle, timeout)
+      .await
+      .result()
+      .await
+  }
+}
+
+static_assertions::assert_impl_all!(ActorRef: Send, Sync);
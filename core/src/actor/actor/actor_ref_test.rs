@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+  use crate::actor::actor::Props;
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{BasePart, InfoPart, MessagePart, SenderPart, SpawnerPart};
+  use crate::actor::message::{MessageHandle, ResponseHandle};
+  use std::env;
+  use std::sync::Arc;
+  use std::time::Duration;
+  use tokio::sync::{Mutex, Notify};
+  use tracing_subscriber::EnvFilter;
+
+  #[tokio::test]
+  async fn test_actor_ref_tell_and_request() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let told = Arc::new(Mutex::new(vec![]));
+    let notify = Arc::new(Notify::new());
+    let cloned_told = told.clone();
+    let cloned_notify = notify.clone();
+    let pid = root_context
+      .spawn_named(
+        Props::from_async_actor_receiver(move |ctx| {
+          let told = cloned_told.clone();
+          let notify = cloned_notify.clone();
+          async move {
+            if let Some(msg) = ctx.get_message_handle().await.to_typed::<String>() {
+              if ctx.get_sender().await.is_some() {
+                ctx.respond(ResponseHandle::new(format!("reply: {}", msg))).await;
+              } else {
+                told.lock().await.push(msg);
+                notify.notify_one();
+              }
+            }
+            Ok(())
+          }
+        })
+        .await,
+        "greeter",
+      )
+      .await
+      .unwrap();
+
+    let actor_ref = root_context.get_actor_ref(pid).await;
+    assert_eq!(actor_ref.path(), format!("{}/greeter", system.get_address().await));
+
+    actor_ref.tell(MessageHandle::new("hello".to_string())).await;
+    tokio::time::timeout(Duration::from_secs(3), notify.notified())
+      .await
+      .expect("told message was not received");
+    assert_eq!(*told.lock().await, vec!["hello".to_string()]);
+
+    let response = actor_ref
+      .request(MessageHandle::new("ping".to_string()), Duration::from_secs(3))
+      .await
+      .unwrap();
+    assert_eq!(response.to_typed::<String>().unwrap(), "reply: ping");
+  }
+}
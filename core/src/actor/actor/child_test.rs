@@ -14,6 +14,10 @@ pub mod tests {
   use crate::actor::message::Message;
   use crate::actor::message::MessageHandle;
   use crate::actor::message::ResponseHandle;
+  use crate::actor::supervisor::Directive;
+  use crate::actor::supervisor::OneForOneStrategy;
+  use crate::actor::supervisor::SupervisorStrategyHandle;
+  use crate::generated::actor::TerminatedReason;
   use async_trait::async_trait;
   use nexus_actor_message_derive_rs::Message;
   use nexus_actor_utils_rs::concurrent::AsyncBarrier;
@@ -168,8 +172,12 @@ pub mod tests {
         .await;
     }
 
-    let future1 = ActorFutureProcess::new(system.clone(), Duration::from_secs(5)).await;
-    let future2 = ActorFutureProcess::new(system.clone(), Duration::from_secs(5)).await;
+    let future1 = ActorFutureProcess::new(system.clone(), Duration::from_secs(5))
+      .await
+      .unwrap();
+    let future2 = ActorFutureProcess::new(system.clone(), Duration::from_secs(5))
+      .await
+      .unwrap();
 
     root_context
       .send(
@@ -198,7 +206,9 @@ pub mod tests {
       .spawn(Props::from_async_actor_receiver(|_| async { Ok(()) }).await)
       .await;
     let cloned_child = child.clone();
-    let future = ActorFutureProcess::new(system.clone(), Duration::from_secs(5)).await;
+    let future = ActorFutureProcess::new(system.clone(), Duration::from_secs(5))
+      .await
+      .unwrap();
     let cloned_future = future.clone();
 
     let ab = AsyncBarrier::new(2);
@@ -278,4 +288,136 @@ pub mod tests {
     let msg = msg_handle.to_typed::<String>().unwrap();
     assert_eq!("foo", msg);
   }
+
+  #[derive(Debug, Clone, PartialEq, Message)]
+  struct ChildTerminated {
+    expected: ExtendedPid,
+    who: ExtendedPid,
+    why: TerminatedReason,
+  }
+
+  #[derive(Debug)]
+  struct StopChildActor {
+    reply_to: ExtendedPid,
+    child: Option<ExtendedPid>,
+    crash: bool,
+  }
+
+  #[async_trait]
+  impl Actor for StopChildActor {
+    async fn post_start(&mut self, mut context_handle: ContextHandle) -> Result<(), ActorError> {
+      if self.crash {
+        let child = context_handle
+          .spawn(
+            Props::from_async_actor_receiver(|ctx| async move {
+              if ctx.get_message_handle().await.to_typed::<String>().is_some() {
+                return Err(ActorError::ReceiveError(ErrorReason::new("crashed", 0)));
+              }
+              Ok(())
+            })
+            .await,
+          )
+          .await;
+        self.child = Some(child.clone());
+        context_handle
+          .send(child, MessageHandle::new("crash".to_string()))
+          .await;
+        return Ok(());
+      }
+      let child = context_handle
+        .spawn(Props::from_async_actor_producer(|_| async { BlackHoleActor }).await)
+        .await;
+      self.child = Some(child.clone());
+      context_handle.stop(&child).await;
+      Ok(())
+    }
+
+    async fn receive(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+      Ok(())
+    }
+
+    async fn on_child_terminated(
+      &mut self,
+      mut context_handle: ContextHandle,
+      pid: &ExtendedPid,
+      reason: TerminatedReason,
+    ) -> Result<(), ActorError> {
+      let msg = ChildTerminated {
+        expected: self.child.clone().unwrap(),
+        who: pid.clone(),
+        why: reason,
+      };
+      context_handle
+        .send(self.reply_to.clone(), MessageHandle::new(msg))
+        .await;
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_actor_on_child_terminated() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let future = ActorFutureProcess::new(system.clone(), Duration::from_secs(5))
+      .await
+      .unwrap();
+    let reply_to = future.get_pid().await;
+    root_context
+      .spawn(
+        Props::from_async_actor_producer(move |_| {
+          let reply_to = reply_to.clone();
+          async move {
+            StopChildActor {
+              reply_to,
+              child: None,
+              crash: false,
+            }
+          }
+        })
+        .await,
+      )
+      .await;
+
+    let result = future.result().await.unwrap();
+    let notice = result.to_typed::<ChildTerminated>().unwrap();
+    assert_eq!(notice.who, notice.expected);
+    assert_eq!(notice.why, TerminatedReason::Stopped);
+  }
+
+  #[tokio::test]
+  async fn test_actor_on_child_terminated_after_supervisor_stop() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let future = ActorFutureProcess::new(system.clone(), Duration::from_secs(5))
+      .await
+      .unwrap();
+    let reply_to = future.get_pid().await;
+    root_context
+      .spawn(
+        Props::from_async_actor_producer_with_opts(
+          move |_| {
+            let reply_to = reply_to.clone();
+            async move {
+              StopChildActor {
+                reply_to,
+                child: None,
+                crash: true,
+              }
+            }
+          },
+          [Props::with_supervisor_strategy(SupervisorStrategyHandle::new(
+            OneForOneStrategy::new(10, Duration::from_secs(10)).with_decider(|_| async { Directive::Stop }),
+          ))],
+        )
+        .await,
+      )
+      .await;
+
+    let result = future.result().await.unwrap();
+    let notice = result.to_typed::<ChildTerminated>().unwrap();
+    assert_eq!(notice.who, notice.expected);
+    assert_eq!(notice.why, TerminatedReason::Failed);
+  }
 }
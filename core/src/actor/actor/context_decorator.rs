@@ -2,6 +2,9 @@ use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
 use crate::actor::actor::context_decorator_chain::ContextDecoratorChain;
+use crate::actor::actor::ActorError;
+use crate::actor::context::{AutoAckContext, ContextHandle};
+use crate::actor::message::MessageHandle;
 
 #[allow(clippy::type_complexity)]
 #[derive(Clone)]
@@ -18,6 +21,29 @@ impl ContextDecorator {
   pub fn run(&self, next: ContextDecoratorChain) -> ContextDecoratorChain {
     (self.0)(next)
   }
+
+  // AutoAck sends the message built by ack_msg_fn to the sender of each successfully received message,
+  // or the one built by nack_msg_fn when the actor returns an error
+  pub fn auto_ack(
+    ack_msg_fn: impl Fn(MessageHandle) -> MessageHandle + Send + Sync + 'static,
+    nack_msg_fn: impl Fn(MessageHandle, &ActorError) -> MessageHandle + Send + Sync + 'static,
+  ) -> Self {
+    let ack_msg_fn = Arc::new(ack_msg_fn);
+    let nack_msg_fn = Arc::new(nack_msg_fn);
+    ContextDecorator::new(move |next| {
+      let ack_msg_fn = ack_msg_fn.clone();
+      let nack_msg_fn = nack_msg_fn.clone();
+      ContextDecoratorChain::new(move |ch| {
+        let next = next.clone();
+        let ack_msg_fn = ack_msg_fn.clone();
+        let nack_msg_fn = nack_msg_fn.clone();
+        async move {
+          let ch = next.run(ch).await;
+          ContextHandle::new(AutoAckContext::from_arc(ch, ack_msg_fn, nack_msg_fn))
+        }
+      })
+    })
+  }
 }
 
 impl Debug for ContextDecorator {
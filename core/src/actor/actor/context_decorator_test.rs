@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+  use std::env;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  use std::sync::Arc;
+  use std::time::Duration;
+
+  use crate::actor::actor::{ActorError, ContextDecorator, ContextDecoratorChain, ErrorReason, Props};
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{BasePart, MessagePart, SenderPart, SpawnerPart};
+  use crate::actor::message::{MessageHandle, ResponseHandle};
+  use tracing_subscriber::EnvFilter;
+
+  #[tokio::test]
+  async fn test_context_decorator_auto_ack() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let auto_ack = ContextDecorator::auto_ack(
+      |msg| MessageHandle::new(format!("ack:{}", msg.to_typed::<String>().unwrap())),
+      |msg, _| MessageHandle::new(format!("nack:{}", msg.to_typed::<String>().unwrap())),
+    );
+    let props = Props::from_async_actor_receiver_with_opts(
+      |ctx| async move {
+        match ctx.get_message_handle().await.to_typed::<String>() {
+          Some(msg) if msg == "fail" => Err(ActorError::ReceiveError(ErrorReason::new("failed to ingest", 0))),
+          _ => Ok(()),
+        }
+      },
+      [Props::with_context_decorators([auto_ack])],
+    )
+    .await;
+    let pid = root_context.spawn(props).await;
+
+    let ack = root_context
+      .request_future(
+        pid.clone(),
+        MessageHandle::new("ok".to_string()),
+        Duration::from_secs(1),
+      )
+      .await
+      .result()
+      .await
+      .unwrap();
+    assert_eq!(ack.to_typed::<String>(), Some("ack:ok".to_string()));
+
+    let nack = root_context
+      .request_future(pid, MessageHandle::new("fail".to_string()), Duration::from_secs(1))
+      .await
+      .result()
+      .await
+      .unwrap();
+    assert_eq!(nack.to_typed::<String>(), Some("nack:fail".to_string()));
+  }
+
+  #[tokio::test]
+  async fn test_context_decorator_runs_once_per_context() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let runs = Arc::new(AtomicUsize::new(0));
+    let cloned_runs = runs.clone();
+    let counting = ContextDecorator::new(move |next| {
+      let runs = cloned_runs.clone();
+      ContextDecoratorChain::new(move |ctx| {
+        let next = next.clone();
+        runs.fetch_add(1, Ordering::SeqCst);
+        async move { next.run(ctx).await }
+      })
+    });
+    let props = Props::from_async_actor_receiver_with_opts(
+      |ctx| async move {
+        if let Some(msg) = ctx.get_message_handle().await.to_typed::<String>() {
+          ctx.respond(ResponseHandle::new(msg)).await;
+        }
+        Ok(())
+      },
+      [Props::with_context_decorators([counting])],
+    )
+    .await;
+    let pid = root_context.spawn(props).await;
+
+    for i in 0..3 {
+      let response = root_context
+        .request_future(pid.clone(), MessageHandle::new(i.to_string()), Duration::from_secs(1))
+        .await
+        .result()
+        .await
+        .unwrap();
+      assert_eq!(response.to_typed::<String>(), Some(i.to_string()));
+    }
+    // the same decorated context dispatches the messages and is handed to the actor
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+  }
+}
@@ -24,6 +24,22 @@ impl Continuer {
     Self(Arc::new(move |m, e| Box::pin(f(m, e))))
   }
 
+  // from_result adapts a continuation that takes the future's outcome as a single Result.
+  // An outcome with neither a message nor an error is passed on as ActorFutureError::NoResult
+  pub fn from_result<F, Fut>(f: F) -> Self
+  where
+    F: Fn(Result<MessageHandle, ActorFutureError>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static, {
+    Self::new(move |m, e| {
+      let result = match (m, e) {
+        (_, Some(error)) => Err(error),
+        (Some(message_handle), None) => Ok(message_handle),
+        (None, None) => Err(ActorFutureError::NoResult),
+      };
+      f(result)
+    })
+  }
+
   pub async fn run(&self, result: Option<MessageHandle>, error: Option<ActorFutureError>) {
     (self.0)(result, error).await
   }
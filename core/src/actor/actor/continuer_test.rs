@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use crate::actor::actor::Continuer;
+  use crate::actor::dispatch::future::ActorFutureError;
+  use crate::actor::message::MessageHandle;
+  use tokio::sync::Mutex;
+
+  #[tokio::test]
+  async fn test_from_result_maps_each_outcome() {
+    let results = Arc::new(Mutex::new(vec![]));
+    let cloned_results = results.clone();
+    let continuer = Continuer::from_result(move |result| {
+      let results = cloned_results.clone();
+      async move {
+        results.lock().await.push(result);
+      }
+    });
+
+    let message_handle = MessageHandle::new("done".to_string());
+    continuer.run(Some(message_handle.clone()), None).await;
+    continuer.run(None, Some(ActorFutureError::TimeoutError)).await;
+    continuer.run(None, None).await;
+
+    assert_eq!(
+      *results.lock().await,
+      vec![
+        Ok(message_handle),
+        Err(ActorFutureError::TimeoutError),
+        Err(ActorFutureError::NoResult)
+      ]
+    );
+  }
+}
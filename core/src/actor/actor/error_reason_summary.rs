@@ -0,0 +1,25 @@
+use std::fmt::{Display, Formatter};
+
+// ErrorReasonSummary is the serializable form of an ErrorReason: the reason's type name, a message and the code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ErrorReasonSummary {
+  pub kind: String,
+  pub message: String,
+  pub code: i32,
+}
+
+impl ErrorReasonSummary {
+  pub fn new(kind: impl Into<String>, message: impl Into<String>, code: i32) -> Self {
+    Self {
+      kind: kind.into(),
+      message: message.into(),
+      code,
+    }
+  }
+}
+
+impl Display for ErrorReasonSummary {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}: {} (code = {})", self.kind, self.message, self.code)
+  }
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+  use crate::actor::actor::{ErrorReason, ErrorReasonSummary};
+  use std::env;
+  use tracing_subscriber::EnvFilter;
+
+  struct Opaque;
+
+  #[test]
+  fn test_error_reason_summary_round_trip() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let reason = ErrorReason::new_serializable(std::io::Error::other("disk full"), 7);
+    let summary = reason.to_summary();
+    assert_eq!(summary.kind, "std::io::error::Error");
+    assert_eq!(summary.message, "disk full");
+    assert_eq!(summary.code, 7);
+
+    let rebuilt = ErrorReason::from_summary(summary.clone());
+    assert_eq!(rebuilt.kind(), "std::io::error::Error");
+    assert_eq!(rebuilt.code, 7);
+    assert!(rebuilt.is_type::<ErrorReasonSummary>());
+    assert_eq!(rebuilt.to_summary(), summary);
+  }
+
+  #[test]
+  fn test_non_serializable_reason_degrades_to_message() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let summary = ErrorReason::new(Opaque, 1).to_summary();
+    assert!(summary.kind.ends_with("Opaque"));
+    assert_eq!(summary.message, format!("non-serializable reason: {}", summary.kind));
+
+    let summary = ErrorReason::new("boom".to_string(), 0).to_summary();
+    assert_eq!(summary.message, "boom");
+  }
+}
@@ -0,0 +1,33 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::actor::message::MessageHandle;
+
+#[derive(Clone)]
+pub struct IdempotencyCheck(Arc<dyn Fn(&MessageHandle) -> bool + Send + Sync + 'static>);
+
+impl IdempotencyCheck {
+  pub fn new(f: impl Fn(&MessageHandle) -> bool + Send + Sync + 'static) -> Self {
+    IdempotencyCheck(Arc::new(f))
+  }
+
+  pub fn run(&self, message_handle: &MessageHandle) -> bool {
+    self.0(message_handle)
+  }
+}
+
+impl Debug for IdempotencyCheck {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "IdempotencyCheck")
+  }
+}
+
+impl PartialEq for IdempotencyCheck {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl Eq for IdempotencyCheck {}
+
+static_assertions::assert_impl_all!(IdempotencyCheck: Send, Sync);
@@ -0,0 +1,10 @@
+use crate::actor::actor::pid::ExtendedPid;
+use nexus_actor_message_derive_rs::Message;
+
+// LifecycleEvent is published to the event stream once an actor has fully started (after PostStart)
+// or fully stopped (after PostStop)
+#[derive(Debug, Clone, PartialEq, Eq, Message)]
+pub enum LifecycleEvent {
+  Started(ExtendedPid),
+  Stopped(ExtendedPid),
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+  use std::env;
+  use std::sync::atomic::{AtomicBool, Ordering};
+  use std::sync::Arc;
+  use std::time::Duration;
+
+  use crate::actor::actor::Props;
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{MessagePart, SpawnerPart, StopperPart};
+  use crate::actor::message::AutoReceiveMessage;
+  use tokio::time::timeout;
+  use tracing_subscriber::EnvFilter;
+
+  #[tokio::test]
+  async fn test_await_started_and_stopped() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let post_started = Arc::new(AtomicBool::new(false));
+    let post_stopped = Arc::new(AtomicBool::new(false));
+    let cloned_post_started = post_started.clone();
+    let cloned_post_stopped = post_stopped.clone();
+    let props = Props::from_async_actor_receiver(move |ctx| {
+      let post_started = cloned_post_started.clone();
+      let post_stopped = cloned_post_stopped.clone();
+      async move {
+        match ctx.get_message_handle().await.to_typed::<AutoReceiveMessage>() {
+          Some(AutoReceiveMessage::PostStart) => {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            post_started.store(true, Ordering::SeqCst);
+          }
+          Some(AutoReceiveMessage::PostStop) => {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            post_stopped.store(true, Ordering::SeqCst);
+          }
+          _ => {}
+        }
+        Ok(())
+      }
+    })
+    .await;
+    let pid = root_context.spawn(props).await;
+
+    timeout(Duration::from_secs(1), pid.started(system.clone()))
+      .await
+      .expect("actor did not start");
+    assert!(post_started.load(Ordering::SeqCst));
+
+    root_context.stop(&pid).await;
+    timeout(Duration::from_secs(1), pid.stopped(system.clone()))
+      .await
+      .expect("actor did not stop");
+    assert!(post_stopped.load(Ordering::SeqCst));
+
+    timeout(Duration::from_millis(100), pid.stopped(system.clone()))
+      .await
+      .expect("already stopped actor should resolve immediately");
+  }
+}
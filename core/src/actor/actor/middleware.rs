@@ -1,3 +1,8 @@
+mod dedup;
+mod dedup_test;
 mod logging;
+mod logging_test;
+mod throttle;
+mod throttle_test;
 
-pub use logging::*;
+pub use {self::dedup::*, self::logging::*, self::throttle::*};
@@ -0,0 +1,95 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use crate::actor::actor::{ReceiverMiddleware, ReceiverMiddlewareChain};
+use crate::actor::context::ReceiverContextHandle;
+use crate::actor::message::MessageEnvelope;
+
+pub const MESSAGE_ID_HEADER: &str = "message-id";
+
+// SeenKeys remembers the most recently seen keys, evicting the least recently seen one when full
+#[derive(Debug)]
+struct SeenKeys {
+  capacity: usize,
+  keys: HashSet<String>,
+  order: VecDeque<String>,
+}
+
+impl SeenKeys {
+  fn new(capacity: usize) -> Self {
+    Self {
+      capacity,
+      keys: HashSet::new(),
+      order: VecDeque::new(),
+    }
+  }
+
+  // Records the key and returns true when it had already been seen
+  fn check_and_insert(&mut self, key: &str) -> bool {
+    if self.keys.contains(key) {
+      if let Some(position) = self.order.iter().position(|k| k == key) {
+        let key = self.order.remove(position).unwrap();
+        self.order.push_back(key);
+      }
+      return true;
+    }
+    if self.capacity == 0 {
+      return false;
+    }
+    if self.order.len() >= self.capacity {
+      if let Some(oldest) = self.order.pop_front() {
+        self.keys.remove(&oldest);
+      }
+    }
+    self.keys.insert(key.to_string());
+    self.order.push_back(key.to_string());
+    false
+  }
+
+  // Forgets the key, so a redelivery of it passes again
+  fn remove(&mut self, key: &str) {
+    if self.keys.remove(key) {
+      self.order.retain(|k| k != key);
+    }
+  }
+}
+
+pub struct DedupMiddleware;
+
+impl DedupMiddleware {
+  pub fn of_receiver(capacity: usize) -> ReceiverMiddleware {
+    Self::of_receiver_with_header(MESSAGE_ID_HEADER, capacity)
+  }
+
+  // OfReceiverWithHeader skips messages whose header value was among the last `capacity` keys seen.
+  // Messages without the header always pass, and the key of a message whose handling fails is forgotten. The keys are shared by every actor spawned from the props.
+  pub fn of_receiver_with_header(header_key: &str, capacity: usize) -> ReceiverMiddleware {
+    let header_key = header_key.to_string();
+    let seen = Arc::new(Mutex::new(SeenKeys::new(capacity)));
+    ReceiverMiddleware::new(move |next| {
+      let header_key = header_key.clone();
+      let seen = seen.clone();
+      ReceiverMiddlewareChain::new(move |context_handle: ReceiverContextHandle, env: MessageEnvelope| {
+        let cloned_next = next.clone();
+        let cloned_seen = seen.clone();
+        let key = env.get_header_value(&header_key);
+        let duplicate = key
+          .as_ref()
+          .map(|key| seen.lock().unwrap().check_and_insert(key))
+          .unwrap_or(false);
+        async move {
+          if duplicate {
+            tracing::debug!("DedupMiddleware: skipping duplicate message");
+            return Ok(());
+          }
+          let result = cloned_next.run(context_handle, env).await;
+          // A failed message was not handled, so its redelivery must not be skipped
+          if let (Err(_), Some(key)) = (&result, key) {
+            cloned_seen.lock().unwrap().remove(&key);
+          }
+          result
+        }
+      })
+    })
+  }
+}
@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::{AtomicBool, Ordering};
+  use std::sync::Arc;
+  use std::time::Duration;
+
+  use crate::actor::actor::{ActorError, DedupMiddleware, ErrorReason, Props, MESSAGE_ID_HEADER};
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{MessagePart, SenderPart, SpawnerPart};
+  use crate::actor::message::{MessageEnvelope, MessageHandle, MessageHeaders};
+  use nexus_actor_message_derive_rs::Message;
+  use tokio::sync::{Mutex, Notify};
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Deposit(u32);
+
+  fn with_message_id(deposit: Deposit, id: &str) -> MessageHandle {
+    let mut headers = MessageHeaders::new();
+    headers.set(MESSAGE_ID_HEADER.to_string(), id.to_string());
+    MessageHandle::new(MessageEnvelope::new(MessageHandle::new(deposit)).with_header(headers))
+  }
+
+  #[tokio::test]
+  async fn test_dedup_middleware_skips_repeated_message_ids() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let notify = Arc::new(Notify::new());
+    let cloned_received = received.clone();
+    let cloned_notify = notify.clone();
+    let props = Props::from_async_actor_receiver_with_opts(
+      move |ctx| {
+        let received = cloned_received.clone();
+        let notify = cloned_notify.clone();
+        async move {
+          if let Some(Deposit(amount)) = ctx.get_message_handle().await.to_typed::<Deposit>() {
+            received.lock().await.push(amount);
+            notify.notify_one();
+          }
+          Ok(())
+        }
+      },
+      [Props::with_receiver_middlewares([DedupMiddleware::of_receiver(2)])],
+    )
+    .await;
+    let pid = root_context.spawn(props).await;
+
+    // "a" is evicted by "c", so its last redelivery passes again; messages without an id always pass
+    let messages = vec![
+      with_message_id(Deposit(1), "a"),
+      with_message_id(Deposit(1), "a"),
+      with_message_id(Deposit(2), "b"),
+      MessageHandle::new(Deposit(3)),
+      MessageHandle::new(Deposit(3)),
+      with_message_id(Deposit(4), "c"),
+      with_message_id(Deposit(2), "b"),
+      with_message_id(Deposit(1), "a"),
+    ];
+    for message in messages {
+      root_context.send(pid.clone(), message).await;
+    }
+
+    tokio::time::timeout(Duration::from_secs(3), async {
+      while received.lock().await.len() < 6 {
+        notify.notified().await;
+      }
+    })
+    .await
+    .expect("messages were not received");
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(*received.lock().await, vec![1, 2, 3, 3, 4, 1]);
+  }
+
+  #[tokio::test]
+  async fn test_dedup_middleware_lets_a_failed_message_be_redelivered() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let failed = Arc::new(AtomicBool::new(false));
+    let notify = Arc::new(Notify::new());
+    let cloned_received = received.clone();
+    let cloned_failed = failed.clone();
+    let cloned_notify = notify.clone();
+    let props = Props::from_async_actor_receiver_with_opts(
+      move |ctx| {
+        let received = cloned_received.clone();
+        let failed = cloned_failed.clone();
+        let notify = cloned_notify.clone();
+        async move {
+          if let Some(Deposit(amount)) = ctx.get_message_handle().await.to_typed::<Deposit>() {
+            // The first delivery fails before the deposit is recorded
+            if !failed.swap(true, Ordering::SeqCst) {
+              notify.notify_one();
+              return Err(ActorError::ReceiveError(ErrorReason::new("deposit failed", 0)));
+            }
+            received.lock().await.push(amount);
+            notify.notify_one();
+          }
+          Ok(())
+        }
+      },
+      [Props::with_receiver_middlewares([DedupMiddleware::of_receiver(2)])],
+    )
+    .await;
+    let pid = root_context.spawn(props).await;
+
+    root_context.send(pid.clone(), with_message_id(Deposit(1), "a")).await;
+    tokio::time::timeout(Duration::from_secs(3), notify.notified())
+      .await
+      .expect("the first delivery was not received");
+    root_context.send(pid.clone(), with_message_id(Deposit(1), "a")).await;
+    root_context.send(pid.clone(), with_message_id(Deposit(1), "a")).await;
+
+    tokio::time::timeout(Duration::from_secs(3), async {
+      while received.lock().await.is_empty() {
+        notify.notified().await;
+      }
+    })
+    .await
+    .expect("the redelivery was skipped");
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(*received.lock().await, vec![1]);
+  }
+}
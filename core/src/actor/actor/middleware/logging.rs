@@ -1,18 +1,104 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
 use crate::actor::actor::{ReceiverMiddleware, ReceiverMiddlewareChain};
-use crate::actor::context::ReceiverContextHandle;
+use crate::actor::context::{InfoPart, ReceiverContextHandle};
+use crate::actor::message::Encoder;
+use crate::actor::message::Field;
+use crate::actor::message::Message;
 use crate::actor::message::MessageEnvelope;
+use crate::actor::message::CORRELATION_ID_HEADER;
+use tokio::time::Instant;
+
+// FieldSink receives the structured fields Logger emits for each processed message
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+pub struct FieldSink(Arc<dyn Fn(&[Field]) + Send + Sync + 'static>);
+
+impl FieldSink {
+  pub fn new(f: impl Fn(&[Field]) + Send + Sync + 'static) -> Self {
+    FieldSink(Arc::new(f))
+  }
+
+  // Tracing logs the fields as a single `key=value` list at info level
+  pub fn tracing() -> Self {
+    FieldSink::new(|fields| {
+      let fields = fields
+        .iter()
+        .map(|field| field.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+      tracing::info!(fields, "Actor processed message");
+    })
+  }
+
+  // Encoded renders the fields with the encoder and hands the result to write, e.g. to ship JSON lines
+  pub fn encoded(encoder: impl Encoder, write: impl Fn(String) + Send + Sync + 'static) -> Self {
+    FieldSink::new(move |fields| write(encoder.encode(fields)))
+  }
+
+  pub fn run(&self, fields: &[Field]) {
+    self.0(fields)
+  }
+}
+
+impl Debug for FieldSink {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "FieldSink")
+  }
+}
+
+impl PartialEq for FieldSink {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl Eq for FieldSink {}
+
+static_assertions::assert_impl_all!(FieldSink: Send, Sync);
 
 pub struct Logger;
 
 impl Logger {
   pub fn of_receiver() -> ReceiverMiddleware {
-    ReceiverMiddleware::new(|next| {
+    Self::of_receiver_with_sink(FieldSink::tracing())
+  }
+
+  // OfReceiverWithSink emits `pid`, `message_type`, `correlation_id` when present, `elapsed_us` and `result`,
+  // followed by the message's own log fields, once the rest of the chain has processed the message
+  pub fn of_receiver_with_sink(sink: FieldSink) -> ReceiverMiddleware {
+    ReceiverMiddleware::new(move |next| {
+      let sink = sink.clone();
       ReceiverMiddlewareChain::new(move |context_handle: ReceiverContextHandle, env: MessageEnvelope| {
         let cloned_next = next.clone();
+        let sink = sink.clone();
         async move {
           let message_handle = env.get_message_handle();
-          tracing::info!("Actor got message: {:?}", message_handle);
-          cloned_next.run(context_handle.clone(), env.clone()).await
+          let correlation_id = env.get_header_value(CORRELATION_ID_HEADER);
+          let start = Instant::now();
+          let result = cloned_next.run(context_handle.clone(), env).await;
+          let elapsed = start.elapsed();
+
+          let pid = context_handle.get_self_opt().await;
+          let mut fields = vec![
+            Field::new("pid", pid.map(|pid| pid.to_string()).unwrap_or_default()),
+            Field::new("message_type", message_handle.get_type_name()),
+          ];
+          if let Some(correlation_id) = correlation_id {
+            fields.push(Field::new("correlation_id", correlation_id));
+          }
+          fields.push(Field::uint64(
+            "elapsed_us",
+            u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX),
+          ));
+          fields.push(match &result {
+            Ok(_) => Field::new("result", "ok"),
+            Err(err) => Field::error("result", err),
+          });
+          fields.extend(message_handle.log_fields());
+          sink.run(&fields);
+          result
         }
       })
     })
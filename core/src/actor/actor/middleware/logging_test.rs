@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+  use std::env;
+  use std::sync::{Arc, Mutex};
+  use std::time::Duration;
+
+  use crate::actor::actor::{ActorError, ErrorReason, FieldSink, Logger, Props};
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{MessagePart, SenderPart, SpawnerPart};
+  use crate::actor::message::{Field, MessageHandle};
+  use nexus_actor_message_derive_rs::Message;
+  use tokio::sync::Notify;
+  use tracing_subscriber::EnvFilter;
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct PlaceOrder {
+    #[message(log)]
+    id: u64,
+    secret: String,
+  }
+
+  #[tokio::test]
+  async fn test_logger_emits_structured_fields() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let logged = Arc::new(Mutex::new(Vec::<Vec<Field>>::new()));
+    let notify = Arc::new(Notify::new());
+    let cloned_logged = logged.clone();
+    let cloned_notify = notify.clone();
+    // Lifecycle messages go through the middleware too, only keep the orders
+    let sink = FieldSink::new(move |fields| {
+      if fields[1].get_value().ends_with("PlaceOrder") {
+        cloned_logged.lock().unwrap().push(fields.to_vec());
+        cloned_notify.notify_one();
+      }
+    });
+    let props = Props::from_async_actor_receiver_with_opts(
+      |ctx| async move {
+        match ctx.get_message_handle().await.to_typed::<PlaceOrder>() {
+          Some(order) if order.id == 0 => Err(ActorError::ReceiveError(ErrorReason::new("invalid order", 0))),
+          _ => Ok(()),
+        }
+      },
+      [Props::with_receiver_middlewares([Logger::of_receiver_with_sink(sink)])],
+    )
+    .await;
+    let pid = root_context.spawn(props).await;
+
+    for id in [42, 0] {
+      let order = PlaceOrder {
+        id,
+        secret: "s3cr3t".to_string(),
+      };
+      root_context.send(pid.clone(), MessageHandle::new(order)).await;
+    }
+    tokio::time::timeout(Duration::from_secs(3), async {
+      while logged.lock().unwrap().len() < 2 {
+        notify.notified().await;
+      }
+    })
+    .await
+    .expect("messages were not logged");
+
+    let logged = logged.lock().unwrap().clone();
+    for (fields, id, result) in [(&logged[0], "42", "ok"), (&logged[1], "0", "Receive error")] {
+      let keys = fields.iter().map(|field| field.get_key()).collect::<Vec<_>>();
+      assert_eq!(keys, vec!["pid", "message_type", "elapsed_us", "result", "id"]);
+      assert_eq!(fields[0].get_value(), pid.to_string());
+      assert!(fields[2].get_value().parse::<u128>().is_ok());
+      assert!(fields[3].get_value().starts_with(result));
+      assert_eq!(fields[4].get_value(), id);
+    }
+  }
+}
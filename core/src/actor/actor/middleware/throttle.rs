@@ -0,0 +1,92 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::actor::actor::{SenderMiddleware, SenderMiddlewareChain};
+use crate::actor::context::{InfoPart, SenderContextHandle};
+use crate::actor::message::{MessageEnvelope, MessageHandle};
+use crate::actor::process::Process;
+
+// ThrottleMode decides what happens to a send when the bucket has no token left
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrottleMode {
+  // Delay waits until a token frees up
+  Delay,
+  // Drop diverts the message to dead letters
+  Drop,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+  rate_per_second: f64,
+  burst: f64,
+  tokens: f64,
+  refilled_at: Instant,
+}
+
+impl TokenBucket {
+  fn new(rate_per_second: u32, burst: u32) -> Self {
+    let burst = f64::from(burst.max(1));
+    Self {
+      rate_per_second: f64::from(rate_per_second.max(1)),
+      burst,
+      tokens: burst,
+      refilled_at: Instant::now(),
+    }
+  }
+
+  // Takes a token, or returns how long until one is available
+  fn try_acquire(&mut self) -> Result<(), Duration> {
+    let now = Instant::now();
+    let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+    self.tokens = (self.tokens + elapsed * self.rate_per_second).min(self.burst);
+    self.refilled_at = now;
+    if self.tokens >= 1.0 {
+      self.tokens -= 1.0;
+      Ok(())
+    } else {
+      Err(Duration::from_secs_f64((1.0 - self.tokens) / self.rate_per_second))
+    }
+  }
+}
+
+pub struct ThrottleMiddleware;
+
+impl ThrottleMiddleware {
+  // OfSender limits outbound sends to `rate_per_second`, allowing bursts of up to `burst` messages.
+  // Rate and burst are at least 1. The bucket is shared by every actor spawned from the props.
+  pub fn of_sender(rate_per_second: u32, burst: u32, mode: ThrottleMode) -> SenderMiddleware {
+    let bucket = Arc::new(Mutex::new(TokenBucket::new(rate_per_second, burst)));
+    SenderMiddleware::new(move |next| {
+      let bucket = bucket.clone();
+      SenderMiddlewareChain::new(
+        move |context_handle: SenderContextHandle, target, envelope: MessageEnvelope| {
+          let cloned_next = next.clone();
+          let bucket = bucket.clone();
+          async move {
+            loop {
+              let acquired = bucket.lock().unwrap().try_acquire();
+              match (acquired, mode) {
+                (Ok(_), _) => break,
+                (Err(wait), ThrottleMode::Delay) => tokio::time::sleep(wait).await,
+                (Err(_), ThrottleMode::Drop) => {
+                  tracing::debug!("ThrottleMiddleware: dropping message to {}", target);
+                  context_handle
+                    .get_actor_system()
+                    .await
+                    .get_dead_letter()
+                    .await
+                    .send_user_message(Some(&target), MessageHandle::new(envelope))
+                    .await;
+                  return;
+                }
+              }
+            }
+            cloned_next.run(context_handle, target, envelope).await;
+          }
+        },
+      )
+    })
+  }
+}
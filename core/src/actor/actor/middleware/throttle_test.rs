@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+  use std::time::Duration;
+
+  use crate::actor::actor::{ExtendedPid, Props, ThrottleMiddleware, ThrottleMode};
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{MessagePart, SenderPart, SpawnerPart};
+  use crate::actor::dispatch::DeadLetterEvent;
+  use crate::actor::message::MessageHandle;
+  use nexus_actor_message_derive_rs::Message;
+  use tokio::sync::{Mutex, Notify};
+  use tokio::time::Instant;
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Burst(u32);
+
+  async fn spawn_recorder(system: &ActorSystem, received: Arc<Mutex<Vec<u32>>>, notify: Arc<Notify>) -> ExtendedPid {
+    system
+      .get_root_context()
+      .await
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let received = received.clone();
+          let notify = notify.clone();
+          async move {
+            if let Some(n) = ctx.get_message_handle().await.to_typed::<u32>() {
+              received.lock().await.push(n);
+              notify.notify_one();
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await
+  }
+
+  async fn spawn_sender(
+    system: &ActorSystem,
+    target: ExtendedPid,
+    rate_per_second: u32,
+    mode: ThrottleMode,
+  ) -> ExtendedPid {
+    let props = Props::from_async_actor_receiver_with_opts(
+      move |mut ctx| {
+        let target = target.clone();
+        async move {
+          if let Some(Burst(count)) = ctx.get_message_handle().await.to_typed::<Burst>() {
+            for n in 0..count {
+              ctx.send(target.clone(), MessageHandle::new(n)).await;
+            }
+          }
+          Ok(())
+        }
+      },
+      [Props::with_sender_middlewares([ThrottleMiddleware::of_sender(
+        rate_per_second,
+        2,
+        mode,
+      )])],
+    )
+    .await;
+    system.get_root_context().await.spawn(props).await
+  }
+
+  #[tokio::test]
+  async fn test_throttle_delays_sends_beyond_burst() {
+    let system = ActorSystem::new().await.unwrap();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let notify = Arc::new(Notify::new());
+    let target = spawn_recorder(&system, received.clone(), notify.clone()).await;
+    let sender = spawn_sender(&system, target, 20, ThrottleMode::Delay).await;
+
+    let started = Instant::now();
+    system
+      .get_root_context()
+      .await
+      .send(sender, MessageHandle::new(Burst(4)))
+      .await;
+    tokio::time::timeout(Duration::from_secs(3), async {
+      while received.lock().await.len() < 4 {
+        notify.notified().await;
+      }
+    })
+    .await
+    .expect("throttled messages were not delivered");
+
+    // Two messages fit the burst, the other two wait 50ms each for a token
+    assert!(
+      started.elapsed() >= Duration::from_millis(90),
+      "elapsed = {:?}",
+      started.elapsed()
+    );
+    assert_eq!(*received.lock().await, vec![0, 1, 2, 3]);
+  }
+
+  #[tokio::test]
+  async fn test_throttle_drops_sends_beyond_burst_to_dead_letters() {
+    let system = ActorSystem::new().await.unwrap();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let notify = Arc::new(Notify::new());
+    let target = spawn_recorder(&system, received.clone(), notify.clone()).await;
+
+    let dead_letters = Arc::new(Mutex::new(Vec::new()));
+    let cloned_dead_letters = dead_letters.clone();
+    let cloned_notify = notify.clone();
+    let cloned_target = target.clone();
+    system
+      .get_event_stream()
+      .await
+      .subscribe(move |msg| {
+        let dead_letters = cloned_dead_letters.clone();
+        let notify = cloned_notify.clone();
+        let target = cloned_target.clone();
+        async move {
+          if let Some(dead_letter) = msg.to_typed::<DeadLetterEvent>() {
+            if dead_letter.pid.as_ref() == Some(&target) {
+              if let Some(n) = dead_letter.message_handle.to_typed::<u32>() {
+                dead_letters.lock().await.push(n);
+                notify.notify_one();
+              }
+            }
+          }
+        }
+      })
+      .await;
+
+    let sender = spawn_sender(&system, target, 1, ThrottleMode::Drop).await;
+    system
+      .get_root_context()
+      .await
+      .send(sender, MessageHandle::new(Burst(4)))
+      .await;
+    tokio::time::timeout(Duration::from_secs(3), async {
+      while received.lock().await.len() + dead_letters.lock().await.len() < 4 {
+        notify.notified().await;
+      }
+    })
+    .await
+    .expect("messages were neither delivered nor dead-lettered");
+
+    assert_eq!(*received.lock().await, vec![0, 1]);
+    assert_eq!(*dead_letters.lock().await, vec![2, 3]);
+  }
+}
@@ -0,0 +1,33 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::actor::actor_system::ActorSystem;
+
+#[derive(Clone)]
+pub struct NameGenerator(Arc<dyn Fn(&ActorSystem) -> String + Send + Sync + 'static>);
+
+impl NameGenerator {
+  pub fn new(f: impl Fn(&ActorSystem) -> String + Send + Sync + 'static) -> Self {
+    NameGenerator(Arc::new(f))
+  }
+
+  pub fn run(&self, actor_system: &ActorSystem) -> String {
+    self.0(actor_system)
+  }
+}
+
+impl Debug for NameGenerator {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "NameGenerator")
+  }
+}
+
+impl PartialEq for NameGenerator {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl Eq for NameGenerator {}
+
+static_assertions::assert_impl_all!(NameGenerator: Send, Sync);
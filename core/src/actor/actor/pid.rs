@@ -3,13 +3,14 @@ use std::hash::Hash;
 use std::sync::Arc;
 
 use crate::actor::actor::actor_process::ActorProcess;
+use crate::actor::actor::lifecycle_event::LifecycleEvent;
 use crate::actor::actor_system::ActorSystem;
 use crate::actor::message::MessageHandle;
 use crate::actor::process::{Process, ProcessHandle};
 use crate::generated::actor::Pid;
 
 use regex::Regex;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Notify};
 
 fn is_valid_address(input: &str) -> bool {
   let re = Regex::new(r"^((\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3})|([a-zA-Z0-9\-\.]+)):\d+$").unwrap();
@@ -132,4 +133,57 @@ impl ExtendedPid {
       .send_system_message(self, message_handle)
       .await;
   }
+
+  // Started resolves once the actor has handled PostStart.
+  // It resolves immediately if the actor has already started or is no longer registered.
+  pub async fn started(&self, actor_system: ActorSystem) {
+    self
+      .wait_for_lifecycle_event(actor_system, LifecycleEvent::Started(self.clone()), |process| {
+        process.is_none_or(|process| {
+          process
+            .as_any()
+            .downcast_ref::<ActorProcess>()
+            .is_some_and(|actor_process| actor_process.is_started())
+        })
+      })
+      .await
+  }
+
+  // Stopped resolves once the actor has handled PostStop.
+  // It resolves immediately if the actor is no longer registered.
+  pub async fn stopped(&self, actor_system: ActorSystem) {
+    self
+      .wait_for_lifecycle_event(actor_system, LifecycleEvent::Stopped(self.clone()), |process| {
+        process.is_none()
+      })
+      .await
+  }
+
+  async fn wait_for_lifecycle_event(
+    &self,
+    actor_system: ActorSystem,
+    expected: LifecycleEvent,
+    already_observed: impl FnOnce(Option<ProcessHandle>) -> bool,
+  ) {
+    let notify = Arc::new(Notify::new());
+    let cloned_notify = notify.clone();
+    let event_stream = actor_system.get_event_stream().await;
+    // Subscribe before checking the current state so that an event published in between is not missed
+    let subscription = event_stream
+      .subscribe(move |evt| {
+        let observed = evt.to_typed::<LifecycleEvent>().is_some_and(|evt| evt == expected);
+        let notify = cloned_notify.clone();
+        async move {
+          if observed {
+            notify.notify_one();
+          }
+        }
+      })
+      .await;
+    let process = actor_system.get_process_registry().await.find_process(self).await;
+    if !already_observed(process) {
+      notify.notified().await;
+    }
+    event_stream.unsubscribe(subscription).await;
+  }
 }
@@ -1,6 +1,8 @@
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use once_cell::sync::Lazy;
@@ -15,13 +17,17 @@ use crate::actor::actor::actor_receiver::ActorReceiver;
 use crate::actor::actor::context_decorator::ContextDecorator;
 use crate::actor::actor::context_decorator_chain::ContextDecoratorChain;
 use crate::actor::actor::context_handler::ContextHandler;
+use crate::actor::actor::idempotency_check::IdempotencyCheck;
 use crate::actor::actor::middleware_chain::{
   make_context_decorator_chain, make_receiver_middleware_chain, make_sender_middleware_chain,
   make_spawn_middleware_chain,
 };
+use crate::actor::actor::name_generator::NameGenerator;
 use crate::actor::actor::pid::ExtendedPid;
+use crate::actor::actor::receive_guard::ReceiveGuard;
 use crate::actor::actor::receiver_middleware::ReceiverMiddleware;
 use crate::actor::actor::receiver_middleware_chain::ReceiverMiddlewareChain;
+use crate::actor::actor::request_response_actor::{RequestResponseActor, RequestResponseActorWrapper};
 use crate::actor::actor::sender_middleware::SenderMiddleware;
 use crate::actor::actor::sender_middleware_chain::SenderMiddlewareChain;
 use crate::actor::actor::spawn_middleware::SpawnMiddleware;
@@ -30,18 +36,19 @@ use crate::actor::actor_system::ActorSystem;
 use crate::actor::context::ActorContext;
 use crate::actor::context::ContextHandle;
 use crate::actor::context::SpawnerContextHandle;
-use crate::actor::context::{InfoPart, ReceiverPart};
+use crate::actor::context::{InfoPart, ReceiverPart, SpawnerPart};
 use crate::actor::dispatch::unbounded_mailbox_creator_with_opts;
 use crate::actor::dispatch::Mailbox;
 use crate::actor::dispatch::MailboxHandle;
 use crate::actor::dispatch::MailboxProducer;
 use crate::actor::dispatch::*;
 use crate::actor::message::AutoReceiveMessage;
+use crate::actor::message::Message;
 use crate::actor::message::MessageHandle;
 use crate::actor::message::SystemMessage;
+use crate::actor::persistence::JournalHandle;
 use crate::actor::process::ProcessHandle;
-use crate::actor::supervisor::SupervisorStrategyHandle;
-use crate::actor::supervisor::DEFAULT_SUPERVISION_STRATEGY;
+use crate::actor::supervisor::{OneForOneStrategy, SupervisorStrategyHandle};
 
 #[derive(Debug, Clone)]
 pub struct Props {
@@ -59,6 +66,15 @@ pub struct Props {
   context_decorator: Vec<ContextDecorator>,
   context_decorator_chain: Option<ContextDecoratorChain>,
   on_init: Vec<ContextHandler>,
+  message_history_size: usize,
+  name_generator: Option<NameGenerator>,
+  dedicated_thread: bool,
+  journal: Option<JournalHandle>,
+  receive_guard: Option<ReceiveGuard>,
+  idempotency_check: Option<IdempotencyCheck>,
+  max_message_bytes: Option<usize>,
+  snapshot: bool,
+  flags: HashMap<String, bool>,
 }
 
 static_assertions::assert_impl_all!(Props: Send, Sync);
@@ -67,15 +83,26 @@ static DEFAULT_DISPATCHER: Lazy<DispatcherHandle> =
   Lazy::new(|| DispatcherHandle::new(TokioRuntimeContextDispatcher::new().unwrap()));
 static DEFAULT_MAILBOX_PRODUCER: Lazy<MailboxProducer> = Lazy::new(|| unbounded_mailbox_creator_with_opts(vec![]));
 
+const MAX_GENERATED_NAME_ATTEMPTS: usize = 10;
+
 static DEFAULT_SPAWNER: Lazy<Spawner> = Lazy::new(|| {
   Spawner::new(
-    |actor_system: ActorSystem, name: String, props: Props, parent_context: SpawnerContextHandle| async move {
+    |actor_system: ActorSystem, name: String, mut props: Props, parent_context: SpawnerContextHandle| async move {
       tracing::debug!("Spawn actor: {}", name);
+      if let Some(spawn_interceptor) = actor_system.get_config().await.spawn_interceptor {
+        spawn_interceptor.run(&mut props);
+      }
       let mut ctx = ActorContext::new(actor_system.clone(), props.clone(), parent_context.get_self_opt().await).await;
       let mut mb = props.produce_mailbox().await;
 
-      let dp = DispatcherHandle::new_arc(actor_system.get_config().await.system_dispatcher.clone());
-      let proc = ActorProcess::new(mb.clone());
+      let dp = if props.dedicated_thread {
+        DedicatedThreadDispatcher::new(&name)
+          .map(DispatcherHandle::new)
+          .map_err(|e| SpawnError::ErrDispatcher(e.to_string()))?
+      } else {
+        actor_system.get_mailbox_dispatcher().await
+      };
+      let proc = ActorProcess::new(mb.clone()).with_diagnostics(ctx.clone());
       let proc_handle = ProcessHandle::new(proc);
       let pr = actor_system.get_process_registry().await;
 
@@ -85,6 +112,7 @@ static DEFAULT_SPAWNER: Lazy<Spawner> = Lazy::new(|| {
       }
 
       ctx.set_self(pid.clone()).await;
+      ctx.register_mailbox_length_observer(mb.clone()).await;
 
       initialize(props, ctx.clone());
 
@@ -202,6 +230,86 @@ impl Props {
     })
   }
 
+  // WithMessageHistory keeps the type names and timestamps of the last `size` messages processed by the actor,
+  // reported by `diagnostics_json`
+  pub fn with_message_history(size: usize) -> PropsOption {
+    PropsOption::new(move |props: &mut Props| {
+      props.message_history_size = size;
+    })
+  }
+
+  // WithNameGenerator names actors spawned without an explicit name, e.g. `order-{id}`.
+  // A generated name that is already taken is retried with a freshly generated one
+  pub fn with_name_generator(name_generator: impl Fn(&ActorSystem) -> String + Send + Sync + 'static) -> PropsOption {
+    let name_generator = NameGenerator::new(name_generator);
+    PropsOption::new(move |props: &mut Props| {
+      props.name_generator = Some(name_generator.clone());
+    })
+  }
+
+  // Runs the mailbox of each actor spawned from these props on a thread of its own, so that every receive of the
+  // actor happens on the same OS thread. Messages are queued as usual. See DedicatedThreadDispatcher for what
+  // else ends up on that thread. The max_concurrent_mailboxes limit of the system does not apply to these actors
+  pub fn with_dedicated_thread() -> PropsOption {
+    PropsOption::new(move |props: &mut Props| {
+      props.dedicated_thread = true;
+    })
+  }
+
+  // WithJournal lets the actor `persist` events to the journal. When the actor starts, and again after every
+  // restart, the journaled events are re-applied to it before it processes any other message
+  pub fn with_journal(journal: JournalHandle) -> PropsOption {
+    PropsOption::new(move |props: &mut Props| {
+      props.journal = Some(journal.clone());
+    })
+  }
+
+  // WithReceiveGuard is consulted before every user message reaches the actor. Messages for which the guard
+  // returns false are sent to dead letters instead of being received. The guard runs on each message, so it can
+  // be toggled at runtime through state it captures
+  pub fn with_receive_guard(guard: impl Fn(&MessageHandle) -> bool + Send + Sync + 'static) -> PropsOption {
+    let guard = ReceiveGuard::new(guard);
+    PropsOption::new(move |props: &mut Props| {
+      props.receive_guard = Some(guard.clone());
+    })
+  }
+
+  // WithIdempotent declares which messages are safe to process more than once. Messages redelivered by
+  // `send_reliable` for which the check returns false are sent to dead letters instead of being received, and
+  // the sender stops redelivering them. Without a check every redelivery is received
+  pub fn with_idempotent(check: impl Fn(&MessageHandle) -> bool + Send + Sync + 'static) -> PropsOption {
+    let check = IdempotencyCheck::new(check);
+    PropsOption::new(move |props: &mut Props| {
+      props.idempotency_check = Some(check.clone());
+    })
+  }
+
+  // WithMaxMessageBytes sends user messages whose serialized size exceeds `max_bytes` to dead letters and
+  // publishes a MessageTooLargeEvent. Sizes come from the sizer set with ConfigOption::with_message_sizer, such as
+  // the remote crate's serialized_size which uses the serializer registry. Messages it cannot serialize are always
+  // received
+  pub fn with_max_message_bytes(max_bytes: usize) -> PropsOption {
+    PropsOption::new(move |props: &mut Props| {
+      props.max_message_bytes = Some(max_bytes);
+    })
+  }
+
+  // WithSnapshot reports the state of a Snapshotable actor in `diagnostics_json`. The snapshot is taken by the
+  // actor itself after each user message it processes, so reading it never races with the actor's receive
+  pub fn with_snapshot() -> PropsOption {
+    PropsOption::new(move |props: &mut Props| {
+      props.snapshot = true;
+    })
+  }
+
+  // WithFlags sets feature flags that the actor reads with `flag`, so that variants of an actor can be spawned
+  // from the same producer. The flags stay the same across restarts
+  pub fn with_flags(flags: HashMap<String, bool>) -> PropsOption {
+    PropsOption::new(move |props: &mut Props| {
+      props.flags.extend(flags.clone());
+    })
+  }
+
   pub fn with_guardian(guardian: SupervisorStrategyHandle) -> PropsOption {
     PropsOption::new(move |props: &mut Props| {
       props.guardian_strategy = Some(guardian.clone());
@@ -214,6 +322,14 @@ impl Props {
     })
   }
 
+  // WithRestartPolicy supervises children with a OneForOneStrategy that restarts a failing child at most
+  // max_retries times within the given duration, and stops a child that fails while starting
+  pub fn with_restart_policy(max_retries: u32, within: Duration) -> PropsOption {
+    Self::with_supervisor_strategy(SupervisorStrategyHandle::new(
+      OneForOneStrategy::new(max_retries, within).with_stop_on_start_failure(),
+    ))
+  }
+
   pub fn with_receiver_middlewares(
     middlewares: impl IntoIterator<Item = ReceiverMiddleware> + Send + Sync,
   ) -> PropsOption {
@@ -275,11 +391,11 @@ impl Props {
     self.producer.clone().unwrap()
   }
 
-  pub(crate) fn get_supervisor_strategy(&self) -> SupervisorStrategyHandle {
-    self
-      .supervisor_strategy
-      .clone()
-      .unwrap_or_else(|| DEFAULT_SUPERVISION_STRATEGY.clone())
+  pub(crate) async fn get_supervisor_strategy(&self, actor_system: &ActorSystem) -> SupervisorStrategyHandle {
+    match &self.supervisor_strategy {
+      Some(strategy) => strategy.clone(),
+      None => actor_system.get_config().await.get_default_supervisor_strategy(),
+    }
   }
 
   pub(crate) fn get_spawn_middleware_chain(&self) -> Option<Spawner> {
@@ -298,10 +414,46 @@ impl Props {
     self.receiver_middleware_chain.clone()
   }
 
+  pub(crate) fn get_message_history_size(&self) -> usize {
+    self.message_history_size
+  }
+
+  pub(crate) fn get_journal(&self) -> Option<JournalHandle> {
+    self.journal.clone()
+  }
+
+  pub(crate) fn get_receive_guard(&self) -> Option<ReceiveGuard> {
+    self.receive_guard.clone()
+  }
+
+  pub(crate) fn get_idempotency_check(&self) -> Option<IdempotencyCheck> {
+    self.idempotency_check.clone()
+  }
+
+  pub(crate) fn get_max_message_bytes(&self) -> Option<usize> {
+    self.max_message_bytes
+  }
+
+  pub(crate) fn get_snapshot(&self) -> bool {
+    self.snapshot
+  }
+
+  pub(crate) fn get_flags(&self) -> &HashMap<String, bool> {
+    &self.flags
+  }
+
   pub(crate) fn get_context_decorator_chain(&self) -> Option<ContextDecoratorChain> {
     self.context_decorator_chain.clone()
   }
 
+  pub fn get_mailbox_producer(&self) -> Option<MailboxProducer> {
+    self.mailbox_producer.clone()
+  }
+
+  pub fn set_mailbox_producer(&mut self, mailbox_producer: MailboxProducer) {
+    self.mailbox_producer = Some(mailbox_producer);
+  }
+
   async fn produce_mailbox(&self) -> MailboxHandle {
     if let Some(mailbox_producer) = &self.mailbox_producer {
       mailbox_producer.run().await
@@ -326,7 +478,29 @@ impl Props {
     A: Actor,
     F: Fn(ContextHandle) -> Fut + Clone + Send + Sync + 'static,
     Fut: Future<Output = A> + Send + 'static, {
-    let producer = ActorProducer::new(f);
+    Props::from_actor_producer_with_opts(ActorProducer::new(f), opts).await
+  }
+
+  // FromRequestResponseActor spawns the produced actor so that each of its responses is sent back to the sender
+  // of the request it answers
+  pub async fn from_request_response_actor<A, T, R, F, Fut>(f: F) -> Props
+  where
+    A: RequestResponseActor<T, R>,
+    T: Message + Clone,
+    R: Message,
+    F: Fn(ContextHandle) -> Fut + Clone + Send + Sync + 'static,
+    Fut: Future<Output = A> + Send + 'static, {
+    Props::from_async_actor_producer(move |ctx| {
+      let f = f.clone();
+      async move { RequestResponseActorWrapper::new(f(ctx).await) }
+    })
+    .await
+  }
+
+  pub(crate) async fn from_actor_producer_with_opts(
+    producer: ActorProducer,
+    opts: impl IntoIterator<Item = PropsOption>,
+  ) -> Props {
     let opts = opts.into_iter().collect::<Vec<_>>();
     let mut props = Props {
       on_init: vec![],
@@ -343,6 +517,15 @@ impl Props {
       sender_middleware_chain: None,
       spawn_middleware_chain: None,
       context_decorator_chain: None,
+      message_history_size: 0,
+      name_generator: None,
+      dedicated_thread: false,
+      journal: None,
+      receive_guard: None,
+      idempotency_check: None,
+      max_message_bytes: None,
+      snapshot: false,
+      flags: HashMap::new(),
     };
     props.configure(&opts).await;
     props
@@ -432,6 +615,32 @@ impl Props {
     self.get_spawner().run(actor_system, name, self, parent_context).await
   }
 
+  // SpawnWithGeneratedName spawns these props from the given context under a name from the props' name
+  // generator, or under the next sequential id when there is none
+  pub(crate) async fn spawn_with_generated_name<C>(self, context: &mut C) -> Result<ExtendedPid, SpawnError>
+  where
+    C: SpawnerPart + InfoPart, {
+    let actor_system = context.get_actor_system().await;
+    let name_generator = match &self.name_generator {
+      Some(name_generator) => name_generator.clone(),
+      None => {
+        let id = actor_system.get_process_registry().await.next_id();
+        return context.spawn_named(self, &id).await;
+      }
+    };
+    let mut attempts = 0;
+    loop {
+      attempts += 1;
+      let name = name_generator.run(&actor_system);
+      match context.spawn_named(self.clone(), &name).await {
+        Err(SpawnError::ErrNameExists(_)) if attempts < MAX_GENERATED_NAME_ATTEMPTS => {
+          tracing::debug!("Generated name is already taken, retrying: {}", name);
+        }
+        result => return result,
+      }
+    }
+  }
+
   async fn configure(&mut self, opts: &[PropsOption]) -> &mut Self {
     for opt in opts {
       opt.run(self).await;
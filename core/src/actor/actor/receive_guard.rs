@@ -0,0 +1,33 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::actor::message::MessageHandle;
+
+#[derive(Clone)]
+pub struct ReceiveGuard(Arc<dyn Fn(&MessageHandle) -> bool + Send + Sync + 'static>);
+
+impl ReceiveGuard {
+  pub fn new(f: impl Fn(&MessageHandle) -> bool + Send + Sync + 'static) -> Self {
+    ReceiveGuard(Arc::new(f))
+  }
+
+  pub fn run(&self, message_handle: &MessageHandle) -> bool {
+    self.0(message_handle)
+  }
+}
+
+impl Debug for ReceiveGuard {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "ReceiveGuard")
+  }
+}
+
+impl PartialEq for ReceiveGuard {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl Eq for ReceiveGuard {}
+
+static_assertions::assert_impl_all!(ReceiveGuard: Send, Sync);
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+  use crate::actor::actor::{Actor, ActorError, Props};
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{ContextHandle, MessagePart, SenderPart, SpawnerPart};
+  use crate::actor::dispatch::DeadLetterEvent;
+  use crate::actor::message::MessageHandle;
+  use async_trait::async_trait;
+  use std::env;
+  use std::sync::atomic::{AtomicBool, Ordering};
+  use std::sync::Arc;
+  use std::time::Duration;
+  use tokio::sync::{Mutex, Notify};
+  use tracing_subscriber::EnvFilter;
+
+  #[derive(Debug, Clone)]
+  struct RecordingActor {
+    received: Arc<Mutex<Vec<String>>>,
+    notify: Arc<Notify>,
+  }
+
+  #[async_trait]
+  impl Actor for RecordingActor {
+    async fn receive(&mut self, ctx: ContextHandle) -> Result<(), ActorError> {
+      if let Some(msg) = ctx.get_message_handle().await.to_typed::<String>() {
+        self.received.lock().await.push(msg);
+        self.notify.notify_one();
+      }
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_receive_guard_drops_messages_until_toggled() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let enabled = Arc::new(AtomicBool::new(false));
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_notify = Arc::new(Notify::new());
+
+    let cloned_enabled = enabled.clone();
+    let cloned_received = received.clone();
+    let cloned_received_notify = received_notify.clone();
+    let props = Props::from_async_actor_producer_with_opts(
+      move |_| {
+        let actor = RecordingActor {
+          received: cloned_received.clone(),
+          notify: cloned_received_notify.clone(),
+        };
+        async move { actor }
+      },
+      [Props::with_receive_guard(move |_| {
+        cloned_enabled.load(Ordering::SeqCst)
+      })],
+    )
+    .await;
+    let pid = root_context.spawn(props).await;
+
+    let dropped = Arc::new(Mutex::new(Vec::new()));
+    let dropped_notify = Arc::new(Notify::new());
+    let cloned_dropped = dropped.clone();
+    let cloned_dropped_notify = dropped_notify.clone();
+    let cloned_pid = pid.clone();
+    system
+      .get_event_stream()
+      .await
+      .subscribe(move |msg| {
+        let cloned_dropped = cloned_dropped.clone();
+        let cloned_dropped_notify = cloned_dropped_notify.clone();
+        let cloned_pid = cloned_pid.clone();
+        async move {
+          if let Some(dead_letter) = msg.to_typed::<DeadLetterEvent>() {
+            if dead_letter.pid.as_ref() == Some(&cloned_pid) {
+              if let Some(s) = dead_letter.message_handle.to_typed::<String>() {
+                cloned_dropped.lock().await.push(s);
+                cloned_dropped_notify.notify_one();
+              }
+            }
+          }
+        }
+      })
+      .await;
+
+    root_context
+      .send(pid.clone(), MessageHandle::new("blocked".to_string()))
+      .await;
+    tokio::time::timeout(Duration::from_secs(3), dropped_notify.notified())
+      .await
+      .expect("guarded message was not dead-lettered");
+    assert!(received.lock().await.is_empty());
+
+    enabled.store(true, Ordering::SeqCst);
+    root_context
+      .send(pid.clone(), MessageHandle::new("allowed".to_string()))
+      .await;
+    tokio::time::timeout(Duration::from_secs(3), received_notify.notified())
+      .await
+      .expect("message was not received after the guard was toggled");
+
+    assert_eq!(*received.lock().await, vec!["allowed".to_string()]);
+    assert_eq!(*dropped.lock().await, vec!["blocked".to_string()]);
+  }
+}
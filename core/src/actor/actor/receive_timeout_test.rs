@@ -5,11 +5,14 @@ pub mod tests {
   use crate::actor::actor::props::Props;
   use crate::actor::actor_system::ActorSystem;
   use crate::actor::context::ContextHandle;
-  use crate::actor::context::{BasePart, MessagePart, SpawnerPart, StopperPart};
-  use crate::actor::message::ReceiveTimeout;
+  use crate::actor::context::{BasePart, MessagePart, SenderPart, SpawnerPart, StopperPart};
+  use crate::actor::message::{MessageHandle, ReceiveTimeout, ResponseHandle};
   use async_trait::async_trait;
+  use nexus_actor_message_derive_rs::Message;
   use nexus_actor_utils_rs::concurrent::AsyncBarrier;
   use std::env;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  use std::sync::{Arc, Mutex};
   use std::time::Duration;
   use tracing_subscriber::EnvFilter;
 
@@ -70,4 +73,206 @@ pub mod tests {
 
     result.result().await.unwrap();
   }
+
+  #[derive(Debug, Clone)]
+  struct RemainingTimeoutActor {
+    observed: Arc<Mutex<Vec<Option<Duration>>>>,
+  }
+
+  #[async_trait]
+  impl Actor for RemainingTimeoutActor {
+    async fn receive(&mut self, ctx: ContextHandle) -> Result<(), ActorError> {
+      if ctx.get_message_handle().await.to_typed::<String>().is_some() {
+        let remaining = ctx.receive_timeout_remaining().await;
+        self.observed.lock().unwrap().push(remaining);
+        ctx.respond(ResponseHandle::new(true)).await;
+      }
+      Ok(())
+    }
+
+    async fn post_start(&mut self, mut ctx: ContextHandle) -> Result<(), ActorError> {
+      let remaining = ctx.receive_timeout_remaining().await;
+      self.observed.lock().unwrap().push(remaining);
+      ctx.set_receive_timeout(&Duration::from_secs(10)).await;
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_receive_timeout_remaining_decreases_between_messages() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let observed = Arc::new(Mutex::new(Vec::new()));
+    let cloned_observed = observed.clone();
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_producer(move |_| {
+          let observed = cloned_observed.clone();
+          async move { RemainingTimeoutActor { observed } }
+        })
+        .await,
+      )
+      .await;
+
+    for delay in [Duration::ZERO, Duration::from_millis(300)] {
+      tokio::time::sleep(delay).await;
+      root_context
+        .request_future(
+          pid.clone(),
+          MessageHandle::new("probe".to_string()),
+          Duration::from_secs(5),
+        )
+        .await
+        .result()
+        .await
+        .unwrap();
+    }
+
+    let observed = observed.lock().unwrap().clone();
+    assert_eq!(observed.len(), 3);
+    assert_eq!(observed[0], None);
+    let first = observed[1].unwrap();
+    let second = observed[2].unwrap();
+    assert!(first <= Duration::from_secs(10) && first > Duration::from_secs(9));
+    assert!(second < first, "{:?} is not less than {:?}", second, first);
+    assert!(second <= Duration::from_millis(9700));
+
+    root_context.stop_future(&pid).await.result().await.unwrap();
+  }
+
+  #[derive(Debug, Clone, PartialEq, Message)]
+  #[message(not_influence_receive_timeout)]
+  struct Heartbeat;
+
+  #[derive(Debug, Clone)]
+  struct TimeoutCountingActor {
+    timeouts: Arc<AtomicUsize>,
+  }
+
+  #[async_trait]
+  impl Actor for TimeoutCountingActor {
+    async fn receive(&mut self, ctx: ContextHandle) -> Result<(), ActorError> {
+      if ctx.get_message_handle().await.to_typed::<ReceiveTimeout>().is_some() {
+        self.timeouts.fetch_add(1, Ordering::SeqCst);
+      }
+      Ok(())
+    }
+
+    async fn post_start(&mut self, mut ctx: ContextHandle) -> Result<(), ActorError> {
+      ctx.set_receive_timeout(&Duration::from_millis(100)).await;
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_receive_timeout_ignores_not_influencing_messages() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let timeouts = Arc::new(AtomicUsize::new(0));
+    let cloned_timeouts = timeouts.clone();
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_producer(move |_| {
+          let timeouts = cloned_timeouts.clone();
+          async move { TimeoutCountingActor { timeouts } }
+        })
+        .await,
+      )
+      .await;
+
+    for _ in 0..15 {
+      root_context
+        .send(pid.clone(), MessageHandle::new("keep-alive".to_string()))
+        .await;
+      tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(timeouts.load(Ordering::SeqCst), 0);
+
+    for _ in 0..15 {
+      root_context.send(pid.clone(), MessageHandle::new(Heartbeat)).await;
+      tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(timeouts.load(Ordering::SeqCst) >= 1);
+
+    root_context.stop_future(&pid).await.result().await.unwrap();
+  }
+
+  #[derive(Debug, Clone)]
+  struct CancelReceiveTimeoutActor {
+    timeouts: Arc<AtomicUsize>,
+    observed: Arc<Mutex<Vec<Option<Duration>>>>,
+  }
+
+  #[async_trait]
+  impl Actor for CancelReceiveTimeoutActor {
+    async fn receive(&mut self, mut ctx: ContextHandle) -> Result<(), ActorError> {
+      let msg = ctx.get_message_handle().await;
+      if msg.to_typed::<ReceiveTimeout>().is_some() {
+        self.timeouts.fetch_add(1, Ordering::SeqCst);
+      } else if msg.to_typed::<String>().is_some() {
+        ctx.set_receive_timeout(&Duration::from_millis(100)).await;
+        ctx.cancel_receive_timeout().await;
+        ctx.set_receive_timeout(&Duration::from_secs(10)).await;
+        ctx.respond(ResponseHandle::new("rearmed".to_string())).await;
+      }
+      Ok(())
+    }
+
+    async fn post_start(&mut self, mut ctx: ContextHandle) -> Result<(), ActorError> {
+      ctx.set_receive_timeout(&Duration::from_millis(100)).await;
+      let before = ctx.get_receive_timeout().await;
+      ctx.cancel_receive_timeout().await;
+      let after = ctx.get_receive_timeout().await;
+      self.observed.lock().unwrap().extend([before, after]);
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_cancel_receive_timeout_stops_the_timer() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let timeouts = Arc::new(AtomicUsize::new(0));
+    let observed = Arc::new(Mutex::new(Vec::new()));
+    let cloned_timeouts = timeouts.clone();
+    let cloned_observed = observed.clone();
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_producer(move |_| {
+          let timeouts = cloned_timeouts.clone();
+          let observed = cloned_observed.clone();
+          async move { CancelReceiveTimeoutActor { timeouts, observed } }
+        })
+        .await,
+      )
+      .await;
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(timeouts.load(Ordering::SeqCst), 0);
+    assert_eq!(
+      observed.lock().unwrap().clone(),
+      vec![Some(Duration::from_millis(100)), None]
+    );
+
+    // a cancelled timer must not fire into a timeout that is set again before it would have expired
+    root_context
+      .request_future(
+        pid.clone(),
+        MessageHandle::new("rearm".to_string()),
+        Duration::from_secs(5),
+      )
+      .await
+      .result()
+      .await
+      .unwrap();
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(timeouts.load(Ordering::SeqCst), 0);
+
+    root_context.stop_future(&pid).await.result().await.unwrap();
+  }
 }
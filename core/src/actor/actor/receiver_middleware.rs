@@ -2,6 +2,7 @@ use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
 use crate::actor::actor::receiver_middleware_chain::ReceiverMiddlewareChain;
+use crate::actor::message::MessageEnvelope;
 
 #[allow(clippy::type_complexity)]
 #[derive(Clone)]
@@ -15,6 +16,20 @@ impl ReceiverMiddleware {
     ReceiverMiddleware(Arc::new(f))
   }
 
+  // Transform returns a middleware that rewrites each envelope before passing it down the chain,
+  // so the actor (and any later middleware) sees the transformed message
+  pub fn transform(f: impl Fn(MessageEnvelope) -> MessageEnvelope + Send + Sync + 'static) -> Self {
+    let f = Arc::new(f);
+    ReceiverMiddleware::new(move |next| {
+      let f = f.clone();
+      ReceiverMiddlewareChain::new(move |context, envelope| {
+        let next = next.clone();
+        let envelope = f(envelope);
+        async move { next.run(context, envelope).await }
+      })
+    })
+  }
+
   pub fn run(&self, next: ReceiverMiddlewareChain) -> ReceiverMiddlewareChain {
     (self.0)(next)
   }
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod tests {
+  use std::env;
+  use std::sync::Arc;
+  use std::time::Duration;
+
+  use crate::actor::actor::{Props, ReceiverMiddleware, ReceiverMiddlewareChain};
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{BasePart, MessagePart, SenderPart, SpawnerPart};
+  use crate::actor::message::MessageEnvelope;
+  use crate::actor::message::MessageHandle;
+  use crate::actor::message::ResponseHandle;
+  use nexus_actor_message_derive_rs::Message;
+  use tokio::sync::Mutex;
+  use tracing_subscriber::EnvFilter;
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Encoded(String);
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Greeting {
+    name: String,
+  }
+
+  #[tokio::test]
+  async fn test_receiver_middleware_transform() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let decode = ReceiverMiddleware::transform(|envelope| match envelope.get_message_handle().to_typed::<Encoded>() {
+      Some(Encoded(payload)) => {
+        let name = payload.strip_prefix("greeting:").unwrap_or(&payload).to_string();
+        envelope.with_message_handle(MessageHandle::new(Greeting { name }))
+      }
+      None => envelope,
+    });
+    let props = Props::from_async_actor_receiver_with_opts(
+      |ctx| async move {
+        let message_handle = ctx.get_message_handle().await;
+        if let Some(greeting) = message_handle.to_typed::<Greeting>() {
+          ctx
+            .respond(ResponseHandle::new(format!("hello {}", greeting.name)))
+            .await;
+        } else if message_handle.to_typed::<Encoded>().is_some() {
+          ctx.respond(ResponseHandle::new("not decoded".to_string())).await;
+        }
+        Ok(())
+      },
+      [Props::with_receiver_middlewares([decode])],
+    )
+    .await;
+    let pid = root_context.spawn(props).await;
+
+    let result = root_context
+      .request_future(
+        pid,
+        MessageHandle::new(Encoded("greeting:world".to_string())),
+        Duration::from_secs(1),
+      )
+      .await
+      .result()
+      .await
+      .unwrap();
+    assert_eq!(result.to_typed::<String>(), Some("hello world".to_string()));
+  }
+
+  #[tokio::test]
+  async fn test_receiver_middleware_reads_typed_message() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let seen = Arc::new(Mutex::new(vec![]));
+    let cloned_seen = seen.clone();
+    let record = ReceiverMiddleware::new(move |next| {
+      let seen = cloned_seen.clone();
+      ReceiverMiddlewareChain::new(move |context_handle, envelope: MessageEnvelope| {
+        let next = next.clone();
+        let seen = seen.clone();
+        async move {
+          if let Some(greeting) = envelope.typed_message::<Greeting>() {
+            seen.lock().await.push(greeting.name);
+          }
+          next.run(context_handle, envelope).await
+        }
+      })
+    });
+    let props = Props::from_async_actor_receiver_with_opts(
+      |ctx| async move {
+        if ctx.get_message_handle().await.to_typed::<Greeting>().is_some() {
+          ctx.respond(ResponseHandle::new("done".to_string())).await;
+        }
+        Ok(())
+      },
+      [Props::with_receiver_middlewares([record])],
+    )
+    .await;
+    let pid = root_context.spawn(props).await;
+
+    root_context
+      .request_future(
+        pid,
+        MessageHandle::new(Greeting {
+          name: "world".to_string(),
+        }),
+        Duration::from_secs(1),
+      )
+      .await
+      .result()
+      .await
+      .unwrap();
+    assert_eq!(*seen.lock().await, vec!["world".to_string()]);
+  }
+}
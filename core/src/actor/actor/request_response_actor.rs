@@ -0,0 +1,41 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use async_trait::async_trait;
+
+use crate::actor::actor::{Actor, ActorError};
+use crate::actor::context::{BasePart, ContextHandle, MessagePart};
+use crate::actor::message::{Message, ResponseHandle};
+
+// RequestResponseActor answers every message of type T with an R. RequestResponseActorWrapper sends the R back to
+// the sender of the message, or to dead letters when it has none. Messages of other types are ignored
+#[async_trait]
+pub trait RequestResponseActor<T: Message + Clone, R: Message>: Debug + Send + Sync + 'static {
+  async fn handle(&mut self, message: T) -> Result<R, ActorError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct RequestResponseActorWrapper<A: RequestResponseActor<T, R>, T: Message + Clone, R: Message> {
+  actor: A,
+  phantom_data: PhantomData<(T, R)>,
+}
+
+impl<A: RequestResponseActor<T, R>, T: Message + Clone, R: Message> RequestResponseActorWrapper<A, T, R> {
+  pub fn new(actor: A) -> Self {
+    Self {
+      actor,
+      phantom_data: PhantomData,
+    }
+  }
+}
+
+#[async_trait]
+impl<A: RequestResponseActor<T, R>, T: Message + Clone, R: Message> Actor for RequestResponseActorWrapper<A, T, R> {
+  async fn receive(&mut self, context_handle: ContextHandle) -> Result<(), ActorError> {
+    if let Some(message) = context_handle.get_message_handle().await.to_typed::<T>() {
+      let response = self.actor.handle(message).await?;
+      context_handle.respond(ResponseHandle::new(response)).await;
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+  use crate::actor::actor::{ActorError, Props, RequestResponseActor};
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{SenderPart, SpawnerPart};
+  use crate::actor::message::MessageHandle;
+  use async_trait::async_trait;
+  use nexus_actor_message_derive_rs::Message;
+  use std::env;
+  use std::time::Duration;
+  use tracing_subscriber::EnvFilter;
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Add {
+    a: i64,
+    b: i64,
+  }
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Sum {
+    value: i64,
+  }
+
+  #[derive(Debug)]
+  struct Calculator;
+
+  #[async_trait]
+  impl RequestResponseActor<Add, Sum> for Calculator {
+    async fn handle(&mut self, message: Add) -> Result<Sum, ActorError> {
+      Ok(Sum {
+        value: message.a + message.b,
+      })
+    }
+  }
+
+  #[tokio::test]
+  async fn test_request_response_actor_replies_to_sender() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let pid = root_context
+      .spawn(Props::from_request_response_actor(|_| async { Calculator }).await)
+      .await;
+
+    let response = root_context
+      .request_future(pid, MessageHandle::new(Add { a: 2, b: 40 }), Duration::from_secs(3))
+      .await
+      .result()
+      .await
+      .unwrap();
+
+    assert_eq!(response.to_typed::<Sum>(), Some(Sum { value: 42 }));
+  }
+}
@@ -0,0 +1,6 @@
+// Snapshotable is implemented by actors that expose their state for diagnostics.
+// Return `Some(self)` from Actor::as_snapshotable and spawn with Props::with_snapshot to report it in
+// `diagnostics_json`
+pub trait Snapshotable: Send + Sync {
+  fn snapshot(&self) -> serde_json::Value;
+}
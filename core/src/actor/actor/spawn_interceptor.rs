@@ -0,0 +1,34 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::actor::actor::props::Props;
+
+// SpawnInterceptor adjusts the props of every actor spawned by the default spawner of an actor system
+#[derive(Clone)]
+pub struct SpawnInterceptor(Arc<dyn Fn(&mut Props) + Send + Sync + 'static>);
+
+impl SpawnInterceptor {
+  pub fn new(f: impl Fn(&mut Props) + Send + Sync + 'static) -> Self {
+    SpawnInterceptor(Arc::new(f))
+  }
+
+  pub fn run(&self, props: &mut Props) {
+    self.0(props)
+  }
+}
+
+impl Debug for SpawnInterceptor {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "SpawnInterceptor")
+  }
+}
+
+impl PartialEq for SpawnInterceptor {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl Eq for SpawnInterceptor {}
+
+static_assertions::assert_impl_all!(SpawnInterceptor: Send, Sync);
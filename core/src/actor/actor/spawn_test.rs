@@ -1,8 +1,9 @@
 #![cfg(test)]
 mod tests {
   use std::env;
-  use std::sync::atomic::{AtomicBool, Ordering};
-  use std::sync::Arc;
+  use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+  use std::sync::{Arc, Mutex};
+  use std::time::Duration;
 
   use async_trait::async_trait;
   use tokio::sync::Notify;
@@ -11,10 +12,15 @@ mod tests {
   use crate::actor::actor::actor::Actor;
   use crate::actor::actor::actor_error::ActorError;
   use crate::actor::actor::props::Props;
+  use crate::actor::actor::spawn_middleware::SpawnMiddleware;
+  use crate::actor::actor::spawner::Spawner;
   use crate::actor::actor_system::ActorSystem;
   use crate::actor::context::ContextHandle;
-  use crate::actor::context::SpawnerPart;
+  use crate::actor::context::{MessagePart, SenderPart, SpawnerPart};
+  use crate::actor::dispatch::bounded_mailbox_creator;
+  use crate::actor::message::MessageHandle;
   use crate::actor::supervisor::SupervisorStrategyHandle;
+  use crate::actor::ConfigOption;
 
   #[derive(Debug, Clone)]
   struct MyActor {
@@ -72,4 +78,123 @@ mod tests {
 
     assert_eq!(actor.is_started.load(Ordering::SeqCst), true);
   }
+
+  #[tokio::test]
+  async fn test_spawn_uses_props_name_generator() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let sequence = Arc::new(AtomicU64::new(1));
+    let props = Props::from_async_actor_receiver_with_opts(
+      |_| async { Ok(()) },
+      [Props::with_name_generator(move |_| {
+        format!("order-{}", sequence.fetch_add(1, Ordering::SeqCst))
+      })],
+    )
+    .await;
+
+    // Take the first generated name so the spawner has to retry
+    root_context.spawn_named(props.clone(), "order-1").await.unwrap();
+
+    let first = root_context.spawn(props.clone()).await;
+    let second = root_context.spawn(props).await;
+
+    assert_eq!(first.id(), "order-2");
+    assert_eq!(second.id(), "order-3");
+  }
+
+  #[tokio::test]
+  async fn test_spawn_interceptor_forces_bounded_mailbox() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new_config_options([ConfigOption::with_spawn_interceptor(|props| {
+      props.set_mailbox_producer(bounded_mailbox_creator(1, true));
+    })])
+    .await
+    .unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let entered = Arc::new(Notify::new());
+    let gate = Arc::new(Notify::new());
+    let received = Arc::new(Mutex::new(vec![]));
+    let cloned_entered = entered.clone();
+    let cloned_gate = gate.clone();
+    let cloned_received = received.clone();
+    let child_props = Props::from_async_actor_receiver(move |ctx| {
+      let entered = cloned_entered.clone();
+      let gate = cloned_gate.clone();
+      let received = cloned_received.clone();
+      async move {
+        if let Some(n) = ctx.get_message_handle().await.to_typed::<i32>() {
+          if n == 0 {
+            entered.notify_one();
+            gate.notified().await;
+          }
+          received.lock().unwrap().push(n);
+        }
+        Ok(())
+      }
+    })
+    .await;
+
+    // The parent's spawn middleware wraps the default spawner, so the interceptor still applies to its children
+    let middleware_runs = Arc::new(AtomicUsize::new(0));
+    let cloned_middleware_runs = middleware_runs.clone();
+    let child_pid = Arc::new(Mutex::new(None));
+    let cloned_child_pid = child_pid.clone();
+    let spawned = Arc::new(Notify::new());
+    let cloned_spawned = spawned.clone();
+    let parent_props = Props::from_async_actor_receiver_with_opts(
+      move |mut ctx| {
+        let child_props = child_props.clone();
+        let child_pid = cloned_child_pid.clone();
+        let spawned = cloned_spawned.clone();
+        async move {
+          if ctx.get_message_handle().await.to_typed::<u32>().is_some() {
+            let pid = ctx.spawn(child_props).await;
+            *child_pid.lock().unwrap() = Some(pid);
+            spawned.notify_one();
+          }
+          Ok(())
+        }
+      },
+      [Props::with_spawn_middleware([SpawnMiddleware::new(move |next| {
+        let middleware_runs = cloned_middleware_runs.clone();
+        Spawner::new(move |s, id, p, sch| {
+          let next = next.clone();
+          middleware_runs.fetch_add(1, Ordering::SeqCst);
+          async move { next.run(s, &id, p, sch).await }
+        })
+      })])],
+    )
+    .await;
+
+    let parent_pid = root_context.spawn(parent_props).await;
+    root_context.send(parent_pid, MessageHandle::new(0u32)).await;
+    spawned.notified().await;
+    let pid = child_pid.lock().unwrap().clone().unwrap();
+
+    root_context.send(pid.clone(), MessageHandle::new(0)).await;
+    entered.notified().await;
+    // The actor is blocked on the first message, so a mailbox of capacity 1 keeps only the newest one
+    for n in 1..=5 {
+      root_context.send(pid.clone(), MessageHandle::new(n)).await;
+    }
+    gate.notify_one();
+    while received.lock().unwrap().len() < 2 {
+      tokio::time::sleep(Duration::from_millis(1)).await;
+    }
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(*received.lock().unwrap(), vec![0, 5]);
+    assert_eq!(middleware_runs.load(Ordering::SeqCst), 1);
+  }
 }
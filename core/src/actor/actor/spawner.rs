@@ -16,6 +16,8 @@ pub enum SpawnError {
   ErrNameExists(ExtendedPid),
   #[error("Actor error: {0}")]
   ErrPreStart(ActorError),
+  #[error("Failed to start dispatcher: {0}")]
+  ErrDispatcher(String),
 }
 
 #[derive(Clone)]
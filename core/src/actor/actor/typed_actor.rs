@@ -1,9 +1,9 @@
-use crate::actor::actor::{Actor, ActorError};
+use crate::actor::actor::{Actor, ActorError, ErrorReason, ExtendedPid};
 use crate::actor::context::{ContextHandle, TypedContextHandle};
 use crate::actor::message::{AutoReceiveMessage, Message};
 use crate::actor::supervisor::SupervisorStrategyHandle;
 use crate::actor::typed_context::TypedMessagePart;
-use crate::generated::actor::Terminated;
+use crate::generated::actor::{Terminated, TerminatedReason};
 use async_trait::async_trait;
 use std::fmt::Debug;
 use std::marker::PhantomData;
@@ -20,7 +20,10 @@ pub trait TypedActor<M: Message + Clone>: Debug + Send + Sync + 'static {
         AutoReceiveMessage::PreStart => self.pre_start(context_handle).await,
         AutoReceiveMessage::PostStart => self.post_start(context_handle).await,
         AutoReceiveMessage::PreRestart => self.pre_restart(context_handle).await,
-        AutoReceiveMessage::PostRestart => self.post_restart(context_handle).await,
+        AutoReceiveMessage::PostRestart => {
+          let reason = context_handle.get_underlying().get_restart_reason().await;
+          self.post_restart_with_reason(context_handle, reason).await
+        }
         AutoReceiveMessage::PreStop => self.pre_stop(context_handle).await,
         AutoReceiveMessage::PostStop => self.post_stop(context_handle).await,
         AutoReceiveMessage::Terminated(t) => self.post_child_terminate(context_handle, &t).await,
@@ -55,6 +58,16 @@ pub trait TypedActor<M: Message + Clone>: Debug + Send + Sync + 'static {
     self.pre_start(context_handle).await
   }
 
+  // PostRestartWithReason receives the failure that caused the restart, None when the actor did not fail
+  // itself. It defaults to post_restart
+  async fn post_restart_with_reason(
+    &mut self,
+    context_handle: TypedContextHandle<M>,
+    _: Option<ErrorReason>,
+  ) -> Result<(), ActorError> {
+    self.post_restart(context_handle).await
+  }
+
   //#[instrument]
   async fn pre_stop(&mut self, _: TypedContextHandle<M>) -> Result<(), ActorError> {
     tracing::debug!("Actor::pre_stop");
@@ -68,8 +81,30 @@ pub trait TypedActor<M: Message + Clone>: Debug + Send + Sync + 'static {
   }
 
   //#[instrument]
-  async fn post_child_terminate(&mut self, _: TypedContextHandle<M>, _: &Terminated) -> Result<(), ActorError> {
+  async fn post_child_terminate(
+    &mut self,
+    context_handle: TypedContextHandle<M>,
+    terminated: &Terminated,
+  ) -> Result<(), ActorError> {
     tracing::debug!("Actor::post_child_terminate");
+    match &terminated.who {
+      Some(who) => {
+        self
+          .on_child_terminated(context_handle, &ExtendedPid::new(who.clone()), terminated.why())
+          .await
+      }
+      None => Ok(()),
+    }
+  }
+
+  //#[instrument]
+  async fn on_child_terminated(
+    &mut self,
+    _: TypedContextHandle<M>,
+    _: &ExtendedPid,
+    _: TerminatedReason,
+  ) -> Result<(), ActorError> {
+    tracing::debug!("Actor::on_child_terminated");
     Ok(())
   }
 
@@ -126,6 +161,15 @@ impl<A: TypedActor<M>, M: Message + Clone> Actor for TypedActorWrapper<A, M> {
     self.actor.post_restart(typed_context_handle).await
   }
 
+  async fn post_restart_with_reason(
+    &mut self,
+    context_handle: ContextHandle,
+    reason: Option<ErrorReason>,
+  ) -> Result<(), ActorError> {
+    let typed_context_handle = TypedContextHandle::new(context_handle);
+    self.actor.post_restart_with_reason(typed_context_handle, reason).await
+  }
+
   async fn pre_stop(&mut self, context_handle: ContextHandle) -> Result<(), ActorError> {
     let typed_context_handle = TypedContextHandle::new(context_handle);
     self.actor.pre_stop(typed_context_handle).await
@@ -1,5 +1,5 @@
 use crate::actor::actor::typed_actor::TypedActor;
-use crate::actor::actor::{Actor, ActorError, ActorHandle};
+use crate::actor::actor::{Actor, ActorError, ActorHandle, ErrorReason};
 use crate::actor::context::TypedContextHandle;
 use crate::actor::message::Message;
 use crate::actor::supervisor::SupervisorStrategyHandle;
@@ -104,6 +104,17 @@ impl<M: Message + Clone> TypedActor<M> for TypeWrapperActorHandle<M> {
       .await
   }
 
+  async fn post_restart_with_reason(
+    &mut self,
+    context_handle: TypedContextHandle<M>,
+    reason: Option<ErrorReason>,
+  ) -> Result<(), ActorError> {
+    self
+      .underlying
+      .post_restart_with_reason(context_handle.get_underlying().clone(), reason)
+      .await
+  }
+
   async fn pre_stop(&mut self, context_handle: TypedContextHandle<M>) -> Result<(), ActorError> {
     self.underlying.pre_stop(context_handle.get_underlying().clone()).await
   }
@@ -22,13 +22,13 @@ impl<M: Message + Clone> TypedActorProducer<M> {
     A: TypedActor<M>,
     F: Fn(TypedContextHandle<M>) -> Fut + Clone + Send + Sync + 'static,
     Fut: Future<Output = A> + Send + 'static, {
-    let p = ActorProducer::new(move |c| {
+    let p = ActorProducer::from_handle(move |c| {
       let f = f.clone();
       async move {
         let ctx = TypedContextHandle::new(c);
         let a = f(ctx).await;
         let a = TypedActorWrapper::new(a);
-        ActorHandle::new(a)
+        ActorHandle::new_typed::<M>(a)
       }
     });
     Self {
@@ -1,4 +1,4 @@
-use crate::actor::actor::typed_actor::{TypedActor, TypedActorWrapper};
+use crate::actor::actor::typed_actor::TypedActor;
 use crate::actor::actor::typed_actor_producer::TypedActorProducer;
 use crate::actor::actor::typed_actor_receiver::TypedActorReceiver;
 use crate::actor::actor::{ActorHandle, ActorProducer, ActorReceiverActor, Props, PropsOption};
@@ -38,20 +38,10 @@ impl<M: Message + Clone> TypedProps<M> {
     A: TypedActor<M>,
     F: Fn(TypedContextHandle<M>) -> Fut + Clone + Send + Sync + 'static,
     Fut: Future<Output = A> + Send + 'static, {
-    Props::from_async_actor_producer_with_opts(
-      move |c| {
-        let f = f.clone();
-        async move {
-          let ctx = TypedContextHandle::new(c);
-          let a = f(ctx).await;
-          let a = TypedActorWrapper::new(a);
-          ActorHandle::new(a)
-        }
-      },
-      opts,
-    )
-    .await
-    .into()
+    let producer = TypedActorProducer::new(f);
+    Props::from_actor_producer_with_opts(producer.get_underlying().clone(), opts)
+      .await
+      .into()
   }
 
   pub async fn from_sync_actor_producer<A, F>(f: F) -> TypedProps<M>
@@ -98,15 +88,15 @@ impl<M: Message + Clone> TypedProps<M> {
   where
     F: Fn(TypedContextHandle<M>) -> Fut + Send + Sync + 'static,
     Fut: Future<Output = Result<(), crate::actor::actor::ActorError>> + Send + 'static, {
-    Props::from_async_actor_receiver_with_opts(
-      move |c| {
-        let r = f(TypedContextHandle::new(c));
-        Box::pin(r) as futures::future::BoxFuture<'static, Result<(), crate::actor::actor::ActorError>>
-      },
-      opts,
-    )
-    .await
-    .into()
+    let actor_receiver = TypedActorReceiver::new(f);
+    let producer = ActorProducer::from_handle(move |_| {
+      let actor_receiver = actor_receiver.clone();
+      async move {
+        let actor = ActorReceiverActor::new(actor_receiver.get_underlying().clone());
+        ActorHandle::new_typed::<M>(actor)
+      }
+    });
+    Props::from_actor_producer_with_opts(producer, opts).await.into()
   }
 
   pub async fn from_sync_actor_receiver<F>(f: F) -> TypedProps<M>
@@ -120,10 +110,7 @@ impl<M: Message + Clone> TypedProps<M> {
     .await
   }
 
-  pub async fn from_sync_actor_receiver_with_opts<F>(
-    f: F,
-    opts: impl IntoIterator<Item = PropsOption>,
-  ) -> TypedProps<M>
+  pub async fn from_sync_actor_receiver_with_opts<F>(f: F, opts: impl IntoIterator<Item = PropsOption>) -> TypedProps<M>
   where
     F: Fn(TypedContextHandle<M>) -> Result<(), crate::actor::actor::ActorError> + Send + Sync + 'static, {
     let f = Arc::new(f);
@@ -150,7 +137,7 @@ impl<M: Message + Clone> TypedProps<M> {
         let actor_receiver = actor_receiver.clone();
         async move {
           let actor = ActorReceiverActor::new(actor_receiver.get_underlying().clone());
-          ActorHandle::new(actor)
+          ActorHandle::new_typed::<M>(actor)
         }
       }));
     })
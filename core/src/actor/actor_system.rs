@@ -1,20 +1,26 @@
 use opentelemetry::metrics::MetricsError;
+use std::future::Future;
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::{oneshot, Mutex, Semaphore};
 use uuid::Uuid;
 
-use crate::actor::actor::ExtendedPid;
-use crate::actor::context::{RootContext, TypedRootContext};
-use crate::actor::dispatch::DeadLetterProcess;
+use crate::actor::actor::{ActorProcess, ExtendedPid};
+use crate::actor::context::{RootContext, StopperPart, TypedRootContext};
+use crate::actor::dispatch::{
+  ConcurrencyLimitedDispatcher, DeadLetterEvent, DeadLetterProcess, Dispatcher, DispatcherHandle, Mailbox,
+  MailboxHandle, MailboxMessage, Runnable,
+};
 use crate::actor::event_stream::EventStreamProcess;
 use crate::actor::guardian::GuardiansValue;
-use crate::actor::message::EMPTY_MESSAGE_HEADER;
+use crate::actor::message::{MessageHandle, MessageSizer, EMPTY_MESSAGE_HEADER};
 use crate::actor::metrics::metrics_impl::Metrics;
 use crate::actor::process::process_registry::ProcessRegistry;
-use crate::actor::process::ProcessHandle;
+use crate::actor::process::{Process, ProcessHandle};
 use crate::actor::supervisor::subscribe_supervision;
-use crate::actor::{Config, ConfigOption};
-use crate::event_stream::EventStream;
+use crate::actor::{Config, ConfigOption, Scheduler, ShutdownHook};
+use crate::event_stream::{EventStream, Subscription, TopicRegistry};
 use crate::extensions::Extensions;
 use crate::generated::actor::Pid;
 
@@ -23,49 +29,140 @@ struct ActorSystemInner {
   process_registry: Option<ProcessRegistry>,
   root_context: Option<RootContext>,
   event_stream: Arc<EventStream>,
+  topic_registry: TopicRegistry,
   guardians: Option<GuardiansValue>,
   dead_letter: Option<DeadLetterProcess>,
   extensions: Extensions,
   config: Config,
+  mailbox_dispatcher: DispatcherHandle,
+  shutdown_hooks: Vec<ShutdownHook>,
   id: String,
 }
 
 impl ActorSystemInner {
   async fn new(config: Config) -> Self {
     let id = Uuid::new_v4().to_string();
+    let mailbox_dispatcher = match config.max_concurrent_mailboxes {
+      0 => DispatcherHandle::new_arc(config.system_dispatcher.clone()),
+      max => DispatcherHandle::new(ConcurrencyLimitedDispatcher::new(
+        config.system_dispatcher.clone(),
+        Arc::new(Semaphore::new(max)),
+      )),
+    };
+    let event_stream = Arc::new(EventStream::new());
     Self {
       id: id.clone(),
       config,
+      mailbox_dispatcher,
       process_registry: None,
       root_context: None,
       guardians: None,
-      event_stream: Arc::new(EventStream::new()),
+      event_stream: event_stream.clone(),
+      topic_registry: TopicRegistry::new(event_stream),
       dead_letter: None,
       extensions: Extensions::new(),
+      shutdown_hooks: vec![],
     }
   }
 }
 
+// StopSummary reports which pids of an ActorSystem::stop_all call terminated within the timeout.
+// Pids whose termination could not be awaited, for example because of max_pending_futures, count as not stopped.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StopSummary {
+  pub stopped: Vec<ExtendedPid>,
+  pub not_stopped: Vec<ExtendedPid>,
+}
+
+impl StopSummary {
+  pub fn all_stopped(&self) -> bool {
+    self.not_stopped.is_empty()
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+  Ready,
+  NotReady,
+}
+
+// Health is a point-in-time summary of the actor system, cheap enough to serve a readiness probe
+#[derive(Debug, Clone, PartialEq)]
+pub struct Health {
+  pub status: HealthStatus,
+  pub dispatcher_running: bool,
+  pub process_count: usize,
+  pub dead_letter_rate: f64,
+  pub pending_futures: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SetThroughputError {
+  #[error("Throughput must be positive: {0}")]
+  InvalidThroughput(i32),
+  #[error("Pid is not local: {0}")]
+  NotLocal(ExtendedPid),
+  #[error("Actor is not alive: {0}")]
+  NotAlive(ExtendedPid),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RebindDispatcherError {
+  #[error("Pid is not local: {0}")]
+  NotLocal(ExtendedPid),
+  #[error("Actor is not alive: {0}")]
+  NotAlive(ExtendedPid),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SuspendError {
+  #[error("Pid is not local: {0}")]
+  NotLocal(ExtendedPid),
+  #[error("Actor is not alive: {0}")]
+  NotAlive(ExtendedPid),
+}
+
+#[derive(Debug, Error)]
+pub enum SystemInitError {
+  #[error("Failed to initialize metrics: {0}")]
+  Metrics(#[from] MetricsError),
+  #[error("Actor system startup timed out after {0:?}")]
+  Timeout(Duration),
+}
+
 #[derive(Debug, Clone)]
 pub struct ActorSystem {
   inner: Arc<Mutex<ActorSystemInner>>,
 }
 
 impl ActorSystem {
-  pub async fn new() -> Result<Self, MetricsError> {
+  pub async fn new() -> Result<Self, SystemInitError> {
     Self::new_config_options([]).await
   }
 
-  pub async fn new_config_options(options: impl IntoIterator<Item = ConfigOption>) -> Result<Self, MetricsError> {
+  pub async fn new_config_options(options: impl IntoIterator<Item = ConfigOption>) -> Result<Self, SystemInitError> {
     let options = options.into_iter().collect::<Vec<_>>();
     let config = Config::from(options);
     Self::new_with_config(config).await
   }
 
-  pub async fn new_with_config(config: Config) -> Result<Self, MetricsError> {
+  pub async fn new_with_config(config: Config) -> Result<Self, SystemInitError> {
+    match config.startup_timeout {
+      Some(timeout) => tokio::time::timeout(timeout, Self::initialize(config))
+        .await
+        .map_err(|_| SystemInitError::Timeout(timeout))?,
+      None => Self::initialize(config).await,
+    }
+  }
+
+  async fn initialize(config: Config) -> Result<Self, SystemInitError> {
     let system = Self {
       inner: Arc::new(Mutex::new(ActorSystemInner::new(config.clone()).await)),
     };
+    // A dispatcher that never runs work would hang this forever, so it is only probed when there is a deadline
+    if config.startup_timeout.is_some() {
+      system.wait_for_dispatcher().await;
+    }
     system
       .set_root_context(RootContext::new(system.clone(), EMPTY_MESSAGE_HEADER.clone(), &[]))
       .await;
@@ -95,6 +192,19 @@ impl ActorSystem {
     Ok(system)
   }
 
+  async fn wait_for_dispatcher(&self) {
+    let (tx, rx) = oneshot::channel();
+    // A closed dispatcher drops the sender, so the receiver does not wait forever
+    let _ = self
+      .get_mailbox_dispatcher()
+      .await
+      .schedule_with_backoff(Runnable::new(move || async move {
+        let _ = tx.send(());
+      }))
+      .await;
+    let _ = rx.await;
+  }
+
   pub async fn new_local_pid(&self, id: &str) -> ExtendedPid {
     let pr = self.get_process_registry().await;
     let pid = Pid {
@@ -119,6 +229,25 @@ impl ActorSystem {
     inner_mg.config.clone()
   }
 
+  // Reads the configured message sizer without cloning the rest of the config, it is needed for every message
+  // received by an actor with a max message bytes limit
+  pub(crate) async fn get_message_sizer(&self) -> Option<MessageSizer> {
+    let inner_mg = self.inner.lock().await;
+    inner_mg.config.message_sizer.clone()
+  }
+
+  // Reads the propagated header keys without cloning the rest of the config, they are needed for every message
+  // sent from a handler
+  pub(crate) async fn get_propagated_headers(&self) -> Arc<[String]> {
+    let inner_mg = self.inner.lock().await;
+    inner_mg.config.propagated_headers.clone()
+  }
+
+  pub(crate) async fn get_mailbox_dispatcher(&self) -> DispatcherHandle {
+    let inner_mg = self.inner.lock().await;
+    inner_mg.mailbox_dispatcher.clone()
+  }
+
   pub async fn get_root_context(&self) -> RootContext {
     let inner_mg = self.inner.lock().await;
     inner_mg.root_context.as_ref().unwrap().clone()
@@ -128,6 +257,10 @@ impl ActorSystem {
     self.get_root_context().await.to_typed()
   }
 
+  pub fn get_scheduler(&self) -> Scheduler {
+    Scheduler::new(self.clone())
+  }
+
   pub async fn get_dead_letter(&self) -> ProcessHandle {
     let inner_mg = self.inner.lock().await;
     let dead_letter = inner_mg.dead_letter.as_ref().unwrap().clone();
@@ -144,6 +277,21 @@ impl ActorSystem {
     inner_mg.event_stream.clone()
   }
 
+  // SubscribeDeadLetters passes every DeadLetterEvent published to the event stream to the handler,
+  // whether the target was stopped or never existed
+  pub async fn subscribe_dead_letters<F, Fut>(&self, f: F) -> Subscription
+  where
+    F: Fn(&DeadLetterEvent) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static, {
+    self.get_event_stream().await.subscribe_typed(f).await
+  }
+
+  // GetTopicRegistry returns the registry of the typed topics published on the system event stream
+  pub async fn get_topic_registry(&self) -> TopicRegistry {
+    let inner_mg = self.inner.lock().await;
+    inner_mg.topic_registry.clone()
+  }
+
   pub async fn get_guardians(&self) -> GuardiansValue {
     let inner_mg = self.inner.lock().await;
     inner_mg.guardians.as_ref().unwrap().clone()
@@ -173,4 +321,195 @@ impl ActorSystem {
     let inner_mg = self.inner.lock().await;
     inner_mg.extensions.clone()
   }
+
+  // Reads the health counters under a single short lock. The dead letter rate is per second, over the last
+  // complete dead letter throttle interval. The system is ready once the dispatcher runs, the root context and
+  // guardians are started, the dead letter process is registered and pending futures are below the high-water mark
+  pub async fn health(&self) -> Health {
+    let inner_mg = self.inner.lock().await;
+    let dispatcher_running = inner_mg.mailbox_dispatcher.is_running();
+    let (process_count, pending_futures) = inner_mg
+      .process_registry
+      .as_ref()
+      .map_or((0, 0), |pr| (pr.get_process_count(), pr.get_pending_futures()));
+    let dead_letter_rate = inner_mg
+      .dead_letter
+      .as_ref()
+      .map_or(0.0, |dead_letter| dead_letter.get_dead_letter_rate());
+    let dead_letter_registered = inner_mg.dead_letter.is_some()
+      && inner_mg
+        .process_registry
+        .as_ref()
+        .is_some_and(|pr| pr.has_local_process("deadletter"));
+    let high_water_mark = inner_mg.config.future_high_water_mark;
+    let futures_below_mark = high_water_mark == 0 || pending_futures < high_water_mark;
+    let ready = dispatcher_running
+      && inner_mg.root_context.is_some()
+      && inner_mg.guardians.is_some()
+      && dead_letter_registered
+      && futures_below_mark;
+    Health {
+      status: if ready {
+        HealthStatus::Ready
+      } else {
+        HealthStatus::NotReady
+      },
+      dispatcher_running,
+      process_count,
+      dead_letter_rate,
+      pending_futures,
+    }
+  }
+
+  // Changes how many messages the actor's mailbox processes before yielding, without restarting the actor
+  pub async fn set_actor_throughput(&self, pid: &ExtendedPid, throughput: i32) -> Result<(), SetThroughputError> {
+    if throughput <= 0 {
+      return Err(SetThroughputError::InvalidThroughput(throughput));
+    }
+    if !self.get_process_registry().await.is_local_pid(pid).await {
+      return Err(SetThroughputError::NotLocal(pid.clone()));
+    }
+    match self.find_actor_mailbox(pid).await {
+      Some(mailbox) => {
+        mailbox.set_throughput(throughput).await;
+        Ok(())
+      }
+      None => Err(SetThroughputError::NotAlive(pid.clone())),
+    }
+  }
+
+  // Moves the actor's mailbox to another dispatcher without restarting the actor.
+  // Queued messages are kept and processed in order once the message in flight has finished
+  pub async fn rebind_dispatcher(
+    &self,
+    pid: &ExtendedPid,
+    dispatcher: DispatcherHandle,
+  ) -> Result<(), RebindDispatcherError> {
+    if !self.get_process_registry().await.is_local_pid(pid).await {
+      return Err(RebindDispatcherError::NotLocal(pid.clone()));
+    }
+    match self.find_actor_mailbox(pid).await {
+      Some(mailbox) => {
+        mailbox.set_dispatcher(dispatcher).await;
+        Ok(())
+      }
+      None => Err(RebindDispatcherError::NotAlive(pid.clone())),
+    }
+  }
+
+  // Pauses processing of the actor's user messages, which queue up until resume is called.
+  // System messages are still processed, so the actor can be watched, restarted or stopped meanwhile.
+  // This is independent of the suspension used by supervision: neither lifts the other
+  pub async fn suspend(&self, pid: &ExtendedPid) -> Result<(), SuspendError> {
+    self.post_mailbox_message(pid, MailboxMessage::SuspendMailboxExternally).await
+  }
+
+  // Resumes processing of the user messages queued while the actor was suspended
+  pub async fn resume(&self, pid: &ExtendedPid) -> Result<(), SuspendError> {
+    self.post_mailbox_message(pid, MailboxMessage::ResumeMailboxExternally).await
+  }
+
+  async fn post_mailbox_message(&self, pid: &ExtendedPid, message: MailboxMessage) -> Result<(), SuspendError> {
+    if !self.get_process_registry().await.is_local_pid(pid).await {
+      return Err(SuspendError::NotLocal(pid.clone()));
+    }
+    match self.find_actor_mailbox(pid).await {
+      Some(mailbox) => {
+        mailbox.post_system_message(MessageHandle::new(message)).await;
+        Ok(())
+      }
+      None => Err(SuspendError::NotAlive(pid.clone())),
+    }
+  }
+
+  async fn find_actor_mailbox(&self, pid: &ExtendedPid) -> Option<MailboxHandle> {
+    let process = self.get_process_registry().await.find_process(pid).await?;
+    process
+      .as_any()
+      .downcast_ref::<ActorProcess>()
+      .filter(|actor_process| !actor_process.is_dead())
+      .map(|actor_process| actor_process.get_mailbox())
+  }
+
+  // Waits until every local actor's mailbox is empty and no mailbox is scheduled or processing a message, which
+  // covers the mailboxes waiting on the dispatcher. The system must be seen idle twice in a row, so that a message
+  // sent while the mailboxes were being checked is not missed. Returns false if the timeout elapses first
+  pub async fn await_quiescent(&self, timeout: Duration) -> bool {
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut idle_checks = 0;
+    loop {
+      if self.is_quiescent().await {
+        idle_checks += 1;
+        if idle_checks == 2 {
+          return true;
+        }
+      } else {
+        idle_checks = 0;
+      }
+      if tokio::time::Instant::now() >= deadline {
+        return false;
+      }
+      tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+  }
+
+  async fn is_quiescent(&self) -> bool {
+    for process in self.get_process_registry().await.get_local_processes() {
+      if let Some(actor_process) = process.as_any().downcast_ref::<ActorProcess>() {
+        if !actor_process.is_dead() && !actor_process.get_mailbox().is_idle().await {
+          return false;
+        }
+      }
+    }
+    true
+  }
+
+  // Wraps the message with the envelope factory of the config, if one is set
+  pub(crate) async fn apply_envelope_factory(&self, message_handle: MessageHandle) -> MessageHandle {
+    match self.get_config().await.envelope_factory {
+      Some(envelope_factory) => MessageHandle::new(envelope_factory.run(message_handle)),
+      None => message_handle,
+    }
+  }
+
+  // Stops all pids at once and waits for their Terminated notifications, sharing a single timeout
+  pub async fn stop_all(&self, pids: impl IntoIterator<Item = ExtendedPid>, timeout: Duration) -> StopSummary {
+    let mut root_context = self.get_root_context().await;
+    let mut futures = vec![];
+    for pid in pids {
+      let future = root_context.stop_future_with_timeout(&pid, timeout).await;
+      futures.push((pid, future));
+    }
+
+    let mut summary = StopSummary::default();
+    for (pid, future) in futures {
+      match future.result().await {
+        Ok(_) => summary.stopped.push(pid),
+        Err(_) => summary.not_stopped.push(pid),
+      }
+    }
+    summary
+  }
+
+  // Registers a hook that ActorSystem::shutdown runs. Hooks run one after another, the last registered first
+  pub async fn register_shutdown_hook(&self, hook: ShutdownHook) {
+    let mut inner_mg = self.inner.lock().await;
+    inner_mg.shutdown_hooks.push(hook);
+  }
+
+  // Runs the registered shutdown hooks, sharing a single timeout. Hooks still running at the deadline are dropped
+  pub async fn shutdown(&self, timeout: Duration) {
+    let hooks = {
+      let inner_mg = self.inner.lock().await;
+      inner_mg.shutdown_hooks.clone()
+    };
+    let deadline = tokio::time::Instant::now() + timeout;
+    for hook in hooks.iter().rev() {
+      let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+      if tokio::time::timeout(remaining, hook.run(remaining)).await.is_err() {
+        tracing::warn!("ActorSystem shutdown timed out after {:?}", timeout);
+        return;
+      }
+    }
+  }
 }
@@ -1,19 +1,33 @@
 #[cfg(test)]
 mod tests {
+  use std::collections::HashSet;
   use std::env;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  use std::sync::{Arc, Mutex};
+  use std::time::Duration;
 
   use crate::actor::actor::ActorError;
+  use crate::actor::actor::Props;
   use crate::actor::actor::{TypedActor, TypedProps};
-  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::actor_system::{
+    ActorSystem, HealthStatus, RebindDispatcherError, SetThroughputError, SuspendError, SystemInitError,
+  };
   use crate::actor::context::InfoPart;
   use crate::actor::context::TypedContextHandle;
-  use crate::actor::message::Message;
+  use crate::actor::context::{MessagePart, SenderPart, SpawnerPart, StopperPart};
+  use crate::actor::dispatch::future::ActorFutureProcess;
+  use crate::actor::dispatch::{
+    DispatchError, Dispatcher, DispatcherHandle, MailboxMessage, Runnable, TokioRuntimeContextDispatcher,
+  };
+  use crate::actor::message::MessageHandle;
   use crate::actor::supervisor::SupervisorStrategyHandle;
   use crate::actor::typed_context::{TypedSenderPart, TypedSpawnerPart};
-  use crate::actor::Config;
+  use crate::actor::ShutdownHook;
+  use crate::actor::{Config, ConfigOption};
   use async_trait::async_trait;
   use nexus_actor_message_derive_rs::Message;
   use nexus_actor_utils_rs::concurrent::AsyncBarrier;
+  use tokio::sync::Notify;
   use tokio::time::sleep;
   use tracing_subscriber::EnvFilter;
 
@@ -81,4 +95,573 @@ mod tests {
 
     cloned_b.wait().await;
   }
+
+  #[tokio::test]
+  async fn test_actor_system_stop_all() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let mut pids = vec![];
+    for _ in 0..5 {
+      let pid = root_context
+        .spawn(Props::from_async_actor_receiver(|_| async { Ok(()) }).await)
+        .await;
+      pids.push(pid);
+    }
+
+    let summary = system.stop_all(pids.clone(), Duration::from_secs(1)).await;
+
+    assert!(summary.all_stopped());
+    assert_eq!(summary.stopped, pids);
+    for pid in pids {
+      assert!(system.get_process_registry().await.find_process(&pid).await.is_none());
+    }
+  }
+
+  #[tokio::test]
+  async fn test_actor_system_health() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let before = system.health().await;
+
+    root_context
+      .spawn(Props::from_async_actor_receiver(|_| async { Ok(()) }).await)
+      .await;
+    let health = system.health().await;
+
+    assert_eq!(health.status, HealthStatus::Ready);
+    assert!(health.dispatcher_running);
+    assert_eq!(health.process_count, before.process_count + 1);
+    assert_eq!(health.pending_futures, 0);
+    assert_eq!(health.dead_letter_rate, 0.0);
+  }
+
+  #[tokio::test]
+  async fn test_actor_system_health_not_ready_above_future_high_water_mark() {
+    let system = ActorSystem::new_config_options([ConfigOption::with_future_high_water_mark(1)])
+      .await
+      .unwrap();
+    assert_eq!(system.health().await.status, HealthStatus::Ready);
+
+    let future = ActorFutureProcess::new(system.clone(), Duration::from_secs(5))
+      .await
+      .unwrap();
+    let health = system.health().await;
+    assert_eq!(health.status, HealthStatus::NotReady);
+    assert!(health.dispatcher_running);
+    assert_eq!(health.pending_futures, 1);
+
+    future.complete(MessageHandle::new("done".to_string())).await;
+    assert_eq!(system.health().await.status, HealthStatus::Ready);
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_actor_system_set_actor_throughput() {
+    let _ = env::set_var("RUST_LOG", "info");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    const MESSAGES: usize = 20;
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    // The paused clock only advances while the mailbox yields, so messages observed at the same instant
+    // were processed within one schedule
+    let ticker = tokio::spawn(async move {
+      loop {
+        tokio::time::advance(Duration::from_millis(1)).await;
+      }
+    });
+
+    let observed = Arc::new(Mutex::new(vec![]));
+    let notify = Arc::new(Notify::new());
+    let cloned_observed = observed.clone();
+    let cloned_notify = notify.clone();
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let observed = cloned_observed.clone();
+          let notify = cloned_notify.clone();
+          async move {
+            if ctx.get_message_handle().await.to_typed::<Hello>().is_some() {
+              let mut mg = observed.lock().unwrap();
+              mg.push(tokio::time::Instant::now());
+              if mg.len() == MESSAGES {
+                notify.notify_one();
+              }
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    let schedules_with_throughput = |throughput| {
+      let system = system.clone();
+      let pid = pid.clone();
+      let observed = observed.clone();
+      let notify = notify.clone();
+      let mut root_context = root_context.clone();
+      async move {
+        system.set_actor_throughput(&pid, throughput).await.unwrap();
+        observed.lock().unwrap().clear();
+        for _ in 0..MESSAGES {
+          root_context
+            .send(pid.clone(), MessageHandle::new(Hello("hello".to_string())))
+            .await;
+        }
+        notify.notified().await;
+        let ticks = observed.lock().unwrap().iter().cloned().collect::<HashSet<_>>();
+        ticks.len()
+      }
+    };
+
+    let slow = schedules_with_throughput(1).await;
+    let fast = schedules_with_throughput(1000).await;
+    tracing::info!("schedules: slow = {}, fast = {}", slow, fast);
+    assert!(fast < slow, "fast = {}, slow = {}", fast, slow);
+
+    ticker.abort();
+
+    assert_eq!(
+      system.set_actor_throughput(&pid, 0).await,
+      Err(SetThroughputError::InvalidThroughput(0))
+    );
+    root_context.stop_future(&pid).await.result().await.unwrap();
+    assert_eq!(
+      system.set_actor_throughput(&pid, 10).await,
+      Err(SetThroughputError::NotAlive(pid.clone()))
+    );
+  }
+
+  #[derive(Debug)]
+  struct CountingDispatcher {
+    scheduled: Arc<AtomicUsize>,
+  }
+
+  #[async_trait]
+  impl Dispatcher for CountingDispatcher {
+    async fn schedule(&self, runner: Runnable) -> Result<(), DispatchError> {
+      self.scheduled.fetch_add(1, Ordering::SeqCst);
+      tokio::spawn(runner.run());
+      Ok(())
+    }
+
+    async fn throughput(&self) -> i32 {
+      300
+    }
+  }
+
+  #[tokio::test]
+  async fn test_actor_system_rebind_dispatcher() {
+    let _ = env::set_var("RUST_LOG", "info");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    const MESSAGES: usize = 50;
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let observed = Arc::new(Mutex::new(vec![]));
+    let notify = Arc::new(Notify::new());
+    let cloned_observed = observed.clone();
+    let cloned_notify = notify.clone();
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let observed = cloned_observed.clone();
+          let notify = cloned_notify.clone();
+          async move {
+            if let Some(Hello(n)) = ctx.get_message_handle().await.to_typed::<Hello>() {
+              sleep(Duration::from_millis(1)).await;
+              let mut mg = observed.lock().unwrap();
+              mg.push(n.parse::<usize>().unwrap());
+              if mg.len() == MESSAGES {
+                notify.notify_one();
+              }
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    for n in 0..MESSAGES / 2 {
+      root_context
+        .send(pid.clone(), MessageHandle::new(Hello(n.to_string())))
+        .await;
+    }
+    while observed.lock().unwrap().len() < 5 {
+      sleep(Duration::from_millis(1)).await;
+    }
+
+    let scheduled = Arc::new(AtomicUsize::new(0));
+    let dispatcher = DispatcherHandle::new(CountingDispatcher {
+      scheduled: scheduled.clone(),
+    });
+    system.rebind_dispatcher(&pid, dispatcher).await.unwrap();
+
+    for n in MESSAGES / 2..MESSAGES {
+      root_context
+        .send(pid.clone(), MessageHandle::new(Hello(n.to_string())))
+        .await;
+    }
+    tokio::time::timeout(Duration::from_secs(5), notify.notified())
+      .await
+      .expect("not all messages were processed");
+
+    assert_eq!(*observed.lock().unwrap(), (0..MESSAGES).collect::<Vec<_>>());
+    assert!(scheduled.load(Ordering::SeqCst) > 0);
+
+    root_context.stop_future(&pid).await.result().await.unwrap();
+    let dispatcher = DispatcherHandle::new(TokioRuntimeContextDispatcher::new().unwrap());
+    assert_eq!(
+      system.rebind_dispatcher(&pid, dispatcher).await,
+      Err(RebindDispatcherError::NotAlive(pid.clone()))
+    );
+  }
+
+  #[tokio::test]
+  async fn test_actor_system_shutdown_runs_hooks_in_reverse_order() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let order = Arc::new(Mutex::new(vec![]));
+
+    // Registered first, so it runs last and is cut off by the deadline
+    let cloned_order = order.clone();
+    system
+      .register_shutdown_hook(ShutdownHook::new(move |remaining| {
+        let order = cloned_order.clone();
+        async move {
+          order.lock().unwrap().push("stuck");
+          sleep(remaining * 10).await;
+          order.lock().unwrap().push("unreachable");
+        }
+      }))
+      .await;
+    for name in ["first", "second"] {
+      let order = order.clone();
+      system
+        .register_shutdown_hook(ShutdownHook::new(move |_| {
+          let order = order.clone();
+          async move { order.lock().unwrap().push(name) }
+        }))
+        .await;
+    }
+
+    let started = std::time::Instant::now();
+    system.shutdown(Duration::from_millis(200)).await;
+    assert!(started.elapsed() < Duration::from_secs(1));
+    assert_eq!(*order.lock().unwrap(), vec!["second", "first", "stuck"]);
+  }
+
+  // ManualDispatcher queues the scheduled work and only runs it when told to
+  #[derive(Default)]
+  struct ManualDispatcher {
+    queued: Mutex<Vec<Runnable>>,
+  }
+
+  impl ManualDispatcher {
+    async fn run_all(&self) {
+      let queued = std::mem::take(&mut *self.queued.lock().unwrap());
+      for runnable in queued {
+        runnable.run().await;
+      }
+    }
+  }
+
+  impl std::fmt::Debug for ManualDispatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      write!(f, "ManualDispatcher")
+    }
+  }
+
+  #[async_trait]
+  impl Dispatcher for ManualDispatcher {
+    async fn schedule(&self, runner: Runnable) -> Result<(), DispatchError> {
+      self.queued.lock().unwrap().push(runner);
+      Ok(())
+    }
+
+    async fn throughput(&self) -> i32 {
+      300
+    }
+  }
+
+  #[tokio::test]
+  async fn test_actor_system_startup_timeout() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let dispatcher = Arc::new(ManualDispatcher::default());
+    let result = ActorSystem::new_config_options([
+      ConfigOption::SetSystemDispatcher(dispatcher.clone()),
+      ConfigOption::with_startup_timeout(Duration::from_millis(100)),
+    ])
+    .await;
+    assert!(matches!(result, Err(SystemInitError::Timeout(timeout)) if timeout == Duration::from_millis(100)));
+    assert_eq!(dispatcher.queued.lock().unwrap().len(), 1);
+    dispatcher.run_all().await;
+
+    let system = ActorSystem::new_config_options([ConfigOption::with_startup_timeout(Duration::from_secs(1))])
+      .await
+      .unwrap();
+    assert!(system.health().await.dispatcher_running);
+  }
+
+  #[tokio::test]
+  async fn test_actor_system_suspend_and_resume() {
+    let _ = env::set_var("RUST_LOG", "info");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    const MESSAGES: usize = 5;
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let processed = Arc::new(AtomicUsize::new(0));
+    let cloned_processed = processed.clone();
+    let props = Props::from_async_actor_receiver(move |ctx| {
+      let processed = cloned_processed.clone();
+      async move {
+        if ctx.get_message_handle().await.to_typed::<Hello>().is_some() {
+          processed.fetch_add(1, Ordering::SeqCst);
+        }
+        Ok(())
+      }
+    })
+    .await;
+    let pid = root_context.spawn(props).await;
+
+    system.suspend(&pid).await.unwrap();
+    for _ in 0..MESSAGES {
+      root_context
+        .send(pid.clone(), MessageHandle::new(Hello("hello".to_string())))
+        .await;
+    }
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(processed.load(Ordering::SeqCst), 0);
+
+    system.resume(&pid).await.unwrap();
+    tokio::time::timeout(Duration::from_secs(5), async {
+      while processed.load(Ordering::SeqCst) < MESSAGES {
+        sleep(Duration::from_millis(10)).await;
+      }
+    })
+    .await
+    .expect("queued messages were not processed after resume");
+
+    // System messages still flow while suspended, so the actor can be stopped
+    system.suspend(&pid).await.unwrap();
+    tokio::time::timeout(Duration::from_secs(5), root_context.stop_future(&pid).await.result())
+      .await
+      .expect("suspended actor did not stop")
+      .unwrap();
+    assert_eq!(system.resume(&pid).await, Err(SuspendError::NotAlive(pid.clone())));
+  }
+
+  #[tokio::test]
+  async fn test_actor_system_suspend_is_independent_of_supervision() {
+    let _ = env::set_var("RUST_LOG", "info");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let processed = Arc::new(AtomicUsize::new(0));
+    let cloned_processed = processed.clone();
+    let props = Props::from_async_actor_receiver(move |ctx| {
+      let processed = cloned_processed.clone();
+      async move {
+        if ctx.get_message_handle().await.to_typed::<Hello>().is_some() {
+          processed.fetch_add(1, Ordering::SeqCst);
+        }
+        Ok(())
+      }
+    })
+    .await;
+    let pid = root_context.spawn(props).await;
+    let wait_processed = |count: usize| {
+      let processed = processed.clone();
+      async move {
+        tokio::time::timeout(Duration::from_secs(5), async {
+          while processed.load(Ordering::SeqCst) < count {
+            sleep(Duration::from_millis(10)).await;
+          }
+        })
+        .await
+        .expect("queued messages were not processed");
+      }
+    };
+
+    // A supervisor resuming the actor does not lift a suspension by the operator
+    system.suspend(&pid).await.unwrap();
+    root_context
+      .send(pid.clone(), MessageHandle::new(Hello("hello".to_string())))
+      .await;
+    pid
+      .send_system_message(system.clone(), MessageHandle::new(MailboxMessage::ResumeMailbox))
+      .await;
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(processed.load(Ordering::SeqCst), 0);
+    system.resume(&pid).await.unwrap();
+    wait_processed(1).await;
+
+    // Resuming by the operator does not lift a suspension by supervision
+    pid
+      .send_system_message(system.clone(), MessageHandle::new(MailboxMessage::SuspendMailbox))
+      .await;
+    system.suspend(&pid).await.unwrap();
+    system.resume(&pid).await.unwrap();
+    root_context
+      .send(pid.clone(), MessageHandle::new(Hello("hello".to_string())))
+      .await;
+    sleep(Duration::from_millis(100)).await;
+    assert_eq!(processed.load(Ordering::SeqCst), 1);
+    pid
+      .send_system_message(system.clone(), MessageHandle::new(MailboxMessage::ResumeMailbox))
+      .await;
+    wait_processed(2).await;
+  }
+
+  #[tokio::test]
+  async fn test_actor_system_await_quiescent() {
+    let _ = env::set_var("RUST_LOG", "info");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    const MESSAGES: usize = 200;
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let processed = Arc::new(AtomicUsize::new(0));
+    let cloned_processed = processed.clone();
+    let counter = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let processed = cloned_processed.clone();
+          async move {
+            if ctx.get_message_handle().await.to_typed::<Hello>().is_some() {
+              sleep(Duration::from_millis(1)).await;
+              processed.fetch_add(1, Ordering::SeqCst);
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+    // Messages reach the counter through a relay, so some are still in flight after the sends below return
+    let cloned_counter = counter.clone();
+    let relay = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |mut ctx| {
+          let counter = cloned_counter.clone();
+          async move {
+            let message_handle = ctx.get_message_handle().await;
+            if message_handle.to_typed::<Hello>().is_some() {
+              ctx.send(counter, message_handle).await;
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    for _ in 0..MESSAGES {
+      root_context
+        .send(relay.clone(), MessageHandle::new(Hello("hello".to_string())))
+        .await;
+    }
+    assert!(system.await_quiescent(Duration::from_secs(5)).await);
+    assert_eq!(processed.load(Ordering::SeqCst), MESSAGES);
+
+    // Messages queued in a suspended mailbox keep the system from becoming quiescent
+    system.suspend(&counter).await.unwrap();
+    root_context
+      .send(counter.clone(), MessageHandle::new(Hello("hello".to_string())))
+      .await;
+    assert!(!system.await_quiescent(Duration::from_millis(200)).await);
+    system.resume(&counter).await.unwrap();
+    assert!(system.await_quiescent(Duration::from_secs(5)).await);
+    assert_eq!(processed.load(Ordering::SeqCst), MESSAGES + 1);
+  }
+
+  #[tokio::test]
+  async fn test_actor_system_subscribe_dead_letters() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let dead_letters = Arc::new(Mutex::new(Vec::new()));
+    let notify = Arc::new(Notify::new());
+    let cloned_dead_letters = dead_letters.clone();
+    let cloned_notify = notify.clone();
+    let subscription = system
+      .subscribe_dead_letters(move |dead_letter| {
+        cloned_dead_letters
+          .lock()
+          .unwrap()
+          .push((dead_letter.pid.clone(), dead_letter.message_handle.to_typed::<String>()));
+        cloned_notify.notify_one();
+        async {}
+      })
+      .await;
+
+    let unknown = system.new_local_pid("never-registered").await;
+    root_context
+      .send(unknown.clone(), MessageHandle::new("hello".to_string()))
+      .await;
+    tokio::time::timeout(Duration::from_secs(3), notify.notified())
+      .await
+      .unwrap();
+    assert_eq!(
+      dead_letters.lock().unwrap().clone(),
+      vec![(Some(unknown), Some("hello".to_string()))]
+    );
+
+    let stopped = root_context
+      .spawn(Props::from_async_actor_receiver(|_| async { Ok(()) }).await)
+      .await;
+    root_context.stop_future(&stopped).await.result().await.unwrap();
+    root_context
+      .send(stopped.clone(), MessageHandle::new("bye".to_string()))
+      .await;
+    tokio::time::timeout(Duration::from_secs(3), notify.notified())
+      .await
+      .unwrap();
+    assert_eq!(
+      dead_letters.lock().unwrap().last().cloned(),
+      Some((Some(stopped), Some("bye".to_string())))
+    );
+
+    system.get_event_stream().await.unsubscribe(subscription).await;
+  }
 }
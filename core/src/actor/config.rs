@@ -1,4 +1,9 @@
+use crate::actor::actor::SpawnInterceptor;
 use crate::actor::dispatch::{Dispatcher, TokioRuntimeContextDispatcher};
+use crate::actor::message::{EnvelopeFactory, MessageSizer};
+use crate::actor::supervisor::{
+  Directive, RootEscalationHandler, SupervisorStrategyHandle, DEFAULT_SUPERVISION_STRATEGY,
+};
 use crate::actor::ConfigOption;
 use opentelemetry::global::GlobalMeterProvider;
 use opentelemetry::metrics::noop::NoopMeterProvider;
@@ -59,7 +64,21 @@ pub struct Config {
   pub dead_letter_throttle_interval: Duration,
   pub dead_letter_throttle_count: usize,
   pub dead_letter_request_logging: bool,
+  pub dead_letter_summary_window: Duration,
+  pub dead_letter_summary_threshold: usize,
   pub developer_supervision_logging: bool,
+  pub message_tracing: bool,
+  pub propagated_headers: Arc<[String]>,
+  pub max_pending_futures: usize,
+  pub future_high_water_mark: usize,
+  pub max_concurrent_mailboxes: usize,
+  pub root_escalation_handler: Option<RootEscalationHandler>,
+  pub root_escalation_directive: Directive,
+  pub spawn_interceptor: Option<SpawnInterceptor>,
+  pub default_supervisor_strategy: Option<SupervisorStrategyHandle>,
+  pub startup_timeout: Option<Duration>,
+  pub message_sizer: Option<MessageSizer>,
+  pub envelope_factory: Option<EnvelopeFactory>,
   // Other fields...
 }
 
@@ -73,7 +92,21 @@ impl Default for Config {
       dead_letter_throttle_interval: Duration::from_secs(1),
       dead_letter_throttle_count: 10,
       dead_letter_request_logging: false,
+      dead_letter_summary_window: Duration::ZERO,
+      dead_letter_summary_threshold: 10,
       developer_supervision_logging: false,
+      message_tracing: true,
+      propagated_headers: Arc::from([]),
+      max_pending_futures: 0,
+      future_high_water_mark: 0,
+      max_concurrent_mailboxes: 0,
+      root_escalation_handler: None,
+      root_escalation_directive: Directive::Stop,
+      spawn_interceptor: None,
+      default_supervisor_strategy: None,
+      startup_timeout: None,
+      message_sizer: None,
+      envelope_factory: None,
       // Set other default values...
     }
   }
@@ -96,4 +129,12 @@ impl Config {
       false
     }
   }
+
+  // GetDefaultSupervisorStrategy returns the strategy used for actors whose parent has none of its own
+  pub fn get_default_supervisor_strategy(&self) -> SupervisorStrategyHandle {
+    self
+      .default_supervisor_strategy
+      .clone()
+      .unwrap_or_else(|| DEFAULT_SUPERVISION_STRATEGY.clone())
+  }
 }
@@ -1,5 +1,8 @@
+use crate::actor::actor::{ErrorReason, Props, SpawnInterceptor};
 use crate::actor::config::Config;
 use crate::actor::dispatch::Dispatcher;
+use crate::actor::message::{EnvelopeFactory, MessageEnvelope, MessageHandle, MessageSizer};
+use crate::actor::supervisor::{Directive, RootEscalationHandler, SupervisorStrategyHandle};
 use crate::actor::MetricsProvider;
 use std::sync::Arc;
 use std::time::Duration;
@@ -12,6 +15,20 @@ pub enum ConfigOption {
   SetDeadLetterThrottleInterval(Duration),
   SetDeadLetterThrottleCount(usize),
   SetDeadLetterRequestLogging(bool),
+  SetDeadLetterSummaryWindow(Duration),
+  SetDeadLetterSummaryThreshold(usize),
+  SetMaxPendingFutures(usize),
+  SetFutureHighWaterMark(usize),
+  SetMaxConcurrentMailboxes(usize),
+  SetMessageTracing(bool),
+  SetPropagatedHeaders(Arc<[String]>),
+  SetRootEscalationHandler(RootEscalationHandler),
+  SetRootEscalationDirective(Directive),
+  SetSpawnInterceptor(SpawnInterceptor),
+  SetDefaultSupervisorStrategy(SupervisorStrategyHandle),
+  SetStartupTimeout(Duration),
+  SetMessageSizer(MessageSizer),
+  SetEnvelopeFactory(EnvelopeFactory),
   // Other options...
 }
 
@@ -38,6 +55,48 @@ impl ConfigOption {
       }
       ConfigOption::SetDeadLetterRequestLogging(enabled) => {
         config.dead_letter_request_logging = *enabled;
+      }
+      ConfigOption::SetDeadLetterSummaryWindow(window) => {
+        config.dead_letter_summary_window = *window;
+      }
+      ConfigOption::SetDeadLetterSummaryThreshold(threshold) => {
+        config.dead_letter_summary_threshold = *threshold;
+      }
+      ConfigOption::SetMaxPendingFutures(max) => {
+        config.max_pending_futures = *max;
+      }
+      ConfigOption::SetFutureHighWaterMark(mark) => {
+        config.future_high_water_mark = *mark;
+      }
+      ConfigOption::SetMaxConcurrentMailboxes(max) => {
+        config.max_concurrent_mailboxes = *max;
+      }
+      ConfigOption::SetMessageTracing(enabled) => {
+        config.message_tracing = *enabled;
+      }
+      ConfigOption::SetPropagatedHeaders(keys) => {
+        config.propagated_headers = keys.clone();
+      }
+      ConfigOption::SetRootEscalationHandler(handler) => {
+        config.root_escalation_handler = Some(handler.clone());
+      }
+      ConfigOption::SetRootEscalationDirective(directive) => {
+        config.root_escalation_directive = *directive;
+      }
+      ConfigOption::SetSpawnInterceptor(interceptor) => {
+        config.spawn_interceptor = Some(interceptor.clone());
+      }
+      ConfigOption::SetDefaultSupervisorStrategy(strategy) => {
+        config.default_supervisor_strategy = Some(strategy.clone());
+      }
+      ConfigOption::SetStartupTimeout(timeout) => {
+        config.startup_timeout = Some(*timeout);
+      }
+      ConfigOption::SetMessageSizer(sizer) => {
+        config.message_sizer = Some(sizer.clone());
+      }
+      ConfigOption::SetEnvelopeFactory(factory) => {
+        config.envelope_factory = Some(factory.clone());
       } // Handle other options...
     }
   }
@@ -53,4 +112,91 @@ impl ConfigOption {
   pub fn with_dead_letter_request_logging(enabled: bool) -> ConfigOption {
     ConfigOption::SetDeadLetterRequestLogging(enabled)
   }
+
+  // Summarizes dead letters with the same target and message type. At most `dead_letter_summary_threshold` of
+  // them are published as DeadLetterEvents within any sliding `window`, the rest are counted and reported in
+  // one DeadLetterSummaryEvent per window. A window of 0 (the default) disables summaries.
+  // Senders of summarized dead letters still get a DeadLetterResponse for each message
+  pub fn with_dead_letter_summary_window(window: Duration) -> ConfigOption {
+    ConfigOption::SetDeadLetterSummaryWindow(window)
+  }
+
+  pub fn with_dead_letter_summary_threshold(threshold: usize) -> ConfigOption {
+    ConfigOption::SetDeadLetterSummaryThreshold(threshold)
+  }
+
+  // A limit of 0 (the default) leaves the number of pending futures unbounded.
+  // With a limit set, futures must have a timeout: a zero duration fails with TimeoutRequiredError
+  pub fn with_max_pending_futures(max: usize) -> ConfigOption {
+    ConfigOption::SetMaxPendingFutures(max)
+  }
+
+  // Publishes a FuturePressureEvent when the number of pending futures reaches `mark`, and again once it
+  // drops back below it. A mark of 0 (the default) disables the events
+  pub fn with_future_high_water_mark(mark: usize) -> ConfigOption {
+    ConfigOption::SetFutureHighWaterMark(mark)
+  }
+
+  // Caps how many mailboxes process messages at the same time, across all actors of the system.
+  // A mailbox keeps its permit while an actor awaits inside receive, so a limit that is too small
+  // can stall actors that wait on each other. A limit of 0 (the default) means unbounded.
+  pub fn with_max_concurrent_mailboxes(max: usize) -> ConfigOption {
+    ConfigOption::SetMaxConcurrentMailboxes(max)
+  }
+
+  // Wraps the handling of every user message in an `actor_message` span with the actor's `pid`, the
+  // `message_type` and the `correlation_id` header when present, so that logs emitted while handling a message
+  // nest under it. Enabled by default; disable it where the cost of a span per message matters
+  pub fn with_message_tracing(enabled: bool) -> ConfigOption {
+    ConfigOption::SetMessageTracing(enabled)
+  }
+
+  // Messages sent by an actor while it handles a message copy these headers from the inbound message, unless
+  // they already carry them. The correlation id is always propagated
+  pub fn with_propagated_headers(keys: impl IntoIterator<Item = impl Into<String>>) -> ConfigOption {
+    ConfigOption::SetPropagatedHeaders(keys.into_iter().map(Into::into).collect())
+  }
+
+  // Called when a failure is escalated past the root, either by a top-level actor or by a guardian's strategy.
+  // The root escalation directive is applied to the failing actor after the handler returns; without a handler
+  // the failure is only logged
+  pub fn with_root_escalation_handler(f: impl Fn(ErrorReason, MessageHandle) + Send + Sync + 'static) -> ConfigOption {
+    ConfigOption::SetRootEscalationHandler(RootEscalationHandler::new(f))
+  }
+
+  // What happens to an actor whose failure is escalated past the root. Stop (the default) stops it together
+  // with its children, Restart restarts it and Resume resumes its mailbox. Escalate behaves like Stop
+  pub fn with_root_escalation_directive(directive: Directive) -> ConfigOption {
+    ConfigOption::SetRootEscalationDirective(directive)
+  }
+
+  // Called with the props of every actor spawned through the default spawner, before its mailbox is produced.
+  // Spawn middleware of the props runs first, props with a custom spawner are not intercepted
+  pub fn with_spawn_interceptor(f: impl Fn(&mut Props) + Send + Sync + 'static) -> ConfigOption {
+    ConfigOption::SetSpawnInterceptor(SpawnInterceptor::new(f))
+  }
+
+  // Replaces DEFAULT_SUPERVISION_STRATEGY for this system. It supervises top-level actors and the children of
+  // actors whose props and actor set no strategy of their own
+  pub fn with_default_supervisor_strategy(strategy: SupervisorStrategyHandle) -> ConfigOption {
+    ConfigOption::SetDefaultSupervisorStrategy(strategy)
+  }
+
+  // Bounds how long ActorSystem creation may take, including a check that the system dispatcher runs scheduled
+  // work. Creation fails with SystemInitError::Timeout when the deadline passes. Without it there is no deadline
+  pub fn with_startup_timeout(timeout: Duration) -> ConfigOption {
+    ConfigOption::SetStartupTimeout(timeout)
+  }
+
+  // Measures messages for actors spawned with Props::with_max_message_bytes. `f` returns the serialized size of
+  // a message, or None for messages that cannot be serialized, which are never rejected
+  pub fn with_message_sizer(f: impl Fn(&MessageHandle) -> Option<usize> + Send + Sync + 'static) -> ConfigOption {
+    ConfigOption::SetMessageSizer(MessageSizer::new(f))
+  }
+
+  // Builds the envelope of every user message sent from the contexts of the system, e.g. to stamp default
+  // headers with `wrap_envelope_with_header`. Without a factory messages are sent as they are
+  pub fn with_envelope_factory(f: impl Fn(MessageHandle) -> MessageEnvelope + Send + Sync + 'static) -> ConfigOption {
+    ConfigOption::SetEnvelopeFactory(EnvelopeFactory::new(f))
+  }
 }
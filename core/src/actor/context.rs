@@ -2,29 +2,48 @@ use std::fmt::Debug;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use futures::future::BoxFuture;
 
 use crate::actor::actor::ActorError;
 use crate::actor::actor::ActorHandle;
+use crate::actor::actor::ActorProcess;
+use crate::actor::actor::ActorRef;
 use crate::actor::actor::Continuer;
 use crate::actor::actor::ExtendedPid;
 use crate::actor::actor::Props;
 use crate::actor::actor::SpawnError;
+use crate::actor::actor::TypedExtendedPid;
 use crate::actor::actor_system::ActorSystem;
 use crate::actor::dispatch::future::ActorFuture;
+use crate::actor::dispatch::future::ActorFutureError;
+use crate::actor::dispatch::future::ActorFutureProcess;
+use crate::actor::dispatch::Mailbox;
+use crate::actor::message::with_ttl;
+use crate::actor::message::Message;
 use crate::actor::message::MessageEnvelope;
 use crate::actor::message::MessageHandle;
+use crate::actor::message::MessageHeaders;
+use crate::actor::message::ReadonlyMessageHeaders;
 use crate::actor::message::ReadonlyMessageHeadersHandle;
 use crate::actor::message::ResponseHandle;
+use crate::actor::persistence::JournalError;
+use crate::actor::process::Process;
 use crate::ctxext::extensions::{ContextExtensionHandle, ContextExtensionId};
 
 mod actor_context;
 mod actor_context_extras;
 mod actor_context_test;
+mod ask_error;
+mod auto_ack_context;
 mod context_handle;
+mod message_history;
 mod mock_context;
 mod receive_timeout_timer;
 mod receiver_context_handle;
+mod reliable_delivery;
 mod root_context;
+mod send_batch;
+mod send_error;
 mod sender_context_handle;
 mod spawner_context_handle;
 mod state;
@@ -33,9 +52,10 @@ mod typed_context_handle;
 mod typed_root_context;
 
 pub use {
-  self::actor_context::*, self::context_handle::*, self::mock_context::*, self::receiver_context_handle::*,
-  self::root_context::*, self::sender_context_handle::*, self::spawner_context_handle::*,
-  self::typed_context_handle::*, self::typed_root_context::*,
+  self::actor_context::*, self::ask_error::*, self::auto_ack_context::*, self::context_handle::*,
+  self::message_history::*, self::mock_context::*, self::receiver_context_handle::*, self::reliable_delivery::*,
+  self::root_context::*, self::send_batch::*, self::send_error::*, self::sender_context_handle::*,
+  self::spawner_context_handle::*, self::typed_context_handle::*, self::typed_root_context::*,
 };
 
 pub trait Context:
@@ -45,6 +65,7 @@ pub trait Context:
   + SpawnerContext
   + BasePart
   + StopperPart
+  + PersistencePart
   + Debug
   + Send
   + Sync
@@ -53,7 +74,27 @@ pub trait Context:
 
 pub trait ExtensionContext: ExtensionPart + Send + Sync + 'static {}
 
-pub trait SenderContext: InfoPart + SenderPart + MessagePart + Send + Sync + 'static {}
+#[async_trait]
+pub trait SenderContext: InfoPart + SenderPart + MessagePart + Send + Sync + 'static {
+  // SendAfter sends a message to the given PID once the delay has elapsed.
+  // Actor contexts cancel the sends that are still pending when the actor stops
+  async fn send_after(&mut self, pid: ExtendedPid, message_handle: MessageHandle, delay: Duration) {
+    self
+      .get_actor_system()
+      .await
+      .get_scheduler()
+      .schedule_once(delay, None, pid, message_handle);
+  }
+
+  // SendAfterFn behaves like SendAfter, with the delay computed from the message itself
+  async fn send_after_fn<F>(&mut self, pid: ExtendedPid, message_handle: MessageHandle, delay: F)
+  where
+    Self: Sized,
+    F: Fn(&MessageHandle) -> Duration + Send + Sync + 'static, {
+    let delay = delay(&message_handle);
+    self.send_after(pid, message_handle, delay).await
+  }
+}
 
 pub trait ReceiverContext: InfoPart + ReceiverPart + MessagePart + ExtensionPart + Send + Sync + 'static {}
 
@@ -76,19 +117,39 @@ pub trait InfoPart: Debug + Send + Sync + 'static {
     self.get_self_opt().await.expect("self pid not found")
   }
 
+  // SelfTyped returns the PID for the current actor as a typed PID.
+  // Returns None if the actor is a typed actor whose message type is not `M`.
+  async fn self_typed<M: Message>(&self) -> Option<TypedExtendedPid<M>>
+  where
+    Self: Sized, {
+    if let Some(expected) = self.get_actor().await.and_then(|a| a.get_message_type_name()) {
+      if expected != std::any::type_name::<M>() {
+        return None;
+      }
+    }
+    Some(TypedExtendedPid::new(self.get_self().await))
+  }
+
   async fn set_self(&mut self, pid: ExtendedPid);
 
   // Actor returns the actor associated with this context
   async fn get_actor(&self) -> Option<ActorHandle>;
 
   async fn get_actor_system(&self) -> ActorSystem;
+
+  // ActorRef returns a handle to the given PID that is bound to the actor system of this context
+  async fn get_actor_ref(&self, pid: ExtendedPid) -> ActorRef
+  where
+    Self: Sized, {
+    ActorRef::new(pid, self.get_actor_system().await)
+  }
 }
 
 #[async_trait]
 pub trait BasePart: Debug + Send + Sync + 'static {
   fn as_any(&self) -> &dyn std::any::Any;
-  // ReceiveTimeout returns the current timeout
-  async fn get_receive_timeout(&self) -> Duration;
+  // ReceiveTimeout returns the current timeout, None when it is not set or has been cancelled
+  async fn get_receive_timeout(&self) -> Option<Duration>;
 
   // Children returns a slice of the actors children
   async fn get_children(&self) -> Vec<ExtendedPid>;
@@ -97,8 +158,14 @@ pub trait BasePart: Debug + Send + Sync + 'static {
   // If the Sender is nil, the actor will panic
   async fn respond(&self, response: ResponseHandle);
 
-  // Stash stashes the current message on a stack for reprocessing when the actor restarts
+  // Stash buffers the current message, with its envelope headers and sender, until it is unstashed.
+  // The stash is kept across receives and cleared when the actor restarts
   async fn stash(&mut self);
+
+  // UnStash sends the oldest stashed message back to the actor's mailbox, returns false when the stash is empty
+  async fn un_stash(&mut self) -> bool;
+
+  // UnStashAll sends every stashed message back to the actor's mailbox, in the order they were stashed
   async fn un_stash_all(&mut self) -> Result<(), ActorError>;
 
   // Watch registers the actor as a monitor for the specified PID
@@ -114,12 +181,40 @@ pub trait BasePart: Debug + Send + Sync + 'static {
   // the not_influence_receive_timeout interface, the timer will not be reset
   async fn set_receive_timeout(&mut self, d: &Duration);
 
+  // CancelReceiveTimeout stops the inactivity timer, no ReceiveTimeout message is sent until it is set again
   async fn cancel_receive_timeout(&mut self);
 
   // Forward forwards current message to the given PID
   async fn forward(&self, pid: &ExtendedPid);
 
   async fn reenter_after(&self, f: ActorFuture, continuation: Continuer);
+
+  // DiagnosticsJson returns diagnostic information about the actor as a JSON string
+  async fn diagnostics_json(&self) -> String {
+    "{}".to_string()
+  }
+
+  // MailboxPressure returns the number of user messages waiting in the actor's mailbox
+  async fn mailbox_pressure(&self) -> usize {
+    0
+  }
+
+  // ShouldShed reports whether the mailbox holds more than `threshold` user messages, so that the actor
+  // can drop best-effort work instead of processing it
+  async fn should_shed(&self, threshold: usize) -> bool {
+    self.mailbox_pressure().await > threshold
+  }
+
+  // ReceiveTimeoutRemaining returns the time left until the current receive timeout fires, or None if no
+  // receive timeout is set
+  async fn receive_timeout_remaining(&self) -> Option<Duration> {
+    None
+  }
+
+  // Flag returns the value of a feature flag set with Props::with_flags, false for flags that are not set
+  async fn flag(&self, _: &str) -> bool {
+    false
+  }
 }
 
 #[async_trait]
@@ -133,6 +228,50 @@ pub trait MessagePart: Debug + Send + Sync + 'static {
       .expect("message envelope not found")
   }
 
+  // AckDelivery acknowledges the current message to its sender if it was sent with `send_reliable`.
+  // Returns false when the message carries no delivery id
+  async fn ack_delivery(&self) -> bool
+  where
+    Self: BasePart + Sized, {
+    let delivery_id = self
+      .get_message_header_handle()
+      .await
+      .and_then(|header| header.get(DELIVERY_ID_HEADER));
+    match delivery_id {
+      Some(delivery_id) => {
+        self.respond(ResponseHandle::new(DeliveryAck::new(delivery_id))).await;
+        true
+      }
+      None => false,
+    }
+  }
+
+  // Tee sends the current message, with its sender and headers, to the given PID while this actor goes on
+  // processing it as well. Returns false when there is no current message
+  async fn tee(&self, pid: &ExtendedPid) -> bool
+  where
+    Self: InfoPart + Sized, {
+    let message_handle = match self.get_message_envelope_opt().await {
+      Some(envelope) => MessageHandle::new(envelope),
+      None => match self.get_message_handle_opt().await {
+        Some(message_handle) => message_handle,
+        None => return false,
+      },
+    };
+    pid
+      .send_user_message(self.get_actor_system().await, message_handle)
+      .await;
+    true
+  }
+
+  // CorrelationId returns the correlation id of the current message scope. When the current message carries
+  // none, one is generated and kept for the rest of the receive
+  async fn correlation_id(&self) -> String;
+
+  // SetCorrelationId replaces the correlation id of the current message scope.
+  // Messages sent during the rest of the receive carry it in their header
+  async fn set_correlation_id(&mut self, id: String);
+
   // Message returns the current message to be processed
   async fn get_message_handle_opt(&self) -> Option<MessageHandle>;
 
@@ -142,6 +281,36 @@ pub trait MessagePart: Debug + Send + Sync + 'static {
 
   // MessageHeader returns the meta information for the currently processed message
   async fn get_message_header_handle(&self) -> Option<ReadonlyMessageHeadersHandle>;
+
+  // Ask sends a request to the given PID and awaits the typed response.
+  // Unlike awaiting `request_future(..).result()` directly, the wait is abandoned with `AskError::Stopped`
+  // as soon as the actor is stopped, so its mailbox can go on to process the pending Stop system message.
+  async fn ask<T>(&self, pid: ExtendedPid, message_handle: MessageHandle, timeout: Duration) -> Result<T, AskError>
+  where
+    Self: SenderPart + InfoPart + Sized,
+    T: Message + Clone, {
+    let future = self.request_future(pid, message_handle, timeout).await;
+    let self_process = match self.get_self_opt().await {
+      Some(self_pid) => {
+        let process = self_pid.ref_process(self.get_actor_system().await).await;
+        process.as_any().downcast_ref::<ActorProcess>().cloned()
+      }
+      None => None,
+    };
+    let result = match self_process {
+      Some(process) => {
+        tokio::select! {
+          result = future.result() => result,
+          _ = process.wait_for_dead() => return Err(AskError::Stopped),
+        }
+      }
+      None => future.result().await,
+    };
+    let response = result?;
+    response
+      .to_typed::<T>()
+      .ok_or_else(|| AskError::TypeMismatch(response.get_type_name()))
+  }
 }
 
 #[async_trait]
@@ -152,6 +321,89 @@ pub trait SenderPart: Debug + Send + Sync + 'static {
   // Send sends a message to the given PID
   async fn send(&mut self, pid: ExtendedPid, message_handle: MessageHandle);
 
+  // TrySend sends a message to the given PID, or fails with `SendError::NoProcess` when no process
+  // is registered for it instead of silently dead-lettering the message. It fails with `SendError::MailboxFull`
+  // instead of making a full dropping mailbox evict its oldest message
+  async fn try_send(&mut self, pid: ExtendedPid, message_handle: MessageHandle) -> Result<(), SendError>
+  where
+    Self: InfoPart + Sized, {
+    let process_registry = self.get_actor_system().await.get_process_registry().await;
+    let Some(process) = process_registry.find_process(&pid).await else {
+      return Err(SendError::NoProcess);
+    };
+    if let Some(actor_process) = process.as_any().downcast_ref::<ActorProcess>() {
+      if actor_process.is_dead() {
+        return Err(SendError::NoProcess);
+      }
+      if actor_process.get_mailbox().is_user_mailbox_full().await {
+        return Err(SendError::MailboxFull);
+      }
+    }
+    self.send(pid, message_handle).await;
+    Ok(())
+  }
+
+  // SendWithTtl sends a message that is dropped, and sent to dead letters as `Expired`, if it is still
+  // waiting in the mailbox once the TTL has elapsed
+  async fn send_with_ttl(&mut self, pid: ExtendedPid, message_handle: MessageHandle, ttl: Duration) {
+    self.send(pid, MessageHandle::new(with_ttl(message_handle, ttl))).await
+  }
+
+  // SendReliable sends a message to the given PID and waits for the receiver to acknowledge it with
+  // `ack_delivery`. Unacknowledged attempts are redelivered with the same delivery id, as allowed by the
+  // retry policy; once the attempts are exhausted the message is sent to dead letters. Receivers that declare
+  // the message not idempotent reject its redelivery, see Props::with_idempotent
+  async fn send_reliable(
+    &mut self,
+    pid: ExtendedPid,
+    message_handle: MessageHandle,
+    retry_policy: RetryPolicy,
+  ) -> Result<(), SendError>
+  where
+    Self: InfoPart + Sized, {
+    let actor_system = self.get_actor_system().await;
+    let delivery_id = uuid::Uuid::new_v4().to_string();
+    for attempt in 1..=retry_policy.get_max_attempts() {
+      let future_process = match ActorFutureProcess::new(actor_system.clone(), retry_policy.get_ack_timeout()).await {
+        Ok(future_process) => future_process,
+        Err(error) => {
+          tracing::warn!("send_reliable: attempt {} could not be sent: {}", attempt, error);
+          continue;
+        }
+      };
+      let mut header = MessageHeaders::new();
+      header.set(DELIVERY_ID_HEADER.to_string(), delivery_id.clone());
+      header.set(DELIVERY_ATTEMPT_HEADER.to_string(), attempt.to_string());
+      let envelope = MessageEnvelope::new(message_handle.clone())
+        .with_header(header)
+        .with_sender(future_process.get_pid().await);
+      self.send(pid.clone(), MessageHandle::new(envelope)).await;
+      let response = future_process.get_future().await.result().await.ok();
+      if response
+        .as_ref()
+        .and_then(|response| response.to_typed::<DeliveryRejected>())
+        .is_some_and(|rejected| rejected.delivery_id == delivery_id)
+      {
+        return Err(SendError::DeliveryRejected);
+      }
+      let acked = response
+        .and_then(|response| response.to_typed::<DeliveryAck>())
+        .is_some_and(|ack| ack.delivery_id == delivery_id);
+      if acked {
+        return Ok(());
+      }
+    }
+    actor_system
+      .get_dead_letter()
+      .await
+      .send_user_message(Some(&pid), message_handle)
+      .await;
+    Err(SendError::DeliveryExhausted(retry_policy.get_max_attempts()))
+  }
+
+  // BeginBatch returns a batch whose sends are only emitted once it is committed
+  async fn begin_batch(&mut self) -> SendBatch;
+
   // Request sends a message to the given PID
   async fn request(&mut self, pid: ExtendedPid, message_handle: MessageHandle);
 
@@ -204,4 +456,40 @@ pub trait StopperPart: Debug + Send + Sync + 'static {
   async fn poison_future(&mut self, pid: &ExtendedPid) -> ActorFuture {
     self.stop_future_with_timeout(pid, Duration::from_secs(10)).await
   }
+
+  // StopAllChildren will stop every child of the actor immediately, see `BasePart::get_children`.
+  async fn stop_all_children(&mut self);
+
+  // StopAllChildrenFuture will stop every child of the actor immediately, and return a future that resolves
+  // once all of them have terminated.
+  async fn stop_all_children_future_with_timeout(
+    &mut self,
+    timeout: Duration,
+  ) -> BoxFuture<'static, Result<(), ActorFutureError>>;
+
+  async fn stop_all_children_future(&mut self) -> BoxFuture<'static, Result<(), ActorFutureError>> {
+    self
+      .stop_all_children_future_with_timeout(Duration::from_secs(10))
+      .await
+  }
+}
+
+#[async_trait]
+pub trait PersistencePart: Debug + Send + Sync + 'static {
+  // Persist appends the event to the journal configured with `Props::with_journal`, using the actor's id as
+  // the persistence id. Events replayed from the journal are not appended again
+  async fn persist(&mut self, event: MessageHandle) -> Result<(), JournalError>;
+
+  // IsRecovering reports whether the current message is an event being replayed from the journal
+  async fn is_recovering(&self) -> bool;
+
+  // PersistSnapshot stores `state` as the actor's state after the events persisted so far. When the actor
+  // starts, the snapshot is delivered as a RecoverSnapshot and only the events persisted after it are replayed
+  async fn persist_snapshot(&mut self, state: MessageHandle) -> Result<(), JournalError>;
+
+  // SequenceNr returns the sequence number of the last event persisted or replayed by the actor
+  async fn sequence_nr(&self) -> u64;
+
+  // DeleteEventsTo removes the actor's journaled events up to and including `to_sequence_nr`
+  async fn delete_events_to(&mut self, to_sequence_nr: u64) -> Result<(), JournalError>;
 }
@@ -6,10 +6,12 @@ use std::time::Duration;
 use crate::actor::actor::Actor;
 use crate::actor::actor::ActorError;
 use crate::actor::actor::ActorHandle;
+use crate::actor::actor::ActorProcess;
 use crate::actor::actor::ActorProducer;
 use crate::actor::actor::Continuer;
 use crate::actor::actor::ErrorReason;
 use crate::actor::actor::ExtendedPid;
+use crate::actor::actor::LifecycleEvent;
 use crate::actor::actor::Props;
 use crate::actor::actor::ReceiverMiddlewareChain;
 use crate::actor::actor::SenderMiddlewareChain;
@@ -17,39 +19,53 @@ use crate::actor::actor::SpawnError;
 use crate::actor::actor_system::ActorSystem;
 use crate::actor::context::actor_context_extras::ActorContextExtras;
 use crate::actor::context::context_handle::ContextHandle;
+use crate::actor::context::message_history::MessageHistory;
 use crate::actor::context::spawner_context_handle::SpawnerContextHandle;
 use crate::actor::context::state::State;
 use crate::actor::context::{
-  BasePart, Context, ExtensionContext, ExtensionPart, InfoPart, MessagePart, ReceiverContext, ReceiverPart,
-  SenderContext, SenderPart, SpawnerContext, SpawnerPart, StopperPart,
+  BasePart, Context, DeliveryRejected, ExtensionContext, ExtensionPart, InfoPart, MessagePart, PersistencePart,
+  ReceiverContext, ReceiverPart, SendBatch, SenderContext, SenderContextHandle, SenderPart, SpawnerContext,
+  SpawnerPart, StopperPart, DELIVERY_ATTEMPT_HEADER, DELIVERY_ID_HEADER,
 };
-use crate::actor::dispatch::future::ActorFutureProcess;
+use crate::actor::dispatch::future::{ActorFuture, ActorFutureError, ActorFutureProcess};
+use crate::actor::dispatch::Mailbox;
+use crate::actor::dispatch::MailboxHandle;
 use crate::actor::dispatch::MailboxMessage;
 use crate::actor::dispatch::MessageInvoker;
 use crate::actor::message::AutoReceiveMessage;
 use crate::actor::message::Continuation;
+use crate::actor::message::Expired;
 use crate::actor::message::Failure;
+use crate::actor::message::Message;
 use crate::actor::message::MessageHandle;
-use crate::actor::message::NotInfluenceReceiveTimeoutHandle;
+use crate::actor::message::MessageHeaders;
+use crate::actor::message::MessageTooLargeEvent;
+use crate::actor::message::ReadonlyMessageHeaders;
 use crate::actor::message::ReadonlyMessageHeadersHandle;
 use crate::actor::message::ReceiveTimeout;
 use crate::actor::message::ResponseHandle;
 use crate::actor::message::SystemMessage;
 use crate::actor::message::TerminateReason;
+use crate::actor::message::CORRELATION_ID_HEADER;
 use crate::actor::message::{
-  unwrap_envelope_header, unwrap_envelope_message, unwrap_envelope_sender, wrap_envelope, MessageEnvelope,
+  unwrap_envelope_header, unwrap_envelope_message, unwrap_envelope_sender, wrap_envelope, wrap_envelope_with_header,
+  MessageEnvelope,
 };
 use crate::actor::message::{AutoRespond, AutoResponsive};
 use crate::actor::metrics::metrics_impl::{Metrics, EXTENSION_ID};
-use crate::actor::process::Process;
-use crate::actor::supervisor::{Supervisor, SupervisorHandle, SupervisorStrategy, DEFAULT_SUPERVISION_STRATEGY};
+use crate::actor::persistence::{Journal, JournalError, RecoverSnapshot, Snapshot};
+use crate::actor::process::{Diagnostics, Process};
+use crate::actor::supervisor::{handle_root_escalation, Supervisor, SupervisorHandle, SupervisorStrategy};
 use crate::ctxext::extensions::{ContextExtensionHandle, ContextExtensionId};
 use crate::generated::actor::{PoisonPill, Terminated, Unwatch, Watch};
 
-use crate::metrics::ActorMetrics;
+use crate::metrics::{ActorMetrics, MailboxLengthRegistration};
 use async_trait::async_trait;
+use futures::future::BoxFuture;
+use opentelemetry::KeyValue;
 use tokio::sync::{Mutex, RwLock};
 use tokio::time::Instant;
+use tracing::{Instrument, Span};
 
 #[derive(Debug, Clone)]
 pub struct ActorContextInner {
@@ -63,6 +79,14 @@ pub struct ActorContextInner {
   producer: Option<ActorProducer>,
   message_or_envelope_opt: Arc<RwLock<Option<MessageHandle>>>,
   state: Option<Arc<AtomicU8>>,
+  message_history: Option<MessageHistory>,
+  mailbox_length_registration: Option<MailboxLengthRegistration>,
+  send_batches: Vec<SendBatch>,
+  recovering: bool,
+  sequence_nr: u64,
+  actor_path: Option<String>,
+  restart_reason: Option<ErrorReason>,
+  snapshot: Option<Arc<serde_json::Value>>,
 }
 
 #[derive(Debug, Clone)]
@@ -88,6 +112,10 @@ static_assertions::assert_impl_all!(ActorContext: Send, Sync);
 
 impl ActorContext {
   pub async fn new(actor_system: ActorSystem, props: Props, parent: Option<ExtendedPid>) -> Self {
+    let message_history = match props.get_message_history_size() {
+      0 => None,
+      size => Some(MessageHistory::new(size)),
+    };
     let mut ctx = ActorContext {
       inner: Arc::new(Mutex::new(ActorContextInner {
         actor: None,
@@ -100,6 +128,14 @@ impl ActorContext {
         producer: None,
         message_or_envelope_opt: Arc::new(RwLock::new(None)),
         state: None,
+        message_history,
+        mailbox_length_registration: None,
+        send_batches: vec![],
+        recovering: false,
+        sequence_nr: 0,
+        actor_path: None,
+        restart_reason: None,
+        snapshot: None,
       })),
     };
     ctx.incarnate_actor().await;
@@ -145,21 +181,19 @@ impl ActorContext {
 
   pub(crate) async fn ensure_extras(&mut self) -> ActorContextExtras {
     if self.get_extras().await.is_none() {
-      let ctxd = self.clone();
-      let ctxd = if let Some(decorator) = &self.get_props().await.get_context_decorator_chain() {
-        decorator.run(ContextHandle::new(ctxd)).await
-      } else {
-        ContextHandle::new(ctxd)
+      // the decorated context both dispatches messages and is handed to the actor, so decorators run once
+      let context = match self.get_props().await.get_context_decorator_chain() {
+        Some(decorator) => decorator.run(ContextHandle::new(self.clone())).await,
+        None => ContextHandle::new(self.clone()),
       };
-      self.set_extras(Some(ActorContextExtras::new(ctxd).await)).await;
+      self.set_extras(Some(ActorContextExtras::new(context).await)).await;
     }
     self.get_extras().await.as_ref().unwrap().clone()
   }
 
   async fn receive_with_context(&mut self) -> ContextHandle {
     if self.get_props().await.get_context_decorator_chain().is_some() {
-      let ctx_extras = self.ensure_extras().await;
-      ctx_extras.get_context().await.clone()
+      self.ensure_extras().await.get_context().await
     } else {
       ContextHandle::new(self.clone())
     }
@@ -232,12 +266,33 @@ impl ActorContext {
     mg.props.get_sender_middleware_chain().clone()
   }
 
+  async fn find_mailbox(&self) -> Option<MailboxHandle> {
+    let self_pid = self.get_self_opt().await?;
+    let process = self
+      .get_actor_system()
+      .await
+      .get_process_registry()
+      .await
+      .find_process(&self_pid)
+      .await?;
+    process
+      .as_any()
+      .downcast_ref::<ActorProcess>()
+      .map(|actor_process| actor_process.get_mailbox())
+  }
+
   pub async fn send_user_message(&self, pid: ExtendedPid, message_handle: MessageHandle) {
+    let message_handle = self.with_propagated_headers(message_handle).await;
+    let message_handle = self
+      .get_actor_system()
+      .await
+      .apply_envelope_factory(message_handle)
+      .await;
     match self.get_sender_middleware_chain().await {
       Some(chain) => {
         let mut cloned = self.clone();
         let context = cloned.ensure_extras().await.get_sender_context().await;
-        chain.run(context, pid, MessageEnvelope::new(message_handle)).await;
+        chain.run(context, pid, wrap_envelope(message_handle)).await;
       }
       _ => {
         pid
@@ -247,6 +302,58 @@ impl ActorContext {
     }
   }
 
+  async fn message_span(&self, message_handle: &MessageHandle) -> Span {
+    let pid = self.get_self_opt().await.map(|pid| pid.to_string()).unwrap_or_default();
+    let message_type = unwrap_envelope_message(message_handle.clone()).get_type_name();
+    let span = tracing::info_span!(
+      "actor_message",
+      pid = %pid,
+      message_type = %message_type,
+      correlation_id = tracing::field::Empty
+    );
+    let correlation_id =
+      unwrap_envelope_header(message_handle.clone()).and_then(|header| header.get(CORRELATION_ID_HEADER));
+    if let Some(correlation_id) = correlation_id {
+      span.record("correlation_id", tracing::field::display(correlation_id));
+    }
+    span
+  }
+
+  // Outbound messages inherit the correlation id and the propagated headers of the current message scope
+  // unless they carry their own
+  async fn with_propagated_headers(&self, message_handle: MessageHandle) -> MessageHandle {
+    let Some(inbound) = self.get_message_header_handle().await else {
+      return message_handle;
+    };
+    let propagated_headers = self.get_actor_system().await.get_propagated_headers().await;
+    let own = unwrap_envelope_header(message_handle.clone());
+    let keys = std::iter::once(CORRELATION_ID_HEADER).chain(propagated_headers.iter().map(String::as_str));
+    let inherited = keys
+      .filter(|key| own.as_ref().and_then(|header| header.get(key)).is_none())
+      .filter_map(|key| inbound.get(key).map(|value| (key.to_string(), value)))
+      .collect::<Vec<_>>();
+    if inherited.is_empty() {
+      return message_handle;
+    }
+    let envelope = wrap_envelope(message_handle);
+    let mut header = envelope
+      .get_header()
+      .map(|header| MessageHeaders::with_values(header.to_map()))
+      .unwrap_or_default();
+    for (key, value) in inherited {
+      header.set(key, value);
+    }
+    MessageHandle::new(envelope.with_header(header))
+  }
+
+  async fn set_message_header(&self, key: &str, value: String) {
+    let inner_mg = self.inner.lock().await;
+    let mut mg = inner_mg.message_or_envelope_opt.write().await;
+    if let Some(moe) = mg.take() {
+      *mg = Some(MessageHandle::new(wrap_envelope_with_header(moe, key, value)));
+    }
+  }
+
   async fn get_message_or_envelop(&self) -> MessageHandle {
     let inner_mg = self.inner.lock().await;
     let mg = inner_mg.message_or_envelope_opt.read().await;
@@ -289,8 +396,134 @@ impl ActorContext {
     result
   }
 
+  // Lifecycle messages are never guarded, so only what was sent to the actor can be dropped
+  async fn is_accepted_by_receive_guard(&self, message_handle: &MessageHandle) -> bool {
+    if message_handle.to_typed::<AutoReceiveMessage>().is_some() {
+      return true;
+    }
+    let guard = self.inner.lock().await.props.get_receive_guard();
+    match guard {
+      Some(guard) => guard.run(&unwrap_envelope_message(message_handle.clone())),
+      None => true,
+    }
+  }
+
+  // A redelivered message may already have been processed, so unless the actor declares it idempotent it is
+  // refused rather than processed twice
+  async fn is_unsafe_redelivery(&self, message_handle: &MessageHandle) -> bool {
+    let Some(check) = self.inner.lock().await.props.get_idempotency_check() else {
+      return false;
+    };
+    let is_redelivery = unwrap_envelope_header(message_handle.clone())
+      .and_then(|header| header.get(DELIVERY_ATTEMPT_HEADER))
+      .and_then(|attempt| attempt.parse::<u32>().ok())
+      .is_some_and(|attempt| attempt > 1);
+    is_redelivery && !check.run(&unwrap_envelope_message(message_handle.clone()))
+  }
+
+  async fn reject_redelivery(&self, message_handle: MessageHandle) {
+    let actor_system = self.get_actor_system().await;
+    let delivery_id = unwrap_envelope_header(message_handle.clone()).and_then(|header| header.get(DELIVERY_ID_HEADER));
+    if let (Some(sender), Some(delivery_id)) = (unwrap_envelope_sender(message_handle.clone()), delivery_id) {
+      sender
+        .send_user_message(
+          actor_system.clone(),
+          MessageHandle::new(DeliveryRejected::new(delivery_id)),
+        )
+        .await;
+    }
+    let self_pid = self.get_self_opt().await;
+    actor_system
+      .get_dead_letter()
+      .await
+      .send_user_message(self_pid.as_ref(), message_handle)
+      .await;
+  }
+
+  // Replaces the snapshot reported by diagnostics_json, for actors spawned with Props::with_snapshot
+  async fn take_snapshot(&self) {
+    let (enabled, actor) = {
+      let inner_mg = self.inner.lock().await;
+      (inner_mg.props.get_snapshot(), inner_mg.actor.clone())
+    };
+    if !enabled {
+      return;
+    }
+    if let Some(actor) = actor {
+      let snapshot = actor.take_snapshot().await.map(Arc::new);
+      self.inner.lock().await.snapshot = snapshot;
+    }
+  }
+
+  // Returns the size and the limit of a user message larger than the max message bytes of the props.
+  // Messages the configured sizer cannot serialize are never oversized
+  async fn oversized_message_size(&self, message_handle: &MessageHandle) -> Option<(usize, usize)> {
+    if message_handle.to_typed::<AutoReceiveMessage>().is_some() {
+      return None;
+    }
+    let max_bytes = self.inner.lock().await.props.get_max_message_bytes()?;
+    let sizer = self.get_actor_system().await.get_message_sizer().await?;
+    let size = sizer.run(&unwrap_envelope_message(message_handle.clone()))?;
+    (size > max_bytes).then_some((size, max_bytes))
+  }
+
+  // GetRestartReason returns the failure that caused the restart in progress, None when the actor restarts
+  // without having failed itself, for example alongside a failed sibling
+  pub(crate) async fn get_restart_reason(&self) -> Option<ErrorReason> {
+    self.inner.lock().await.restart_reason.clone()
+  }
+
+  // Re-applies the journaled events to the freshly incarnated actor. Runs while the mailbox is processing a
+  // system message, so no other user message is received until the replay has completed
+  async fn replay_journal(&mut self) -> Result<(), ActorError> {
+    let journal = match self.get_props().await.get_journal() {
+      Some(journal) => journal,
+      None => return Ok(()),
+    };
+    let persistence_id = self.get_self().await.id().to_string();
+    let snapshot = journal
+      .load_snapshot(&persistence_id)
+      .await
+      .map_err(|err| ActorError::InitializationError(ErrorReason::from_error(err, 0)))?;
+    let sequence_nr = snapshot.as_ref().map_or(0, |snapshot| snapshot.sequence_nr);
+    let events = journal
+      .read(&persistence_id, sequence_nr + 1)
+      .await
+      .map_err(|err| ActorError::InitializationError(ErrorReason::from_error(err, 0)))?;
+    {
+      let mut mg = self.inner.lock().await;
+      mg.recovering = true;
+      mg.sequence_nr = sequence_nr;
+    }
+    let mut result = match snapshot {
+      Some(snapshot) => {
+        self
+          .invoke_user_message(MessageHandle::new(RecoverSnapshot(snapshot.state)))
+          .await
+      }
+      None => Ok(()),
+    };
+    if result.is_ok() {
+      for (sequence_nr, event) in events {
+        result = self.invoke_user_message(event).await;
+        if result.is_err() {
+          tracing::error!("Failed to replay journaled event: sequence_nr = {}", sequence_nr);
+          break;
+        }
+        self.inner.lock().await.sequence_nr = sequence_nr;
+      }
+    }
+    self.inner.lock().await.recovering = false;
+    result
+  }
+
   async fn restart(&mut self) -> Result<(), ActorError> {
+    if let Some(extras) = self.get_extras().await {
+      extras.get_stash().await.clear().await;
+    }
+    self.inner.lock().await.snapshot = None;
     self.incarnate_actor().await;
+    self.replay_journal().await?;
     self
       .get_self_opt()
       .await
@@ -303,12 +536,11 @@ impl ActorContext {
     let result = self
       .invoke_user_message(MessageHandle::new(AutoReceiveMessage::PostRestart))
       .await;
+    self.inner.lock().await.restart_reason = None;
     if result.is_err() {
       tracing::error!("Failed to handle Restarted message");
-      return result;
     }
-
-    self.un_stash_all().await
+    result
   }
 
   async fn finalize_stop(&mut self) -> Result<(), ActorError> {
@@ -319,16 +551,30 @@ impl ActorContext {
       .await
       .remove_process(&self.get_self_opt().await.unwrap())
       .await;
+    self.unregister_mailbox_length_observer().await;
     let result = self
       .invoke_user_message(MessageHandle::new(AutoReceiveMessage::PostStop))
       .await;
+    self
+      .get_actor_system()
+      .await
+      .get_event_stream()
+      .await
+      .publish(MessageHandle::new(LifecycleEvent::Stopped(self.get_self().await)))
+      .await;
     if result.is_err() {
       tracing::error!("Failed to handle Stopped message");
       return result;
     }
+    // An unresolved failure means the supervisor chose to stop this actor
+    let why = if self.inner.lock().await.restart_reason.is_some() {
+      TerminateReason::Failed
+    } else {
+      TerminateReason::Stopped
+    };
     let other_stopped = MessageHandle::new(SystemMessage::Terminate(Terminated {
       who: self.get_self_opt().await.map(|x| x.inner_pid),
-      why: TerminateReason::Stopped as i32,
+      why: why as i32,
     }));
     if let Some(extras) = self.get_extras().await {
       let watchers = extras.get_watchers().await;
@@ -346,15 +592,6 @@ impl ActorContext {
     Ok(())
   }
 
-  async fn stop_all_children(&mut self) {
-    let extras = self.ensure_extras().await;
-    let children = extras.get_children().await;
-    for child in children.to_vec().await {
-      let child = ExtendedPid::new(child);
-      self.stop(&child).await;
-    }
-  }
-
   async fn try_restart_or_terminate(&mut self) -> Result<(), ActorError> {
     match self.get_extras().await {
       Some(extras) if extras.get_children().await.is_empty().await => {
@@ -389,9 +626,25 @@ impl ActorContext {
   }
 
   async fn handle_start(&mut self) -> Result<(), ActorError> {
+    self.replay_journal().await?;
     self
       .invoke_user_message(MessageHandle::new(AutoReceiveMessage::PostStart))
       .await?;
+    let self_pid = self.get_self().await;
+    let actor_system = self.get_actor_system().await;
+    if let Some(actor_process) = self_pid
+      .ref_process(actor_system.clone())
+      .await
+      .as_any()
+      .downcast_ref::<ActorProcess>()
+    {
+      actor_process.set_started();
+    }
+    actor_system
+      .get_event_stream()
+      .await
+      .publish(MessageHandle::new(LifecycleEvent::Started(self_pid)))
+      .await;
     Ok(())
   }
 
@@ -414,6 +667,10 @@ impl ActorContext {
       return result;
     }
     self.stop_all_children().await;
+    if let Some(extras) = self.get_extras().await {
+      extras.cancel_scheduled_sends().await;
+      extras.cancel_pending_futures().await;
+    }
     let result = self.try_restart_or_terminate().await;
     if result.is_err() {
       tracing::error!("Failed to try_restart_or_terminate");
@@ -438,6 +695,9 @@ impl ActorContext {
       return result;
     }
     self.stop_all_children().await;
+    if let Some(extras) = self.get_extras().await {
+      extras.cancel_scheduled_sends().await;
+    }
     let result = self.try_restart_or_terminate().await;
     if result.is_err() {
       tracing::error!("Failed to try_restart_or_terminate");
@@ -479,12 +739,14 @@ impl ActorContext {
       .await;
       return;
     }
+    let actor_system = self.get_actor_system().await;
     self
       .get_props()
       .await
-      .get_supervisor_strategy()
+      .get_supervisor_strategy(&actor_system)
+      .await
       .handle_child_failure(
-        self.get_actor_system().await,
+        actor_system,
         SupervisorHandle::new(self.clone()),
         f.who.clone(),
         f.restart_stats.clone(),
@@ -515,7 +777,12 @@ impl ActorContext {
   }
 
   async fn handle_root_failure(&mut self, failure: &Failure) {
-    DEFAULT_SUPERVISION_STRATEGY
+    self
+      .get_actor_system()
+      .await
+      .get_config()
+      .await
+      .get_default_supervisor_strategy()
       .handle_child_failure(
         self.get_actor_system().await,
         SupervisorHandle::new(self.clone()),
@@ -527,6 +794,56 @@ impl ActorContext {
       .await;
   }
 
+  pub(crate) async fn register_mailbox_length_observer(&self, mailbox: MailboxHandle) {
+    self
+      .metrics_foreach(|am, m| {
+        let am = am.clone();
+        let m = m.clone();
+        let mailbox = mailbox.clone();
+        async move {
+          let labels = self.metrics_labels(&m).await;
+          let registration = am.register_mailbox_length_observer(
+            move || mailbox.try_get_user_messages_count().map(|count| count.max(0) as u64),
+            labels,
+          );
+          let mut inner_mg = self.inner.lock().await;
+          inner_mg.mailbox_length_registration = Some(registration);
+        }
+      })
+      .await;
+  }
+
+  // The gauge callback outlives the actor, so its observer has to be removed explicitly on stop
+  async fn unregister_mailbox_length_observer(&self) {
+    let registration = {
+      let mut inner_mg = self.inner.lock().await;
+      inner_mg.mailbox_length_registration.take()
+    };
+    if let Some(registration) = registration {
+      self
+        .metrics_foreach(|am, _| {
+          let am = am.clone();
+          let registration = registration.clone();
+          async move {
+            am.unregister_mailbox_length_observer(&registration);
+          }
+        })
+        .await;
+    }
+  }
+
+  async fn metrics_labels(&self, m: &Metrics) -> Vec<KeyValue> {
+    let mut labels = m.common_labels(self).await;
+    let actor_path = {
+      let inner_mg = self.inner.lock().await;
+      inner_mg.actor_path.clone()
+    };
+    if let Some(actor_path) = actor_path {
+      labels.push(KeyValue::new("actor_path", actor_path));
+    }
+    labels
+  }
+
   async fn metrics_foreach<F, Fut>(&self, f: F)
   where
     F: Fn(&ActorMetrics, &Metrics) -> Fut,
@@ -563,6 +880,8 @@ impl InfoPart for ActorContext {
 
   async fn set_self(&mut self, pid: ExtendedPid) {
     let mut mg = self.inner.lock().await;
+    // a child's id is its parent's id followed by its own name, so the id already spells the actor's path
+    mg.actor_path = Some(pid.id().to_string());
     mg.self_pid = Some(pid);
   }
 
@@ -583,27 +902,23 @@ impl BasePart for ActorContext {
     self
   }
 
-  async fn get_receive_timeout(&self) -> Duration {
+  async fn get_receive_timeout(&self) -> Option<Duration> {
     let inner_mg = self.inner.lock().await;
-    *inner_mg
-      .receive_timeout
-      .as_ref()
-      .expect("Failed to retrieve receive_timeout")
+    inner_mg.receive_timeout
   }
 
   async fn get_children(&self) -> Vec<ExtendedPid> {
-    self
-      .get_extras()
-      .await
-      .as_ref()
-      .expect("Failed to retrieve extras")
-      .get_children()
-      .await
-      .to_vec()
-      .await
-      .into_iter()
-      .map(ExtendedPid::new)
-      .collect()
+    match self.get_extras().await {
+      Some(extras) => extras
+        .get_children()
+        .await
+        .to_vec()
+        .await
+        .into_iter()
+        .map(ExtendedPid::new)
+        .collect(),
+      None => vec![],
+    }
   }
 
   async fn respond(&self, response: ResponseHandle) {
@@ -629,20 +944,29 @@ impl BasePart for ActorContext {
   async fn stash(&mut self) {
     let extra = self.ensure_extras().await;
     let mut stash = extra.get_stash().await;
-    stash.push(self.get_message_handle().await).await;
+    stash.push(self.get_message_or_envelop().await).await;
   }
 
-  async fn un_stash_all(&mut self) -> Result<(), ActorError> {
-    if let Some(extras) = self.get_extras().await {
-      while !extras.get_stash().await.is_empty().await {
-        let msg = extras.get_stash().await.pop().await.unwrap();
-        let result = self.invoke_user_message(msg).await;
-        if result.is_err() {
-          tracing::error!("Failed to handle stashed message");
-          return result;
-        }
+  async fn un_stash(&mut self) -> bool {
+    let stashed = match self.get_extras().await {
+      Some(extras) => extras.get_stash().await.pop_front().await,
+      None => None,
+    };
+    match stashed {
+      Some(message_or_envelope) => {
+        self
+          .get_self()
+          .await
+          .send_user_message(self.get_actor_system().await, message_or_envelope)
+          .await;
+        true
       }
+      None => false,
     }
+  }
+
+  async fn un_stash_all(&mut self) -> Result<(), ActorError> {
+    while self.un_stash().await {}
     Ok(())
   }
 
@@ -701,17 +1025,22 @@ impl BasePart for ActorContext {
         extras.kill_receive_timeout_timer().await;
       }
     }
+    let mut inner_mg = self.inner.lock().await;
+    inner_mg.receive_timeout = None;
   }
 
   async fn forward(&self, pid: &ExtendedPid) {
-    let inner_mg = self.inner.lock().await;
-    let mg = inner_mg.message_or_envelope_opt.read().await;
-    if let Some(message_or_envelope) = &*mg {
+    let message_or_envelope = {
+      let inner_mg = self.inner.lock().await;
+      let mg = inner_mg.message_or_envelope_opt.read().await;
+      mg.clone()
+    };
+    if let Some(message_or_envelope) = message_or_envelope {
       if let Some(sm) = message_or_envelope.to_typed::<SystemMessage>() {
         panic!("SystemMessage cannot be forwarded: {:?}", sm);
       } else {
         pid
-          .send_user_message(self.get_actor_system().await, message_or_envelope.clone())
+          .send_user_message(self.get_actor_system().await, message_or_envelope)
           .await;
       }
     }
@@ -747,6 +1076,53 @@ impl BasePart for ActorContext {
       })
       .await
   }
+
+  async fn diagnostics_json(&self) -> String {
+    let mailbox = match self.find_mailbox().await {
+      Some(mailbox) => Some(serde_json::json!({
+        "user_messages": mailbox.get_user_messages_count().await,
+        "system_messages": mailbox.get_system_messages_count().await,
+      })),
+      None => None,
+    };
+    let (children, restart_failures) = match self.get_extras().await {
+      Some(mut extras) => (
+        extras.get_children().await.len().await,
+        extras.restart_stats().await.failure_count().await,
+      ),
+      None => (0, 0),
+    };
+    let inner_mg = self.inner.lock().await;
+    serde_json::json!({
+      "pid": inner_mg.self_pid.as_ref().map(|pid| pid.to_string()),
+      "actor_type": inner_mg.actor.as_ref().map(|actor| actor.get_type_name()),
+      "mailbox": mailbox,
+      "receive_timeout_ms": inner_mg.receive_timeout.map(|timeout| timeout.as_millis() as u64),
+      "children": children,
+      "restart_failures": restart_failures,
+      "message_history": inner_mg.message_history.as_ref().map(|history| history.get_entries()),
+      "snapshot": inner_mg.snapshot.as_deref(),
+    })
+    .to_string()
+  }
+
+  async fn mailbox_pressure(&self) -> usize {
+    match self.find_mailbox().await {
+      Some(mailbox) => mailbox.get_user_messages_count().await.max(0) as usize,
+      None => 0,
+    }
+  }
+  async fn receive_timeout_remaining(&self) -> Option<Duration> {
+    match self.get_extras().await {
+      Some(extras) => extras.get_receive_timeout_remaining().await,
+      None => None,
+    }
+  }
+
+  async fn flag(&self, name: &str) -> bool {
+    let inner_mg = self.inner.lock().await;
+    inner_mg.props.get_flags().get(name).copied().unwrap_or(false)
+  }
 }
 
 #[async_trait]
@@ -780,6 +1156,27 @@ impl MessagePart for ActorContext {
       None
     }
   }
+
+  async fn correlation_id(&self) -> String {
+    let correlation_id = self
+      .get_message_header_handle()
+      .await
+      .and_then(|header| header.get(CORRELATION_ID_HEADER));
+    match correlation_id {
+      Some(correlation_id) => correlation_id,
+      None => {
+        let correlation_id = uuid::Uuid::new_v4().to_string();
+        self
+          .set_message_header(CORRELATION_ID_HEADER, correlation_id.clone())
+          .await;
+        correlation_id
+      }
+    }
+  }
+
+  async fn set_correlation_id(&mut self, id: String) {
+    self.set_message_header(CORRELATION_ID_HEADER, id).await;
+  }
 }
 
 #[async_trait]
@@ -798,6 +1195,12 @@ impl SenderPart for ActorContext {
     self.send_user_message(pid, message_handle).await;
   }
 
+  async fn begin_batch(&mut self) -> SendBatch {
+    let batch = SendBatch::new(SenderContextHandle::new(self.clone()));
+    self.inner.lock().await.send_batches.push(batch.clone());
+    batch
+  }
+
   async fn request(&mut self, pid: ExtendedPid, message_handle: MessageHandle) {
     let env = MessageEnvelope::new(message_handle).with_sender(self.get_self_opt().await.unwrap());
     let message_handle = MessageHandle::new(env);
@@ -816,11 +1219,18 @@ impl SenderPart for ActorContext {
     message_handle: MessageHandle,
     timeout: Duration,
   ) -> crate::actor::dispatch::future::ActorFuture {
-    let future_process = ActorFutureProcess::new(self.get_actor_system().await, timeout.clone()).await;
+    let future_process = match ActorFutureProcess::new(self.get_actor_system().await, timeout.clone()).await {
+      Ok(future_process) => future_process,
+      Err(error) => return ActorFuture::failed(self.get_actor_system().await, error).await,
+    };
+    let future = future_process.get_future().await;
+    // Requests still waiting for a response when the actor stops are cancelled
+    let mut cloned = self.clone();
+    cloned.ensure_extras().await.add_pending_future(future.clone()).await;
     let future_pid = future_process.get_pid().await;
     let moe = MessageEnvelope::new(message_handle).with_sender(future_pid);
     self.send_user_message(pid, MessageHandle::new(moe)).await;
-    future_process.get_future().await
+    future
   }
 }
 
@@ -837,13 +1247,7 @@ impl ReceiverPart for ActorContext {
 #[async_trait]
 impl SpawnerPart for ActorContext {
   async fn spawn(&mut self, props: Props) -> ExtendedPid {
-    match self
-      .spawn_named(
-        props,
-        &self.get_actor_system().await.get_process_registry().await.next_id(),
-      )
-      .await
-    {
+    match props.spawn_with_generated_name(self).await {
       Ok(pid) => pid,
       Err(e) => panic!("Failed to spawn child: {:?}", e),
     }
@@ -912,7 +1316,10 @@ impl StopperPart for ActorContext {
     pid: &ExtendedPid,
     timeout: Duration,
   ) -> crate::actor::dispatch::future::ActorFuture {
-    let future_process = ActorFutureProcess::new(self.get_actor_system().await, timeout).await;
+    let future_process = match ActorFutureProcess::new(self.get_actor_system().await, timeout).await {
+      Ok(future_process) => future_process,
+      Err(error) => return ActorFuture::failed(self.get_actor_system().await, error).await,
+    };
     pid
       .send_system_message(
         self.get_actor_system().await,
@@ -937,7 +1344,10 @@ impl StopperPart for ActorContext {
     pid: &ExtendedPid,
     timeout: Duration,
   ) -> crate::actor::dispatch::future::ActorFuture {
-    let future_process = ActorFutureProcess::new(self.get_actor_system().await, timeout).await;
+    let future_process = match ActorFutureProcess::new(self.get_actor_system().await, timeout).await {
+      Ok(future_process) => future_process,
+      Err(error) => return ActorFuture::failed(self.get_actor_system().await, error).await,
+    };
 
     pid
       .send_system_message(
@@ -951,6 +1361,31 @@ impl StopperPart for ActorContext {
 
     future_process.get_future().await
   }
+
+  async fn stop_all_children(&mut self) {
+    let extras = self.ensure_extras().await;
+    let children = extras.get_children().await;
+    for child in children.to_vec().await {
+      let child = ExtendedPid::new(child);
+      self.stop(&child).await;
+    }
+  }
+
+  async fn stop_all_children_future_with_timeout(
+    &mut self,
+    timeout: Duration,
+  ) -> BoxFuture<'static, Result<(), ActorFutureError>> {
+    let mut futures = Vec::new();
+    for child in BasePart::get_children(self).await {
+      futures.push(self.stop_future_with_timeout(&child, timeout).await);
+    }
+    Box::pin(async move {
+      for future in futures {
+        future.result().await?;
+      }
+      Ok(())
+    })
+  }
 }
 
 #[async_trait]
@@ -966,7 +1401,70 @@ impl ExtensionPart for ActorContext {
   }
 }
 
-impl SenderContext for ActorContext {}
+#[async_trait]
+impl PersistencePart for ActorContext {
+  async fn persist(&mut self, event: MessageHandle) -> Result<(), JournalError> {
+    let (journal, recovering) = {
+      let mg = self.inner.lock().await;
+      (mg.props.get_journal(), mg.recovering)
+    };
+    let journal = journal.ok_or(JournalError::NotConfigured)?;
+    if recovering {
+      return Ok(());
+    }
+    let persistence_id = self.get_self().await.id().to_string();
+    journal.append(&persistence_id, event).await?;
+    self.inner.lock().await.sequence_nr += 1;
+    Ok(())
+  }
+
+  async fn is_recovering(&self) -> bool {
+    let mg = self.inner.lock().await;
+    mg.recovering
+  }
+
+  async fn persist_snapshot(&mut self, state: MessageHandle) -> Result<(), JournalError> {
+    let (journal, sequence_nr) = {
+      let mg = self.inner.lock().await;
+      (mg.props.get_journal(), mg.sequence_nr)
+    };
+    let journal = journal.ok_or(JournalError::NotConfigured)?;
+    let persistence_id = self.get_self().await.id().to_string();
+    journal
+      .persist_snapshot(&persistence_id, Snapshot { sequence_nr, state })
+      .await
+  }
+
+  async fn sequence_nr(&self) -> u64 {
+    let mg = self.inner.lock().await;
+    mg.sequence_nr
+  }
+
+  async fn delete_events_to(&mut self, to_sequence_nr: u64) -> Result<(), JournalError> {
+    let journal = {
+      let mg = self.inner.lock().await;
+      mg.props.get_journal()
+    };
+    let journal = journal.ok_or(JournalError::NotConfigured)?;
+    let persistence_id = self.get_self().await.id().to_string();
+    journal.delete_to(&persistence_id, to_sequence_nr).await
+  }
+}
+
+#[async_trait]
+impl SenderContext for ActorContext {
+  async fn send_after(&mut self, pid: ExtendedPid, message_handle: MessageHandle, delay: Duration) {
+    let mut cloned_self = self.clone();
+    let token = self
+      .get_actor_system()
+      .await
+      .get_scheduler()
+      .schedule_once_fn(delay, move || async move {
+        cloned_self.send(pid, message_handle).await;
+      });
+    self.ensure_extras().await.add_scheduled_send(token).await;
+  }
+}
 impl ReceiverContext for ActorContext {}
 
 impl SpawnerContext for ActorContext {}
@@ -975,6 +1473,13 @@ impl ExtensionContext for ActorContext {}
 
 impl Context for ActorContext {}
 
+#[async_trait]
+impl Diagnostics for ActorContext {
+  async fn get_diagnostics(&self) -> String {
+    self.diagnostics_json().await
+  }
+}
+
 #[async_trait]
 impl MessageInvoker for ActorContext {
   async fn invoke_system_message(&mut self, message_handle: MessageHandle) -> Result<(), ActorError> {
@@ -1023,6 +1528,58 @@ impl MessageInvoker for ActorContext {
     if state.as_ref().as_ref().unwrap().load(Ordering::SeqCst) == State::Stopped as u8 {
       return Ok(());
     }
+
+    if !self.is_accepted_by_receive_guard(&message_handle).await {
+      let self_pid = self.get_self_opt().await;
+      self
+        .get_actor_system()
+        .await
+        .get_dead_letter()
+        .await
+        .send_user_message(self_pid.as_ref(), message_handle)
+        .await;
+      return Ok(());
+    }
+
+    if self.is_unsafe_redelivery(&message_handle).await {
+      self.reject_redelivery(message_handle).await;
+      return Ok(());
+    }
+
+    if let Some((size, max_bytes)) = self.oversized_message_size(&message_handle).await {
+      let self_pid = self.get_self_opt().await;
+      let actor_system = self.get_actor_system().await;
+      tracing::warn!(
+        "Message exceeds max message bytes: pid = {:?}, size = {}, max_bytes = {}",
+        self_pid,
+        size,
+        max_bytes
+      );
+      actor_system
+        .get_event_stream()
+        .await
+        .publish(MessageHandle::new(MessageTooLargeEvent {
+          pid: self_pid.clone(),
+          message_handle: message_handle.clone(),
+          size,
+          max_bytes,
+        }))
+        .await;
+      actor_system
+        .get_dead_letter()
+        .await
+        .send_user_message(self_pid.as_ref(), message_handle)
+        .await;
+      return Ok(());
+    }
+
+    {
+      let mut inner_mg = self.inner.lock().await;
+      if let Some(message_history) = inner_mg.message_history.as_mut() {
+        message_history.record(unwrap_envelope_message(message_handle.clone()).get_type_name());
+      }
+    }
+
     let mut influence_timeout = true;
 
     let receive_timeout = {
@@ -1031,7 +1588,7 @@ impl MessageInvoker for ActorContext {
     };
 
     if receive_timeout.unwrap_or_else(|| Duration::from_millis(0)) > Duration::from_millis(0) {
-      influence_timeout = !message_handle.to_typed::<NotInfluenceReceiveTimeoutHandle>().is_some();
+      influence_timeout = unwrap_envelope_message(message_handle.clone()).influences_receive_timeout();
       if influence_timeout {
         let mg = self.get_extras().await;
         if let Some(extras) = mg {
@@ -1040,30 +1597,42 @@ impl MessageInvoker for ActorContext {
       }
     }
 
-    let result = if self
-      .get_actor_system()
-      .await
-      .get_config()
-      .await
-      .metrics_provider
-      .is_some()
-    {
+    let config = self.get_actor_system().await.get_config().await;
+    let span = if config.message_tracing {
+      self.message_span(&message_handle).await
+    } else {
+      Span::none()
+    };
+
+    let result = if config.metrics_provider.is_some() {
       let start = Instant::now();
-      let result = self.process_message(message_handle).await;
+      let result = self.process_message(message_handle).instrument(span).await;
       let duration = start.elapsed();
-      self
-        .metrics_foreach(|am, _| {
+      let ctx: &Self = self;
+      ctx
+        .metrics_foreach(|am, m| {
           let am = am.clone();
+          let m = m.clone();
           async move {
-            am.record_actor_message_receive_duration(duration.as_secs_f64()).await;
+            am.record_actor_message_receive_duration_with_opts(duration.as_secs_f64(), &ctx.metrics_labels(&m).await)
+              .await;
           }
         })
         .await;
       result
     } else {
-      self.process_message(message_handle).await
+      self.process_message(message_handle).instrument(span).await
     };
 
+    self.take_snapshot().await;
+
+    let send_batches = std::mem::take(&mut self.inner.lock().await.send_batches);
+    if result.is_err() {
+      for batch in send_batches {
+        batch.rollback().await;
+      }
+    }
+
     let receive_timeout = {
       let inner_mg = self.inner.lock().await;
       inner_mg.receive_timeout.clone()
@@ -1081,6 +1650,7 @@ impl MessageInvoker for ActorContext {
 
   async fn escalate_failure(&mut self, reason: ErrorReason, message_handle: MessageHandle) {
     tracing::info!("[ACTOR] Recovering: reason = {:?}", reason.backtrace(),);
+    self.inner.lock().await.restart_reason = Some(reason.clone());
 
     self
       .metrics_foreach(|am, _| {
@@ -1114,6 +1684,21 @@ impl MessageInvoker for ActorContext {
         .await;
     }
   }
+
+  async fn expire_user_message(&mut self, expired: Expired) {
+    let self_pid = self.get_self_opt().await;
+    self
+      .get_actor_system()
+      .await
+      .get_dead_letter()
+      .await
+      .send_user_message(self_pid.as_ref(), MessageHandle::new(expired))
+      .await;
+  }
+
+  async fn mailbox_resumed(&mut self) {
+    self.inner.lock().await.restart_reason = None;
+  }
 }
 
 #[async_trait]
@@ -1157,7 +1742,7 @@ impl Supervisor for ActorContext {
         let am = am.clone();
         let m = m.clone();
         async move {
-          am.increment_actor_failure_count_with_opts(&m.common_labels(self).await)
+          am.increment_actor_failure_count_with_opts(&self.metrics_labels(&m).await)
             .await;
         }
       })
@@ -1166,21 +1751,27 @@ impl Supervisor for ActorContext {
     let mut cloned_self = self.clone();
 
     let failure = Failure::new(
-      self_pid,
+      self_pid.clone(),
       reason,
       cloned_self.ensure_extras().await.restart_stats().await,
       message_handle,
     );
 
-    self
-      .get_self_opt()
-      .await
-      .unwrap()
-      .send_system_message(self.get_actor_system().await, MessageHandle::new(failure.clone()))
+    self_pid
+      .send_system_message(
+        self.get_actor_system().await,
+        MessageHandle::new(MailboxMessage::SuspendMailbox),
+      )
       .await;
 
     if self.get_parent().await.is_none() {
-      cloned_self.handle_root_failure(&failure).await
+      handle_root_escalation(
+        &self.get_actor_system().await,
+        &self_pid,
+        failure.reason.clone(),
+        failure.message_handle.clone(),
+      )
+      .await;
     } else {
       self
         .get_parent()
@@ -1,6 +1,8 @@
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
 
 use crate::actor::actor::ExtendedPid;
 use crate::actor::actor::PidSet;
@@ -11,6 +13,7 @@ use crate::actor::context::receive_timeout_timer::ReceiveTimeoutTimer;
 use crate::actor::context::receiver_context_handle::ReceiverContextHandle;
 use crate::actor::context::sender_context_handle::SenderContextHandle;
 use crate::actor::context::InfoPart;
+use crate::actor::dispatch::future::{ActorFuture, ActorFutureError};
 use crate::actor::dispatch::Runnable;
 use crate::actor::message::MessageHandles;
 use crate::ctxext::extensions::ContextExtensions;
@@ -19,11 +22,14 @@ use crate::ctxext::extensions::ContextExtensions;
 struct ActorContextExtrasInner {
   children: PidSet,
   pub(crate) receive_timeout_timer: Option<ReceiveTimeoutTimer>,
+  receive_timeout_deadline: Option<Instant>,
   rs: Arc<RwLock<Option<RestartStatistics>>>,
   stash: MessageHandles,
   watchers: PidSet,
   context: ContextHandle,
   extensions: ContextExtensions,
+  scheduled_sends: Vec<CancellationToken>,
+  pending_futures: Vec<ActorFuture>,
 }
 
 impl ActorContextExtrasInner {
@@ -31,11 +37,14 @@ impl ActorContextExtrasInner {
     Self {
       children: PidSet::new().await,
       receive_timeout_timer: None,
+      receive_timeout_deadline: None,
       rs: Arc::new(RwLock::new(None)),
       stash: MessageHandles::new(vec![]),
       watchers: PidSet::new().await,
       context,
       extensions: ContextExtensions::new(),
+      scheduled_sends: vec![],
+      pending_futures: vec![],
     }
   }
 }
@@ -106,17 +115,19 @@ impl ActorContextExtras {
       Some(_) => return,
       None => {
         inner_mg.receive_timeout_timer = Some(ReceiveTimeoutTimer::new(duration));
+        inner_mg.receive_timeout_deadline = Some(Instant::now() + duration);
       }
     }
   }
 
   pub async fn init_or_reset_receive_timeout_timer(&mut self, d: Duration, context: Arc<RwLock<ActorContext>>) {
-    self.stop_receive_timeout_timer().await;
-
-    let timer = Arc::new(RwLock::new(Box::pin(tokio::time::sleep(d))));
+    let timer = ReceiveTimeoutTimer::new(d);
     {
       let mut mg = self.inner.write().await;
-      mg.receive_timeout_timer = Some(ReceiveTimeoutTimer::from_underlying(timer.clone()));
+      if let Some(mut previous) = mg.receive_timeout_timer.replace(timer.clone()) {
+        previous.stop();
+      }
+      mg.receive_timeout_deadline = Some(Instant::now() + d);
     }
 
     let context = context.clone();
@@ -125,35 +136,53 @@ impl ActorContextExtras {
       mg.get_actor_system().await.get_config().await.system_dispatcher.clone()
     };
 
-    dispatcher
-      .schedule(Runnable::new(move || async move {
-        let mut mg = timer.write().await;
-        mg.as_mut().await;
-        let mut locked_context = context.write().await;
-        locked_context.receive_timeout_handler().await;
+    let result = dispatcher
+      .schedule_with_backoff(Runnable::new(move || async move {
+        if timer.wait().await {
+          let mut locked_context = context.write().await;
+          locked_context.receive_timeout_handler().await;
+        }
       }))
       .await;
+    if let Err(err) = result {
+      tracing::error!("Failed to schedule the receive timeout: {}", err);
+    }
   }
 
   pub async fn reset_receive_timeout_timer(&self, duration: Duration) {
     let mut mg = self.inner.write().await;
-    if let Some(t) = &mut mg.receive_timeout_timer {
-      t.reset(tokio::time::Instant::now() + duration).await;
+    let inner = &mut *mg;
+    let deadline = Instant::now() + duration;
+    if let Some(t) = &mut inner.receive_timeout_timer {
+      t.reset(deadline);
+      inner.receive_timeout_deadline = Some(deadline);
     }
   }
 
+  // Pauses the timer while a message is received, reset_receive_timeout_timer starts it again
   pub async fn stop_receive_timeout_timer(&self) {
     let mut mg = self.inner.write().await;
     if let Some(t) = &mut mg.receive_timeout_timer {
-      t.stop().await;
+      t.pause();
     }
   }
 
+  // The scheduled wait holds its own clone of the timer and of the context. Stopping cancels that wait, so
+  // the task ends instead of outliving the timer
   pub async fn kill_receive_timeout_timer(&self) {
     let mut mg = self.inner.write().await;
-    if mg.receive_timeout_timer.is_some() {
-      mg.receive_timeout_timer = None
+    if let Some(mut t) = mg.receive_timeout_timer.take() {
+      t.stop();
     }
+    mg.receive_timeout_deadline = None;
+  }
+
+  // The timer is stopped while a message is received, so during a receive this is the time that was left
+  // when the message arrived
+  pub async fn get_receive_timeout_remaining(&self) -> Option<Duration> {
+    let mg = self.inner.read().await;
+    mg.receive_timeout_deadline
+      .map(|deadline| deadline.saturating_duration_since(Instant::now()))
   }
 
   pub async fn wait_for_timeout(&self) {
@@ -163,7 +192,40 @@ impl ActorContextExtras {
     }
 
     if let Some(t) = &mg.receive_timeout_timer {
-      t.wait().await
+      t.wait().await;
+    }
+  }
+
+  pub async fn add_scheduled_send(&self, token: CancellationToken) {
+    let mut mg = self.inner.write().await;
+    mg.scheduled_sends.retain(|token| !token.is_cancelled());
+    mg.scheduled_sends.push(token);
+  }
+
+  pub async fn cancel_scheduled_sends(&self) {
+    let mut mg = self.inner.write().await;
+    for token in mg.scheduled_sends.drain(..) {
+      token.cancel();
+    }
+  }
+
+  pub async fn add_pending_future(&self, future: ActorFuture) {
+    let mut mg = self.inner.write().await;
+    let mut pending_futures = Vec::with_capacity(mg.pending_futures.len() + 1);
+    for pending_future in mg.pending_futures.drain(..) {
+      if !pending_future.is_done().await {
+        pending_futures.push(pending_future);
+      }
+    }
+    pending_futures.push(future);
+    mg.pending_futures = pending_futures;
+  }
+
+  // Fails the futures of requests made by the actor that are still waiting for a response
+  pub async fn cancel_pending_futures(&self) {
+    let pending_futures = std::mem::take(&mut self.inner.write().await.pending_futures);
+    for future in pending_futures {
+      future.fail(ActorFutureError::Cancelled).await;
     }
   }
 
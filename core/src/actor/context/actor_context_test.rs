@@ -1,20 +1,49 @@
 #[cfg(test)]
 mod tests {
+  use std::collections::HashMap;
   use std::env;
-  use std::time::Duration;
+  use std::sync::Arc;
+  use std::time::{Duration, Instant};
 
+  use crate::actor::actor::Actor;
   use crate::actor::actor::ActorError;
   use crate::actor::actor::Continuer;
   use crate::actor::actor::ErrorReason;
+  use crate::actor::actor::ExtendedPid;
   use crate::actor::actor::Props;
+  use crate::actor::actor::Snapshotable;
+  use crate::actor::actor::{TypedExtendedPid, TypedProps};
   use crate::actor::actor_system::ActorSystem;
-  use crate::actor::context::{BasePart, InfoPart, MessagePart, SenderPart, SpawnerPart};
+  use crate::actor::context::actor_context_extras::ActorContextExtras;
+  use crate::actor::context::mock_context::MockContext;
+  use crate::actor::context::receive_timeout_timer::ReceiveTimeoutTimer;
+  use crate::actor::context::{
+    AskError, BasePart, ContextHandle, InfoPart, MessagePart, RetryPolicy, RootContext, SendError, SenderContext,
+    SenderPart, SpawnerPart, StopperPart, DELIVERY_ATTEMPT_HEADER,
+  };
+  use crate::actor::dispatch::future::{ActorFutureError, ActorFutureProcess};
   use crate::actor::message::AutoRespond;
   use crate::actor::message::Message;
   use crate::actor::message::MessageHandle;
+  use crate::actor::message::ReadonlyMessageHeaders;
   use crate::actor::message::ResponseHandle;
   use crate::actor::message::Touched;
+  use crate::actor::message::{wrap_envelope_with_header, CORRELATION_ID_HEADER};
+  use crate::actor::message::{MessageEnvelope, MessageHeaders};
+  use crate::actor::metrics::metrics_impl::{Metrics, EXTENSION_ID};
+  use crate::actor::supervisor::{AllForOneStrategy, Directive, SupervisorStrategyHandle};
+  use crate::actor::typed_context::{TypedMessagePart, TypedSenderPart, TypedSpawnerPart};
+  use crate::actor::{ConfigOption, MetricsProvider};
+  use crate::metrics::{ActorMetrics, ProtoMetrics};
+  use async_trait::async_trait;
   use nexus_actor_message_derive_rs::Message;
+  use opentelemetry::KeyValue;
+  use opentelemetry_sdk::metrics::data::{self, ResourceMetrics, Temporality};
+  use opentelemetry_sdk::metrics::reader::{AggregationSelector, MetricReader, TemporalitySelector};
+  use opentelemetry_sdk::metrics::{Aggregation, InstrumentKind, ManualReader, MeterProviderBuilder, Pipeline};
+  use opentelemetry_sdk::Resource;
+  use std::sync::Weak;
+  use tokio::sync::{Mutex, Notify};
   use tracing_subscriber::EnvFilter;
 
   #[tokio::test]
@@ -83,6 +112,59 @@ mod tests {
     assert_eq!(response, "done".to_string());
   }
 
+  #[tokio::test]
+  async fn test_reenter_after_delivers_future_error_as_result() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let silent = root_context
+      .spawn(Props::from_async_actor_receiver(|_| async { Ok(()) }).await)
+      .await;
+
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let silent = silent.clone();
+          async move {
+            if ctx.get_message_handle().await.to_typed::<String>().is_some() {
+              let future = ctx
+                .request_future(silent, MessageHandle::new(1u32), Duration::from_millis(50))
+                .await;
+              let cloned_ctx = ctx.clone();
+              ctx
+                .reenter_after(
+                  future,
+                  Continuer::from_result(move |result| {
+                    let cloned_ctx = cloned_ctx.clone();
+                    async move {
+                      let outcome = match result {
+                        Ok(_) => "ok".to_string(),
+                        Err(err) => err.to_string(),
+                      };
+                      cloned_ctx.respond(ResponseHandle::new(outcome)).await;
+                    }
+                  }),
+                )
+                .await;
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    let res = root_context
+      .request_future(pid, MessageHandle::new("start".to_string()), Duration::from_secs(5))
+      .await
+      .result()
+      .await
+      .unwrap();
+    assert_eq!(
+      *res.to_typed::<String>().unwrap(),
+      ActorFutureError::TimeoutError.to_string()
+    );
+  }
+
   #[derive(Debug, Clone, PartialEq, Eq, Message)]
   struct DummyAutoRespond {}
 
@@ -151,4 +233,1767 @@ mod tests {
     assert!(result2.is_some());
     assert_eq!(result2.unwrap().who.unwrap(), pid.inner_pid);
   }
+
+  #[tokio::test]
+  async fn test_actor_context_ask() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let echo_pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| async move {
+          if let Some(msg) = ctx.get_message_handle().await.to_typed::<String>() {
+            ctx.respond(ResponseHandle::new(format!("echo:{}", msg))).await;
+          }
+          Ok(())
+        })
+        .await,
+      )
+      .await;
+
+    let asker_pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let echo_pid = echo_pid.clone();
+          async move {
+            if let Some(msg) = ctx.get_message_handle().await.to_typed::<String>() {
+              if msg == "start" {
+                let reply = ctx
+                  .ask::<String>(
+                    echo_pid,
+                    MessageHandle::new("hello".to_string()),
+                    Duration::from_secs(1),
+                  )
+                  .await
+                  .unwrap();
+                ctx.respond(ResponseHandle::new(reply)).await;
+              }
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    let result = root_context
+      .request_future(
+        asker_pid,
+        MessageHandle::new("start".to_string()),
+        Duration::from_secs(1),
+      )
+      .await
+      .result()
+      .await
+      .unwrap();
+
+    assert_eq!(result.to_typed::<String>(), Some("echo:hello".to_string()));
+  }
+
+  #[tokio::test]
+  async fn test_actor_context_ask_is_interrupted_by_stop() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let silent_pid = root_context
+      .spawn(Props::from_async_actor_receiver(move |_| async move { Ok(()) }).await)
+      .await;
+
+    let ask_result = Arc::new(Mutex::new(None));
+    let cloned_ask_result = ask_result.clone();
+    let asker_pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let silent_pid = silent_pid.clone();
+          let ask_result = cloned_ask_result.clone();
+          async move {
+            if let Some(msg) = ctx.get_message_handle().await.to_typed::<String>() {
+              if msg == "start" {
+                let result = ctx
+                  .ask::<String>(
+                    silent_pid,
+                    MessageHandle::new("hello".to_string()),
+                    Duration::from_secs(10),
+                  )
+                  .await;
+                *ask_result.lock().await = Some(result);
+              }
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    root_context
+      .send(asker_pid.clone(), MessageHandle::new("start".to_string()))
+      .await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let started_at = Instant::now();
+    root_context.stop_future(&asker_pid).await.result().await.unwrap();
+
+    assert!(started_at.elapsed() < Duration::from_secs(5));
+    assert_eq!(*ask_result.lock().await, Some(Err(AskError::Stopped)));
+  }
+
+  #[tokio::test]
+  async fn test_actor_context_ask_is_interrupted_by_supervisor_stop() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let silent_pid = root_context
+      .spawn(Props::from_async_actor_receiver(move |_| async move { Ok(()) }).await)
+      .await;
+
+    let ask_result = Arc::new(Mutex::new(None));
+    let failing_pid = Arc::new(Mutex::new(None));
+    let cloned_ask_result = ask_result.clone();
+    let cloned_failing_pid = failing_pid.clone();
+    // The parent stops all of its children, including the asking one, as soon as any of them fails
+    let parent_pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver_with_opts(
+          move |mut ctx| {
+            let silent_pid = silent_pid.clone();
+            let ask_result = cloned_ask_result.clone();
+            let failing_pid = cloned_failing_pid.clone();
+            async move {
+              if ctx.get_message_handle().await.to_typed::<String>().is_none() {
+                return Ok(());
+              }
+              let asker_pid = ctx
+                .spawn(
+                  Props::from_async_actor_receiver(move |ctx| {
+                    let silent_pid = silent_pid.clone();
+                    let ask_result = ask_result.clone();
+                    async move {
+                      if ctx.get_message_handle().await.to_typed::<String>().is_some() {
+                        let result = ctx
+                          .ask::<String>(
+                            silent_pid,
+                            MessageHandle::new("hello".to_string()),
+                            Duration::from_secs(10),
+                          )
+                          .await;
+                        *ask_result.lock().await = Some(result);
+                      }
+                      Ok(())
+                    }
+                  })
+                  .await,
+                )
+                .await;
+              let failing = ctx
+                .spawn(
+                  Props::from_async_actor_receiver(move |ctx| async move {
+                    if ctx.get_message_handle().await.to_typed::<String>().is_some() {
+                      return Err(ActorError::ReceiveError(ErrorReason::new("fail", 0)));
+                    }
+                    Ok(())
+                  })
+                  .await,
+                )
+                .await;
+              ctx.send(asker_pid, MessageHandle::new("start".to_string())).await;
+              *failing_pid.lock().await = Some(failing);
+              Ok(())
+            }
+          },
+          [Props::with_supervisor_strategy(SupervisorStrategyHandle::new(
+            AllForOneStrategy::new(10, Duration::from_secs(10)).with_decider(|_| async { Directive::Stop }),
+          ))],
+        )
+        .await,
+      )
+      .await;
+
+    root_context
+      .send(parent_pid, MessageHandle::new("spawn".to_string()))
+      .await;
+    let deadline = Instant::now() + Duration::from_secs(3);
+    let failing = loop {
+      if let Some(pid) = failing_pid.lock().await.clone() {
+        break pid;
+      }
+      assert!(Instant::now() < deadline, "children were not spawned");
+      tokio::time::sleep(Duration::from_millis(10)).await;
+    };
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    root_context.send(failing, MessageHandle::new("fail".to_string())).await;
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while ask_result.lock().await.is_none() {
+      assert!(Instant::now() < deadline, "ask was not interrupted by the supervisor");
+      tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(*ask_result.lock().await, Some(Err(AskError::Stopped)));
+  }
+
+  #[tokio::test]
+  async fn test_pending_asks_are_cancelled_when_actor_stops() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let silent_pid = root_context
+      .spawn(Props::from_async_actor_receiver(move |_| async move { Ok(()) }).await)
+      .await;
+
+    let pending = Arc::new(Mutex::new(None));
+    let asked = Arc::new(Notify::new());
+    let cloned_pending = pending.clone();
+    let cloned_asked = asked.clone();
+    let asker_pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let silent_pid = silent_pid.clone();
+          let pending = cloned_pending.clone();
+          let asked = cloned_asked.clone();
+          async move {
+            if ctx.get_message_handle().await.to_typed::<String>().is_some() {
+              let future = ctx
+                .request_future(
+                  silent_pid,
+                  MessageHandle::new("hello".to_string()),
+                  Duration::from_secs(30),
+                )
+                .await;
+              *pending.lock().await = Some(future);
+              asked.notify_one();
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    root_context
+      .send(asker_pid.clone(), MessageHandle::new("ask".to_string()))
+      .await;
+    asked.notified().await;
+    let future = pending.lock().await.take().unwrap();
+
+    root_context.stop_future(&asker_pid).await.result().await.unwrap();
+
+    let result = tokio::time::timeout(Duration::from_secs(1), future.result())
+      .await
+      .expect("pending ask was not cancelled");
+    assert_eq!(result.err(), Some(ActorFutureError::Cancelled));
+    assert_eq!(system.get_process_registry().await.get_pending_futures(), 0);
+  }
+
+  #[tokio::test]
+  async fn test_ask_fails_when_pending_futures_exhausted() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new_config_options([ConfigOption::with_max_pending_futures(2)])
+      .await
+      .unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let silent_pid = root_context
+      .spawn(Props::from_async_actor_receiver(move |_| async move { Ok(()) }).await)
+      .await;
+
+    let mut pending = Vec::new();
+    for _ in 0..2 {
+      pending.push(
+        root_context
+          .request_future(
+            silent_pid.clone(),
+            MessageHandle::new("hello".to_string()),
+            Duration::from_millis(200),
+          )
+          .await,
+      );
+    }
+
+    let result = root_context
+      .ask::<String>(
+        silent_pid.clone(),
+        MessageHandle::new("hello".to_string()),
+        Duration::from_secs(1),
+      )
+      .await;
+    assert_eq!(
+      result,
+      Err(AskError::FutureError(ActorFutureError::TooManyPendingFuturesError))
+    );
+
+    for future in pending {
+      assert_eq!(future.result().await.err(), Some(ActorFutureError::TimeoutError));
+    }
+    assert_eq!(system.get_process_registry().await.get_pending_futures(), 0);
+  }
+
+  #[tokio::test]
+  async fn test_try_send_to_unknown_pid() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let unknown_pid = system.new_local_pid("never-spawned").await;
+    let result = root_context
+      .try_send(unknown_pid, MessageHandle::new("hello".to_string()))
+      .await;
+    assert_eq!(result, Err(SendError::NoProcess));
+
+    let pid = root_context
+      .spawn(Props::from_async_actor_receiver(move |_| async move { Ok(()) }).await)
+      .await;
+    let result = root_context
+      .try_send(pid.clone(), MessageHandle::new("hello".to_string()))
+      .await;
+    assert_eq!(result, Ok(()));
+
+    root_context.stop_future(&pid).await.result().await.unwrap();
+    let result = root_context
+      .try_send(pid, MessageHandle::new("hello".to_string()))
+      .await;
+    assert_eq!(result, Err(SendError::NoProcess));
+  }
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Greeting(String);
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct GreetRequest {
+    reply_to: TypedExtendedPid<Greeting>,
+  }
+
+  #[tokio::test]
+  async fn test_self_typed_reply_from_worker() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_typed_root_context().await;
+
+    let worker_props = Props::from_async_actor_receiver(move |ctx| async move {
+      if let Some(msg) = ctx.get_message_handle().await.to_typed::<GreetRequest>() {
+        msg
+          .reply_to
+          .send_user_message(ctx.get_actor_system().await, Greeting("pong".to_string()))
+          .await;
+      }
+      Ok(())
+    })
+    .await;
+
+    let replied = Arc::new(Notify::new());
+    let cloned_replied = replied.clone();
+    let props = TypedProps::<Greeting>::from_async_actor_receiver(move |ctx| {
+      let worker_props = worker_props.clone();
+      let replied = cloned_replied.clone();
+      async move {
+        match ctx.get_message_opt().await {
+          Some(Greeting(msg)) if msg == "start" => {
+            let mut ctx = ctx.get_underlying().clone();
+            assert!(ctx.self_typed::<GreetRequest>().await.is_none());
+            let reply_to = ctx.self_typed::<Greeting>().await.unwrap();
+            let worker_pid = ctx.spawn(worker_props).await;
+            ctx
+              .send(worker_pid, MessageHandle::new(GreetRequest { reply_to }))
+              .await;
+          }
+          Some(Greeting(msg)) if msg == "pong" => replied.notify_one(),
+          _ => {}
+        }
+        Ok(())
+      }
+    })
+    .await;
+
+    let pid = root_context.spawn(props).await;
+    root_context.send(pid, Greeting("start".to_string())).await;
+
+    tokio::time::timeout(Duration::from_secs(1), replied.notified())
+      .await
+      .expect("worker did not reply to the typed self pid");
+  }
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct First;
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Second;
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Third;
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct GetDiagnostics;
+
+  #[tokio::test]
+  async fn test_message_history_in_diagnostics_json() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let props = Props::from_async_actor_receiver_with_opts(
+      move |ctx| async move {
+        if ctx.get_message_handle().await.to_typed::<GetDiagnostics>().is_some() {
+          ctx.respond(ResponseHandle::new(ctx.diagnostics_json().await)).await;
+        }
+        Ok(())
+      },
+      [Props::with_message_history(3)],
+    )
+    .await;
+    let pid = root_context.spawn(props).await;
+
+    root_context.send(pid.clone(), MessageHandle::new(First)).await;
+    root_context.send(pid.clone(), MessageHandle::new(Second)).await;
+    root_context.send(pid.clone(), MessageHandle::new(Third)).await;
+
+    let result = root_context
+      .request_future(pid.clone(), MessageHandle::new(GetDiagnostics), Duration::from_secs(1))
+      .await
+      .result()
+      .await
+      .unwrap();
+    let diagnostics: serde_json::Value = serde_json::from_str(&result.to_typed::<String>().unwrap()).unwrap();
+
+    assert_eq!(diagnostics["pid"], pid.to_string());
+    let type_names = diagnostics["message_history"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|entry| {
+        entry["type_name"]
+          .as_str()
+          .unwrap()
+          .rsplit("::")
+          .next()
+          .unwrap()
+          .to_string()
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(type_names, vec!["Second", "Third", "GetDiagnostics"]);
+  }
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Increment;
+
+  #[derive(Debug)]
+  struct CounterActor {
+    count: u32,
+  }
+
+  impl Snapshotable for CounterActor {
+    fn snapshot(&self) -> serde_json::Value {
+      serde_json::json!({ "count": self.count })
+    }
+  }
+
+  #[async_trait]
+  impl Actor for CounterActor {
+    fn as_snapshotable(&self) -> Option<&dyn Snapshotable> {
+      Some(self)
+    }
+
+    async fn receive(&mut self, ctx: ContextHandle) -> Result<(), ActorError> {
+      let message_handle = ctx.get_message_handle().await;
+      if message_handle.to_typed::<Increment>().is_some() {
+        self.count += 1;
+      } else if message_handle.to_typed::<GetDiagnostics>().is_some() {
+        ctx.respond(ResponseHandle::new(ctx.diagnostics_json().await)).await;
+      }
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_snapshot_in_diagnostics_json() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let props =
+      Props::from_sync_actor_producer_with_opts(|_| CounterActor { count: 0 }, [Props::with_snapshot()]).await;
+    let pid = root_context.spawn(props).await;
+
+    for _ in 0..3 {
+      root_context.send(pid.clone(), MessageHandle::new(Increment)).await;
+    }
+
+    // The snapshot is taken after each message, so it reflects every increment processed before the request
+    let result = root_context
+      .request_future(pid.clone(), MessageHandle::new(GetDiagnostics), Duration::from_secs(1))
+      .await
+      .result()
+      .await
+      .unwrap();
+    let diagnostics: serde_json::Value = serde_json::from_str(&result.to_typed::<String>().unwrap()).unwrap();
+    assert_eq!(diagnostics["snapshot"], serde_json::json!({ "count": 3 }));
+
+    // Without the option no snapshot is taken
+    let props = Props::from_sync_actor_producer(|_| CounterActor { count: 0 }).await;
+    let pid = root_context.spawn(props).await;
+    root_context.send(pid.clone(), MessageHandle::new(Increment)).await;
+    let result = root_context
+      .request_future(pid, MessageHandle::new(GetDiagnostics), Duration::from_secs(1))
+      .await
+      .result()
+      .await
+      .unwrap();
+    let diagnostics: serde_json::Value = serde_json::from_str(&result.to_typed::<String>().unwrap()).unwrap();
+    assert!(diagnostics["snapshot"].is_null());
+  }
+
+  async fn get_actor_metrics(system: &ActorSystem) -> ActorMetrics {
+    let extension = system.get_extensions().await.get(*EXTENSION_ID).await.unwrap();
+    let extension_mg = extension.lock().await;
+    let metrics = extension_mg.as_any().downcast_ref::<Metrics>().unwrap();
+    metrics
+      .get_proto_metrics()
+      .unwrap()
+      .get(ProtoMetrics::INTERNAL_ACTOR_METRICS)
+      .unwrap()
+  }
+
+  #[derive(Debug)]
+  struct PoisonRecorder {
+    events: Arc<Mutex<Vec<String>>>,
+  }
+
+  #[async_trait]
+  impl Actor for PoisonRecorder {
+    async fn receive(&mut self, ctx: ContextHandle) -> Result<(), ActorError> {
+      if let Some(n) = ctx.get_message_handle().await.to_typed::<u32>() {
+        self.events.lock().await.push(n.to_string());
+      }
+      Ok(())
+    }
+
+    async fn post_stop(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+      self.events.lock().await.push("post_stop".to_string());
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_poison_stops_after_queued_user_messages() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let cloned_events = events.clone();
+    let pid = root_context
+      .spawn(
+        Props::from_sync_actor_producer(move |_| PoisonRecorder {
+          events: cloned_events.clone(),
+        })
+        .await,
+      )
+      .await;
+
+    for n in 1..=3u32 {
+      root_context.send(pid.clone(), MessageHandle::new(n)).await;
+    }
+    root_context.poison_future(&pid).await.result().await.unwrap();
+
+    assert_eq!(*events.lock().await, vec!["1", "2", "3", "post_stop"]);
+  }
+  #[tokio::test]
+  async fn test_mailbox_length_observer_is_removed_on_stop() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let meter_provider = MeterProviderBuilder::default()
+      .with_reader(ManualReader::builder().build())
+      .build();
+    let metrics_provider = Arc::new(MetricsProvider::Sdk(meter_provider));
+    let system = ActorSystem::new_config_options([ConfigOption::SetMetricsProvider(metrics_provider)])
+      .await
+      .unwrap();
+    let mut root_context = system.get_root_context().await;
+    let actor_metrics = get_actor_metrics(&system).await;
+    let initial_count = actor_metrics.get_mailbox_length_observer_count();
+
+    let pid = root_context
+      .spawn(Props::from_async_actor_receiver(|_| async { Ok(()) }).await)
+      .await;
+    assert_eq!(actor_metrics.get_mailbox_length_observer_count(), initial_count + 1);
+
+    root_context.stop_future(&pid).await.result().await.unwrap();
+    assert_eq!(actor_metrics.get_mailbox_length_observer_count(), initial_count);
+  }
+
+  #[derive(Debug, Clone)]
+  struct SharedReader(Arc<ManualReader>);
+
+  impl AggregationSelector for SharedReader {
+    fn aggregation(&self, kind: InstrumentKind) -> Aggregation {
+      self.0.aggregation(kind)
+    }
+  }
+
+  impl TemporalitySelector for SharedReader {
+    fn temporality(&self, kind: InstrumentKind) -> Temporality {
+      self.0.temporality(kind)
+    }
+  }
+
+  impl MetricReader for SharedReader {
+    fn register_pipeline(&self, pipeline: Weak<Pipeline>) {
+      self.0.register_pipeline(pipeline)
+    }
+
+    fn collect(&self, rm: &mut ResourceMetrics) -> opentelemetry::metrics::Result<()> {
+      self.0.collect(rm)
+    }
+
+    fn force_flush(&self) -> opentelemetry::metrics::Result<()> {
+      self.0.force_flush()
+    }
+
+    fn shutdown(&self) -> opentelemetry::metrics::Result<()> {
+      self.0.shutdown()
+    }
+  }
+
+  fn actor_paths_of(rm: &ResourceMetrics, name: &str) -> Vec<String> {
+    let actor_paths = |attributes: &[KeyValue]| {
+      attributes
+        .iter()
+        .filter(|kv| kv.key.as_str() == "actor_path")
+        .map(|kv| kv.value.to_string())
+        .collect::<Vec<_>>()
+    };
+    rm.scope_metrics
+      .iter()
+      .flat_map(|sm| sm.metrics.iter())
+      .filter(|metric| metric.name == name)
+      .flat_map(|metric| {
+        let data = metric.data.as_any();
+        if let Some(histogram) = data.downcast_ref::<data::Histogram<f64>>() {
+          histogram
+            .data_points
+            .iter()
+            .flat_map(|dp| actor_paths(&dp.attributes))
+            .collect::<Vec<_>>()
+        } else if let Some(gauge) = data.downcast_ref::<data::Gauge<u64>>() {
+          gauge
+            .data_points
+            .iter()
+            .flat_map(|dp| actor_paths(&dp.attributes))
+            .collect::<Vec<_>>()
+        } else {
+          vec![]
+        }
+      })
+      .collect()
+  }
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct SpawnedChild {
+    pid: ExtendedPid,
+  }
+
+  #[tokio::test]
+  async fn test_child_metrics_are_labeled_with_actor_path() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let reader = SharedReader(Arc::new(ManualReader::builder().build()));
+    let meter_provider = MeterProviderBuilder::default().with_reader(reader.clone()).build();
+    let metrics_provider = Arc::new(MetricsProvider::Sdk(meter_provider));
+    let system = ActorSystem::new_config_options([ConfigOption::SetMetricsProvider(metrics_provider)])
+      .await
+      .unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let parent = root_context
+      .spawn(
+        Props::from_async_actor_receiver(|mut ctx| async move {
+          if ctx.get_message_handle().await.to_typed::<String>().is_some() {
+            let child = ctx
+              .spawn(
+                Props::from_async_actor_receiver(|ctx| async move {
+                  if let Some(msg) = ctx.get_message_handle().await.to_typed::<String>() {
+                    ctx.respond(ResponseHandle::new(msg)).await;
+                  }
+                  Ok(())
+                })
+                .await,
+              )
+              .await;
+            ctx.respond(ResponseHandle::new(SpawnedChild { pid: child })).await;
+          }
+          Ok(())
+        })
+        .await,
+      )
+      .await;
+
+    let child = root_context
+      .request_future(
+        parent.clone(),
+        MessageHandle::new("spawn".to_string()),
+        Duration::from_secs(3),
+      )
+      .await
+      .result()
+      .await
+      .unwrap()
+      .to_typed::<SpawnedChild>()
+      .unwrap()
+      .pid;
+    root_context
+      .request_future(
+        child.clone(),
+        MessageHandle::new("ping".to_string()),
+        Duration::from_secs(3),
+      )
+      .await
+      .result()
+      .await
+      .unwrap();
+
+    let mut rm = ResourceMetrics {
+      resource: Resource::empty(),
+      scope_metrics: vec![],
+    };
+    reader.collect(&mut rm).unwrap();
+
+    let child_path = child.id().to_string();
+    assert!(child_path.starts_with(&format!("{}/", parent.id())));
+    let receive_paths = actor_paths_of(&rm, "nexus_actor_actor_message_receive_duration_seconds");
+    assert!(receive_paths.contains(&parent.id().to_string()));
+    assert!(receive_paths.contains(&child_path));
+    let mailbox_paths = actor_paths_of(&rm, "nexus_actor_actor_mailbox_length_gauge");
+    assert!(mailbox_paths.contains(&child_path));
+  }
+
+  #[tokio::test]
+  async fn test_tee_forks_current_message_with_original_sender() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let sibling = root_context
+      .spawn(
+        Props::from_async_actor_receiver(|ctx| async move {
+          if let Some(msg) = ctx.get_message_handle().await.to_typed::<String>() {
+            ctx.respond(ResponseHandle::new(format!("sibling: {}", msg))).await;
+          }
+          Ok(())
+        })
+        .await,
+      )
+      .await;
+
+    let processed = Arc::new(Mutex::new(vec![]));
+    let cloned_processed = processed.clone();
+    let cloned_sibling = sibling.clone();
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let processed = cloned_processed.clone();
+          let sibling = cloned_sibling.clone();
+          async move {
+            if let Some(msg) = ctx.get_message_handle().await.to_typed::<String>() {
+              processed.lock().await.push(msg);
+              assert!(ctx.tee(&sibling).await);
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    let response = root_context
+      .request_future(pid, MessageHandle::new("work".to_string()), Duration::from_secs(3))
+      .await
+      .result()
+      .await
+      .unwrap();
+
+    assert_eq!(response.to_typed::<String>().unwrap(), "sibling: work");
+    assert_eq!(*processed.lock().await, vec!["work".to_string()]);
+  }
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Retry {
+    attempt: u32,
+  }
+
+  fn retry_delay(message_handle: &MessageHandle) -> Duration {
+    let attempt = message_handle.to_typed::<Retry>().map_or(0, |retry| retry.attempt);
+    Duration::from_millis(50 * attempt as u64)
+  }
+
+  async fn spawn_retry_recorder(
+    root_context: &mut RootContext,
+    expected: usize,
+  ) -> (ExtendedPid, Arc<Mutex<Vec<u32>>>, Arc<Notify>) {
+    let received = Arc::new(Mutex::new(vec![]));
+    let notify = Arc::new(Notify::new());
+    let cloned_received = received.clone();
+    let cloned_notify = notify.clone();
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let received = cloned_received.clone();
+          let notify = cloned_notify.clone();
+          async move {
+            if let Some(retry) = ctx.get_message_handle().await.to_typed::<Retry>() {
+              let mut mg = received.lock().await;
+              mg.push(retry.attempt);
+              if mg.len() == expected {
+                notify.notify_one();
+              }
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+    (pid, received, notify)
+  }
+
+  #[tokio::test]
+  async fn test_send_after_fn_fires_in_computed_order() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let (recorder, received, notify) = spawn_retry_recorder(&mut root_context, 3).await;
+
+    let scheduler = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |mut ctx| {
+          let recorder = recorder.clone();
+          async move {
+            if ctx.get_message_handle().await.to_typed::<First>().is_some() {
+              for attempt in [3, 1, 2] {
+                ctx
+                  .send_after_fn(recorder.clone(), MessageHandle::new(Retry { attempt }), retry_delay)
+                  .await;
+              }
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+    root_context.send(scheduler, MessageHandle::new(First)).await;
+
+    tokio::time::timeout(Duration::from_secs(2), notify.notified())
+      .await
+      .unwrap();
+    assert_eq!(*received.lock().await, vec![1, 2, 3]);
+  }
+
+  #[tokio::test]
+  async fn test_send_after_from_root_context_uses_the_default() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let (recorder, received, notify) = spawn_retry_recorder(&mut root_context, 1).await;
+
+    root_context
+      .send_after_fn(recorder, MessageHandle::new(Retry { attempt: 1 }), retry_delay)
+      .await;
+
+    tokio::time::timeout(Duration::from_secs(2), notify.notified())
+      .await
+      .unwrap();
+    assert_eq!(*received.lock().await, vec![1]);
+  }
+
+  #[tokio::test]
+  async fn test_send_after_fn_is_cancelled_on_stop() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let (recorder, received, _) = spawn_retry_recorder(&mut root_context, 1).await;
+
+    let scheduled = Arc::new(Notify::new());
+    let cloned_scheduled = scheduled.clone();
+    let scheduler = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |mut ctx| {
+          let recorder = recorder.clone();
+          let scheduled = cloned_scheduled.clone();
+          async move {
+            if ctx.get_message_handle().await.to_typed::<First>().is_some() {
+              ctx
+                .send_after_fn(recorder, MessageHandle::new(Retry { attempt: 4 }), retry_delay)
+                .await;
+              scheduled.notify_one();
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+    root_context.send(scheduler.clone(), MessageHandle::new(First)).await;
+    scheduled.notified().await;
+
+    root_context.stop_future(&scheduler).await.result().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    assert!(received.lock().await.is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_send_batch_is_discarded_when_receive_fails() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let (recorder, received, notify) = spawn_retry_recorder(&mut root_context, 2).await;
+
+    let failed_batch = Arc::new(Mutex::new(None));
+    let cloned_failed_batch = failed_batch.clone();
+    let batcher = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |mut ctx| {
+          let recorder = recorder.clone();
+          let failed_batch = cloned_failed_batch.clone();
+          async move {
+            let message_handle = ctx.get_message_handle().await;
+            let attempts = if message_handle.to_typed::<First>().is_some() {
+              [1, 2]
+            } else if message_handle.to_typed::<Second>().is_some() {
+              [3, 4]
+            } else {
+              return Ok(());
+            };
+            let batch = ctx.begin_batch().await;
+            for attempt in attempts {
+              batch
+                .send(recorder.clone(), MessageHandle::new(Retry { attempt }))
+                .await;
+            }
+            if message_handle.to_typed::<First>().is_some() {
+              *failed_batch.lock().await = Some(batch);
+              return Err(ActorError::ReceiveError(ErrorReason::new("batch failed", 0)));
+            }
+            batch.commit().await;
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+    root_context.send(batcher.clone(), MessageHandle::new(First)).await;
+    root_context.send(batcher, MessageHandle::new(Second)).await;
+
+    tokio::time::timeout(Duration::from_secs(2), notify.notified())
+      .await
+      .unwrap();
+    assert_eq!(*received.lock().await, vec![3, 4]);
+    assert!(failed_batch.lock().await.as_ref().unwrap().is_empty().await);
+  }
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Work {
+    id: u32,
+    best_effort: bool,
+  }
+
+  #[tokio::test]
+  async fn test_should_shed_drops_best_effort_messages_above_threshold() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let gate = Arc::new(Notify::new());
+    let processed = Arc::new(Mutex::new(vec![]));
+    let done = Arc::new(Notify::new());
+    let cloned_gate = gate.clone();
+    let cloned_processed = processed.clone();
+    let cloned_done = done.clone();
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let gate = cloned_gate.clone();
+          let processed = cloned_processed.clone();
+          let done = cloned_done.clone();
+          async move {
+            let message_handle = ctx.get_message_handle().await;
+            if message_handle.to_typed::<First>().is_some() {
+              gate.notified().await;
+            } else if let Some(work) = message_handle.to_typed::<Work>() {
+              if work.best_effort && ctx.should_shed(2).await {
+                return Ok(());
+              }
+              let mut mg = processed.lock().await;
+              mg.push(work.id);
+              if work.id == 5 {
+                done.notify_one();
+              }
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    root_context.send(pid.clone(), MessageHandle::new(First)).await;
+    for (id, best_effort) in [(1, true), (2, true), (3, false), (4, true), (5, true)] {
+      root_context
+        .send(pid.clone(), MessageHandle::new(Work { id, best_effort }))
+        .await;
+    }
+    gate.notify_one();
+
+    tokio::time::timeout(Duration::from_secs(2), done.notified())
+      .await
+      .unwrap();
+    assert_eq!(*processed.lock().await, vec![3, 4, 5]);
+  }
+
+  #[tokio::test]
+  async fn test_send_reliable_stops_redelivering_once_acked() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let attempts = Arc::new(Mutex::new(Vec::new()));
+    let cloned_attempts = attempts.clone();
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let attempts = cloned_attempts.clone();
+          async move {
+            if ctx.get_message_handle().await.to_typed::<First>().is_some() {
+              let attempt = ctx
+                .get_message_header_handle()
+                .await
+                .and_then(|header| header.get(DELIVERY_ATTEMPT_HEADER))
+                .unwrap();
+              attempts.lock().await.push(attempt.clone());
+              if attempt == "2" {
+                assert!(ctx.ack_delivery().await);
+              }
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    let retry_policy = RetryPolicy::new(5, Duration::from_millis(100));
+    let result = root_context
+      .send_reliable(pid, MessageHandle::new(First), retry_policy)
+      .await;
+    assert_eq!(result, Ok(()));
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(*attempts.lock().await, vec!["1".to_string(), "2".to_string()]);
+  }
+
+  #[tokio::test]
+  async fn test_send_reliable_only_redelivers_idempotent_messages() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let attempts = Arc::new(Mutex::new(Vec::new()));
+    let cloned_attempts = attempts.clone();
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver_with_opts(
+          move |ctx| {
+            let attempts = cloned_attempts.clone();
+            async move {
+              let message_handle = ctx.get_message_handle().await;
+              let name = match (message_handle.to_typed::<First>(), message_handle.to_typed::<Second>()) {
+                (Some(_), _) => "first",
+                (_, Some(_)) => "second",
+                _ => return Ok(()),
+              };
+              let attempt = ctx
+                .get_message_header_handle()
+                .await
+                .and_then(|header| header.get(DELIVERY_ATTEMPT_HEADER))
+                .unwrap();
+              attempts.lock().await.push(format!("{}-{}", name, attempt));
+              Ok(())
+            }
+          },
+          [Props::with_idempotent(|message_handle| {
+            message_handle.to_typed::<First>().is_some()
+          })],
+        )
+        .await,
+      )
+      .await;
+
+    let retry_policy = RetryPolicy::new(3, Duration::from_millis(100));
+    let result = root_context
+      .send_reliable(pid.clone(), MessageHandle::new(First), retry_policy.clone())
+      .await;
+    assert_eq!(result, Err(SendError::DeliveryExhausted(3)));
+
+    let result = root_context
+      .send_reliable(pid, MessageHandle::new(Second), retry_policy)
+      .await;
+    assert_eq!(result, Err(SendError::DeliveryRejected));
+
+    assert_eq!(
+      *attempts.lock().await,
+      vec!["first-1", "first-2", "first-3", "second-1"]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_correlation_id_is_stable_and_propagated() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let observed = Arc::new(Mutex::new(Vec::new()));
+    let notify = Arc::new(Notify::new());
+
+    let cloned_observed = observed.clone();
+    let cloned_notify = notify.clone();
+    let downstream = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let observed = cloned_observed.clone();
+          let notify = cloned_notify.clone();
+          async move {
+            if ctx.get_message_handle().await.to_typed::<Second>().is_some() {
+              observed.lock().await.push(ctx.correlation_id().await);
+              notify.notify_one();
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    let cloned_observed = observed.clone();
+    let upstream = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |mut ctx| {
+          let observed = cloned_observed.clone();
+          let downstream = downstream.clone();
+          async move {
+            if ctx.get_message_handle().await.to_typed::<First>().is_some() {
+              let first = ctx.correlation_id().await;
+              let second = ctx.correlation_id().await;
+              observed.lock().await.push(first);
+              observed.lock().await.push(second);
+              ctx.send(downstream, MessageHandle::new(Second)).await;
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    root_context.send(upstream, MessageHandle::new(First)).await;
+    tokio::time::timeout(Duration::from_secs(3), notify.notified())
+      .await
+      .expect("downstream did not receive the message");
+
+    let observed = observed.lock().await.clone();
+    assert_eq!(observed.len(), 3);
+    assert!(!observed[0].is_empty());
+    assert_eq!(observed[0], observed[1]);
+    assert_eq!(observed[0], observed[2]);
+  }
+
+  #[tokio::test]
+  async fn test_correlation_id_of_the_root_context_is_kept_across_hops() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    root_context.set_correlation_id("corr-root".to_string()).await;
+
+    let observed = Arc::new(Mutex::new(Vec::new()));
+    let notify = Arc::new(Notify::new());
+
+    let cloned_observed = observed.clone();
+    let cloned_notify = notify.clone();
+    let downstream = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let observed = cloned_observed.clone();
+          let notify = cloned_notify.clone();
+          async move {
+            if ctx.get_message_handle().await.to_typed::<Second>().is_some() {
+              observed.lock().await.push(ctx.correlation_id().await);
+              notify.notify_one();
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    let cloned_observed = observed.clone();
+    let upstream = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |mut ctx| {
+          let observed = cloned_observed.clone();
+          let downstream = downstream.clone();
+          async move {
+            if ctx.get_message_handle().await.to_typed::<First>().is_some() {
+              observed.lock().await.push(ctx.correlation_id().await);
+              ctx.send(downstream, MessageHandle::new(Second)).await;
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    root_context.send(upstream, MessageHandle::new(First)).await;
+    tokio::time::timeout(Duration::from_secs(3), notify.notified())
+      .await
+      .expect("downstream did not receive the message");
+
+    assert_eq!(
+      *observed.lock().await,
+      vec!["corr-root".to_string(), "corr-root".to_string()]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_configured_headers_are_propagated_to_outbound_sends() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new_config_options([ConfigOption::with_propagated_headers(["trace-id"])])
+      .await
+      .unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let observed = Arc::new(Mutex::new(None));
+    let notify = Arc::new(Notify::new());
+
+    let cloned_observed = observed.clone();
+    let cloned_notify = notify.clone();
+    let parent = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |mut ctx| {
+          let observed = cloned_observed.clone();
+          let notify = cloned_notify.clone();
+          async move {
+            if ctx.get_message_handle().await.to_typed::<First>().is_some() {
+              let props = Props::from_async_actor_receiver(move |ctx| {
+                let observed = observed.clone();
+                let notify = notify.clone();
+                async move {
+                  if ctx.get_message_handle().await.to_typed::<Second>().is_some() {
+                    let header = ctx.get_message_header_handle().await.unwrap();
+                    *observed.lock().await = Some((header.get("trace-id"), header.get("tenant")));
+                    notify.notify_one();
+                  }
+                  Ok(())
+                }
+              })
+              .await;
+              let child = ctx.spawn(props).await;
+              ctx.send(child, MessageHandle::new(Second)).await;
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    let mut header = MessageHeaders::default();
+    header.set("trace-id".to_string(), "trace-1".to_string());
+    header.set("tenant".to_string(), "acme".to_string());
+    let envelope = MessageEnvelope::new(MessageHandle::new(First)).with_header(header);
+    root_context.send(parent, MessageHandle::new(envelope)).await;
+    tokio::time::timeout(Duration::from_secs(3), notify.notified())
+      .await
+      .expect("child did not receive the message");
+
+    assert_eq!(observed.lock().await.clone(), Some((Some("trace-1".to_string()), None)));
+  }
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Stashable(String);
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Ready;
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Crash;
+
+  #[tokio::test]
+  async fn test_stash_redelivers_in_order_and_is_cleared_on_restart() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    // The actor stashes everything until it receives Ready. The flag lives outside the actor so it survives restarts
+    let ready = Arc::new(Mutex::new(false));
+    let received = Arc::new(Mutex::new(vec![]));
+    let notify = Arc::new(Notify::new());
+    let cloned_ready = ready.clone();
+    let cloned_received = received.clone();
+    let cloned_notify = notify.clone();
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |mut ctx| {
+          let ready = cloned_ready.clone();
+          let received = cloned_received.clone();
+          let notify = cloned_notify.clone();
+          async move {
+            let message_handle = ctx.get_message_handle().await;
+            if let Some(Stashable(name)) = message_handle.to_typed::<Stashable>() {
+              if !*ready.lock().await {
+                ctx.stash().await;
+                return Ok(());
+              }
+              let trace = ctx
+                .get_message_header_handle()
+                .await
+                .and_then(|headers| headers.get("trace"));
+              received.lock().await.push((name, trace, ctx.get_sender().await));
+              notify.notify_one();
+            } else if message_handle.to_typed::<Ready>().is_some() {
+              *ready.lock().await = true;
+              ctx.un_stash_all().await?;
+            } else if message_handle.to_typed::<Crash>().is_some() {
+              return Err(ActorError::ReceiveError(ErrorReason::new("crash", 0)));
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+    let sender = root_context
+      .spawn(Props::from_async_actor_receiver(|_| async { Ok(()) }).await)
+      .await;
+
+    for name in ["first", "second", "third"] {
+      let mut headers = MessageHeaders::new();
+      headers.set("trace".to_string(), format!("trace-{}", name));
+      let envelope = MessageEnvelope::new(MessageHandle::new(Stashable(name.to_string())))
+        .with_header(headers)
+        .with_sender(sender.clone());
+      root_context.send(pid.clone(), MessageHandle::new(envelope)).await;
+    }
+    root_context.send(pid.clone(), MessageHandle::new(Ready)).await;
+
+    tokio::time::timeout(Duration::from_secs(3), async {
+      while received.lock().await.len() < 3 {
+        notify.notified().await;
+      }
+    })
+    .await
+    .expect("stashed messages were not redelivered");
+    let expected = ["first", "second", "third"]
+      .into_iter()
+      .map(|name| (name.to_string(), Some(format!("trace-{}", name)), Some(sender.clone())))
+      .collect::<Vec<_>>();
+    assert_eq!(*received.lock().await, expected);
+
+    // Messages stashed before a restart are dropped
+    received.lock().await.clear();
+    *ready.lock().await = false;
+    root_context
+      .send(pid.clone(), MessageHandle::new(Stashable("lost".to_string())))
+      .await;
+    root_context.send(pid.clone(), MessageHandle::new(Crash)).await;
+    root_context.send(pid.clone(), MessageHandle::new(Ready)).await;
+    root_context
+      .send(pid.clone(), MessageHandle::new(Stashable("after".to_string())))
+      .await;
+
+    tokio::time::timeout(Duration::from_secs(3), notify.notified())
+      .await
+      .expect("message after restart was not received");
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(*received.lock().await, vec![("after".to_string(), None, None)]);
+  }
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct GetVariant;
+
+  #[tokio::test]
+  async fn test_flags_select_actor_behavior() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let spawn_variant = |flags: HashMap<String, bool>| async move {
+      Props::from_async_actor_receiver_with_opts(
+        |ctx| async move {
+          let message_handle = ctx.get_message_handle().await;
+          if message_handle.to_typed::<GetVariant>().is_some() {
+            let variant = if ctx.flag("fast_path").await { "fast" } else { "slow" };
+            ctx.respond(ResponseHandle::new(variant.to_string())).await;
+          } else if message_handle.to_typed::<Crash>().is_some() {
+            return Err(ActorError::ReceiveError(ErrorReason::new("crash", 0)));
+          }
+          Ok(())
+        },
+        [Props::with_flags(flags)],
+      )
+      .await
+    };
+    let fast = root_context
+      .spawn(spawn_variant(HashMap::from([("fast_path".to_string(), true)])).await)
+      .await;
+    let slow = root_context.spawn(spawn_variant(HashMap::new()).await).await;
+
+    let mut variants = vec![];
+    for pid in [&fast, &slow] {
+      // Flags are still there after the actor restarts
+      for crash in [false, true] {
+        if crash {
+          root_context.send(pid.clone(), MessageHandle::new(Crash)).await;
+        }
+        let result = root_context
+          .request_future(pid.clone(), MessageHandle::new(GetVariant), Duration::from_secs(1))
+          .await
+          .result()
+          .await
+          .unwrap();
+        variants.push(result.to_typed::<String>().unwrap());
+      }
+    }
+    assert_eq!(variants, vec!["fast", "fast", "slow", "slow"]);
+  }
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct ProxiedRequest;
+
+  #[tokio::test]
+  async fn test_forward_keeps_original_sender_and_headers() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let delegate = root_context
+      .spawn(
+        Props::from_async_actor_receiver(|ctx| async move {
+          if ctx.get_message_handle().await.to_typed::<ProxiedRequest>().is_some() {
+            let trace = ctx
+              .get_message_header_handle()
+              .await
+              .and_then(|headers| headers.get("trace"))
+              .unwrap_or_default();
+            ctx.respond(ResponseHandle::new(trace)).await;
+          }
+          Ok(())
+        })
+        .await,
+      )
+      .await;
+    let proxy = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let delegate = delegate.clone();
+          async move {
+            if ctx.get_message_handle().await.to_typed::<ProxiedRequest>().is_some() {
+              ctx.forward(&delegate).await;
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    let future_process = ActorFutureProcess::new(system.clone(), Duration::from_secs(3))
+      .await
+      .unwrap();
+    let mut headers = MessageHeaders::new();
+    headers.set("trace".to_string(), "trace-1".to_string());
+    let envelope = MessageEnvelope::new(MessageHandle::new(ProxiedRequest))
+      .with_header(headers)
+      .with_sender(future_process.get_pid().await);
+    root_context.send(proxy, MessageHandle::new(envelope)).await;
+
+    let reply = future_process.result().await.unwrap();
+    assert_eq!(reply.to_typed::<String>().unwrap(), "trace-1");
+  }
+
+  #[derive(Clone)]
+  struct TestWriter(Arc<std::sync::Mutex<Vec<u8>>>);
+
+  impl std::io::Write for TestWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+      self.0.lock().unwrap().extend_from_slice(buf);
+      Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_message_tracing_wraps_receive_in_a_span() {
+    let output = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let writer = TestWriter(output.clone());
+    let subscriber = tracing_subscriber::fmt()
+      .with_ansi(false)
+      .with_max_level(tracing::Level::INFO)
+      .with_writer(move || writer.clone())
+      .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    for (enabled, correlation_id) in [(true, "corr-1"), (false, "corr-2")] {
+      let system = ActorSystem::new_config_options([ConfigOption::with_message_tracing(enabled)])
+        .await
+        .unwrap();
+      let mut root_context = system.get_root_context().await;
+      let handled = Arc::new(Notify::new());
+      let cloned_handled = handled.clone();
+      let pid = root_context
+        .spawn(
+          Props::from_async_actor_receiver(move |ctx| {
+            let handled = cloned_handled.clone();
+            async move {
+              if let Some(msg) = ctx.get_message_handle().await.to_typed::<String>() {
+                tracing::info!("handled {}", msg);
+                handled.notify_one();
+              }
+              Ok(())
+            }
+          })
+          .await,
+        )
+        .await;
+
+      let message_handle = MessageHandle::new(wrap_envelope_with_header(
+        MessageHandle::new(correlation_id.to_string()),
+        CORRELATION_ID_HEADER,
+        correlation_id.to_string(),
+      ));
+      root_context.send(pid.clone(), message_handle).await;
+      tokio::time::timeout(Duration::from_secs(3), handled.notified())
+        .await
+        .expect("message was not handled");
+
+      let output = String::from_utf8(output.lock().unwrap().clone()).unwrap();
+      let line = output
+        .lines()
+        .find(|line| line.ends_with(&format!("handled {}", correlation_id)))
+        .expect("log line not found");
+      let span = format!(
+        "actor_message{{pid={} message_type={} correlation_id={}}}",
+        pid,
+        MessageHandle::new(correlation_id.to_string()).get_type_name(),
+        correlation_id
+      );
+      assert_eq!(line.contains(&span), enabled, "{}", line);
+    }
+  }
+
+  #[tokio::test]
+  async fn test_stop_all_children_future_waits_for_termination() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let workers = Arc::new(Mutex::new(Vec::new()));
+    let cloned_workers = workers.clone();
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |mut ctx| {
+          let workers = cloned_workers.clone();
+          async move {
+            match ctx.get_message_handle().await.to_typed::<String>().as_deref() {
+              Some("spawn") => {
+                for _ in 0..3 {
+                  let props = Props::from_async_actor_receiver(|_| async { Ok(()) }).await;
+                  workers.lock().await.push(ctx.spawn(props).await);
+                }
+                ctx
+                  .respond(ResponseHandle::new(ctx.get_children().await.len() as u32))
+                  .await;
+              }
+              Some("reset") => {
+                ctx.stop_all_children_future().await.await.unwrap();
+                ctx.respond(ResponseHandle::new(0u32)).await;
+              }
+              Some("count") => {
+                ctx
+                  .respond(ResponseHandle::new(ctx.get_children().await.len() as u32))
+                  .await;
+              }
+              _ => {}
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    let request = |message: &str| {
+      let root_context = root_context.clone();
+      let pid = pid.clone();
+      let message = message.to_string();
+      async move {
+        let future = root_context
+          .request_future(pid, MessageHandle::new(message), Duration::from_secs(3))
+          .await;
+        *future.result().await.unwrap().to_typed::<u32>().as_ref().unwrap()
+      }
+    };
+
+    assert_eq!(request("spawn").await, 3);
+    assert_eq!(request("reset").await, 0);
+    for worker in workers.lock().await.iter() {
+      assert!(system.get_process_registry().await.find_process(worker).await.is_none());
+    }
+    assert_eq!(request("count").await, 0);
+  }
+
+  #[tokio::test]
+  async fn test_stopped_receive_timeout_timer_releases_its_waiter() {
+    let timer = ReceiveTimeoutTimer::new(Duration::from_secs(60));
+    let waiter = tokio::spawn({
+      let timer = timer.clone();
+      async move { timer.wait().await }
+    });
+
+    timer.clone().stop();
+    let fired = tokio::time::timeout(Duration::from_secs(1), waiter)
+      .await
+      .expect("the waiter is still parked")
+      .unwrap();
+    assert!(!fired);
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_paused_receive_timeout_timer_fires_after_reset() {
+    let mut timer = ReceiveTimeoutTimer::new(Duration::from_millis(100));
+    timer.pause();
+    let waiter = tokio::spawn({
+      let timer = timer.clone();
+      async move { timer.wait().await }
+    });
+
+    tokio::time::sleep(Duration::from_secs(10)).await;
+    assert!(!waiter.is_finished());
+
+    timer.reset(tokio::time::Instant::now() + Duration::from_millis(100));
+    assert!(waiter.await.unwrap());
+  }
+
+  #[tokio::test]
+  async fn test_killed_receive_timeout_timer_releases_its_waiter() {
+    let system = ActorSystem::new().await.unwrap();
+    let extras = ActorContextExtras::new(ContextHandle::new(MockContext::new(system))).await;
+    extras.init_receive_timeout_timer(Duration::from_secs(60)).await;
+    let timer = extras.get_receive_timeout_timer().await.unwrap();
+    let waiter = tokio::spawn(async move { timer.wait().await });
+
+    extras.kill_receive_timeout_timer().await;
+    let fired = tokio::time::timeout(Duration::from_secs(1), waiter)
+      .await
+      .expect("the waiter is still parked")
+      .unwrap();
+    assert!(!fired);
+    assert!(extras.get_receive_timeout_timer().await.is_none());
+  }
 }
@@ -0,0 +1,14 @@
+use crate::actor::dispatch::future::ActorFutureError;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AskError {
+  #[error("Future error: {0}")]
+  FutureError(#[from] ActorFutureError),
+  #[error("Unexpected response type: {0}")]
+  TypeMismatch(String),
+  #[error("Actor stopped while awaiting the response")]
+  Stopped,
+}
+
+static_assertions::assert_impl_all!(AskError: Send, Sync);
@@ -0,0 +1,319 @@
+use std::any::Any;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::future::BoxFuture;
+
+use crate::actor::actor::ActorError;
+use crate::actor::actor::ActorHandle;
+use crate::actor::actor::Continuer;
+use crate::actor::actor::ExtendedPid;
+use crate::actor::actor::Props;
+use crate::actor::actor::SpawnError;
+use crate::actor::actor_system::ActorSystem;
+use crate::actor::context::context_handle::ContextHandle;
+use crate::actor::context::{
+  BasePart, Context, ExtensionContext, ExtensionPart, InfoPart, MessagePart, PersistencePart, ReceiverContext,
+  ReceiverPart, SendBatch, SenderContext, SenderPart, SpawnerContext, SpawnerPart, StopperPart,
+};
+use crate::actor::dispatch::future::{ActorFuture, ActorFutureError};
+use crate::actor::message::MessageEnvelope;
+use crate::actor::message::MessageHandle;
+use crate::actor::message::ReadonlyMessageHeadersHandle;
+use crate::actor::message::ResponseHandle;
+use crate::actor::persistence::JournalError;
+use crate::ctxext::extensions::{ContextExtensionHandle, ContextExtensionId};
+
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+pub struct AutoAckContext {
+  inner: ContextHandle,
+  ack_msg_fn: Arc<dyn Fn(MessageHandle) -> MessageHandle + Send + Sync + 'static>,
+  nack_msg_fn: Arc<dyn Fn(MessageHandle, &ActorError) -> MessageHandle + Send + Sync + 'static>,
+}
+
+impl AutoAckContext {
+  pub fn new(
+    inner: ContextHandle,
+    ack_msg_fn: impl Fn(MessageHandle) -> MessageHandle + Send + Sync + 'static,
+    nack_msg_fn: impl Fn(MessageHandle, &ActorError) -> MessageHandle + Send + Sync + 'static,
+  ) -> Self {
+    Self::from_arc(inner, Arc::new(ack_msg_fn), Arc::new(nack_msg_fn))
+  }
+
+  #[allow(clippy::type_complexity)]
+  pub(crate) fn from_arc(
+    inner: ContextHandle,
+    ack_msg_fn: Arc<dyn Fn(MessageHandle) -> MessageHandle + Send + Sync + 'static>,
+    nack_msg_fn: Arc<dyn Fn(MessageHandle, &ActorError) -> MessageHandle + Send + Sync + 'static>,
+  ) -> Self {
+    Self {
+      inner,
+      ack_msg_fn,
+      nack_msg_fn,
+    }
+  }
+}
+
+impl Debug for AutoAckContext {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("AutoAckContext").field("inner", &self.inner).finish()
+  }
+}
+
+impl ExtensionContext for AutoAckContext {}
+
+#[async_trait]
+impl ExtensionPart for AutoAckContext {
+  async fn get(&mut self, id: ContextExtensionId) -> Option<ContextExtensionHandle> {
+    self.inner.get(id).await
+  }
+
+  async fn set(&mut self, ext: ContextExtensionHandle) {
+    self.inner.set(ext).await
+  }
+}
+
+#[async_trait]
+impl SenderContext for AutoAckContext {
+  async fn send_after(&mut self, pid: ExtendedPid, message_handle: MessageHandle, delay: Duration) {
+    self.inner.send_after(pid, message_handle, delay).await
+  }
+}
+
+#[async_trait]
+impl InfoPart for AutoAckContext {
+  async fn get_parent(&self) -> Option<ExtendedPid> {
+    self.inner.get_parent().await
+  }
+
+  async fn get_self_opt(&self) -> Option<ExtendedPid> {
+    self.inner.get_self_opt().await
+  }
+
+  async fn set_self(&mut self, pid: ExtendedPid) {
+    self.inner.set_self(pid).await
+  }
+
+  async fn get_actor(&self) -> Option<ActorHandle> {
+    self.inner.get_actor().await
+  }
+
+  async fn get_actor_system(&self) -> ActorSystem {
+    self.inner.get_actor_system().await
+  }
+}
+
+#[async_trait]
+impl SenderPart for AutoAckContext {
+  async fn get_sender(&self) -> Option<ExtendedPid> {
+    self.inner.get_sender().await
+  }
+
+  async fn send(&mut self, pid: ExtendedPid, message_handle: MessageHandle) {
+    self.inner.send(pid, message_handle).await
+  }
+
+  async fn begin_batch(&mut self) -> SendBatch {
+    self.inner.begin_batch().await
+  }
+
+  async fn request(&mut self, pid: ExtendedPid, message_handle: MessageHandle) {
+    self.inner.request(pid, message_handle).await
+  }
+
+  async fn request_with_custom_sender(&mut self, pid: ExtendedPid, message_handle: MessageHandle, sender: ExtendedPid) {
+    self.inner.request_with_custom_sender(pid, message_handle, sender).await
+  }
+
+  async fn request_future(&self, pid: ExtendedPid, message_handle: MessageHandle, timeout: Duration) -> ActorFuture {
+    self.inner.request_future(pid, message_handle, timeout).await
+  }
+}
+
+#[async_trait]
+impl MessagePart for AutoAckContext {
+  async fn get_message_envelope_opt(&self) -> Option<MessageEnvelope> {
+    self.inner.get_message_envelope_opt().await
+  }
+
+  async fn get_message_handle_opt(&self) -> Option<MessageHandle> {
+    self.inner.get_message_handle_opt().await
+  }
+
+  async fn get_message_header_handle(&self) -> Option<ReadonlyMessageHeadersHandle> {
+    self.inner.get_message_header_handle().await
+  }
+
+  async fn correlation_id(&self) -> String {
+    self.inner.correlation_id().await
+  }
+
+  async fn set_correlation_id(&mut self, id: String) {
+    self.inner.set_correlation_id(id).await
+  }
+}
+
+impl ReceiverContext for AutoAckContext {}
+
+#[async_trait]
+impl ReceiverPart for AutoAckContext {
+  async fn receive(&mut self, envelope: MessageEnvelope) -> Result<(), ActorError> {
+    let sender_opt = envelope.get_sender();
+    let message_handle = envelope.get_message_handle();
+    let result = self.inner.receive(envelope).await;
+    if let Some(sender) = sender_opt {
+      let reply = match &result {
+        Ok(_) => (self.ack_msg_fn)(message_handle),
+        Err(error) => (self.nack_msg_fn)(message_handle, error),
+      };
+      self.inner.send(sender, reply).await;
+    }
+    result
+  }
+}
+
+impl SpawnerContext for AutoAckContext {}
+
+#[async_trait]
+impl SpawnerPart for AutoAckContext {
+  async fn spawn(&mut self, props: Props) -> ExtendedPid {
+    self.inner.spawn(props).await
+  }
+
+  async fn spawn_prefix(&mut self, props: Props, prefix: &str) -> ExtendedPid {
+    self.inner.spawn_prefix(props, prefix).await
+  }
+
+  async fn spawn_named(&mut self, props: Props, id: &str) -> Result<ExtendedPid, SpawnError> {
+    self.inner.spawn_named(props, id).await
+  }
+}
+
+#[async_trait]
+impl BasePart for AutoAckContext {
+  fn as_any(&self) -> &dyn Any {
+    self
+  }
+
+  async fn get_receive_timeout(&self) -> Option<Duration> {
+    self.inner.get_receive_timeout().await
+  }
+
+  async fn get_children(&self) -> Vec<ExtendedPid> {
+    self.inner.get_children().await
+  }
+
+  async fn respond(&self, response: ResponseHandle) {
+    self.inner.respond(response).await
+  }
+
+  async fn stash(&mut self) {
+    self.inner.stash().await
+  }
+
+  async fn un_stash(&mut self) -> bool {
+    self.inner.un_stash().await
+  }
+
+  async fn un_stash_all(&mut self) -> Result<(), ActorError> {
+    self.inner.un_stash_all().await
+  }
+
+  async fn watch(&mut self, pid: &ExtendedPid) {
+    self.inner.watch(pid).await
+  }
+
+  async fn unwatch(&mut self, pid: &ExtendedPid) {
+    self.inner.unwatch(pid).await
+  }
+
+  async fn set_receive_timeout(&mut self, d: &Duration) {
+    self.inner.set_receive_timeout(d).await
+  }
+
+  async fn cancel_receive_timeout(&mut self) {
+    self.inner.cancel_receive_timeout().await
+  }
+
+  async fn forward(&self, pid: &ExtendedPid) {
+    self.inner.forward(pid).await
+  }
+
+  async fn reenter_after(&self, f: ActorFuture, continuation: Continuer) {
+    self.inner.reenter_after(f, continuation).await
+  }
+
+  async fn diagnostics_json(&self) -> String {
+    self.inner.diagnostics_json().await
+  }
+
+  async fn mailbox_pressure(&self) -> usize {
+    self.inner.mailbox_pressure().await
+  }
+
+  async fn receive_timeout_remaining(&self) -> Option<Duration> {
+    self.inner.receive_timeout_remaining().await
+  }
+
+  async fn flag(&self, name: &str) -> bool {
+    self.inner.flag(name).await
+  }
+}
+
+#[async_trait]
+impl PersistencePart for AutoAckContext {
+  async fn persist(&mut self, event: MessageHandle) -> Result<(), JournalError> {
+    self.inner.persist(event).await
+  }
+
+  async fn is_recovering(&self) -> bool {
+    self.inner.is_recovering().await
+  }
+
+  async fn persist_snapshot(&mut self, state: MessageHandle) -> Result<(), JournalError> {
+    self.inner.persist_snapshot(state).await
+  }
+
+  async fn sequence_nr(&self) -> u64 {
+    self.inner.sequence_nr().await
+  }
+
+  async fn delete_events_to(&mut self, to_sequence_nr: u64) -> Result<(), JournalError> {
+    self.inner.delete_events_to(to_sequence_nr).await
+  }
+}
+
+#[async_trait]
+impl StopperPart for AutoAckContext {
+  async fn stop(&mut self, pid: &ExtendedPid) {
+    self.inner.stop(pid).await
+  }
+
+  async fn stop_future_with_timeout(&mut self, pid: &ExtendedPid, timeout: Duration) -> ActorFuture {
+    self.inner.stop_future_with_timeout(pid, timeout).await
+  }
+
+  async fn poison(&mut self, pid: &ExtendedPid) {
+    self.inner.poison(pid).await
+  }
+
+  async fn poison_future_with_timeout(&mut self, pid: &ExtendedPid, timeout: Duration) -> ActorFuture {
+    self.inner.poison_future_with_timeout(pid, timeout).await
+  }
+
+  async fn stop_all_children(&mut self) {
+    self.inner.stop_all_children().await
+  }
+
+  async fn stop_all_children_future_with_timeout(
+    &mut self,
+    timeout: Duration,
+  ) -> BoxFuture<'static, Result<(), ActorFutureError>> {
+    self.inner.stop_all_children_future_with_timeout(timeout).await
+  }
+}
+
+impl Context for AutoAckContext {}
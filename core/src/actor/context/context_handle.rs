@@ -3,25 +3,29 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use futures::future::BoxFuture;
 use tokio::sync::RwLock;
 
 use crate::actor::actor::ActorError;
 use crate::actor::actor::ActorHandle;
 use crate::actor::actor::Continuer;
+use crate::actor::actor::ErrorReason;
 use crate::actor::actor::ExtendedPid;
 use crate::actor::actor::Props;
 use crate::actor::actor::SpawnError;
 use crate::actor::actor_system::ActorSystem;
 use crate::actor::context::actor_context::ActorContext;
+use crate::actor::context::auto_ack_context::AutoAckContext;
 use crate::actor::context::{
-  BasePart, Context, ExtensionContext, ExtensionPart, InfoPart, MessagePart, ReceiverContext, ReceiverPart,
-  SenderContext, SenderPart, SpawnerContext, SpawnerPart, StopperPart,
+  BasePart, Context, ExtensionContext, ExtensionPart, InfoPart, MessagePart, PersistencePart, ReceiverContext,
+  ReceiverPart, SendBatch, SenderContext, SenderPart, SpawnerContext, SpawnerPart, StopperPart,
 };
-use crate::actor::dispatch::future::ActorFuture;
+use crate::actor::dispatch::future::{ActorFuture, ActorFutureError};
 use crate::actor::message::MessageEnvelope;
 use crate::actor::message::MessageHandle;
 use crate::actor::message::ReadonlyMessageHeadersHandle;
 use crate::actor::message::ResponseHandle;
+use crate::actor::persistence::JournalError;
 use crate::ctxext::extensions::{ContextExtensionHandle, ContextExtensionId};
 
 #[derive(Debug, Clone)]
@@ -40,6 +44,13 @@ impl ContextHandle {
     let mg = self.0.read().await;
     mg.as_any().downcast_ref::<ActorContext>().cloned()
   }
+
+  pub(crate) async fn get_restart_reason(&self) -> Option<ErrorReason> {
+    match self.to_actor_context().await {
+      Some(actor_context) => actor_context.get_restart_reason().await,
+      None => None,
+    }
+  }
 }
 
 impl ExtensionContext for ContextHandle {}
@@ -57,7 +68,13 @@ impl ExtensionPart for ContextHandle {
   }
 }
 
-impl SenderContext for ContextHandle {}
+#[async_trait]
+impl SenderContext for ContextHandle {
+  async fn send_after(&mut self, pid: ExtendedPid, message_handle: MessageHandle, delay: Duration) {
+    let mut mg = self.0.write().await;
+    mg.send_after(pid, message_handle, delay).await
+  }
+}
 
 #[async_trait]
 impl InfoPart for ContextHandle {
@@ -99,6 +116,11 @@ impl SenderPart for ContextHandle {
     mg.send(pid, message_handle).await
   }
 
+  async fn begin_batch(&mut self) -> SendBatch {
+    let mut mg = self.0.write().await;
+    mg.begin_batch().await
+  }
+
   async fn request(&mut self, pid: ExtendedPid, message_handle: MessageHandle) {
     let mut mg = self.0.write().await;
     mg.request(pid, message_handle).await
@@ -131,13 +153,38 @@ impl MessagePart for ContextHandle {
     let mg = self.0.read().await;
     mg.get_message_header_handle().await
   }
+
+  async fn correlation_id(&self) -> String {
+    let mg = self.0.read().await;
+    mg.correlation_id().await
+  }
+
+  async fn set_correlation_id(&mut self, id: String) {
+    let mut mg = self.0.write().await;
+    mg.set_correlation_id(id).await
+  }
 }
 
 impl ReceiverContext for ContextHandle {}
 
 #[async_trait]
 impl ReceiverPart for ContextHandle {
+  // Contexts that are handles over shared state are received on a clone, so the lock is not held while the
+  // actor runs and the actor can be given this very handle, as it is by context decorators
   async fn receive(&mut self, envelope: MessageEnvelope) -> Result<(), ActorError> {
+    let (auto_ack_context, actor_context) = {
+      let mg = self.0.read().await;
+      (
+        mg.as_any().downcast_ref::<AutoAckContext>().cloned(),
+        mg.as_any().downcast_ref::<ActorContext>().cloned(),
+      )
+    };
+    if let Some(mut context) = auto_ack_context {
+      return context.receive(envelope).await;
+    }
+    if let Some(mut context) = actor_context {
+      return context.receive(envelope).await;
+    }
     let mut mg = self.0.write().await;
     mg.receive(envelope).await
   }
@@ -169,7 +216,7 @@ impl BasePart for ContextHandle {
     self
   }
 
-  async fn get_receive_timeout(&self) -> Duration {
+  async fn get_receive_timeout(&self) -> Option<Duration> {
     let mg = self.0.read().await;
     mg.get_receive_timeout().await
   }
@@ -189,6 +236,11 @@ impl BasePart for ContextHandle {
     mg.stash().await
   }
 
+  async fn un_stash(&mut self) -> bool {
+    let mut mg = self.0.write().await;
+    mg.un_stash().await
+  }
+
   async fn un_stash_all(&mut self) -> Result<(), ActorError> {
     let mut mg = self.0.write().await;
     mg.un_stash_all().await
@@ -223,6 +275,54 @@ impl BasePart for ContextHandle {
     let mg = self.0.read().await;
     mg.reenter_after(f, continuation).await
   }
+
+  async fn diagnostics_json(&self) -> String {
+    let mg = self.0.read().await;
+    mg.diagnostics_json().await
+  }
+
+  async fn mailbox_pressure(&self) -> usize {
+    let mg = self.0.read().await;
+    mg.mailbox_pressure().await
+  }
+
+  async fn receive_timeout_remaining(&self) -> Option<Duration> {
+    let mg = self.0.read().await;
+    mg.receive_timeout_remaining().await
+  }
+
+  async fn flag(&self, name: &str) -> bool {
+    let mg = self.0.read().await;
+    mg.flag(name).await
+  }
+}
+
+#[async_trait]
+impl PersistencePart for ContextHandle {
+  async fn persist(&mut self, event: MessageHandle) -> Result<(), JournalError> {
+    let mut mg = self.0.write().await;
+    mg.persist(event).await
+  }
+
+  async fn is_recovering(&self) -> bool {
+    let mg = self.0.read().await;
+    mg.is_recovering().await
+  }
+
+  async fn persist_snapshot(&mut self, state: MessageHandle) -> Result<(), JournalError> {
+    let mut mg = self.0.write().await;
+    mg.persist_snapshot(state).await
+  }
+
+  async fn sequence_nr(&self) -> u64 {
+    let mg = self.0.read().await;
+    mg.sequence_nr().await
+  }
+
+  async fn delete_events_to(&mut self, to_sequence_nr: u64) -> Result<(), JournalError> {
+    let mut mg = self.0.write().await;
+    mg.delete_events_to(to_sequence_nr).await
+  }
 }
 
 #[async_trait]
@@ -246,6 +346,19 @@ impl StopperPart for ContextHandle {
     let mut mg = self.0.write().await;
     mg.poison_future_with_timeout(pid, timeout).await
   }
+
+  async fn stop_all_children(&mut self) {
+    let mut mg = self.0.write().await;
+    mg.stop_all_children().await
+  }
+
+  async fn stop_all_children_future_with_timeout(
+    &mut self,
+    timeout: Duration,
+  ) -> BoxFuture<'static, Result<(), ActorFutureError>> {
+    let mut mg = self.0.write().await;
+    mg.stop_all_children_future_with_timeout(timeout).await
+  }
 }
 
 impl Context for ContextHandle {}
@@ -0,0 +1,42 @@
+use std::collections::VecDeque;
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MessageHistoryEntry {
+  pub type_name: String,
+  pub timestamp_millis: i64,
+}
+
+// MessageHistory keeps the type names of the last `capacity` messages processed by an actor
+#[derive(Debug, Clone)]
+pub struct MessageHistory {
+  capacity: usize,
+  entries: VecDeque<MessageHistoryEntry>,
+}
+
+impl MessageHistory {
+  pub fn new(capacity: usize) -> Self {
+    Self {
+      capacity,
+      entries: VecDeque::with_capacity(capacity),
+    }
+  }
+
+  pub fn record(&mut self, type_name: String) {
+    if self.capacity == 0 {
+      return;
+    }
+    if self.entries.len() == self.capacity {
+      self.entries.pop_front();
+    }
+    self.entries.push_back(MessageHistoryEntry {
+      type_name,
+      timestamp_millis: chrono::Utc::now().timestamp_millis(),
+    });
+  }
+
+  pub fn get_entries(&self) -> Vec<MessageHistoryEntry> {
+    self.entries.iter().cloned().collect()
+  }
+}
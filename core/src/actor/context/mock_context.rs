@@ -6,17 +6,19 @@ use crate::actor::actor::Props;
 use crate::actor::actor::SpawnError;
 use crate::actor::actor_system::ActorSystem;
 use crate::actor::context::{
-  BasePart, Context, ExtensionContext, ExtensionPart, InfoPart, MessagePart, ReceiverContext, ReceiverPart,
-  SenderContext, SenderPart, SpawnerContext, SpawnerPart, StopperPart,
+  BasePart, Context, ExtensionContext, ExtensionPart, InfoPart, MessagePart, PersistencePart, ReceiverContext,
+  ReceiverPart, SendBatch, SenderContext, SenderContextHandle, SenderPart, SpawnerContext, SpawnerPart, StopperPart,
 };
-use crate::actor::dispatch::future::{ActorFuture, ActorFutureProcess};
+use crate::actor::dispatch::future::{ActorFuture, ActorFutureError, ActorFutureProcess};
 use crate::actor::message::MessageEnvelope;
 use crate::actor::message::MessageHandle;
 use crate::actor::message::ReadonlyMessageHeadersHandle;
 use crate::actor::message::ResponseHandle;
+use crate::actor::persistence::JournalError;
 use crate::actor::process::Process;
 use crate::ctxext::extensions::{ContextExtensionHandle, ContextExtensionId};
 use async_trait::async_trait;
+use futures::future::BoxFuture;
 use std::any::Any;
 use std::time::Duration;
 
@@ -73,12 +75,19 @@ impl SenderPart for MockContext {
 
   async fn send(&mut self, _: ExtendedPid, _: MessageHandle) {}
 
+  async fn begin_batch(&mut self) -> SendBatch {
+    SendBatch::new(SenderContextHandle::new(self.clone()))
+  }
+
   async fn request(&mut self, _: ExtendedPid, _: MessageHandle) {}
 
   async fn request_with_custom_sender(&mut self, _: ExtendedPid, _: MessageHandle, _: ExtendedPid) {}
 
   async fn request_future(&self, _: ExtendedPid, message_handle: MessageHandle, timeout: Duration) -> ActorFuture {
-    let process = ActorFutureProcess::new(self.system.clone(), timeout).await;
+    let process = match ActorFutureProcess::new(self.system.clone(), timeout).await {
+      Ok(process) => process,
+      Err(error) => return ActorFuture::failed(self.system.clone(), error).await,
+    };
     process.send_user_message(None, message_handle).await;
     process.get_future().await
   }
@@ -97,6 +106,12 @@ impl MessagePart for MockContext {
   async fn get_message_header_handle(&self) -> Option<ReadonlyMessageHeadersHandle> {
     None
   }
+
+  async fn correlation_id(&self) -> String {
+    uuid::Uuid::new_v4().to_string()
+  }
+
+  async fn set_correlation_id(&mut self, _: String) {}
 }
 
 impl ReceiverContext for MockContext {}
@@ -131,7 +146,7 @@ impl BasePart for MockContext {
     self
   }
 
-  async fn get_receive_timeout(&self) -> Duration {
+  async fn get_receive_timeout(&self) -> Option<Duration> {
     todo!()
   }
 
@@ -147,6 +162,10 @@ impl BasePart for MockContext {
     todo!()
   }
 
+  async fn un_stash(&mut self) -> bool {
+    todo!()
+  }
+
   async fn un_stash_all(&mut self) -> Result<(), ActorError> {
     todo!()
   }
@@ -176,6 +195,29 @@ impl BasePart for MockContext {
   }
 }
 
+#[async_trait]
+impl PersistencePart for MockContext {
+  async fn persist(&mut self, _: MessageHandle) -> Result<(), JournalError> {
+    Err(JournalError::NotConfigured)
+  }
+
+  async fn is_recovering(&self) -> bool {
+    false
+  }
+
+  async fn persist_snapshot(&mut self, _: MessageHandle) -> Result<(), JournalError> {
+    Err(JournalError::NotConfigured)
+  }
+
+  async fn sequence_nr(&self) -> u64 {
+    0
+  }
+
+  async fn delete_events_to(&mut self, _: u64) -> Result<(), JournalError> {
+    Err(JournalError::NotConfigured)
+  }
+}
+
 #[async_trait]
 impl StopperPart for MockContext {
   async fn stop(&mut self, _: &ExtendedPid) {}
@@ -189,6 +231,15 @@ impl StopperPart for MockContext {
   async fn poison_future_with_timeout(&mut self, _: &ExtendedPid, _: Duration) -> ActorFuture {
     todo!()
   }
+
+  async fn stop_all_children(&mut self) {}
+
+  async fn stop_all_children_future_with_timeout(
+    &mut self,
+    _: Duration,
+  ) -> BoxFuture<'static, Result<(), ActorFutureError>> {
+    todo!()
+  }
 }
 
 impl Context for MockContext {}
@@ -1,41 +1,62 @@
-use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::RwLock;
 
+use tokio::sync::watch;
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+
+// SleepContainer is a resettable deadline. A None deadline pauses the timer; stop cancels every pending wait
 #[derive(Debug, Clone)]
-pub struct SleepContainer(Arc<RwLock<Pin<Box<tokio::time::Sleep>>>>);
-impl SleepContainer {
-  pub fn from_sleep(sleep: tokio::time::Sleep) -> Self {
-    SleepContainer(Arc::new(RwLock::new(Box::pin(sleep))))
-  }
+pub struct SleepContainer {
+  deadline: Arc<watch::Sender<Option<Instant>>>,
+  cancellation: CancellationToken,
+}
 
+impl SleepContainer {
   pub fn new(duration: Duration) -> Self {
-    Self::from_sleep(tokio::time::sleep(duration))
+    let (deadline, _) = watch::channel(Some(Instant::now() + duration));
+    SleepContainer {
+      deadline: Arc::new(deadline),
+      cancellation: CancellationToken::new(),
+    }
   }
 
-  pub fn from_underlying(underlying: Arc<RwLock<Pin<Box<tokio::time::Sleep>>>>) -> Self {
-    Self(underlying)
+  pub fn init(&mut self, instant: Instant) {
+    self.deadline.send_replace(Some(instant));
   }
 
-  pub async fn init(&mut self, instant: tokio::time::Instant) {
-    let mut timer = self.0.write().await;
-    *timer = Box::pin(tokio::time::sleep_until(instant));
+  pub fn reset(&mut self, instant: Instant) {
+    self.deadline.send_replace(Some(instant));
   }
 
-  pub async fn reset(&mut self, instant: tokio::time::Instant) {
-    let mut sleep = self.0.write().await;
-    sleep.as_mut().reset(instant);
+  pub fn pause(&mut self) {
+    self.deadline.send_replace(None);
   }
 
-  pub async fn stop(&mut self) {
-    let mut sleep = self.0.write().await;
-    sleep.as_mut().reset(tokio::time::Instant::now());
+  pub fn stop(&mut self) {
+    self.cancellation.cancel();
   }
 
-  pub async fn wait(&self) {
-    let mut sleep = self.0.write().await;
-    sleep.as_mut().await;
+  // Wait returns true once the deadline has passed, or false as soon as the timer is stopped
+  pub async fn wait(&self) -> bool {
+    let mut deadline_rx = self.deadline.subscribe();
+    loop {
+      let deadline = *deadline_rx.borrow_and_update();
+      tokio::select! {
+        _ = self.cancellation.cancelled() => return false,
+        changed = deadline_rx.changed() => {
+          if changed.is_err() {
+            return false;
+          }
+        }
+        _ = async {
+          match deadline {
+            Some(deadline) => tokio::time::sleep_until(deadline).await,
+            None => std::future::pending().await,
+          }
+        } => return true,
+      }
+    }
   }
 }
 
@@ -47,27 +68,23 @@ impl ReceiveTimeoutTimer {
     ReceiveTimeoutTimer(SleepContainer::new(duration))
   }
 
-  pub fn from_sleep(sleep: tokio::time::Sleep) -> Self {
-    ReceiveTimeoutTimer(SleepContainer::from_sleep(sleep))
-  }
-
-  pub fn from_underlying(underlying: Arc<RwLock<Pin<Box<tokio::time::Sleep>>>>) -> Self {
-    ReceiveTimeoutTimer(SleepContainer::from_underlying(underlying))
+  pub fn reset(&mut self, instant: Instant) {
+    self.0.reset(instant);
   }
 
-  pub async fn reset(&mut self, instant: tokio::time::Instant) {
-    self.0.reset(instant).await;
+  pub fn init(&mut self, instant: Instant) {
+    self.0.init(instant);
   }
 
-  pub async fn init(&mut self, instant: tokio::time::Instant) {
-    self.0.init(instant).await;
+  pub fn pause(&mut self) {
+    self.0.pause();
   }
 
-  pub async fn stop(&mut self) {
-    self.0.stop().await;
+  pub fn stop(&mut self) {
+    self.0.stop();
   }
 
-  pub async fn wait(&self) {
-    self.0.wait().await;
+  pub async fn wait(&self) -> bool {
+    self.0.wait().await
   }
 }
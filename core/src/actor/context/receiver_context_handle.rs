@@ -80,6 +80,16 @@ impl MessagePart for ReceiverContextHandle {
     let mg = self.0.read().await;
     mg.get_message_header_handle().await
   }
+
+  async fn correlation_id(&self) -> String {
+    let mg = self.0.read().await;
+    mg.correlation_id().await
+  }
+
+  async fn set_correlation_id(&mut self, id: String) {
+    let mut mg = self.0.write().await;
+    mg.set_correlation_id(id).await
+  }
 }
 
 #[async_trait]
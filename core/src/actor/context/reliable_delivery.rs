@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use nexus_actor_message_derive_rs::Message;
+
+// Header carrying the id shared by every attempt of a reliable delivery
+pub const DELIVERY_ID_HEADER: &str = "nexus-delivery-id";
+// Header carrying the 1-based attempt number of a reliable delivery
+pub const DELIVERY_ATTEMPT_HEADER: &str = "nexus-delivery-attempt";
+
+// RetryPolicy controls how often `send_reliable` redelivers a message that has not been acknowledged
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+  max_attempts: u32,
+  ack_timeout: Duration,
+}
+
+impl RetryPolicy {
+  pub fn new(max_attempts: u32, ack_timeout: Duration) -> Self {
+    Self {
+      max_attempts: max_attempts.max(1),
+      ack_timeout,
+    }
+  }
+
+  pub fn get_max_attempts(&self) -> u32 {
+    self.max_attempts
+  }
+
+  pub fn get_ack_timeout(&self) -> Duration {
+    self.ack_timeout
+  }
+}
+
+impl Default for RetryPolicy {
+  fn default() -> Self {
+    Self::new(3, Duration::from_secs(1))
+  }
+}
+
+// DeliveryAck is the response a receiver sends back to acknowledge a reliable delivery
+#[derive(Debug, Clone, PartialEq, Eq, Message)]
+pub struct DeliveryAck {
+  pub delivery_id: String,
+}
+
+impl DeliveryAck {
+  pub fn new(delivery_id: String) -> Self {
+    Self { delivery_id }
+  }
+}
+
+// DeliveryRejected is sent back instead of an ack when a receiver refuses a redelivery of a message that is not
+// idempotent, see Props::with_idempotent
+#[derive(Debug, Clone, PartialEq, Eq, Message)]
+pub struct DeliveryRejected {
+  pub delivery_id: String,
+}
+
+impl DeliveryRejected {
+  pub fn new(delivery_id: String) -> Self {
+    Self { delivery_id }
+  }
+}
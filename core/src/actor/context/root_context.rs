@@ -2,6 +2,8 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use futures::future::BoxFuture;
+use futures::FutureExt;
 
 use crate::actor::actor::make_sender_middleware_chain;
 use crate::actor::actor::ActorHandle;
@@ -15,14 +17,18 @@ use crate::actor::actor_system::ActorSystem;
 use crate::actor::context::sender_context_handle::SenderContextHandle;
 use crate::actor::context::spawner_context_handle::SpawnerContextHandle;
 use crate::actor::context::{
-  InfoPart, MessagePart, SenderContext, SenderPart, SpawnerContext, SpawnerPart, StopperPart, TypedRootContext,
+  InfoPart, MessagePart, SendBatch, SenderContext, SenderPart, SpawnerContext, SpawnerPart, StopperPart,
+  TypedRootContext,
 };
-use crate::actor::dispatch::future::{ActorFuture, ActorFutureProcess};
+use crate::actor::dispatch::future::{ActorFuture, ActorFutureError, ActorFutureProcess};
 use crate::actor::message::MessageEnvelope;
 use crate::actor::message::MessageHandle;
 use crate::actor::message::MessageHeaders;
+use crate::actor::message::ReadonlyMessageHeaders;
 use crate::actor::message::ReadonlyMessageHeadersHandle;
 use crate::actor::message::SystemMessage;
+use crate::actor::message::CORRELATION_ID_HEADER;
+use crate::actor::message::{unwrap_envelope_header, wrap_envelope, wrap_envelope_with_header};
 use crate::actor::process::Process;
 use crate::actor::supervisor::SupervisorStrategyHandle;
 use crate::generated::actor::{PoisonPill, Watch};
@@ -73,9 +79,11 @@ impl RootContext {
   }
 
   async fn send_user_message(&self, pid: ExtendedPid, message_handle: MessageHandle) {
+    let message_handle = self.with_correlation_header(message_handle);
+    let message_handle = self.actor_system.apply_envelope_factory(message_handle).await;
     if self.sender_middleware_chain.is_some() {
       let sch = SenderContextHandle::new(self.clone());
-      let me = MessageEnvelope::new(message_handle);
+      let me = wrap_envelope(message_handle);
       self.sender_middleware_chain.clone().unwrap().run(sch, pid, me).await;
     } else {
       tracing::debug!("Sending user message to pid: {}", pid);
@@ -83,6 +91,25 @@ impl RootContext {
     }
   }
 
+  // Outbound messages carry the correlation id of the root context unless they carry their own, so the
+  // receiving actor reuses it instead of starting a new one
+  fn with_correlation_header(&self, message_handle: MessageHandle) -> MessageHandle {
+    let Some(correlation_id) = self.message_headers.get(CORRELATION_ID_HEADER) else {
+      return message_handle;
+    };
+    let has_own = unwrap_envelope_header(message_handle.clone())
+      .and_then(|header| header.get(CORRELATION_ID_HEADER))
+      .is_some();
+    if has_own {
+      return message_handle;
+    }
+    MessageHandle::new(wrap_envelope_with_header(
+      message_handle,
+      CORRELATION_ID_HEADER,
+      correlation_id,
+    ))
+  }
+
   pub fn to_typed(self) -> TypedRootContext {
     TypedRootContext::new(self)
   }
@@ -132,6 +159,10 @@ impl SenderPart for RootContext {
     self.send_user_message(pid, message_handle).await
   }
 
+  async fn begin_batch(&mut self) -> SendBatch {
+    SendBatch::new(SenderContextHandle::new(self.clone()))
+  }
+
   async fn request(&mut self, pid: ExtendedPid, message_handle: MessageHandle) {
     self.send_user_message(pid, message_handle).await
   }
@@ -146,7 +177,10 @@ impl SenderPart for RootContext {
   }
 
   async fn request_future(&self, pid: ExtendedPid, message_handle: MessageHandle, timeout: Duration) -> ActorFuture {
-    let future_process = ActorFutureProcess::new(self.get_actor_system().await, timeout).await;
+    let future_process = match ActorFutureProcess::new(self.get_actor_system().await, timeout).await {
+      Ok(future_process) => future_process,
+      Err(error) => return ActorFuture::failed(self.get_actor_system().await, error).await,
+    };
     let future_pid = future_process.get_pid().await;
     let moe = MessageEnvelope::new(message_handle).with_sender(future_pid.clone());
     self.send_user_message(pid, MessageHandle::new(moe)).await;
@@ -167,6 +201,19 @@ impl MessagePart for RootContext {
   async fn get_message_header_handle(&self) -> Option<ReadonlyMessageHeadersHandle> {
     Some(ReadonlyMessageHeadersHandle::new_arc(self.message_headers.clone()))
   }
+
+  async fn correlation_id(&self) -> String {
+    self
+      .message_headers
+      .get(CORRELATION_ID_HEADER)
+      .unwrap_or_else(|| uuid::Uuid::new_v4().to_string())
+  }
+
+  async fn set_correlation_id(&mut self, id: String) {
+    let mut headers = MessageHeaders::with_values(self.message_headers.to_map());
+    headers.set(CORRELATION_ID_HEADER.to_string(), id);
+    self.message_headers = Arc::new(headers);
+  }
 }
 
 impl SenderContext for RootContext {}
@@ -174,13 +221,7 @@ impl SenderContext for RootContext {}
 #[async_trait]
 impl SpawnerPart for RootContext {
   async fn spawn(&mut self, props: Props) -> ExtendedPid {
-    match self
-      .spawn_named(
-        props,
-        &self.get_actor_system().await.get_process_registry().await.next_id(),
-      )
-      .await
-    {
+    match props.spawn_with_generated_name(self).await {
       Ok(pid) => pid,
       Err(e) => panic!("Failed to spawn actor: {:?}", e),
     }
@@ -239,7 +280,10 @@ impl StopperPart for RootContext {
   }
 
   async fn stop_future_with_timeout(&mut self, pid: &ExtendedPid, timeout: Duration) -> ActorFuture {
-    let future_process = ActorFutureProcess::new(self.get_actor_system().await, timeout).await;
+    let future_process = match ActorFutureProcess::new(self.get_actor_system().await, timeout).await {
+      Ok(future_process) => future_process,
+      Err(error) => return ActorFuture::failed(self.get_actor_system().await, error).await,
+    };
 
     let future_pid = future_process.get_pid().await.clone();
     pid
@@ -262,7 +306,10 @@ impl StopperPart for RootContext {
   }
 
   async fn poison_future_with_timeout(&mut self, pid: &ExtendedPid, timeout: Duration) -> ActorFuture {
-    let future_process = ActorFutureProcess::new(self.get_actor_system().await, timeout).await;
+    let future_process = match ActorFutureProcess::new(self.get_actor_system().await, timeout).await {
+      Ok(future_process) => future_process,
+      Err(error) => return ActorFuture::failed(self.get_actor_system().await, error).await,
+    };
 
     let future_pid = future_process.get_pid().await.clone();
     pid
@@ -277,4 +324,14 @@ impl StopperPart for RootContext {
 
     future_process.get_future().await
   }
+
+  // The root context has no children
+  async fn stop_all_children(&mut self) {}
+
+  async fn stop_all_children_future_with_timeout(
+    &mut self,
+    _: Duration,
+  ) -> BoxFuture<'static, Result<(), ActorFutureError>> {
+    async { Ok(()) }.boxed()
+  }
 }
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::actor::actor::ExtendedPid;
+use crate::actor::context::{SenderContextHandle, SenderPart};
+use crate::actor::message::MessageHandle;
+
+// SendBatch buffers sends so that they are emitted together on `commit`, or not at all on `rollback`.
+// Batches begun by an actor are rolled back when its receive returns an error
+#[derive(Debug, Clone)]
+pub struct SendBatch {
+  sender: SenderContextHandle,
+  pending: Arc<Mutex<Vec<(ExtendedPid, MessageHandle)>>>,
+}
+
+impl SendBatch {
+  pub fn new(sender: SenderContextHandle) -> Self {
+    Self {
+      sender,
+      pending: Arc::new(Mutex::new(vec![])),
+    }
+  }
+
+  pub async fn send(&self, pid: ExtendedPid, message_handle: MessageHandle) {
+    self.pending.lock().await.push((pid, message_handle));
+  }
+
+  pub async fn len(&self) -> usize {
+    self.pending.lock().await.len()
+  }
+
+  pub async fn is_empty(&self) -> bool {
+    self.pending.lock().await.is_empty()
+  }
+
+  pub async fn commit(&self) {
+    let pending = std::mem::take(&mut *self.pending.lock().await);
+    let mut sender = self.sender.clone();
+    for (pid, message_handle) in pending {
+      sender.send(pid, message_handle).await;
+    }
+  }
+
+  pub async fn rollback(&self) {
+    self.pending.lock().await.clear();
+  }
+}
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SendError {
+  #[error("No process is registered for the target PID")]
+  NoProcess,
+  #[error("The target's mailbox is full")]
+  MailboxFull,
+  #[error("Delivery was not acknowledged after {0} attempts")]
+  DeliveryExhausted(u32),
+  #[error("Redelivery was rejected because the message is not idempotent")]
+  DeliveryRejected,
+}
+
+static_assertions::assert_impl_all!(SendError: Send, Sync);
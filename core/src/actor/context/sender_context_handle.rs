@@ -7,7 +7,7 @@ use tokio::sync::RwLock;
 use crate::actor::actor::ActorHandle;
 use crate::actor::actor::ExtendedPid;
 use crate::actor::actor_system::ActorSystem;
-use crate::actor::context::{InfoPart, MessagePart, SenderContext, SenderPart};
+use crate::actor::context::{InfoPart, MessagePart, SendBatch, SenderContext, SenderPart};
 use crate::actor::dispatch::future::ActorFuture;
 use crate::actor::message::MessageEnvelope;
 use crate::actor::message::MessageHandle;
@@ -66,6 +66,11 @@ impl SenderPart for SenderContextHandle {
     mg.send(pid, message_handle).await
   }
 
+  async fn begin_batch(&mut self) -> SendBatch {
+    let mut mg = self.0.write().await;
+    mg.begin_batch().await
+  }
+
   async fn request(&mut self, pid: ExtendedPid, message_handle: MessageHandle) {
     let mut mg = self.0.write().await;
     mg.request(pid, message_handle).await
@@ -98,6 +103,22 @@ impl MessagePart for SenderContextHandle {
     let mg = self.0.read().await;
     mg.get_message_header_handle().await
   }
+
+  async fn correlation_id(&self) -> String {
+    let mg = self.0.read().await;
+    mg.correlation_id().await
+  }
+
+  async fn set_correlation_id(&mut self, id: String) {
+    let mut mg = self.0.write().await;
+    mg.set_correlation_id(id).await
+  }
 }
 
-impl SenderContext for SenderContextHandle {}
+#[async_trait]
+impl SenderContext for SenderContextHandle {
+  async fn send_after(&mut self, pid: ExtendedPid, message_handle: MessageHandle, delay: Duration) {
+    let mut mg = self.0.write().await;
+    mg.send_after(pid, message_handle, delay).await
+  }
+}
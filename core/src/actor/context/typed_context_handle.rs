@@ -1,13 +1,14 @@
 use crate::actor::actor::{ActorError, ActorHandle, Continuer, ExtendedPid, SpawnError, TypedExtendedPid, TypedProps};
 use crate::actor::actor_system::ActorSystem;
 use crate::actor::context::{
-  BasePart, ContextHandle, ExtensionContext, ExtensionPart, InfoPart, MessagePart, ReceiverPart, SenderPart,
-  SpawnerPart, StopperPart,
+  BasePart, ContextHandle, ExtensionContext, ExtensionPart, InfoPart, MessagePart, PersistencePart, ReceiverPart,
+  SenderContext, SenderPart, SpawnerPart, StopperPart,
 };
 use crate::actor::dispatch::future::ActorFuture;
 use crate::actor::message::{
   Message, MessageHandle, ReadonlyMessageHeadersHandle, ResponseHandle, TypedMessageEnvelope,
 };
+use crate::actor::persistence::JournalError;
 use crate::actor::typed_context::{
   TypedContext, TypedInfoPart, TypedMessagePart, TypedReceiverContext, TypedReceiverPart, TypedSenderContext,
   TypedSenderPart, TypedSpawnerContext, TypedSpawnerPart, TypedStopperPart,
@@ -50,7 +51,12 @@ impl<M: Message> ExtensionPart for TypedContextHandle<M> {
   }
 }
 
-impl<M: Message + Clone> TypedSenderContext<M> for TypedContextHandle<M> {}
+#[async_trait]
+impl<M: Message + Clone> TypedSenderContext<M> for TypedContextHandle<M> {
+  async fn send_after(&mut self, pid: ExtendedPid, message_handle: MessageHandle, delay: Duration) {
+    self.underlying.send_after(pid, message_handle, delay).await
+  }
+}
 
 #[async_trait]
 impl<M: Message> TypedInfoPart<M> for TypedContextHandle<M> {
@@ -176,7 +182,7 @@ impl<M: Message> BasePart for TypedContextHandle<M> {
     self
   }
 
-  async fn get_receive_timeout(&self) -> Duration {
+  async fn get_receive_timeout(&self) -> Option<Duration> {
     self.underlying.get_receive_timeout().await
   }
 
@@ -192,6 +198,10 @@ impl<M: Message> BasePart for TypedContextHandle<M> {
     self.underlying.stash().await
   }
 
+  async fn un_stash(&mut self) -> bool {
+    self.underlying.un_stash().await
+  }
+
   async fn un_stash_all(&mut self) -> Result<(), ActorError> {
     self.underlying.un_stash_all().await
   }
@@ -219,6 +229,45 @@ impl<M: Message> BasePart for TypedContextHandle<M> {
   async fn reenter_after(&self, f: ActorFuture, continuation: Continuer) {
     self.underlying.reenter_after(f, continuation).await
   }
+
+  async fn diagnostics_json(&self) -> String {
+    self.underlying.diagnostics_json().await
+  }
+
+  async fn mailbox_pressure(&self) -> usize {
+    self.underlying.mailbox_pressure().await
+  }
+
+  async fn receive_timeout_remaining(&self) -> Option<Duration> {
+    self.underlying.receive_timeout_remaining().await
+  }
+
+  async fn flag(&self, name: &str) -> bool {
+    self.underlying.flag(name).await
+  }
+}
+
+#[async_trait]
+impl<M: Message> PersistencePart for TypedContextHandle<M> {
+  async fn persist(&mut self, event: MessageHandle) -> Result<(), JournalError> {
+    self.underlying.persist(event).await
+  }
+
+  async fn is_recovering(&self) -> bool {
+    self.underlying.is_recovering().await
+  }
+
+  async fn persist_snapshot(&mut self, state: MessageHandle) -> Result<(), JournalError> {
+    self.underlying.persist_snapshot(state).await
+  }
+
+  async fn sequence_nr(&self) -> u64 {
+    self.underlying.sequence_nr().await
+  }
+
+  async fn delete_events_to(&mut self, to_sequence_nr: u64) -> Result<(), JournalError> {
+    self.underlying.delete_events_to(to_sequence_nr).await
+  }
 }
 
 #[async_trait]
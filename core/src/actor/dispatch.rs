@@ -5,6 +5,7 @@ mod default_mailbox;
 mod dispatcher;
 mod dispatcher_test;
 pub mod future;
+mod future_pressure_event;
 mod future_test;
 mod mailbox;
 mod mailbox_handle;
@@ -13,12 +14,14 @@ mod mailbox_middleware;
 mod mailbox_producer;
 mod mailbox_test;
 mod message_invoker;
+mod priority_mailbox;
+mod priority_mailbox_test;
 pub mod throttler;
 mod throttler_test;
 mod unbounded;
 
 pub use {
-  self::bounded::*, self::dead_letter_process::*, self::dispatcher::*, self::mailbox::*, self::mailbox_handle::*,
-  self::mailbox_message::*, self::mailbox_middleware::*, self::mailbox_producer::*, self::message_invoker::*,
-  self::unbounded::*,
+  self::bounded::*, self::dead_letter_process::*, self::dispatcher::*, self::future_pressure_event::*,
+  self::mailbox::*, self::mailbox_handle::*, self::mailbox_message::*, self::mailbox_middleware::*,
+  self::mailbox_producer::*, self::message_invoker::*, self::priority_mailbox::*, self::unbounded::*,
 };
@@ -36,13 +36,17 @@ impl QueueBase<MessageHandle> for BoundedMailboxQueue {
   async fn capacity(&self) -> QueueSize {
     self.user_mailbox.capacity().await
   }
+
+  // Only a dropping mailbox is ever full, a non-dropping one grows its ring instead
+  async fn is_full(&self) -> bool {
+    self.dropping && self.user_mailbox.len().await == QueueSize::Limited(self.initial_capacity)
+  }
 }
 
 #[async_trait]
 impl QueueWriter<MessageHandle> for BoundedMailboxQueue {
   async fn offer(&mut self, element: MessageHandle) -> Result<(), QueueError<MessageHandle>> {
-    let len = self.user_mailbox.len().await;
-    if self.dropping && len == QueueSize::Limited(self.initial_capacity) {
+    if self.is_full().await {
       let _ = self.user_mailbox.poll().await;
     }
     self.user_mailbox.offer(element).await
@@ -1,3 +1,7 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
 use crate::actor::actor::ExtendedPid;
 use crate::actor::actor_system::ActorSystem;
 use crate::actor::context::SenderPart;
@@ -16,14 +20,87 @@ use crate::metrics::ActorMetrics;
 use async_trait::async_trait;
 use nexus_actor_message_derive_rs::Message;
 
+// DeadLetterWindow counts dead letters in fixed intervals, keeping the count of the last complete one
+#[derive(Debug)]
+struct DeadLetterWindow {
+  interval: Duration,
+  started_at: Instant,
+  current: u64,
+  previous: u64,
+}
+
+impl DeadLetterWindow {
+  fn new(interval: Duration) -> Self {
+    Self {
+      interval,
+      started_at: Instant::now(),
+      current: 0,
+      previous: 0,
+    }
+  }
+
+  fn roll(&mut self) {
+    let elapsed = self.started_at.elapsed();
+    if self.interval.is_zero() || elapsed < self.interval {
+      return;
+    }
+    self.previous = if elapsed < self.interval * 2 { self.current } else { 0 };
+    self.current = 0;
+    self.started_at = Instant::now();
+  }
+
+  fn record(&mut self) {
+    self.roll();
+    self.current += 1;
+  }
+
+  fn rate_per_second(&mut self) -> f64 {
+    self.roll();
+    if self.interval.is_zero() {
+      return 0.0;
+    }
+    self.previous as f64 / self.interval.as_secs_f64()
+  }
+}
+
+// Dead letters are summarized per target and message type
+type DeadLetterSummaryKey = (Option<ExtendedPid>, String);
+
+// DeadLetterSummary keeps the publish times of one target and message type within the sliding summary window,
+// and counts the dead letters suppressed since the last DeadLetterSummaryEvent
+#[derive(Debug, Default)]
+struct DeadLetterSummary {
+  published: VecDeque<tokio::time::Instant>,
+  suppressed: usize,
+}
+
+impl DeadLetterSummary {
+  fn expire(&mut self, now: tokio::time::Instant, window: Duration) {
+    while self
+      .published
+      .front()
+      .is_some_and(|published_at| now.duration_since(*published_at) >= window)
+    {
+      self.published.pop_front();
+    }
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct DeadLetterProcess {
   actor_system: ActorSystem,
+  window: Arc<Mutex<DeadLetterWindow>>,
+  summaries: Arc<Mutex<HashMap<DeadLetterSummaryKey, DeadLetterSummary>>>,
 }
 
 impl DeadLetterProcess {
   pub async fn new(actor_system: ActorSystem) -> Self {
-    let myself = Self { actor_system };
+    let interval = actor_system.get_config().await.dead_letter_throttle_interval;
+    let myself = Self {
+      actor_system,
+      window: Arc::new(Mutex::new(DeadLetterWindow::new(interval))),
+      summaries: Arc::new(Mutex::new(HashMap::new())),
+    };
     let dead_letter_throttle_count = myself
       .actor_system
       .get_config()
@@ -114,13 +191,13 @@ impl DeadLetterProcess {
           if let Some(dle) = cloned_msg.to_typed::<DeadLetterEvent>() {
             if let Some(SystemMessage::Watch(watch)) = dle.message_handle.to_typed::<SystemMessage>() {
               let actor_system = cloned_self.actor_system.clone();
-              let pid = watch.watcher.clone().unwrap();
-              let e_pid = ExtendedPid::new(pid.clone());
-              e_pid
+              let watcher = ExtendedPid::new(watch.watcher.clone().unwrap());
+              // The watchee is the dead letter's target, it never existed or has already stopped
+              watcher
                 .send_system_message(
                   actor_system,
                   MessageHandle::new(SystemMessage::Terminate(Terminated {
-                    who: Some(pid),
+                    who: dle.pid.as_ref().map(|pid| pid.inner_pid.clone()),
                     why: TerminateReason::NotFound as i32,
                   })),
                 )
@@ -134,6 +211,91 @@ impl DeadLetterProcess {
     myself
   }
 
+  // GetDeadLetterRate returns the dead letters per second over the last complete throttle interval
+  pub fn get_dead_letter_rate(&self) -> f64 {
+    self.window.lock().unwrap().rate_per_second()
+  }
+
+  // Returns true when the dead letter is only counted towards a DeadLetterSummaryEvent instead of being published.
+  // At most `threshold` dead letters of a target and message type are published within any window, and the
+  // suppressed ones are reported once per window for as long as the key keeps receiving dead letters
+  async fn is_summarized(&self, pid: Option<&ExtendedPid>, message_handle: &MessageHandle) -> bool {
+    let (window, threshold) = {
+      let config = self.actor_system.get_config().await;
+      (config.dead_letter_summary_window, config.dead_letter_summary_threshold)
+    };
+    if window.is_zero() {
+      return false;
+    }
+    let key: DeadLetterSummaryKey = (pid.cloned(), message_handle.get_type_name());
+    let now = tokio::time::Instant::now();
+    let (summarized, is_new) = {
+      let mut summaries = self.summaries.lock().unwrap();
+      let is_new = !summaries.contains_key(&key);
+      let summary = summaries.entry(key.clone()).or_default();
+      summary.expire(now, window);
+      let summarized = summary.published.len() >= threshold;
+      if summarized {
+        summary.suppressed += 1;
+      } else {
+        summary.published.push_back(now);
+      }
+      (summarized, is_new)
+    };
+
+    if is_new {
+      let cloned_self = self.clone();
+      tokio::spawn(async move {
+        cloned_self.report_summaries(key, window).await;
+      });
+    }
+    summarized
+  }
+
+  // Publishes the suppressed count of a key every window, and forgets the key once a window passes without
+  // any of its dead letters
+  async fn report_summaries(&self, key: DeadLetterSummaryKey, window: Duration) {
+    loop {
+      tokio::time::sleep(window).await;
+      let (suppressed, idle) = {
+        let mut summaries = self.summaries.lock().unwrap();
+        let Some(summary) = summaries.get_mut(&key) else {
+          return;
+        };
+        summary.expire(tokio::time::Instant::now(), window);
+        let suppressed = std::mem::take(&mut summary.suppressed);
+        let idle = summary.published.is_empty() && suppressed == 0;
+        if idle {
+          summaries.remove(&key);
+        }
+        (suppressed, idle)
+      };
+      if suppressed > 0 {
+        let (pid, message_type) = key.clone();
+        tracing::info!(
+          "DeadLetterProcess: {} dead letters of {} to {:?} were summarized",
+          suppressed,
+          message_type,
+          pid
+        );
+        self
+          .actor_system
+          .get_event_stream()
+          .await
+          .publish(MessageHandle::new(DeadLetterSummaryEvent {
+            pid,
+            message_type,
+            count: suppressed,
+            window,
+          }))
+          .await;
+      }
+      if idle {
+        return;
+      }
+    }
+  }
+
   async fn metrics_foreach<F, Fut>(&self, f: F)
   where
     F: Fn(&ActorMetrics, &Metrics) -> Fut,
@@ -153,6 +315,7 @@ impl DeadLetterProcess {
 impl Process for DeadLetterProcess {
   async fn send_user_message(&self, pid: Option<&ExtendedPid>, message_handle: MessageHandle) {
     tracing::debug!("DeadLetterProcess: send_user_message: msg = {:?}", message_handle);
+    self.window.lock().unwrap().record();
     self
       .metrics_foreach(|am, _| {
         let am = am.clone();
@@ -161,6 +324,18 @@ impl Process for DeadLetterProcess {
       .await;
 
     let (_, msg, sender) = unwrap_envelope(message_handle.clone());
+    if self.is_summarized(pid, &msg).await {
+      // the event is not published, but the sender is still answered for every message
+      if let Some(sender) = sender {
+        self
+          .actor_system
+          .get_root_context()
+          .await
+          .send(sender, MessageHandle::new(DeadLetterResponse { target: None }))
+          .await;
+      }
+      return;
+    }
     self
       .actor_system
       .get_event_stream()
@@ -175,6 +350,7 @@ impl Process for DeadLetterProcess {
   }
 
   async fn send_system_message(&self, pid: &ExtendedPid, message_handle: MessageHandle) {
+    self.window.lock().unwrap().record();
     self
       .actor_system
       .get_event_stream()
@@ -207,3 +383,13 @@ pub struct DeadLetterEvent {
   pub message_handle: MessageHandle,
   pub sender: Option<ExtendedPid>,
 }
+
+// DeadLetterSummaryEvent reports the dead letters of one target and message type that were not published as
+// DeadLetterEvents during a summary window, see ConfigOption::with_dead_letter_summary_window
+#[derive(Debug, Clone, PartialEq, Eq, Message)]
+pub struct DeadLetterSummaryEvent {
+  pub pid: Option<ExtendedPid>,
+  pub message_type: String,
+  pub count: usize,
+  pub window: Duration,
+}
@@ -2,17 +2,20 @@
 mod test {
   use crate::actor::actor::Props;
   use crate::actor::actor_system::ActorSystem;
-  use crate::actor::context::{SenderPart, SpawnerPart, StopperPart};
-  use crate::actor::dispatch::dead_letter_process::DeadLetterEvent;
+  use crate::actor::context::{MessagePart, SenderPart, SpawnerPart, StopperPart};
+  use crate::actor::dispatch::dead_letter_process::{DeadLetterEvent, DeadLetterSummaryEvent};
   use crate::actor::dispatch::future::ActorFutureProcess;
   use crate::actor::interaction_test::tests::BlackHoleActor;
+  use crate::actor::message::Expired;
   use crate::actor::message::MessageHandle;
   use crate::actor::message::SystemMessage;
-  use crate::generated::actor::Watch;
+  use crate::actor::ConfigOption;
+  use crate::generated::actor::{DeadLetterResponse, Watch};
+  use nexus_actor_message_derive_rs::Message;
   use std::env;
   use std::sync::Arc;
   use std::time::Duration;
-  use tokio::sync::Mutex;
+  use tokio::sync::{Mutex, Notify};
   use tracing_subscriber::EnvFilter;
 
   #[tokio::test]
@@ -71,7 +74,9 @@ mod test {
       .spawn(Props::from_async_actor_producer(|_| async { BlackHoleActor }).await)
       .await;
     let _ = root_context.stop_future(&pid).await.result().await.unwrap();
-    let f = ActorFutureProcess::new(system.clone(), Duration::from_secs(5)).await;
+    let f = ActorFutureProcess::new(system.clone(), Duration::from_secs(5))
+      .await
+      .unwrap();
 
     pid
       .send_system_message(
@@ -84,4 +89,270 @@ mod test {
 
     f.result().await.unwrap();
   }
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Slow;
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Urgent;
+
+  #[tokio::test]
+  async fn test_dead_letter_expired_message() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let processed_urgent = Arc::new(Mutex::new(false));
+    let cloned_processed_urgent = processed_urgent.clone();
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let processed_urgent = cloned_processed_urgent.clone();
+          async move {
+            let message_handle = ctx.get_message_handle().await;
+            if message_handle.to_typed::<Slow>().is_some() {
+              tokio::time::sleep(Duration::from_millis(200)).await;
+            } else if message_handle.to_typed::<Urgent>().is_some() {
+              *processed_urgent.lock().await = true;
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    let expired = Arc::new(Notify::new());
+    let cloned_expired = expired.clone();
+    let cloned_pid = pid.clone();
+    let sub = system
+      .get_event_stream()
+      .await
+      .subscribe(move |msg| {
+        let expired = cloned_expired.clone();
+        let pid = cloned_pid.clone();
+        async move {
+          if let Some(dead_letter) = msg.to_typed::<DeadLetterEvent>() {
+            let expired_message = dead_letter.message_handle.to_typed::<Expired>();
+            if dead_letter.pid == Some(pid)
+              && expired_message.is_some_and(|expired| expired.message_handle.to_typed::<Urgent>().is_some())
+            {
+              expired.notify_one();
+            }
+          }
+        }
+      })
+      .await;
+
+    root_context.send(pid.clone(), MessageHandle::new(Slow)).await;
+    root_context
+      .send_with_ttl(pid, MessageHandle::new(Urgent), Duration::from_millis(50))
+      .await;
+
+    tokio::time::timeout(Duration::from_secs(2), expired.notified())
+      .await
+      .unwrap();
+    system.get_event_stream().await.unsubscribe(sub).await;
+
+    assert!(!*processed_urgent.lock().await);
+  }
+
+  #[tokio::test]
+  async fn test_dead_letter_summary_bounds_published_events() {
+    let _ = env::set_var("RUST_LOG", "info");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new_config_options([
+      ConfigOption::with_dead_letter_summary_window(Duration::from_millis(500)),
+      ConfigOption::with_dead_letter_summary_threshold(5),
+    ])
+    .await
+    .unwrap();
+    let mut root_context = system.get_root_context().await;
+    let pid = root_context
+      .spawn(Props::from_async_actor_producer(|_| async { BlackHoleActor }).await)
+      .await;
+    let _ = root_context.stop_future(&pid).await.result().await.unwrap();
+
+    let published = Arc::new(Mutex::new(0));
+    let summaries = Arc::new(Mutex::new(vec![]));
+    let notify = Arc::new(Notify::new());
+    let cloned_published = published.clone();
+    let cloned_summaries = summaries.clone();
+    let cloned_notify = notify.clone();
+    let cloned_pid = pid.clone();
+    let sub = system
+      .get_event_stream()
+      .await
+      .subscribe(move |msg| {
+        let published = cloned_published.clone();
+        let summaries = cloned_summaries.clone();
+        let notify = cloned_notify.clone();
+        let pid = cloned_pid.clone();
+        async move {
+          if let Some(dead_letter) = msg.to_typed::<DeadLetterEvent>() {
+            if dead_letter.pid == Some(pid) {
+              *published.lock().await += 1;
+            }
+          } else if let Some(summary) = msg.to_typed::<DeadLetterSummaryEvent>() {
+            if summary.pid == Some(pid) {
+              summaries.lock().await.push(summary.count);
+              notify.notify_one();
+            }
+          }
+        }
+      })
+      .await;
+
+    for i in 0..10_000 {
+      root_context.send(pid.clone(), MessageHandle::new(i)).await;
+    }
+
+    // Every dead letter is either published or counted in a summary
+    tokio::time::timeout(Duration::from_secs(10), async {
+      while *published.lock().await + summaries.lock().await.iter().sum::<usize>() < 10_000 {
+        notify.notified().await;
+      }
+    })
+    .await
+    .expect("dead letters were not summarized");
+    system.get_event_stream().await.unsubscribe(sub).await;
+
+    // the last window may publish without suppressing anything, so it has no summary
+    let windows = summaries.lock().await.len();
+    assert!(windows >= 1);
+    assert!(*published.lock().await <= (windows + 1) * 5);
+    assert!(*published.lock().await + windows < 100);
+  }
+
+  #[tokio::test]
+  async fn test_dead_letter_summary_covers_requests_and_answers_each_sender() {
+    let _ = env::set_var("RUST_LOG", "info");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new_config_options([
+      ConfigOption::with_dead_letter_summary_window(Duration::from_secs(10)),
+      ConfigOption::with_dead_letter_summary_threshold(5),
+    ])
+    .await
+    .unwrap();
+    let mut root_context = system.get_root_context().await;
+    let pid = root_context
+      .spawn(Props::from_async_actor_producer(|_| async { BlackHoleActor }).await)
+      .await;
+    let _ = root_context.stop_future(&pid).await.result().await.unwrap();
+
+    let responses = Arc::new(Mutex::new(0));
+    let cloned_responses = responses.clone();
+    let sender = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let responses = cloned_responses.clone();
+          async move {
+            if ctx.get_message_handle().await.is_typed::<DeadLetterResponse>() {
+              *responses.lock().await += 1;
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    let published = Arc::new(Mutex::new(0));
+    let cloned_published = published.clone();
+    let cloned_pid = pid.clone();
+    let sub = system
+      .get_event_stream()
+      .await
+      .subscribe(move |msg| {
+        let published = cloned_published.clone();
+        let pid = cloned_pid.clone();
+        async move {
+          if msg
+            .to_typed::<DeadLetterEvent>()
+            .is_some_and(|dead_letter| dead_letter.pid == Some(pid))
+          {
+            *published.lock().await += 1;
+          }
+        }
+      })
+      .await;
+
+    for i in 0..20 {
+      root_context
+        .request_with_custom_sender(pid.clone(), MessageHandle::new(i), sender.clone())
+        .await;
+    }
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+      while *responses.lock().await < 20 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+      }
+    })
+    .await
+    .expect("not every sender was answered");
+    assert_eq!(*published.lock().await, 5);
+
+    system.get_event_stream().await.unsubscribe(sub).await;
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_dead_letter_summary_window_slides() {
+    let system = ActorSystem::new_config_options([
+      ConfigOption::with_dead_letter_summary_window(Duration::from_secs(1)),
+      ConfigOption::with_dead_letter_summary_threshold(5),
+    ])
+    .await
+    .unwrap();
+    let mut root_context = system.get_root_context().await;
+    let pid = root_context
+      .spawn(Props::from_async_actor_producer(|_| async { BlackHoleActor }).await)
+      .await;
+    let _ = root_context.stop_future(&pid).await.result().await.unwrap();
+
+    let published = Arc::new(Mutex::new(0));
+    let cloned_published = published.clone();
+    let cloned_pid = pid.clone();
+    let sub = system
+      .get_event_stream()
+      .await
+      .subscribe(move |msg| {
+        let published = cloned_published.clone();
+        let pid = cloned_pid.clone();
+        async move {
+          if msg
+            .to_typed::<DeadLetterEvent>()
+            .is_some_and(|dead_letter| dead_letter.pid == Some(pid))
+          {
+            *published.lock().await += 1;
+          }
+        }
+      })
+      .await;
+
+    root_context.send(pid.clone(), MessageHandle::new(0)).await;
+    tokio::time::advance(Duration::from_millis(900)).await;
+    for i in 0..10 {
+      root_context.send(pid.clone(), MessageHandle::new(i)).await;
+    }
+    assert_eq!(*published.lock().await, 5);
+
+    // Only the first dead letter has left the window, so a burst right after the boundary is still bounded
+    tokio::time::advance(Duration::from_millis(200)).await;
+    for i in 0..10 {
+      root_context.send(pid.clone(), MessageHandle::new(i)).await;
+    }
+    assert_eq!(*published.lock().await, 6);
+
+    system.get_event_stream().await.unsubscribe(sub).await;
+  }
 }
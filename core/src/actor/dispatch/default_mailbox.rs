@@ -7,7 +7,7 @@ use crate::actor::dispatch::mailbox_handle::MailboxHandle;
 use crate::actor::dispatch::mailbox_message::MailboxMessage;
 use crate::actor::dispatch::mailbox_middleware::{MailboxMiddleware, MailboxMiddlewareHandle};
 use crate::actor::dispatch::message_invoker::{MessageInvoker, MessageInvokerHandle};
-use crate::actor::message::MessageHandle;
+use crate::actor::message::{expired_message, MessageHandle};
 use async_trait::async_trait;
 use nexus_actor_utils_rs::collections::{QueueError, QueueReader, QueueWriter};
 use tokio::sync::{Mutex, RwLock};
@@ -22,8 +22,11 @@ struct DefaultMailboxInner {
   user_messages_count: Arc<AtomicI32>,
   system_messages_count: Arc<AtomicI32>,
   suspended: Arc<AtomicBool>,
+  externally_suspended: Arc<AtomicBool>,
+  throughput: Arc<AtomicI32>,
   invoker_opt: Arc<RwLock<Option<MessageInvokerHandle>>>,
   dispatcher_opt: Arc<RwLock<Option<DispatcherHandle>>>,
+  dispatcher_rebound: Arc<AtomicBool>,
   middlewares: Vec<MailboxMiddlewareHandle>,
 }
 
@@ -48,8 +51,11 @@ impl DefaultMailbox {
         user_messages_count: Arc::new(AtomicI32::new(0)),
         system_messages_count: Arc::new(AtomicI32::new(0)),
         suspended: Arc::new(AtomicBool::new(false)),
+        externally_suspended: Arc::new(AtomicBool::new(false)),
+        throughput: Arc::new(AtomicI32::new(0)),
         invoker_opt: Arc::new(RwLock::new(None)),
         dispatcher_opt: Arc::new(RwLock::new(None)),
+        dispatcher_rebound: Arc::new(AtomicBool::new(false)),
         middlewares: vec![],
       })),
     }
@@ -87,6 +93,11 @@ impl DefaultMailbox {
     *dispatcher_opt_mg = dispatcher_opt;
   }
 
+  async fn take_dispatcher_rebound(&self) -> bool {
+    let inner_mg = self.inner.lock().await;
+    inner_mg.dispatcher_rebound.swap(false, Ordering::SeqCst)
+  }
+
   async fn initialize_scheduler_status(&self) {
     let inner_mg = self.inner.lock().await;
     inner_mg.scheduler_status.store(false, Ordering::SeqCst);
@@ -104,9 +115,30 @@ impl DefaultMailbox {
     inner_mg.suspended.store(suspended, Ordering::SeqCst);
   }
 
+  async fn set_externally_suspended(&self, suspended: bool) {
+    let inner_mg = self.inner.lock().await;
+    inner_mg.externally_suspended.store(suspended, Ordering::SeqCst);
+  }
+
   async fn is_suspended(&self) -> bool {
     let inner_mg = self.inner.lock().await;
-    inner_mg.suspended.load(Ordering::SeqCst)
+    inner_mg.suspended.load(Ordering::SeqCst) || inner_mg.externally_suspended.load(Ordering::SeqCst)
+  }
+
+  async fn is_scheduled(&self) -> bool {
+    let inner_mg = self.inner.lock().await;
+    inner_mg.scheduler_status.load(Ordering::SeqCst)
+  }
+
+  async fn get_throughput(&self, dispatcher: &DispatcherHandle) -> i32 {
+    let throughput = {
+      let inner_mg = self.inner.lock().await;
+      inner_mg.throughput.load(Ordering::SeqCst)
+    };
+    match throughput {
+      0 => dispatcher.throughput().await,
+      throughput => throughput,
+    }
   }
 
   async fn increment_system_messages_count(&self) {
@@ -162,22 +194,28 @@ impl DefaultMailbox {
     if self.compare_exchange_scheduler_status(false, true).await.is_ok() {
       let dispatcher = self.get_dispatcher_opt().await.expect("Dispatcher is not set");
       let self_clone = self.to_handle().await;
-      dispatcher
-        .schedule(Runnable::new(move || {
+      let result = dispatcher
+        .schedule_with_backoff(Runnable::new(move || {
           let self_clone = self_clone.clone();
           async move {
             self_clone.process_messages().await;
           }
         }))
         .await;
+      if let Err(err) = result {
+        // The messages stay queued, the next post schedules the mailbox again
+        tracing::error!("Failed to schedule mailbox: {}", err);
+        self.initialize_scheduler_status().await;
+      }
     }
   }
 
-  async fn run(&self) {
+  // Returns true when processing stopped because the mailbox was moved to another dispatcher
+  async fn run(&self) -> bool {
     let mut i = 0;
 
     if self.get_dispatcher_opt().await.is_none() || self.get_message_invoker_opt().await.is_none() {
-      return;
+      return false;
     }
 
     let dispatcher = self.get_dispatcher_opt().await.clone().expect("Dispatcher is not set");
@@ -187,16 +225,21 @@ impl DefaultMailbox {
       .clone()
       .expect("Message invoker is not set");
 
-    let t = dispatcher.throughput().await;
+    let mut t = self.get_throughput(&dispatcher).await;
 
     loop {
-      if i > t {
+      if i >= t {
         i = 0;
         tokio::task::yield_now().await;
+        t = self.get_throughput(&dispatcher).await;
       }
 
       i += 1;
 
+      if self.take_dispatcher_rebound().await {
+        return true;
+      }
+
       if let Ok(Some(msg)) = self.poll_system_mailbox().await {
         self.decrement_system_messages_count().await;
         let mailbox_message = msg.to_typed::<MailboxMessage>();
@@ -206,11 +249,18 @@ impl DefaultMailbox {
           }
           Some(MailboxMessage::ResumeMailbox) => {
             self.set_suspended(false).await;
+            message_invoker.mailbox_resumed().await;
+          }
+          Some(MailboxMessage::SuspendMailboxExternally) => {
+            self.set_externally_suspended(true).await;
+          }
+          Some(MailboxMessage::ResumeMailboxExternally) => {
+            self.set_externally_suspended(false).await;
           }
           _ => {
             if let Err(err) = message_invoker.invoke_system_message(msg.clone()).await {
               message_invoker
-                .escalate_failure(err.reason().cloned().unwrap(), msg.clone())
+                .escalate_failure(err.to_escalated_reason(&msg).unwrap(), msg.clone())
                 .await;
             }
           }
@@ -222,22 +272,26 @@ impl DefaultMailbox {
       }
 
       if self.is_suspended().await {
-        break;
+        return false;
       }
 
       if let Ok(Some(message)) = self.poll_user_mailbox().await {
         self.decrement_user_messages_count().await;
+        if let Some(expired) = expired_message(&message) {
+          message_invoker.expire_user_message(expired).await;
+          continue;
+        }
         let result = message_invoker.invoke_user_message(message.clone()).await;
         if let Err(e) = result {
           message_invoker
-            .escalate_failure(e.reason().cloned().unwrap(), message.clone())
+            .escalate_failure(e.to_escalated_reason(&message).unwrap(), message.clone())
             .await;
         }
         for mut middleware in self.get_middlewares().await {
           middleware.message_received(message.clone()).await;
         }
       } else {
-        break;
+        return false;
       }
     }
   }
@@ -250,6 +304,11 @@ impl Mailbox for DefaultMailbox {
     inner_mg.user_messages_count.load(Ordering::SeqCst)
   }
 
+  fn try_get_user_messages_count(&self) -> Option<i32> {
+    let inner_mg = self.inner.try_lock().ok()?;
+    Some(inner_mg.user_messages_count.load(Ordering::SeqCst))
+  }
+
   async fn get_system_messages_count(&self) -> i32 {
     let inner_mg = self.inner.lock().await;
     inner_mg.system_messages_count.load(Ordering::SeqCst)
@@ -257,9 +316,15 @@ impl Mailbox for DefaultMailbox {
 
   async fn process_messages(&self) {
     loop {
-      self.run().await;
+      let rebound = self.run().await;
 
       self.initialize_scheduler_status().await;
+      if rebound {
+        // The remaining messages are processed by the new dispatcher
+        self.schedule().await;
+        return;
+      }
+
       let system_messages_count = self.get_system_messages_count().await;
       let user_messages_count = self.get_user_messages_count().await;
 
@@ -333,4 +398,28 @@ impl Mailbox for DefaultMailbox {
   async fn to_handle(&self) -> MailboxHandle {
     MailboxHandle::new(self.clone())
   }
+
+  async fn set_throughput(&self, throughput: i32) {
+    let inner_mg = self.inner.lock().await;
+    inner_mg.throughput.store(throughput, Ordering::SeqCst);
+  }
+
+  async fn set_dispatcher(&self, dispatcher: DispatcherHandle) {
+    let inner_mg = self.inner.lock().await;
+    let mut dispatcher_opt_mg = inner_mg.dispatcher_opt.write().await;
+    *dispatcher_opt_mg = Some(dispatcher);
+    inner_mg.dispatcher_rebound.store(true, Ordering::SeqCst);
+  }
+
+  async fn is_user_mailbox_full(&self) -> bool {
+    let inner_mg = self.inner.lock().await;
+    let user_mailbox_sender_mg = inner_mg.user_mailbox_sender.lock().await;
+    user_mailbox_sender_mg.is_full().await
+  }
+
+  async fn is_idle(&self) -> bool {
+    !self.is_scheduled().await
+      && self.get_user_messages_count().await <= 0
+      && self.get_system_messages_count().await <= 0
+  }
 }
@@ -1,10 +1,16 @@
 use std::fmt::Debug;
 use std::future::Future;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use futures::future::BoxFuture;
+use thiserror::Error;
 use tokio::runtime::{Builder, Runtime};
+use tokio::sync::{mpsc, Semaphore};
+
+const SCHEDULE_INITIAL_BACKOFF: Duration = Duration::from_millis(1);
+const SCHEDULE_MAX_BACKOFF: Duration = Duration::from_millis(100);
 
 pub struct Runnable(Box<dyn FnOnce() -> BoxFuture<'static, ()> + Send + 'static>);
 
@@ -21,11 +27,58 @@ impl Runnable {
   }
 }
 
+impl Debug for Runnable {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Runnable")
+  }
+}
+
+// DispatchError is returned when a dispatcher does not accept a runnable. The runnable is handed back,
+// so the caller can retry it instead of losing the work.
+#[derive(Debug, Error)]
+pub enum DispatchError {
+  #[error("dispatcher is full")]
+  Full(Runnable),
+  #[error("dispatcher is closed")]
+  Closed(Runnable),
+}
+
+impl DispatchError {
+  pub fn into_runnable(self) -> Runnable {
+    match self {
+      DispatchError::Full(runner) | DispatchError::Closed(runner) => runner,
+    }
+  }
+}
+
 // Dispatcher trait
 #[async_trait]
 pub trait Dispatcher: Debug + Send + Sync + 'static {
-  async fn schedule(&self, runner: Runnable);
+  async fn schedule(&self, runner: Runnable) -> Result<(), DispatchError>;
   async fn throughput(&self) -> i32;
+
+  // ScheduleWithBackoff retries a runnable rejected by a full dispatcher, waiting twice as long after each
+  // rejection. It only gives up, returning the runnable, once the dispatcher is closed.
+  async fn schedule_with_backoff(&self, runner: Runnable) -> Result<(), DispatchError> {
+    let mut runner = runner;
+    let mut backoff = SCHEDULE_INITIAL_BACKOFF;
+    loop {
+      match self.schedule(runner).await {
+        Err(DispatchError::Full(rejected)) => {
+          tracing::debug!("Dispatcher is full, retrying in {:?}", backoff);
+          tokio::time::sleep(backoff).await;
+          backoff = (backoff * 2).min(SCHEDULE_MAX_BACKOFF);
+          runner = rejected;
+        }
+        result => return result,
+      }
+    }
+  }
+
+  // IsRunning reports whether the dispatcher can currently execute scheduled work
+  fn is_running(&self) -> bool {
+    true
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -43,13 +96,17 @@ impl DispatcherHandle {
 
 #[async_trait]
 impl Dispatcher for DispatcherHandle {
-  async fn schedule(&self, runner: Runnable) {
-    self.0.schedule(runner).await;
+  async fn schedule(&self, runner: Runnable) -> Result<(), DispatchError> {
+    self.0.schedule(runner).await
   }
 
   async fn throughput(&self) -> i32 {
     self.0.throughput().await
   }
+
+  fn is_running(&self) -> bool {
+    self.0.is_running()
+  }
 }
 
 // --- TokioRuntimeContextDispatcher implementation
@@ -72,13 +129,18 @@ impl TokioRuntimeContextDispatcher {
 
 #[async_trait]
 impl Dispatcher for TokioRuntimeContextDispatcher {
-  async fn schedule(&self, runner: Runnable) {
+  async fn schedule(&self, runner: Runnable) -> Result<(), DispatchError> {
     tokio::spawn(runner.run());
+    Ok(())
   }
 
   async fn throughput(&self) -> i32 {
     self.throughput
   }
+
+  fn is_running(&self) -> bool {
+    tokio::runtime::Handle::try_current().is_ok()
+  }
 }
 
 // --- TokioRuntimeDispatcher implementation
@@ -113,8 +175,9 @@ impl TokioRuntimeDispatcher {
 
 #[async_trait]
 impl Dispatcher for TokioRuntimeDispatcher {
-  async fn schedule(&self, runner: Runnable) {
+  async fn schedule(&self, runner: Runnable) -> Result<(), DispatchError> {
     self.runtime.spawn(runner.run());
+    Ok(())
   }
 
   async fn throughput(&self) -> i32 {
@@ -147,8 +210,9 @@ impl SingleWorkerDispatcher {
 
 #[async_trait]
 impl Dispatcher for SingleWorkerDispatcher {
-  async fn schedule(&self, runner: Runnable) {
+  async fn schedule(&self, runner: Runnable) -> Result<(), DispatchError> {
     self.runtime.spawn(runner.run());
+    Ok(())
   }
 
   async fn throughput(&self) -> i32 {
@@ -176,11 +240,114 @@ impl CurrentThreadDispatcher {
 
 #[async_trait]
 impl Dispatcher for CurrentThreadDispatcher {
-  async fn schedule(&self, runner: Runnable) {
-    runner.run().await
+  async fn schedule(&self, runner: Runnable) -> Result<(), DispatchError> {
+    runner.run().await;
+    Ok(())
   }
 
   async fn throughput(&self) -> i32 {
     self.throughput
   }
 }
+
+// --- DedicatedThreadDispatcher implementation
+
+// DedicatedThreadDispatcher owns one OS thread and runs everything scheduled on it there, driven by a
+// current-thread tokio runtime. Work that needs thread affinity, such as some FFI, can rely on the thread staying
+// the same. Futures spawned with tokio::spawn from that thread also run on it, and a receive that blocks the
+// thread stalls everything else scheduled on it. The thread exits once the dispatcher and its clones are dropped.
+#[derive(Debug, Clone)]
+pub struct DedicatedThreadDispatcher {
+  sender: mpsc::UnboundedSender<Runnable>,
+  thread_id: std::thread::ThreadId,
+  throughput: i32,
+}
+
+impl DedicatedThreadDispatcher {
+  pub fn new(thread_name: &str) -> Result<Self, std::io::Error> {
+    let runtime = Builder::new_current_thread().enable_all().build()?;
+    let (sender, mut receiver) = mpsc::unbounded_channel::<Runnable>();
+    let join_handle = std::thread::Builder::new()
+      .name(thread_name.to_string())
+      .spawn(move || {
+        runtime.block_on(async move {
+          while let Some(runner) = receiver.recv().await {
+            tokio::spawn(runner.run());
+          }
+        })
+      })?;
+    Ok(Self {
+      sender,
+      thread_id: join_handle.thread().id(),
+      throughput: 300,
+    })
+  }
+
+  pub fn with_throughput(mut self, throughput: i32) -> Self {
+    self.throughput = throughput;
+    self
+  }
+
+  pub fn get_thread_id(&self) -> std::thread::ThreadId {
+    self.thread_id
+  }
+}
+
+#[async_trait]
+impl Dispatcher for DedicatedThreadDispatcher {
+  async fn schedule(&self, runner: Runnable) -> Result<(), DispatchError> {
+    self.sender.send(runner).map_err(|err| {
+      tracing::error!(
+        "DedicatedThreadDispatcher thread has exited: thread_id = {:?}",
+        self.thread_id
+      );
+      DispatchError::Closed(err.0)
+    })
+  }
+
+  async fn throughput(&self) -> i32 {
+    self.throughput
+  }
+
+  fn is_running(&self) -> bool {
+    !self.sender.is_closed()
+  }
+}
+
+// --- ConcurrencyLimitedDispatcher implementation
+
+// ConcurrencyLimitedDispatcher holds a permit of a shared semaphore while each runnable runs.
+// Used for mailboxes, it bounds how many mailboxes process messages at the same time.
+#[derive(Debug, Clone)]
+pub struct ConcurrencyLimitedDispatcher {
+  underlying: Arc<dyn Dispatcher>,
+  semaphore: Arc<Semaphore>,
+}
+
+impl ConcurrencyLimitedDispatcher {
+  pub fn new(underlying: Arc<dyn Dispatcher>, semaphore: Arc<Semaphore>) -> Self {
+    Self { underlying, semaphore }
+  }
+}
+
+#[async_trait]
+impl Dispatcher for ConcurrencyLimitedDispatcher {
+  async fn schedule(&self, runner: Runnable) -> Result<(), DispatchError> {
+    let semaphore = self.semaphore.clone();
+    self
+      .underlying
+      .schedule(Runnable::new(move || async move {
+        let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+        runner.run().await
+      }))
+      .await
+  }
+
+  async fn throughput(&self) -> i32 {
+    self.underlying.throughput().await
+  }
+
+  fn is_running(&self) -> bool {
+    self.underlying.is_running()
+  }
+}
@@ -1,20 +1,31 @@
 #[cfg(test)]
 mod test {
+  use std::collections::HashSet;
+  use std::future::Future;
+  use std::pin::Pin;
+  use std::sync::atomic::{AtomicUsize, Ordering};
   use std::sync::Arc;
+  use std::task::{Context, Poll};
+  use std::time::Duration;
 
   use crate::actor::actor::ActorError;
   use crate::actor::actor::ErrorReason;
+  use crate::actor::actor::Props;
   use crate::actor::actor::Task;
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{MessagePart, SenderPart, SpawnerPart};
   use crate::actor::dispatch::default_mailbox::DefaultMailbox;
-  use crate::actor::dispatch::dispatcher::{CurrentThreadDispatcher, DispatcherHandle};
+  use crate::actor::dispatch::dispatcher::{
+    CurrentThreadDispatcher, DispatchError, Dispatcher, DispatcherHandle, Runnable,
+  };
   use crate::actor::dispatch::mailbox::Mailbox;
   use crate::actor::dispatch::message_invoker::{MessageInvoker, MessageInvokerHandle};
-  use crate::actor::message::Message;
   use crate::actor::message::MessageHandle;
+  use crate::actor::ConfigOption;
   use async_trait::async_trait;
   use nexus_actor_message_derive_rs::Message;
   use nexus_actor_utils_rs::collections::MpscUnboundedChannelQueue;
-  use tokio::sync::{Mutex, RwLock};
+  use tokio::sync::{Mutex, RwLock, Semaphore};
 
   // TestMessageInvoker implementation
   #[derive(Debug, Clone, PartialEq)]
@@ -114,4 +125,287 @@ mod test {
     assert_eq!(received[1], ReceivedMessage::User);
     assert_eq!(received[2], ReceivedMessage::Task);
   }
+
+  // Counts how often the mailbox task is polled, every yield makes the next message land in a new poll
+  struct PollCounting {
+    inner: Pin<Box<dyn Future<Output = ()> + Send>>,
+    polls: Arc<AtomicUsize>,
+  }
+
+  impl Future for PollCounting {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+      self.polls.fetch_add(1, Ordering::SeqCst);
+      self.inner.as_mut().poll(cx)
+    }
+  }
+
+  #[derive(Debug)]
+  struct PollCountingDispatcher {
+    polls: Arc<AtomicUsize>,
+    throughput: i32,
+  }
+
+  #[async_trait]
+  impl Dispatcher for PollCountingDispatcher {
+    async fn schedule(&self, runner: Runnable) -> Result<(), DispatchError> {
+      tokio::spawn(PollCounting {
+        inner: Box::pin(runner.run()),
+        polls: self.polls.clone(),
+      });
+      Ok(())
+    }
+
+    async fn throughput(&self) -> i32 {
+      self.throughput
+    }
+  }
+
+  #[derive(Debug)]
+  struct PollRecordingInvoker {
+    polls: Arc<AtomicUsize>,
+    observed: Arc<Mutex<Vec<usize>>>,
+  }
+
+  #[async_trait]
+  impl MessageInvoker for PollRecordingInvoker {
+    async fn invoke_system_message(&mut self, _: MessageHandle) -> Result<(), ActorError> {
+      Ok(())
+    }
+
+    async fn invoke_user_message(&mut self, _: MessageHandle) -> Result<(), ActorError> {
+      self.observed.lock().await.push(self.polls.load(Ordering::SeqCst));
+      Ok(())
+    }
+
+    async fn escalate_failure(&mut self, _: ErrorReason, _: MessageHandle) {}
+  }
+
+  #[tokio::test]
+  async fn test_mailbox_yields_after_dispatcher_throughput() {
+    const THROUGHPUT: usize = 3;
+    const MESSAGES: usize = 12;
+
+    let polls = Arc::new(AtomicUsize::new(0));
+    let observed = Arc::new(Mutex::new(Vec::new()));
+    let mut mailbox = DefaultMailbox::new(MpscUnboundedChannelQueue::new(), MpscUnboundedChannelQueue::new());
+    let invoker = Arc::new(RwLock::new(PollRecordingInvoker {
+      polls: polls.clone(),
+      observed: observed.clone(),
+    }));
+    let dispatcher = PollCountingDispatcher {
+      polls,
+      throughput: THROUGHPUT as i32,
+    };
+    mailbox
+      .register_handlers(
+        Some(MessageInvokerHandle::new(invoker)),
+        Some(DispatcherHandle::new(dispatcher)),
+      )
+      .await;
+
+    for _ in 0..MESSAGES {
+      mailbox.post_user_message(MessageHandle::new(TestUserMessage)).await;
+    }
+    while observed.lock().await.len() < MESSAGES {
+      tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    let observed = observed.lock().await.clone();
+    let largest_slice = observed
+      .iter()
+      .map(|poll| observed.iter().filter(|other| *other == poll).count())
+      .max()
+      .unwrap();
+    assert!(largest_slice <= THROUGHPUT, "observed = {:?}", observed);
+  }
+
+  #[tokio::test]
+  async fn test_max_concurrent_mailboxes() {
+    let system = ActorSystem::new_config_options([ConfigOption::with_max_concurrent_mailboxes(2)])
+      .await
+      .unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let processing = Arc::new(AtomicUsize::new(0));
+    let max_processing = Arc::new(AtomicUsize::new(0));
+    let processed = Arc::new(AtomicUsize::new(0));
+
+    let mut pids = Vec::new();
+    for _ in 0..6 {
+      let processing = processing.clone();
+      let max_processing = max_processing.clone();
+      let processed = processed.clone();
+      let props = Props::from_async_actor_receiver(move |ctx| {
+        let processing = processing.clone();
+        let max_processing = max_processing.clone();
+        let processed = processed.clone();
+        async move {
+          if ctx.get_message_handle().await.to_typed::<String>().is_some() {
+            let current = processing.fetch_add(1, Ordering::SeqCst) + 1;
+            max_processing.fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            processing.fetch_sub(1, Ordering::SeqCst);
+            processed.fetch_add(1, Ordering::SeqCst);
+          }
+          Ok(())
+        }
+      })
+      .await;
+      pids.push(root_context.spawn(props).await);
+    }
+
+    for pid in pids {
+      root_context.send(pid, MessageHandle::new("work".to_string())).await;
+    }
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+      while processed.load(Ordering::SeqCst) < 6 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+      }
+    })
+    .await
+    .expect("not all actors processed their message");
+    assert!(max_processing.load(Ordering::SeqCst) <= 2);
+  }
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+  async fn test_dedicated_thread_runs_every_receive_on_the_same_thread() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let threads = Arc::new(std::sync::Mutex::new(vec![]));
+    let cloned_threads = threads.clone();
+    let props = Props::from_async_actor_receiver_with_opts(
+      move |ctx| {
+        let threads = cloned_threads.clone();
+        async move {
+          if ctx.get_message_handle().await.to_typed::<String>().is_some() {
+            let record = || {
+              let current = std::thread::current();
+              threads
+                .lock()
+                .unwrap()
+                .push((current.id(), current.name().map(str::to_string)));
+            };
+            record();
+            // Awaiting inside receive must not move the actor to another thread either
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            record();
+          }
+          Ok(())
+        }
+      },
+      [Props::with_dedicated_thread()],
+    )
+    .await;
+    let pid = root_context.spawn_named(props, "pinned").await.unwrap();
+
+    for n in 0..20 {
+      root_context.send(pid.clone(), MessageHandle::new(n.to_string())).await;
+      tokio::task::yield_now().await;
+    }
+    tokio::time::timeout(Duration::from_secs(5), async {
+      while threads.lock().unwrap().len() < 40 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+      }
+    })
+    .await
+    .expect("not all messages were processed");
+
+    let threads = threads.lock().unwrap().clone();
+    let ids = threads.iter().map(|(id, _)| *id).collect::<HashSet<_>>();
+    assert_eq!(ids.len(), 1);
+    assert!(!ids.contains(&std::thread::current().id()));
+    assert_eq!(threads[0].1.as_deref(), Some("pinned"));
+  }
+
+  // SaturatedDispatcher runs at most `capacity` runnables at a time and rejects the rest
+  #[derive(Debug)]
+  struct SaturatedDispatcher {
+    capacity: Arc<Semaphore>,
+    rejected: AtomicUsize,
+  }
+
+  #[async_trait]
+  impl Dispatcher for SaturatedDispatcher {
+    async fn schedule(&self, runner: Runnable) -> Result<(), DispatchError> {
+      match self.capacity.clone().try_acquire_owned() {
+        Ok(permit) => {
+          tokio::spawn(async move {
+            runner.run().await;
+            drop(permit);
+          });
+          Ok(())
+        }
+        Err(_) => {
+          self.rejected.fetch_add(1, Ordering::SeqCst);
+          Err(DispatchError::Full(runner))
+        }
+      }
+    }
+
+    async fn throughput(&self) -> i32 {
+      300
+    }
+  }
+
+  #[tokio::test]
+  async fn test_saturated_dispatcher_does_not_lose_messages() {
+    const ACTORS: usize = 8;
+    const MESSAGES: usize = 25;
+
+    let dispatcher = Arc::new(SaturatedDispatcher {
+      capacity: Arc::new(Semaphore::new(2)),
+      rejected: AtomicUsize::new(0),
+    });
+    let system = ActorSystem::new_config_options([ConfigOption::SetSystemDispatcher(dispatcher.clone())])
+      .await
+      .unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let processed = Arc::new(AtomicUsize::new(0));
+    let mut pids = Vec::new();
+    for _ in 0..ACTORS {
+      let processed = processed.clone();
+      let props = Props::from_async_actor_receiver(move |ctx| {
+        let processed = processed.clone();
+        async move {
+          if ctx.get_message_handle().await.to_typed::<String>().is_some() {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            processed.fetch_add(1, Ordering::SeqCst);
+          }
+          Ok(())
+        }
+      })
+      .await;
+      pids.push(root_context.spawn(props).await);
+    }
+
+    let senders = pids
+      .into_iter()
+      .map(|pid| {
+        let mut root_context = root_context.clone();
+        tokio::spawn(async move {
+          for n in 0..MESSAGES {
+            root_context.send(pid.clone(), MessageHandle::new(n.to_string())).await;
+          }
+        })
+      })
+      .collect::<Vec<_>>();
+    for sender in senders {
+      sender.await.unwrap();
+    }
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+      while processed.load(Ordering::SeqCst) < ACTORS * MESSAGES {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+      }
+    })
+    .await
+    .expect("messages were lost while the dispatcher was saturated");
+    assert_eq!(processed.load(Ordering::SeqCst), ACTORS * MESSAGES);
+    assert!(dispatcher.rejected.load(Ordering::SeqCst) > 0);
+  }
 }
@@ -6,6 +6,7 @@ use std::time::Duration;
 use crate::actor::actor::ExtendedPid;
 use crate::actor::actor_system::ActorSystem;
 use crate::actor::dispatch::Runnable;
+use crate::actor::message::unwrap_envelope_message;
 use crate::actor::message::Message;
 use crate::actor::message::MessageHandle;
 use crate::actor::metrics::metrics_impl::{Metrics, EXTENSION_ID};
@@ -17,7 +18,7 @@ use futures::future::BoxFuture;
 use nexus_actor_message_derive_rs::Message;
 use opentelemetry::KeyValue;
 use thiserror::Error;
-use tokio::sync::{Notify, RwLock};
+use tokio::sync::{Mutex, Notify, RwLock};
 
 #[derive(Debug, Clone, PartialEq, Eq, Message, Error)]
 pub enum ActorFutureError {
@@ -25,6 +26,52 @@ pub enum ActorFutureError {
   TimeoutError,
   #[error("future: dead letter")]
   DeadLetterError,
+  #[error("future: too many pending futures")]
+  TooManyPendingFuturesError,
+  #[error("future: a timeout is required while pending futures are limited")]
+  TimeoutRequiredError,
+  #[error("future: cancelled")]
+  Cancelled,
+  #[error("future: completed without a result")]
+  NoResult,
+}
+
+// PipeResult is what pipe_typed_to delivers, with the response already downcast to T.
+// Every outcome of the future is delivered, a response of another type as TypeMismatch
+#[derive(Debug, Clone, PartialEq)]
+pub enum PipeResult<T> {
+  Ok(T),
+  Timeout,
+  DeadLetter,
+  Cancelled,
+  TooManyPendingFutures,
+  TimeoutRequired,
+  NoResult,
+  TypeMismatch { expected: String, actual: String },
+}
+
+impl<T: Message + Clone + PartialEq> Message for PipeResult<T> {
+  fn eq_message(&self, other: &dyn Message) -> bool {
+    other.as_any().downcast_ref::<Self>() == Some(self)
+  }
+
+  fn as_any(&self) -> &(dyn Any + Send + Sync + 'static) {
+    self
+  }
+
+  fn get_type_name(&self) -> String {
+    std::any::type_name_of_val(self).to_string()
+  }
+}
+
+// FutureReplies is what ActorFuture::all completes with, one reply per input future in input order
+#[derive(Debug, Clone, PartialEq, Eq, Message)]
+pub struct FutureReplies(Vec<MessageHandle>);
+
+impl FutureReplies {
+  pub fn get_replies(&self) -> &Vec<MessageHandle> {
+    &self.0
+  }
 }
 
 #[derive(Clone)]
@@ -56,31 +103,57 @@ impl Debug for Completion {
   }
 }
 
+async fn metrics_foreach<F, Fut>(system: &ActorSystem, f: F)
+where
+  F: Fn(&ActorMetrics, &Metrics) -> Fut,
+  Fut: std::future::Future<Output = ()>, {
+  if system.get_config().await.is_metrics_enabled() {
+    if let Some(extension_arc) = system.get_extensions().await.get(*EXTENSION_ID).await {
+      let mut extension = extension_arc.lock().await;
+      if let Some(m) = extension.as_any_mut().downcast_mut::<Metrics>() {
+        m.foreach(f).await;
+      }
+    }
+  }
+}
+
+async fn publish_future_pressure(system: &ActorSystem) {
+  let high_water_mark = system.get_config().await.future_high_water_mark;
+  if let Some(event) = system
+    .get_process_registry()
+    .await
+    .update_future_pressure(high_water_mark)
+  {
+    tracing::info!("future pressure changed: {:?}", event);
+    system.get_event_stream().await.publish(MessageHandle::new(event)).await;
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct ActorFutureProcess {
   future: Arc<RwLock<ActorFuture>>,
 }
 
 impl ActorFutureProcess {
-  pub async fn new(system: ActorSystem, duration: Duration) -> Arc<Self> {
-    let inner = Arc::new(RwLock::new(ActorFutureInner {
-      actor_system: system.clone(),
-      pid: None,
-      done: false,
-      result: None,
-      error: None,
-      pipes: Vec::new(),
-      completions: Vec::new(),
-    }));
-    let notify = Arc::new(Notify::new());
-
-    let future = ActorFuture { inner, notify };
+  // New registers a future process that times out after duration, or never when duration is zero.
+  // While max_pending_futures is set, a zero duration is rejected: its slot would only be released on completion
+  pub async fn new(system: ActorSystem, duration: Duration) -> Result<Arc<Self>, ActorFutureError> {
+    let process_registry = system.get_process_registry().await;
+    let max_pending_futures = system.get_config().await.max_pending_futures;
+    if max_pending_futures > 0 && duration.is_zero() {
+      return Err(ActorFutureError::TimeoutRequiredError);
+    }
+    if !process_registry.try_acquire_future_slot(max_pending_futures) {
+      tracing::warn!("too many pending futures: max = {}", max_pending_futures);
+      return Err(ActorFutureError::TooManyPendingFuturesError);
+    }
+    publish_future_pressure(&system).await;
 
+    let future = ActorFuture::new(system.clone());
     let future_process = Arc::new(ActorFutureProcess {
       future: Arc::new(RwLock::new(future.clone())),
     });
 
-    let process_registry = system.get_process_registry().await;
     let id = process_registry.next_id();
 
     let (pid, ok) = process_registry
@@ -96,20 +169,42 @@ impl ActorFutureProcess {
     future_process
       .metrics_foreach(|am, _| {
         let am = am.clone();
-        async move { am.increment_futures_started_count().await }
+        async move {
+          am.increment_futures_started_count().await;
+          am.increment_futures_pending_count().await;
+        }
       })
       .await;
 
-    future_process.set_pid(pid).await;
+    future_process.set_pid(pid.clone()).await;
+
+    let cloned_system = system.clone();
+    future
+      .continue_with(move |_, _| {
+        let system = cloned_system.clone();
+        let pid = pid.clone();
+        async move {
+          let process_registry = system.get_process_registry().await;
+          process_registry.remove_process(&pid).await;
+          process_registry.release_future_slot();
+          publish_future_pressure(&system).await;
+          metrics_foreach(&system, |am, _| {
+            let am = am.clone();
+            async move { am.decrement_futures_pending_count().await }
+          })
+          .await;
+        }
+      })
+      .await;
 
     if duration > Duration::from_secs(0) {
       let future_process_clone = Arc::clone(&future_process);
 
-      system
+      let result = system
         .get_config()
         .await
         .system_dispatcher
-        .schedule(Runnable::new(move || async move {
+        .schedule_with_backoff(Runnable::new(move || async move {
           let future = future_process_clone.get_future().await;
 
           tokio::select! {
@@ -123,30 +218,19 @@ impl ActorFutureProcess {
           }
         }))
         .await;
+      if let Err(err) = result {
+        tracing::error!("Failed to schedule the future timeout: {}", err);
+      }
     }
 
-    future_process
+    Ok(future_process)
   }
 
   async fn metrics_foreach<F, Fut>(&self, f: F)
   where
     F: Fn(&ActorMetrics, &Metrics) -> Fut,
     Fut: std::future::Future<Output = ()>, {
-    if self.get_actor_system().await.get_config().await.is_metrics_enabled() {
-      if let Some(extension_arc) = self
-        .get_actor_system()
-        .await
-        .get_extensions()
-        .await
-        .get(*EXTENSION_ID)
-        .await
-      {
-        let mut extension = extension_arc.lock().await;
-        if let Some(m) = extension.as_any_mut().downcast_mut::<Metrics>() {
-          m.foreach(f).await;
-        }
-      }
-    }
+    metrics_foreach(&self.get_actor_system().await, f).await
   }
 
   async fn get_actor_system(&self) -> ActorSystem {
@@ -181,6 +265,13 @@ impl ActorFutureProcess {
     future_mg.pipe_to(pid).await;
   }
 
+  pub async fn pipe_typed_to<T>(&self, pid: ExtendedPid)
+  where
+    T: Message + Clone + PartialEq, {
+    let future_mg = self.future.read().await;
+    future_mg.pipe_typed_to::<T>(pid).await;
+  }
+
   pub async fn result(&self) -> Result<MessageHandle, ActorFutureError> {
     let future_mg = self.future.read().await;
     future_mg.result().await
@@ -246,14 +337,16 @@ impl ActorFutureProcess {
 #[async_trait]
 impl Process for ActorFutureProcess {
   async fn send_user_message(&self, _: Option<&ExtendedPid>, message_handle: MessageHandle) {
+    // Responses may arrive wrapped in an envelope carrying headers, the future completes with the message itself
+    let message_handle = unwrap_envelope_message(message_handle);
     let cloned_self = self.clone();
     let future = self.future.read().await.clone();
     let dispatcher = {
       let mg = future.inner.read().await;
       mg.actor_system.get_config().await.system_dispatcher.clone()
     };
-    dispatcher
-      .schedule(Runnable::new(move || {
+    let result = dispatcher
+      .schedule_with_backoff(Runnable::new(move || {
         let future = future.clone();
         let cloned_self = cloned_self.clone();
         async move {
@@ -266,6 +359,9 @@ impl Process for ActorFutureProcess {
         }
       }))
       .await;
+    if let Err(err) = result {
+      tracing::error!("Failed to schedule the future completion: {}", err);
+    }
   }
 
   async fn send_system_message(&self, _: &ExtendedPid, message_handle: MessageHandle) {
@@ -275,8 +371,8 @@ impl Process for ActorFutureProcess {
       let mg = future.inner.read().await;
       mg.actor_system.get_config().await.system_dispatcher.clone()
     };
-    dispatcher
-      .schedule(Runnable::new(move || {
+    let result = dispatcher
+      .schedule_with_backoff(Runnable::new(move || {
         let future = future.clone();
         let cloned_self = cloned_self.clone();
         async move {
@@ -285,6 +381,9 @@ impl Process for ActorFutureProcess {
         }
       }))
       .await;
+    if let Err(err) = result {
+      tracing::error!("Failed to schedule the future completion: {}", err);
+    }
   }
 
   async fn stop(&self, _pid: &ExtendedPid) {}
@@ -316,7 +415,114 @@ pub struct ActorFuture {
 
 static_assertions::assert_impl_all!(ActorFuture: Send, Sync);
 
+// Cancelling runs on its own task, since completions hold the completing future's lock
+fn cancel_futures(futures: Vec<ActorFuture>) {
+  tokio::spawn(async move {
+    for future in futures {
+      future.fail(ActorFutureError::Cancelled).await;
+    }
+  });
+}
+
 impl ActorFuture {
+  fn new(actor_system: ActorSystem) -> Self {
+    let inner = Arc::new(RwLock::new(ActorFutureInner {
+      actor_system,
+      pid: None,
+      done: false,
+      result: None,
+      error: None,
+      pipes: Vec::new(),
+      completions: Vec::new(),
+    }));
+    let notify = Arc::new(Notify::new());
+    ActorFuture { inner, notify }
+  }
+
+  // Failed returns a future that has already failed with the given error, for requests
+  // that could not be issued at all
+  pub async fn failed(actor_system: ActorSystem, error: ActorFutureError) -> Self {
+    let future = ActorFuture::new(actor_system);
+    future.fail(error).await;
+    future
+  }
+
+  // All completes with FutureReplies once every input has completed, or fails with the first
+  // error. Inputs still pending when it resolves are cancelled.
+  pub async fn all(actor_system: ActorSystem, futures: Vec<ActorFuture>) -> Self {
+    let combined = ActorFuture::new(actor_system);
+    if futures.is_empty() {
+      combined.complete(MessageHandle::new(FutureReplies(Vec::new()))).await;
+      return combined;
+    }
+    let replies = Arc::new(Mutex::new(vec![None; futures.len()]));
+    for (index, future) in futures.iter().enumerate() {
+      let combined = combined.clone();
+      let replies = replies.clone();
+      let futures = futures.clone();
+      future
+        .continue_with(move |result, error| {
+          let combined = combined.clone();
+          let replies = replies.clone();
+          let futures = futures.clone();
+          async move {
+            if let Some(error) = error {
+              combined.fail(error).await;
+              cancel_futures(futures);
+              return;
+            }
+            let mut mg = replies.lock().await;
+            mg[index] = result;
+            if mg.iter().all(|reply| reply.is_some()) {
+              let replies = mg.iter().flatten().cloned().collect::<Vec<_>>();
+              combined.complete(MessageHandle::new(FutureReplies(replies))).await;
+            }
+          }
+        })
+        .await;
+    }
+    combined
+  }
+
+  // Any completes with the first successful reply, or fails with the last error once every
+  // input has failed. Inputs still pending when it resolves are cancelled.
+  pub async fn any(actor_system: ActorSystem, futures: Vec<ActorFuture>) -> Self {
+    let combined = ActorFuture::new(actor_system);
+    if futures.is_empty() {
+      combined.fail(ActorFutureError::Cancelled).await;
+      return combined;
+    }
+    let remaining = Arc::new(Mutex::new(futures.len()));
+    for future in futures.iter() {
+      let combined = combined.clone();
+      let remaining = remaining.clone();
+      let futures = futures.clone();
+      future
+        .continue_with(move |result, error| {
+          let combined = combined.clone();
+          let remaining = remaining.clone();
+          let futures = futures.clone();
+          async move {
+            match (result, error) {
+              (Some(message_handle), None) => {
+                combined.complete(message_handle).await;
+                cancel_futures(futures);
+              }
+              (_, error) => {
+                let mut mg = remaining.lock().await;
+                *mg -= 1;
+                if *mg == 0 {
+                  combined.fail(error.unwrap_or(ActorFutureError::Cancelled)).await;
+                }
+              }
+            }
+          }
+        })
+        .await;
+    }
+    combined
+  }
+
   pub async fn result(&self) -> Result<MessageHandle, ActorFutureError> {
     loop {
       {
@@ -333,6 +539,11 @@ impl ActorFuture {
     }
   }
 
+  pub async fn is_done(&self) -> bool {
+    let inner = self.inner.read().await;
+    inner.done
+  }
+
   pub async fn wait(&self) -> Option<ActorFutureError> {
     self.result().await.err()
   }
@@ -355,6 +566,40 @@ impl ActorFuture {
     }
   }
 
+  // PipeTypedTo sends the result to the given PID as a PipeResult<T>, so the receiver does not
+  // have to handle ActorFutureError or downcast the response itself
+  pub async fn pipe_typed_to<T>(&self, pid: ExtendedPid)
+  where
+    T: Message + Clone + PartialEq, {
+    let actor_system = self.get_actor_system().await;
+    self
+      .continue_with(move |result, error| {
+        let actor_system = actor_system.clone();
+        let pid = pid.clone();
+        async move {
+          let pipe_result = match (result, error) {
+            (_, Some(ActorFutureError::TimeoutError)) => PipeResult::<T>::Timeout,
+            (_, Some(ActorFutureError::DeadLetterError)) => PipeResult::<T>::DeadLetter,
+            (_, Some(ActorFutureError::Cancelled)) => PipeResult::<T>::Cancelled,
+            (_, Some(ActorFutureError::TooManyPendingFuturesError)) => PipeResult::<T>::TooManyPendingFutures,
+            (_, Some(ActorFutureError::TimeoutRequiredError)) => PipeResult::<T>::TimeoutRequired,
+            (_, Some(ActorFutureError::NoResult)) | (None, None) => PipeResult::<T>::NoResult,
+            (Some(message_handle), None) => match message_handle.to_typed::<T>() {
+              Some(message) => PipeResult::Ok(message),
+              None => PipeResult::TypeMismatch {
+                expected: std::any::type_name::<T>().to_string(),
+                actual: message_handle.get_type_name(),
+              },
+            },
+          };
+          pid
+            .send_user_message(actor_system, MessageHandle::new(pipe_result))
+            .await;
+        }
+      })
+      .await;
+  }
+
   async fn send_to_pipes(&self, inner: &mut ActorFutureInner) {
     let message = if let Some(error) = &inner.error {
       MessageHandle::new(error.clone())
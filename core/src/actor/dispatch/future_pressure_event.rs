@@ -0,0 +1,9 @@
+use nexus_actor_message_derive_rs::Message;
+
+// FuturePressureEvent is published to the event stream when the number of pending futures reaches the
+// configured high-water mark, and again once it drops back below it
+#[derive(Debug, Clone, PartialEq, Eq, Message)]
+pub enum FuturePressureEvent {
+  HighWaterMarkReached { pending: usize, high_water_mark: usize },
+  Recovered { pending: usize, high_water_mark: usize },
+}
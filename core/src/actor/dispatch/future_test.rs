@@ -4,15 +4,18 @@ mod tests {
   use std::sync::atomic::{AtomicBool, Ordering};
   use std::sync::Arc;
 
-  use crate::actor::actor::ExtendedPid;
+  use crate::actor::actor::{ExtendedPid, Props};
   use crate::actor::actor_system::ActorSystem;
-  use crate::actor::dispatch::future::{ActorFutureError, ActorFutureProcess};
+  use crate::actor::context::{MessagePart, SpawnerPart};
+  use crate::actor::dispatch::future::{ActorFuture, ActorFutureError, ActorFutureProcess, FutureReplies, PipeResult};
+  use crate::actor::dispatch::FuturePressureEvent;
   use crate::actor::message::Message;
   use crate::actor::message::MessageHandle;
   use crate::actor::process::{Process, ProcessHandle};
+  use crate::actor::ConfigOption;
   use async_trait::async_trait;
   use nexus_actor_utils_rs::concurrent::AsyncBarrier;
-  use tokio::sync::Notify;
+  use tokio::sync::{mpsc, Notify};
   use tokio::time::{sleep, Duration};
 
   #[derive(Debug, Clone)]
@@ -77,7 +80,7 @@ mod tests {
 
     let barrier = AsyncBarrier::new(4);
 
-    let future_process = ActorFutureProcess::new(system, Duration::from_secs(1)).await;
+    let future_process = ActorFutureProcess::new(system, Duration::from_secs(1)).await.unwrap();
 
     future_process.pipe_to(a1.get_pid()).await;
     future_process.pipe_to(a2.get_pid()).await;
@@ -132,7 +135,9 @@ mod tests {
 
     let barrier = AsyncBarrier::new(4);
 
-    let future_process = ActorFutureProcess::new(system, Duration::from_millis(100)).await;
+    let future_process = ActorFutureProcess::new(system, Duration::from_millis(100))
+      .await
+      .unwrap();
 
     future_process.pipe_to(a1.get_pid()).await;
     future_process.pipe_to(a2.get_pid()).await;
@@ -170,7 +175,9 @@ mod tests {
   #[tokio::test]
   async fn test_new_future_timeout_no_race() {
     let system = ActorSystem::new().await.unwrap();
-    let future_process = ActorFutureProcess::new(system, Duration::from_millis(200)).await;
+    let future_process = ActorFutureProcess::new(system, Duration::from_millis(200))
+      .await
+      .unwrap();
     let barrier = AsyncBarrier::new(2);
 
     tokio::spawn({
@@ -207,7 +214,7 @@ mod tests {
   #[tokio::test]
   async fn test_future_result_dead_letter_response() {
     let system = ActorSystem::new().await.unwrap();
-    let future_process = ActorFutureProcess::new(system, Duration::from_secs(1)).await;
+    let future_process = ActorFutureProcess::new(system, Duration::from_secs(1)).await.unwrap();
     future_process.fail(ActorFutureError::DeadLetterError).await;
 
     let result = future_process.result().await;
@@ -217,7 +224,9 @@ mod tests {
   #[tokio::test]
   async fn test_future_result_timeout() {
     let system = ActorSystem::new().await.unwrap();
-    let future_process = ActorFutureProcess::new(system, Duration::from_millis(50)).await;
+    let future_process = ActorFutureProcess::new(system, Duration::from_millis(50))
+      .await
+      .unwrap();
 
     sleep(Duration::from_millis(100)).await;
 
@@ -228,7 +237,7 @@ mod tests {
   #[tokio::test]
   async fn test_future_result_success() {
     let system = ActorSystem::new().await.unwrap();
-    let future_process = ActorFutureProcess::new(system, Duration::from_secs(1)).await;
+    let future_process = ActorFutureProcess::new(system, Duration::from_secs(1)).await.unwrap();
     future_process
       .complete(MessageHandle::new("response".to_string()))
       .await;
@@ -236,4 +245,243 @@ mod tests {
     let result = assert_future_success(&future_process).await;
     assert_eq!(result.as_any().downcast_ref::<String>().unwrap(), "response");
   }
+
+  #[tokio::test]
+  async fn test_future_pipe_typed_to() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let pid = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let tx = tx.clone();
+          async move {
+            if let Some(result) = ctx.get_message_handle().await.to_typed::<PipeResult<String>>() {
+              tx.send(result).unwrap();
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    let timeout_future = ActorFutureProcess::new(system.clone(), Duration::from_millis(100))
+      .await
+      .unwrap();
+    timeout_future.pipe_typed_to::<String>(pid.clone()).await;
+    assert_eq!(rx.recv().await, Some(PipeResult::Timeout));
+
+    let success_future = ActorFutureProcess::new(system.clone(), Duration::from_secs(1))
+      .await
+      .unwrap();
+    success_future.pipe_typed_to::<String>(pid.clone()).await;
+    success_future.complete(MessageHandle::new("hello".to_string())).await;
+    assert_eq!(rx.recv().await, Some(PipeResult::Ok("hello".to_string())));
+
+    let mismatch_future = ActorFutureProcess::new(system.clone(), Duration::from_secs(1))
+      .await
+      .unwrap();
+    mismatch_future.pipe_typed_to::<String>(pid.clone()).await;
+    mismatch_future.complete(MessageHandle::new(42_i32)).await;
+    assert_eq!(
+      rx.recv().await,
+      Some(PipeResult::TypeMismatch {
+        expected: std::any::type_name::<String>().to_string(),
+        actual: MessageHandle::new(42_i32).get_type_name(),
+      })
+    );
+
+    let failed_future = ActorFutureProcess::new(system.clone(), Duration::from_secs(1))
+      .await
+      .unwrap();
+    failed_future.pipe_typed_to::<String>(pid).await;
+    failed_future.fail(ActorFutureError::NoResult).await;
+    assert_eq!(rx.recv().await, Some(PipeResult::NoResult));
+  }
+
+  #[tokio::test]
+  async fn test_future_pressure_events() {
+    let system = ActorSystem::new_config_options([ConfigOption::with_future_high_water_mark(2)])
+      .await
+      .unwrap();
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    system
+      .get_event_stream()
+      .await
+      .subscribe(move |msg| {
+        let tx = tx.clone();
+        async move {
+          if let Some(event) = msg.to_typed::<FuturePressureEvent>() {
+            tx.send(event).unwrap();
+          }
+        }
+      })
+      .await;
+
+    let first = ActorFutureProcess::new(system.clone(), Duration::from_secs(5))
+      .await
+      .unwrap();
+    assert!(rx.try_recv().is_err());
+
+    let second = ActorFutureProcess::new(system.clone(), Duration::from_secs(5))
+      .await
+      .unwrap();
+    assert_eq!(
+      rx.recv().await,
+      Some(FuturePressureEvent::HighWaterMarkReached {
+        pending: 2,
+        high_water_mark: 2,
+      })
+    );
+
+    first.complete(MessageHandle::new("done".to_string())).await;
+    assert_eq!(
+      rx.recv().await,
+      Some(FuturePressureEvent::Recovered {
+        pending: 1,
+        high_water_mark: 2,
+      })
+    );
+
+    second.complete(MessageHandle::new("done".to_string())).await;
+    sleep(Duration::from_millis(50)).await;
+    assert!(rx.try_recv().is_err());
+  }
+
+  #[tokio::test]
+  async fn test_future_all_collects_replies_in_order() {
+    let system = ActorSystem::new().await.unwrap();
+    let first = ActorFutureProcess::new(system.clone(), Duration::from_secs(1))
+      .await
+      .unwrap();
+    let second = ActorFutureProcess::new(system.clone(), Duration::from_secs(1))
+      .await
+      .unwrap();
+    let all = ActorFuture::all(
+      system.clone(),
+      vec![first.get_future().await, second.get_future().await],
+    )
+    .await;
+
+    second.complete(MessageHandle::new(2u32)).await;
+    first.complete(MessageHandle::new(1u32)).await;
+
+    let result = all.result().await.unwrap();
+    let replies = result.to_typed::<FutureReplies>().unwrap();
+    let values = replies
+      .get_replies()
+      .iter()
+      .map(|reply| reply.to_typed::<u32>().unwrap())
+      .collect::<Vec<_>>();
+    assert_eq!(values, vec![1, 2]);
+  }
+
+  #[tokio::test]
+  async fn test_future_all_fails_with_first_error() {
+    let system = ActorSystem::new().await.unwrap();
+    let timing_out = ActorFutureProcess::new(system.clone(), Duration::from_millis(50))
+      .await
+      .unwrap();
+    let pending = ActorFutureProcess::new(system.clone(), Duration::from_secs(10))
+      .await
+      .unwrap();
+    let all = ActorFuture::all(
+      system.clone(),
+      vec![timing_out.get_future().await, pending.get_future().await],
+    )
+    .await;
+
+    assert_eq!(all.result().await.unwrap_err(), ActorFutureError::TimeoutError);
+    assert_eq!(pending.result().await.unwrap_err(), ActorFutureError::Cancelled);
+  }
+
+  #[tokio::test]
+  async fn test_future_any_completes_with_first_success() {
+    let system = ActorSystem::new().await.unwrap();
+    let failing = ActorFutureProcess::new(system.clone(), Duration::from_secs(1))
+      .await
+      .unwrap();
+    let succeeding = ActorFutureProcess::new(system.clone(), Duration::from_secs(1))
+      .await
+      .unwrap();
+    let pending = ActorFutureProcess::new(system.clone(), Duration::from_secs(10))
+      .await
+      .unwrap();
+    let any = ActorFuture::any(
+      system.clone(),
+      vec![
+        failing.get_future().await,
+        succeeding.get_future().await,
+        pending.get_future().await,
+      ],
+    )
+    .await;
+
+    failing.fail(ActorFutureError::DeadLetterError).await;
+    succeeding.complete(MessageHandle::new("reply".to_string())).await;
+
+    let result = any.result().await.unwrap();
+    assert_eq!(result.to_typed::<String>().unwrap(), "reply");
+    assert_eq!(pending.result().await.unwrap_err(), ActorFutureError::Cancelled);
+  }
+
+  #[tokio::test]
+  async fn test_future_any_fails_when_every_input_fails() {
+    let system = ActorSystem::new().await.unwrap();
+    let first = ActorFutureProcess::new(system.clone(), Duration::from_secs(1))
+      .await
+      .unwrap();
+    let second = ActorFutureProcess::new(system.clone(), Duration::from_millis(50))
+      .await
+      .unwrap();
+    let any = ActorFuture::any(
+      system.clone(),
+      vec![first.get_future().await, second.get_future().await],
+    )
+    .await;
+
+    first.fail(ActorFutureError::DeadLetterError).await;
+
+    assert_eq!(any.result().await.unwrap_err(), ActorFutureError::TimeoutError);
+  }
+
+  #[tokio::test]
+  async fn test_future_process_is_removed_from_registry_once_resolved() {
+    let system = ActorSystem::new().await.unwrap();
+    let process_registry = system.get_process_registry().await;
+    let before = process_registry.get_process_count();
+    let future_process = ActorFutureProcess::new(system.clone(), Duration::from_secs(1))
+      .await
+      .unwrap();
+    assert_eq!(process_registry.get_process_count(), before + 1);
+
+    future_process.complete(MessageHandle::new(1u32)).await;
+
+    assert_eq!(process_registry.get_process_count(), before);
+  }
+
+  #[tokio::test]
+  async fn test_future_without_timeout_is_rejected_while_pending_futures_are_limited() {
+    let system = ActorSystem::new_config_options([ConfigOption::with_max_pending_futures(1)])
+      .await
+      .unwrap();
+    let process_registry = system.get_process_registry().await;
+
+    let result = ActorFutureProcess::new(system.clone(), Duration::ZERO).await;
+    assert_eq!(result.err(), Some(ActorFutureError::TimeoutRequiredError));
+    assert_eq!(process_registry.get_pending_futures(), 0);
+
+    let future_process = ActorFutureProcess::new(system.clone(), Duration::from_secs(1))
+      .await
+      .unwrap();
+    future_process.complete(MessageHandle::new(1u32)).await;
+    assert_eq!(process_registry.get_pending_futures(), 0);
+
+    let unlimited = ActorSystem::new().await.unwrap();
+    let future_process = ActorFutureProcess::new(unlimited, Duration::ZERO).await.unwrap();
+    future_process.complete(MessageHandle::new(1u32)).await;
+  }
 }
@@ -24,5 +24,30 @@ pub trait Mailbox: Debug + Send + Sync {
   async fn start(&self);
   async fn user_message_count(&self) -> i32;
 
+  // Non-blocking variant of get_user_messages_count for synchronous callers such as metric callbacks.
+  // Returns None when the count cannot be read without waiting.
+  fn try_get_user_messages_count(&self) -> Option<i32> {
+    None
+  }
+
   async fn to_handle(&self) -> MailboxHandle;
+
+  // Overrides how many messages are processed before the mailbox yields, 0 restores the dispatcher's value
+  async fn set_throughput(&self, _throughput: i32) {}
+
+  // Moves message processing to another dispatcher. Queued messages are kept, and the message being processed
+  // finishes on the current dispatcher before the mailbox continues on the new one
+  async fn set_dispatcher(&self, _dispatcher: DispatcherHandle) {}
+
+  // IsUserMailboxFull reports whether posting another user message would make a dropping bounded mailbox
+  // evict its oldest message
+  async fn is_user_mailbox_full(&self) -> bool {
+    false
+  }
+
+  // IsIdle reports whether the mailbox has no queued messages and is neither scheduled on its dispatcher nor
+  // processing a message
+  async fn is_idle(&self) -> bool {
+    self.get_user_messages_count().await <= 0 && self.get_system_messages_count().await <= 0
+  }
 }
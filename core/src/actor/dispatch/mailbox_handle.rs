@@ -81,8 +81,33 @@ impl Mailbox for MailboxHandle {
     mg.user_message_count().await
   }
 
+  fn try_get_user_messages_count(&self) -> Option<i32> {
+    let mg = self.0.try_read().ok()?;
+    mg.try_get_user_messages_count()
+  }
+
   async fn to_handle(&self) -> MailboxHandle {
     let mg = self.0.read().await;
     mg.to_handle().await
   }
+
+  async fn set_throughput(&self, throughput: i32) {
+    let mg = self.0.read().await;
+    mg.set_throughput(throughput).await;
+  }
+
+  async fn set_dispatcher(&self, dispatcher: DispatcherHandle) {
+    let mg = self.0.read().await;
+    mg.set_dispatcher(dispatcher).await;
+  }
+
+  async fn is_user_mailbox_full(&self) -> bool {
+    let mg = self.0.read().await;
+    mg.is_user_mailbox_full().await
+  }
+
+  async fn is_idle(&self) -> bool {
+    let mg = self.0.read().await;
+    mg.is_idle().await
+  }
 }
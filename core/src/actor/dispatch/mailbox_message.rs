@@ -1,8 +1,11 @@
-use crate::actor::message::Message;
 use nexus_actor_message_derive_rs::Message;
 
 #[derive(Debug, Clone, PartialEq, Eq, Message)]
 pub enum MailboxMessage {
   SuspendMailbox,
   ResumeMailbox,
+  // Sent by ActorSystem::suspend and resume. They are tracked apart from the suspension used by supervision,
+  // user messages are processed only when neither is in effect
+  SuspendMailboxExternally,
+  ResumeMailboxExternally,
 }
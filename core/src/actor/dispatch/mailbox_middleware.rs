@@ -1,16 +1,22 @@
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use crate::actor::message::MessageHandle;
 use async_trait::async_trait;
 use tokio::sync::RwLock;
 
-// MailboxMiddleware trait
+// MailboxMiddleware observes a mailbox, it is installed with unbounded_mailbox_creator_with_opts.
+// The hooks see both user and system messages
 #[async_trait]
 pub trait MailboxMiddleware: Debug + Send + Sync {
+  // MailboxStarted is called once when the actor is spawned, right after its Start system message is posted
   async fn mailbox_started(&mut self);
+  // MessagePosted is called for every message before it is enqueued
   async fn message_posted(&mut self, message_handle: MessageHandle);
+  // MessageReceived is called for every message after the actor has handled it
   async fn message_received(&mut self, message_handle: MessageHandle);
+  // MailboxEmpty is called when the mailbox has processed all of its messages and goes idle
   async fn mailbox_empty(&mut self);
 }
 
@@ -59,3 +65,40 @@ impl MailboxMiddleware for MailboxMiddlewareHandle {
     mg.mailbox_empty().await;
   }
 }
+
+// MessageCounterMiddleware counts the messages posted to and received from a mailbox.
+// Clones share the counts, so a clone kept outside the mailbox can read them
+#[derive(Debug, Clone, Default)]
+pub struct MessageCounterMiddleware {
+  posted: Arc<AtomicUsize>,
+  received: Arc<AtomicUsize>,
+}
+
+impl MessageCounterMiddleware {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn get_posted_count(&self) -> usize {
+    self.posted.load(Ordering::SeqCst)
+  }
+
+  pub fn get_received_count(&self) -> usize {
+    self.received.load(Ordering::SeqCst)
+  }
+}
+
+#[async_trait]
+impl MailboxMiddleware for MessageCounterMiddleware {
+  async fn mailbox_started(&mut self) {}
+
+  async fn message_posted(&mut self, _: MessageHandle) {
+    self.posted.fetch_add(1, Ordering::SeqCst);
+  }
+
+  async fn message_received(&mut self, _: MessageHandle) {
+    self.received.fetch_add(1, Ordering::SeqCst);
+  }
+
+  async fn mailbox_empty(&mut self) {}
+}
@@ -2,18 +2,25 @@
 mod tests {
   use crate::actor::actor::ActorError;
   use crate::actor::actor::ErrorReason;
+  use crate::actor::actor::Props;
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{BasePart, MessagePart, SenderPart, SpawnerPart};
   use crate::actor::dispatch::bounded::BoundedMailboxQueue;
   use crate::actor::dispatch::dispatcher::{DispatcherHandle, TokioRuntimeContextDispatcher};
   use crate::actor::dispatch::mailbox::Mailbox;
   use crate::actor::dispatch::message_invoker::{MessageInvoker, MessageInvokerHandle};
   use crate::actor::dispatch::unbounded::unbounded_mpsc_mailbox_creator;
-  use crate::actor::message::MessageHandle;
+  use crate::actor::dispatch::{
+    unbounded_mailbox_creator_with_opts, MailboxMiddleware, MailboxMiddlewareHandle, MessageCounterMiddleware,
+  };
+  use crate::actor::message::{MessageHandle, ResponseHandle};
   use async_trait::async_trait;
   use nexus_actor_utils_rs::collections::{QueueReader, QueueWriter, RingQueue};
   use rand::rngs::SmallRng;
+  use rand::SeedableRng;
   use rand::Rng;
   use std::env;
-  use std::sync::Arc;
+  use std::sync::{Arc, Mutex};
   use std::time::Duration;
   use tokio::sync::RwLock;
   use tokio::time::sleep;
@@ -89,7 +96,7 @@ mod tests {
       .await;
 
     let mut join_handles = Vec::new();
-    let rng = SmallRng::from_thread_rng();
+    let rng = SmallRng::from_os_rng();
 
     for j in 0..c {
       let cmax = max / c;
@@ -147,7 +154,7 @@ mod tests {
       .await;
 
     let mut join_handles = Vec::new();
-    let rng = SmallRng::from_thread_rng();
+    let rng = SmallRng::from_os_rng();
 
     for j in 0..c {
       let cmax = max / c;
@@ -205,4 +212,78 @@ mod tests {
     let value = result.unwrap().to_typed::<String>().unwrap();
     assert_eq!(value, "2".to_string());
   }
+
+  #[derive(Debug, Clone)]
+  struct HookRecorder {
+    hooks: Arc<Mutex<Vec<&'static str>>>,
+  }
+
+  #[async_trait]
+  impl MailboxMiddleware for HookRecorder {
+    async fn mailbox_started(&mut self) {
+      self.hooks.lock().unwrap().push("started");
+    }
+
+    async fn message_posted(&mut self, _: MessageHandle) {
+      self.hooks.lock().unwrap().push("posted");
+    }
+
+    async fn message_received(&mut self, _: MessageHandle) {
+      self.hooks.lock().unwrap().push("received");
+    }
+
+    async fn mailbox_empty(&mut self) {
+      self.hooks.lock().unwrap().push("empty");
+    }
+  }
+
+  #[tokio::test]
+  async fn test_mailbox_middleware_hooks() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let counter = MessageCounterMiddleware::new();
+    let hooks = Arc::new(Mutex::new(Vec::new()));
+    let recorder = HookRecorder { hooks: hooks.clone() };
+    let props = Props::from_async_actor_receiver_with_opts(
+      |ctx| async move {
+        if let Some(msg) = ctx.get_message_handle().await.to_typed::<String>() {
+          ctx.respond(ResponseHandle::new(msg)).await;
+        }
+        Ok(())
+      },
+      [Props::with_mailbox_producer(unbounded_mailbox_creator_with_opts([
+        MailboxMiddlewareHandle::new(counter.clone()),
+        MailboxMiddlewareHandle::new(recorder),
+      ]))],
+    )
+    .await;
+    let pid = root_context.spawn(props).await;
+
+    for i in 0..3 {
+      root_context
+        .request_future(pid.clone(), MessageHandle::new(i.to_string()), Duration::from_secs(3))
+        .await
+        .result()
+        .await
+        .unwrap();
+    }
+    // replies are sent before message_received runs, wait until every posted message has been seen
+    tokio::time::timeout(Duration::from_secs(3), async {
+      while counter.get_received_count() < counter.get_posted_count() || !hooks.lock().unwrap().contains(&"empty") {
+        sleep(Duration::from_millis(10)).await;
+      }
+    })
+    .await
+    .unwrap();
+    // the Start system message is counted as well
+    assert_eq!(counter.get_received_count(), 4);
+
+    let hooks = hooks.lock().unwrap().clone();
+    assert_eq!(hooks.iter().filter(|hook| **hook == "started").count(), 1);
+    assert!(hooks.contains(&"empty"));
+    assert_eq!(
+      hooks.iter().filter(|hook| **hook == "received").count(),
+      counter.get_received_count()
+    );
+  }
 }
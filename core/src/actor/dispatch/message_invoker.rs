@@ -6,6 +6,7 @@ use tokio::sync::RwLock;
 
 use crate::actor::actor::ActorError;
 use crate::actor::actor::ErrorReason;
+use crate::actor::message::Expired;
 use crate::actor::message::MessageHandle;
 
 // MessageInvoker trait
@@ -14,6 +15,12 @@ pub trait MessageInvoker: Debug + Send + Sync {
   async fn invoke_system_message(&mut self, message_handle: MessageHandle) -> Result<(), ActorError>;
   async fn invoke_user_message(&mut self, message_handle: MessageHandle) -> Result<(), ActorError>;
   async fn escalate_failure(&mut self, reason: ErrorReason, message_handle: MessageHandle);
+
+  // Called instead of invoke_user_message for a message whose TTL elapsed while it was queued
+  async fn expire_user_message(&mut self, _: Expired) {}
+
+  // Called after the mailbox resumes processing user messages following a failure
+  async fn mailbox_resumed(&mut self) {}
 }
 
 #[derive(Debug, Clone)]
@@ -58,6 +65,16 @@ impl MessageInvoker for MessageInvokerHandle {
     let mut mg = self.0.write().await;
     mg.escalate_failure(reason, message_handle).await;
   }
+
+  async fn expire_user_message(&mut self, expired: Expired) {
+    let mut mg = self.0.write().await;
+    mg.expire_user_message(expired).await;
+  }
+
+  async fn mailbox_resumed(&mut self) {
+    let mut mg = self.0.write().await;
+    mg.mailbox_resumed().await;
+  }
 }
 
 static_assertions::assert_impl_all!(MessageInvokerHandle: Send, Sync);
@@ -0,0 +1,229 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use nexus_actor_utils_rs::collections::{
+  MpscUnboundedChannelQueue, QueueBase, QueueError, QueueReader, QueueSize, QueueWriter, RingQueue,
+};
+use tokio::sync::RwLock;
+
+use crate::actor::dispatch::default_mailbox::DefaultMailbox;
+use crate::actor::dispatch::dispatcher::DispatcherHandle;
+use crate::actor::dispatch::mailbox::Mailbox;
+use crate::actor::dispatch::mailbox_handle::MailboxHandle;
+use crate::actor::dispatch::mailbox_middleware::MailboxMiddlewareHandle;
+use crate::actor::dispatch::mailbox_producer::MailboxProducer;
+use crate::actor::dispatch::message_invoker::MessageInvokerHandle;
+use crate::actor::dispatch::unbounded::UnboundedMailboxQueue;
+use crate::actor::message::unwrap_envelope_message;
+use crate::actor::message::MessageHandle;
+
+pub const PRIORITY_MAILBOX_LEVELS: u8 = 8;
+pub const PRIORITY_MAILBOX_DEFAULT_PRIORITY: u8 = PRIORITY_MAILBOX_LEVELS / 2;
+
+// PriorityFunc assigns a priority to a user message, 0 being the highest. Messages without an assigned priority
+// should get PRIORITY_MAILBOX_DEFAULT_PRIORITY, priorities past the lowest level are queued at the lowest level.
+// It is given the message itself, unwrapped from its envelope
+#[derive(Clone)]
+pub struct PriorityFunc(Arc<dyn Fn(&MessageHandle) -> u8 + Send + Sync + 'static>);
+
+impl PriorityFunc {
+  pub fn new(f: impl Fn(&MessageHandle) -> u8 + Send + Sync + 'static) -> Self {
+    Self(Arc::new(f))
+  }
+
+  pub fn run(&self, message_handle: &MessageHandle) -> u8 {
+    (self.0)(message_handle).min(PRIORITY_MAILBOX_LEVELS - 1)
+  }
+}
+
+impl Debug for PriorityFunc {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "PriorityFunc")
+  }
+}
+
+impl PartialEq for PriorityFunc {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl Eq for PriorityFunc {}
+
+static_assertions::assert_impl_all!(PriorityFunc: Send, Sync);
+
+// PriorityMailboxQueue keeps one queue per priority level and polls the highest non-empty level first.
+// Messages of the same priority keep their order
+#[derive(Debug, Clone)]
+pub struct PriorityMailboxQueue {
+  priority_queues: Arc<RwLock<Vec<RingQueue<MessageHandle>>>>,
+  priority_func: PriorityFunc,
+}
+
+impl PriorityMailboxQueue {
+  pub fn new(priority_func: PriorityFunc) -> Self {
+    let queues = (0..PRIORITY_MAILBOX_LEVELS).map(|_| RingQueue::new(10)).collect();
+    Self {
+      priority_queues: Arc::new(RwLock::new(queues)),
+      priority_func,
+    }
+  }
+}
+
+#[async_trait]
+impl QueueBase<MessageHandle> for PriorityMailboxQueue {
+  async fn len(&self) -> QueueSize {
+    let queues_mg = self.priority_queues.read().await;
+    let mut len = QueueSize::Limited(0);
+    for queue in queues_mg.iter() {
+      len = len + queue.len().await;
+    }
+    len
+  }
+
+  async fn capacity(&self) -> QueueSize {
+    QueueSize::Limitless
+  }
+}
+
+#[async_trait]
+impl QueueReader<MessageHandle> for PriorityMailboxQueue {
+  async fn poll(&mut self) -> Result<Option<MessageHandle>, QueueError<MessageHandle>> {
+    let mut queues_mg = self.priority_queues.write().await;
+    for queue in queues_mg.iter_mut() {
+      if let Some(message_handle) = queue.poll().await? {
+        return Ok(Some(message_handle));
+      }
+    }
+    Ok(None)
+  }
+
+  async fn clean_up(&mut self) {
+    let mut queues_mg = self.priority_queues.write().await;
+    for queue in queues_mg.iter_mut() {
+      queue.clean_up().await;
+    }
+  }
+}
+
+#[async_trait]
+impl QueueWriter<MessageHandle> for PriorityMailboxQueue {
+  async fn offer(&mut self, element: MessageHandle) -> Result<(), QueueError<MessageHandle>> {
+    let priority = self.priority_func.run(&unwrap_envelope_message(element.clone())) as usize;
+    let mut queues_mg = self.priority_queues.write().await;
+    queues_mg[priority].offer(element).await
+  }
+}
+
+// PriorityMailbox processes user messages by the priority its PriorityFunc assigns them.
+// System messages are still processed ahead of every user message
+#[derive(Debug, Clone)]
+pub struct PriorityMailbox {
+  underlying: DefaultMailbox,
+}
+
+impl PriorityMailbox {
+  pub fn new(priority_func: PriorityFunc) -> Self {
+    Self {
+      underlying: DefaultMailbox::new(
+        PriorityMailboxQueue::new(priority_func),
+        UnboundedMailboxQueue::new(MpscUnboundedChannelQueue::new()),
+      ),
+    }
+  }
+
+  pub async fn with_middlewares(self, middlewares: impl IntoIterator<Item = MailboxMiddlewareHandle>) -> Self {
+    Self {
+      underlying: self.underlying.with_middlewares(middlewares).await,
+    }
+  }
+}
+
+#[async_trait]
+impl Mailbox for PriorityMailbox {
+  async fn get_user_messages_count(&self) -> i32 {
+    self.underlying.get_user_messages_count().await
+  }
+
+  async fn get_system_messages_count(&self) -> i32 {
+    self.underlying.get_system_messages_count().await
+  }
+
+  async fn process_messages(&self) {
+    self.underlying.process_messages().await
+  }
+
+  async fn post_user_message(&self, message_handle: MessageHandle) {
+    self.underlying.post_user_message(message_handle).await
+  }
+
+  async fn post_system_message(&self, message_handle: MessageHandle) {
+    self.underlying.post_system_message(message_handle).await
+  }
+
+  async fn register_handlers(
+    &mut self,
+    message_invoker_handle: Option<MessageInvokerHandle>,
+    dispatcher_handle: Option<DispatcherHandle>,
+  ) {
+    self
+      .underlying
+      .register_handlers(message_invoker_handle, dispatcher_handle)
+      .await
+  }
+
+  async fn start(&self) {
+    self.underlying.start().await
+  }
+
+  async fn user_message_count(&self) -> i32 {
+    self.underlying.user_message_count().await
+  }
+
+  fn try_get_user_messages_count(&self) -> Option<i32> {
+    self.underlying.try_get_user_messages_count()
+  }
+
+  async fn to_handle(&self) -> MailboxHandle {
+    MailboxHandle::new(self.clone())
+  }
+
+  async fn set_throughput(&self, throughput: i32) {
+    self.underlying.set_throughput(throughput).await
+  }
+
+  async fn set_dispatcher(&self, dispatcher: DispatcherHandle) {
+    self.underlying.set_dispatcher(dispatcher).await
+  }
+
+  async fn is_user_mailbox_full(&self) -> bool {
+    self.underlying.is_user_mailbox_full().await
+  }
+
+  async fn is_idle(&self) -> bool {
+    self.underlying.is_idle().await
+  }
+}
+
+pub fn priority_mailbox_creator_with_opts(
+  priority_func: PriorityFunc,
+  mailbox_stats: impl IntoIterator<Item = MailboxMiddlewareHandle> + Send + Sync,
+) -> MailboxProducer {
+  let cloned_mailbox_stats = mailbox_stats.into_iter().collect::<Vec<_>>();
+  MailboxProducer::new(move || {
+    let priority_func = priority_func.clone();
+    let cloned_mailbox_stats = cloned_mailbox_stats.clone();
+    async move {
+      MailboxHandle::new(
+        PriorityMailbox::new(priority_func)
+          .with_middlewares(cloned_mailbox_stats)
+          .await,
+      )
+    }
+  })
+}
+
+pub fn priority_mailbox_creator(priority_func: PriorityFunc) -> MailboxProducer {
+  priority_mailbox_creator_with_opts(priority_func, [])
+}
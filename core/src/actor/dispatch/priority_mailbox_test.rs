@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+  use std::env;
+  use std::sync::Arc;
+  use std::time::Duration;
+
+  use crate::actor::actor::{ExtendedPid, Props};
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{MessagePart, RootContext, SenderPart, SpawnerPart};
+  use crate::actor::dispatch::priority_mailbox::{
+    priority_mailbox_creator, PriorityFunc, PRIORITY_MAILBOX_DEFAULT_PRIORITY,
+  };
+  use crate::actor::message::{MessageEnvelope, MessageHandle};
+  use nexus_actor_message_derive_rs::Message;
+  use tokio::sync::Mutex;
+  use tracing_subscriber::EnvFilter;
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Control(String);
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Data(String);
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Background(String);
+
+  async fn spawn_recorder(root_context: &mut RootContext) -> (ExtendedPid, Arc<Mutex<Vec<String>>>) {
+    let priority_func = PriorityFunc::new(|message_handle| {
+      if message_handle.is_typed::<Control>() {
+        0
+      } else if message_handle.is_typed::<Background>() {
+        u8::MAX
+      } else {
+        PRIORITY_MAILBOX_DEFAULT_PRIORITY
+      }
+    });
+    let received = Arc::new(Mutex::new(vec![]));
+    let cloned_received = received.clone();
+    let props = Props::from_async_actor_receiver_with_opts(
+      move |ctx| {
+        let received = cloned_received.clone();
+        async move {
+          let message_handle = ctx.get_message_handle().await;
+          let name = if let Some(Control(name)) = message_handle.to_typed::<Control>() {
+            Some(name)
+          } else if let Some(Data(name)) = message_handle.to_typed::<Data>() {
+            Some(name)
+          } else {
+            message_handle.to_typed::<Background>().map(|Background(name)| name)
+          };
+          if let Some(name) = name {
+            received.lock().await.push(name);
+          }
+          Ok(())
+        }
+      },
+      [Props::with_mailbox_producer(priority_mailbox_creator(priority_func))],
+    )
+    .await;
+    (root_context.spawn(props).await, received)
+  }
+
+  async fn wait_for(received: &Mutex<Vec<String>>, count: usize) {
+    tokio::time::timeout(Duration::from_secs(5), async {
+      while received.lock().await.len() < count {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+      }
+    })
+    .await
+    .expect("not all messages were processed");
+  }
+
+  #[tokio::test]
+  async fn test_priority_mailbox_processes_higher_priorities_first() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let (pid, received) = spawn_recorder(&mut root_context).await;
+
+    // Queue everything up before the actor gets to process any of it
+    system.suspend(&pid).await.unwrap();
+    let messages = [
+      MessageHandle::new(Data("data-1".to_string())),
+      MessageHandle::new(Background("background".to_string())),
+      MessageHandle::new(Control("control-1".to_string())),
+      MessageHandle::new(Data("data-2".to_string())),
+      MessageHandle::new(Control("control-2".to_string())),
+    ];
+    for message_handle in messages {
+      root_context.send(pid.clone(), message_handle).await;
+    }
+    system.resume(&pid).await.unwrap();
+
+    wait_for(&received, 5).await;
+    assert_eq!(
+      *received.lock().await,
+      vec!["control-1", "control-2", "data-1", "data-2", "background"]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_priority_mailbox_prioritizes_enveloped_messages_by_their_message() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let (pid, received) = spawn_recorder(&mut root_context).await;
+
+    system.suspend(&pid).await.unwrap();
+    root_context
+      .send(
+        pid.clone(),
+        MessageHandle::new(MessageEnvelope::new(MessageHandle::new(Background(
+          "background".to_string(),
+        )))),
+      )
+      .await;
+    root_context
+      .send(pid.clone(), MessageHandle::new(Data("data".to_string())))
+      .await;
+    root_context
+      .request_with_custom_sender(
+        pid.clone(),
+        MessageHandle::new(Control("control".to_string())),
+        pid.clone(),
+      )
+      .await;
+    system.resume(&pid).await.unwrap();
+
+    wait_for(&received, 3).await;
+    assert_eq!(*received.lock().await, vec!["control", "data", "background"]);
+  }
+}
@@ -35,8 +35,8 @@ impl Throttle {
 
     let throttle_clone = Arc::clone(&throttle);
 
-    dispatcher
-      .schedule(Runnable::new(move || async move {
+    let result = dispatcher
+      .schedule_with_backoff(Runnable::new(move || async move {
         let mut interval = interval(period);
         loop {
           interval.tick().await;
@@ -47,6 +47,9 @@ impl Throttle {
         }
       }))
       .await;
+    if let Err(err) = result {
+      tracing::error!("Failed to schedule the throttler: {}", err);
+    }
 
     throttle
   }
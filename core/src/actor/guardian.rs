@@ -14,7 +14,7 @@ use crate::actor::message::MessageHandle;
 use crate::actor::message::SystemMessage;
 use crate::actor::process::{Process, ProcessHandle};
 use crate::actor::supervisor::SupervisorStrategyHandle;
-use crate::actor::supervisor::{Supervisor, SupervisorHandle, SupervisorStrategy};
+use crate::actor::supervisor::{handle_root_escalation, Supervisor, SupervisorHandle, SupervisorStrategy};
 
 #[derive(Debug, Clone)]
 pub struct GuardiansValue {
@@ -108,7 +108,10 @@ impl Process for GuardianProcess {
         .strategy
         .handle_child_failure(
           self.guardians.actor_system.clone(),
-          SupervisorHandle::new(self.clone()),
+          SupervisorHandle::new(GuardianSupervisor {
+            actor_system: self.guardians.actor_system.clone(),
+            who: failure.who.clone(),
+          }),
           failure.who.clone(),
           failure.restart_stats.clone(),
           failure.reason.clone(),
@@ -131,24 +134,29 @@ impl Process for GuardianProcess {
   }
 }
 
+// GuardianSupervisor supervises a single failure reported to a guardian, so that an escalation knows which
+// child failed
+#[derive(Debug, Clone)]
+struct GuardianSupervisor {
+  actor_system: ActorSystem,
+  who: ExtendedPid,
+}
+
 #[async_trait]
-impl Supervisor for GuardianProcess {
+impl Supervisor for GuardianSupervisor {
   async fn get_children(&self) -> Vec<ExtendedPid> {
     panic!("guardian does not hold its children PIDs");
   }
 
-  async fn escalate_failure(&self, _: ErrorReason, _: MessageHandle) {
-    panic!("guardian cannot escalate failure");
+  async fn escalate_failure(&self, reason: ErrorReason, message_handle: MessageHandle) {
+    handle_root_escalation(&self.actor_system, &self.who, reason, message_handle).await;
   }
 
   async fn restart_children(&self, pids: &[ExtendedPid]) {
     for pid in pids {
       // Implement send_system_message for PID
       pid
-        .send_system_message(
-          self.guardians.actor_system.clone(),
-          MessageHandle::new(SystemMessage::Restart),
-        )
+        .send_system_message(self.actor_system.clone(), MessageHandle::new(SystemMessage::Restart))
         .await;
     }
   }
@@ -156,10 +164,7 @@ impl Supervisor for GuardianProcess {
   async fn stop_children(&self, pids: &[ExtendedPid]) {
     for pid in pids {
       pid
-        .send_system_message(
-          self.guardians.actor_system.clone(),
-          MessageHandle::new(SystemMessage::Stop),
-        )
+        .send_system_message(self.actor_system.clone(), MessageHandle::new(SystemMessage::Stop))
         .await;
     }
   }
@@ -168,7 +173,7 @@ impl Supervisor for GuardianProcess {
     for pid in pids {
       pid
         .send_system_message(
-          self.guardians.actor_system.clone(),
+          self.actor_system.clone(),
           MessageHandle::new(MailboxMessage::ResumeMailbox),
         )
         .await;
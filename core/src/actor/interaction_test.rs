@@ -6,7 +6,6 @@ pub mod tests {
   use crate::actor::actor_system::ActorSystem;
   use crate::actor::context::ContextHandle;
   use crate::actor::context::{BasePart, MessagePart, SenderPart, SpawnerPart};
-  use crate::actor::message::Message;
   use crate::actor::message::MessageHandle;
   use crate::actor::message::ResponseHandle;
   use async_trait::async_trait;
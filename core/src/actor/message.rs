@@ -2,8 +2,13 @@ mod auto_receive_message;
 mod auto_respond;
 mod continuation;
 mod dead_letter_response;
+mod envelope_factory;
+mod envelope_factory_test;
 mod failure;
 mod ignore_dead_letter_logging;
+mod log_encoder;
+mod log_encoder_test;
+mod log_field;
 mod message;
 mod message_batch;
 mod message_batch_test;
@@ -12,7 +17,11 @@ mod message_handles;
 mod message_headers;
 mod message_or_envelope;
 mod message_or_envelope_test;
+mod message_sizer;
+mod message_sizer_test;
+mod message_ttl;
 mod not_influence_receive_timeout;
+mod poison_pill;
 mod readonly_message_headers;
 mod receive_timeout;
 mod response;
@@ -23,8 +32,8 @@ mod typed_message_or_envelope;
 
 pub(crate) use self::auto_receive_message::*;
 pub use self::{
-  auto_respond::*, continuation::*, failure::*, ignore_dead_letter_logging::*, message::*, message_batch::*,
-  message_handle::*, message_handles::*, message_headers::*, message_or_envelope::*, not_influence_receive_timeout::*,
-  readonly_message_headers::*, receive_timeout::*, response::*, system_message::*, terminate_reason::*, touched::*,
-  typed_message_or_envelope::*,
+  auto_respond::*, continuation::*, envelope_factory::*, failure::*, ignore_dead_letter_logging::*, log_encoder::*, log_field::*,
+  message::*, message_batch::*, message_handle::*, message_handles::*, message_headers::*, message_or_envelope::*,
+  message_sizer::*, message_ttl::*, not_influence_receive_timeout::*, poison_pill::*, readonly_message_headers::*,
+  receive_timeout::*, response::*, system_message::*, terminate_reason::*, touched::*, typed_message_or_envelope::*,
 };
@@ -1,5 +1,4 @@
 use crate::actor::actor::ExtendedPid;
-use crate::actor::message::message::Message;
 use crate::actor::message::message_handle::MessageHandle;
 use crate::generated::actor::Terminated;
 use nexus_actor_message_derive_rs::Message;
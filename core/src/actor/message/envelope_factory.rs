@@ -0,0 +1,37 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::actor::message::message_handle::MessageHandle;
+use crate::actor::message::message_or_envelope::MessageEnvelope;
+
+// EnvelopeFactory builds the envelope of every user message sent from a context of the actor system.
+// It receives the message as sent, which may already be an envelope, e.g. for requests
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+pub struct EnvelopeFactory(Arc<dyn Fn(MessageHandle) -> MessageEnvelope + Send + Sync + 'static>);
+
+impl EnvelopeFactory {
+  pub fn new(f: impl Fn(MessageHandle) -> MessageEnvelope + Send + Sync + 'static) -> Self {
+    EnvelopeFactory(Arc::new(f))
+  }
+
+  pub fn run(&self, message_handle: MessageHandle) -> MessageEnvelope {
+    self.0(message_handle)
+  }
+}
+
+impl Debug for EnvelopeFactory {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "EnvelopeFactory")
+  }
+}
+
+impl PartialEq for EnvelopeFactory {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl Eq for EnvelopeFactory {}
+
+static_assertions::assert_impl_all!(EnvelopeFactory: Send, Sync);
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+  use std::env;
+  use std::sync::Arc;
+  use std::time::Duration;
+
+  use crate::actor::actor::Props;
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{MessagePart, SenderPart, SpawnerPart};
+  use crate::actor::message::{wrap_envelope_with_header, MessageHandle, ReadonlyMessageHeaders};
+  use crate::actor::ConfigOption;
+  use tokio::sync::{Mutex, Notify};
+  use tracing_subscriber::EnvFilter;
+
+  #[tokio::test]
+  async fn test_envelope_factory_stamps_default_headers() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new_config_options([ConfigOption::with_envelope_factory(|message_handle| {
+      wrap_envelope_with_header(message_handle, "node-id", "node-1".to_string())
+    })])
+    .await
+    .unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let received = Arc::new(Mutex::new(vec![]));
+    let notify = Arc::new(Notify::new());
+    let cloned_received = received.clone();
+    let cloned_notify = notify.clone();
+    let echo = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |ctx| {
+          let received = cloned_received.clone();
+          let notify = cloned_notify.clone();
+          async move {
+            if let Some(text) = ctx.get_message_handle().await.to_typed::<String>() {
+              let node_id = ctx
+                .get_message_header_handle()
+                .await
+                .and_then(|headers| headers.get("node-id"));
+              let has_sender = ctx.get_sender().await.is_some();
+              received.lock().await.push((text, node_id, has_sender));
+              notify.notify_one();
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+
+    // Sent from the root context
+    root_context
+      .send(echo.clone(), MessageHandle::new("from root".to_string()))
+      .await;
+    // Requested by another actor, the factory keeps the sender of the request envelope
+    let cloned_echo = echo.clone();
+    let requester = root_context
+      .spawn(
+        Props::from_async_actor_receiver(move |mut ctx| {
+          let echo = cloned_echo.clone();
+          async move {
+            if ctx.get_message_handle().await.to_typed::<i32>().is_some() {
+              ctx.request(echo, MessageHandle::new("from actor".to_string())).await;
+            }
+            Ok(())
+          }
+        })
+        .await,
+      )
+      .await;
+    root_context.send(requester, MessageHandle::new(1)).await;
+
+    tokio::time::timeout(Duration::from_secs(3), async {
+      while received.lock().await.len() < 2 {
+        notify.notified().await;
+      }
+    })
+    .await
+    .expect("messages were not received");
+    assert_eq!(
+      *received.lock().await,
+      vec![
+        ("from root".to_string(), Some("node-1".to_string()), false),
+        ("from actor".to_string(), Some("node-1".to_string()), true),
+      ]
+    );
+  }
+}
@@ -1,4 +1,3 @@
-use crate::actor::message::message::Message;
 use nexus_actor_message_derive_rs::Message;
 use std::fmt::Display;
 
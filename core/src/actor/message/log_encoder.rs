@@ -0,0 +1,45 @@
+use std::fmt::Debug;
+
+use serde_json::Value;
+
+use crate::actor::message::log_field::{Field, FieldType};
+
+// Encoder renders the fields of one log entry as a single line
+pub trait Encoder: Debug + Send + Sync + 'static {
+  fn encode(&self, fields: &[Field]) -> String;
+}
+
+// JsonEncoder renders the fields as a flat JSON object, keeping the order of the fields.
+// Durations are written as nanoseconds, and objects that cannot be serialized as their Debug representation
+#[derive(Debug, Clone, Default)]
+pub struct JsonEncoder;
+
+impl JsonEncoder {
+  pub fn new() -> Self {
+    Self
+  }
+
+  fn to_json(value: &FieldType) -> Value {
+    match value {
+      FieldType::Bool(value) => Value::from(*value),
+      FieldType::Float(value) => Value::from(*value),
+      FieldType::Int(value) => Value::from(*value),
+      FieldType::Int64(value) => Value::from(*value),
+      FieldType::Uint(value) => Value::from(*value),
+      FieldType::Uint64(value) => Value::from(*value),
+      FieldType::Duration(value) => Value::from(u64::try_from(value.as_nanos()).unwrap_or(u64::MAX)),
+      FieldType::String(value) | FieldType::Stringer(value) | FieldType::Error(value) => Value::from(value.as_str()),
+      FieldType::Object { json, debug } => json.clone().unwrap_or_else(|| Value::from(debug.as_str())),
+    }
+  }
+}
+
+impl Encoder for JsonEncoder {
+  fn encode(&self, fields: &[Field]) -> String {
+    let members = fields
+      .iter()
+      .map(|field| format!("{}:{}", Value::from(field.get_key()), Self::to_json(field.get_type())))
+      .collect::<Vec<_>>();
+    format!("{{{}}}", members.join(","))
+  }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+  use std::time::Duration;
+
+  use serde::Serialize;
+
+  use crate::actor::message::{Encoder, Field, JsonEncoder};
+
+  #[derive(Debug, Serialize)]
+  struct Customer {
+    id: u32,
+    name: String,
+  }
+
+  #[test]
+  fn test_json_encoder_encodes_scalar_fields() {
+    let fields = vec![
+      Field::bool("ok", true),
+      Field::float("ratio", 0.5),
+      Field::int("delta", -3),
+      Field::int64("offset", -9_000_000_000),
+      Field::uint("count", 2),
+      Field::uint64("sequence", 18_000_000_000),
+      Field::new("name", "order \"42\""),
+      Field::duration("elapsed", Duration::from_micros(1500)),
+    ];
+
+    assert_eq!(
+      JsonEncoder::new().encode(&fields),
+      r#"{"ok":true,"ratio":0.5,"delta":-3,"offset":-9000000000,"count":2,"sequence":18000000000,"name":"order \"42\"","elapsed":1500000}"#
+    );
+  }
+
+  #[test]
+  fn test_json_encoder_encodes_stringers_errors_and_objects() {
+    let error = std::io::Error::other("disk full");
+    let mut unserializable = HashMap::new();
+    unserializable.insert((1, 2), 3);
+    let fields = vec![
+      Field::stringer("id", &42),
+      Field::error("error", &error),
+      Field::object(
+        "customer",
+        &Customer {
+          id: 7,
+          name: "alice".to_string(),
+        },
+      ),
+      Field::object("pairs", &unserializable),
+    ];
+
+    assert_eq!(
+      JsonEncoder::new().encode(&fields),
+      r#"{"id":"42","error":"disk full","customer":{"id":7,"name":"alice"},"pairs":"{(1, 2): 3}"}"#
+    );
+  }
+
+  #[test]
+  fn test_json_encoder_encodes_no_fields_as_empty_object() {
+    assert_eq!(JsonEncoder::new().encode(&[]), "{}");
+  }
+}
@@ -0,0 +1,125 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
+
+use serde::Serialize;
+
+// FieldType is the typed value of a Field, so encoders can render it without parsing strings back
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldType {
+  Bool(bool),
+  Float(f64),
+  Int(isize),
+  Int64(i64),
+  Uint(usize),
+  Uint64(u64),
+  String(String),
+  Duration(Duration),
+  Stringer(String),
+  Error(String),
+  // Object keeps the serde representation of the value, or None when it could not be serialized,
+  // next to its Debug representation
+  Object {
+    json: Option<serde_json::Value>,
+    debug: String,
+  },
+}
+
+impl Display for FieldType {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      FieldType::Bool(value) => write!(f, "{}", value),
+      FieldType::Float(value) => write!(f, "{}", value),
+      FieldType::Int(value) => write!(f, "{}", value),
+      FieldType::Int64(value) => write!(f, "{}", value),
+      FieldType::Uint(value) => write!(f, "{}", value),
+      FieldType::Uint64(value) => write!(f, "{}", value),
+      FieldType::Duration(value) => write!(f, "{:?}", value),
+      FieldType::String(value) | FieldType::Stringer(value) | FieldType::Error(value) => write!(f, "{}", value),
+      FieldType::Object { debug, .. } => write!(f, "{}", debug),
+    }
+  }
+}
+
+// Field is a key/value pair that logging middleware emits as structured context for a message
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+  key: String,
+  value: FieldType,
+}
+
+impl Field {
+  pub fn new(key: impl Into<String>, value: impl Into<String>) -> Self {
+    Self::of_type(key, FieldType::String(value.into()))
+  }
+
+  pub fn of_type(key: impl Into<String>, value: FieldType) -> Self {
+    Self { key: key.into(), value }
+  }
+
+  pub fn bool(key: impl Into<String>, value: bool) -> Self {
+    Self::of_type(key, FieldType::Bool(value))
+  }
+
+  pub fn float(key: impl Into<String>, value: f64) -> Self {
+    Self::of_type(key, FieldType::Float(value))
+  }
+
+  pub fn int(key: impl Into<String>, value: isize) -> Self {
+    Self::of_type(key, FieldType::Int(value))
+  }
+
+  pub fn int64(key: impl Into<String>, value: i64) -> Self {
+    Self::of_type(key, FieldType::Int64(value))
+  }
+
+  pub fn uint(key: impl Into<String>, value: usize) -> Self {
+    Self::of_type(key, FieldType::Uint(value))
+  }
+
+  pub fn uint64(key: impl Into<String>, value: u64) -> Self {
+    Self::of_type(key, FieldType::Uint64(value))
+  }
+
+  pub fn duration(key: impl Into<String>, value: Duration) -> Self {
+    Self::of_type(key, FieldType::Duration(value))
+  }
+
+  // Stringer renders the value with its Display implementation
+  pub fn stringer(key: impl Into<String>, value: &impl Display) -> Self {
+    Self::of_type(key, FieldType::Stringer(value.to_string()))
+  }
+
+  pub fn error(key: impl Into<String>, value: &impl std::error::Error) -> Self {
+    Self::of_type(key, FieldType::Error(value.to_string()))
+  }
+
+  // Object keeps the serde representation of the value, falling back to Debug when it cannot be serialized
+  pub fn object<T: Serialize + Debug>(key: impl Into<String>, value: &T) -> Self {
+    Self::of_type(
+      key,
+      FieldType::Object {
+        json: serde_json::to_value(value).ok(),
+        debug: format!("{:?}", value),
+      },
+    )
+  }
+
+  pub fn get_key(&self) -> &str {
+    &self.key
+  }
+
+  pub fn get_type(&self) -> &FieldType {
+    &self.value
+  }
+
+  // GetValue returns the value rendered as text
+  pub fn get_value(&self) -> String {
+    self.value.to_string()
+  }
+}
+
+impl Display for Field {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}={}", self.key, self.value)
+  }
+}
@@ -1,3 +1,4 @@
+use crate::actor::message::log_field::Field;
 use nexus_actor_utils_rs::collections::DEFAULT_PRIORITY;
 use std::any::Any;
 use std::fmt::Debug;
@@ -10,6 +11,18 @@ pub trait Message: Debug + Send + Sync + 'static {
   fn as_any(&self) -> &(dyn Any + Send + Sync + 'static);
 
   fn get_type_name(&self) -> String;
+
+  // LogFields returns the fields logging middleware emits for this message.
+  // `#[derive(Message)]` generates it from the fields annotated with `#[message(log)]`
+  fn log_fields(&self) -> Vec<Field> {
+    vec![]
+  }
+
+  // InfluencesReceiveTimeout reports whether receiving this message resets the receive timeout.
+  // `#[derive(Message)]` returns false for types annotated with `#[message(not_influence_receive_timeout)]`
+  fn influences_receive_timeout(&self) -> bool {
+    true
+  }
 }
 
 impl Message for i8 {
@@ -226,6 +239,22 @@ mod tests {
     pub who: String,
   }
 
+  #[derive(Debug, Clone, PartialEq, Message)]
+  pub struct Order {
+    #[message(log)]
+    pub id: u64,
+    pub secret: String,
+    #[message(log)]
+    pub customer: String,
+  }
+
+  #[derive(Debug, Clone, PartialEq, Message)]
+  pub struct Retry(#[message(log)] u32);
+
+  #[derive(Debug, Clone, PartialEq, Message)]
+  #[message(not_influence_receive_timeout)]
+  pub struct Heartbeat;
+
   #[test]
   fn test_message_derive() {
     let msg1 = Hello {
@@ -241,4 +270,43 @@ mod tests {
     assert!(msg1.eq_message(&msg2));
     assert!(!msg1.eq_message(&msg3));
   }
+
+  #[test]
+  fn test_message_derive_log_fields() {
+    let order = Order {
+      id: 42,
+      secret: "s3cr3t".to_string(),
+      customer: "alice".to_string(),
+    };
+
+    assert_eq!(
+      order.log_fields(),
+      vec![Field::stringer("id", &42), Field::stringer("customer", &"alice")]
+    );
+    assert_eq!(Retry(3).log_fields(), vec![Field::stringer("0", &3)]);
+    assert!(Hello {
+      who: "World".to_string()
+    }
+    .log_fields()
+    .is_empty());
+  }
+
+  // Derived without importing the Message trait or Field
+  mod unqualified {
+    #[derive(Debug, Clone, PartialEq, nexus_actor_message_derive_rs::Message)]
+    pub struct Ping(#[message(log)] pub u32);
+  }
+
+  #[test]
+  fn test_message_derive_uses_qualified_paths() {
+    let ping = unqualified::Ping(7);
+    assert!(ping.eq_message(&unqualified::Ping(7)));
+    assert_eq!(ping.log_fields(), vec![Field::stringer("0", &7)]);
+  }
+
+  #[test]
+  fn test_message_derive_not_influence_receive_timeout() {
+    assert!(!Heartbeat.influences_receive_timeout());
+    assert!(Retry(1).influences_receive_timeout());
+  }
 }
@@ -1,4 +1,4 @@
-use crate::actor::message::{Message, MessageHandle};
+use crate::actor::message::MessageHandle;
 use nexus_actor_message_derive_rs::Message;
 
 #[derive(Debug, Clone, PartialEq, Eq, Message)]
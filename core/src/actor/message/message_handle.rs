@@ -1,3 +1,4 @@
+use crate::actor::message::log_field::Field;
 use crate::actor::message::message::Message;
 use nexus_actor_utils_rs::collections::{Element, PriorityMessage};
 use std::any::Any;
@@ -56,6 +57,14 @@ impl Message for MessageHandle {
   fn get_type_name(&self) -> String {
     self.0.get_type_name()
   }
+
+  fn log_fields(&self) -> Vec<Field> {
+    self.0.log_fields()
+  }
+
+  fn influences_receive_timeout(&self) -> bool {
+    self.0.influences_receive_timeout()
+  }
 }
 
 impl PartialEq for MessageHandle {
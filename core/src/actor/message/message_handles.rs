@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 use tokio::sync::RwLock;
@@ -5,7 +6,7 @@ use tokio::sync::RwLock;
 use crate::actor::message::message_handle::MessageHandle;
 
 #[derive(Debug, Clone)]
-pub struct MessageHandles(Arc<RwLock<Vec<MessageHandle>>>);
+pub struct MessageHandles(Arc<RwLock<VecDeque<MessageHandle>>>);
 
 impl MessageHandles {
   pub fn new(msgs: impl IntoIterator<Item = MessageHandle>) -> Self {
@@ -13,11 +14,15 @@ impl MessageHandles {
   }
 
   pub async fn push(&mut self, msg: MessageHandle) {
-    self.0.write().await.push(msg);
+    self.0.write().await.push_back(msg);
   }
 
   pub async fn pop(&mut self) -> Option<MessageHandle> {
-    self.0.write().await.pop()
+    self.0.write().await.pop_back()
+  }
+
+  pub async fn pop_front(&mut self) -> Option<MessageHandle> {
+    self.0.write().await.pop_front()
   }
 
   pub async fn len(&self) -> usize {
@@ -33,6 +38,6 @@ impl MessageHandles {
   }
 
   pub async fn to_values(&self) -> Vec<MessageHandle> {
-    self.0.read().await.clone()
+    self.0.read().await.iter().cloned().collect()
   }
 }
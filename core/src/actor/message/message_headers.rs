@@ -5,6 +5,9 @@ use std::sync::Arc;
 
 use crate::actor::message::readonly_message_headers::ReadonlyMessageHeaders;
 
+// Header carrying the correlation id of a message, see `MessagePart::correlation_id`
+pub const CORRELATION_ID_HEADER: &str = "correlation-id";
+
 #[derive(Debug, Default, Clone)]
 pub struct MessageHeaders {
   inner: Arc<DashMap<String, String>>,
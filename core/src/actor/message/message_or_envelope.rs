@@ -36,10 +36,21 @@ impl MessageEnvelope {
     self
   }
 
+  // WithMessageHandle replaces the message, keeping the header and sender
+  pub fn with_message_handle(mut self, message_handle: MessageHandle) -> Self {
+    self.message_handle = message_handle;
+    self
+  }
+
   pub fn get_message_handle(&self) -> MessageHandle {
     self.message_handle.clone()
   }
 
+  // TypedMessage returns a copy of the inner message if it is a `T`
+  pub fn typed_message<T: Clone + 'static>(&self) -> Option<T> {
+    self.message_handle.to_typed::<T>()
+  }
+
   pub fn get_sender(&self) -> Option<ExtendedPid> {
     self.sender.clone()
   }
@@ -74,6 +85,18 @@ pub fn wrap_envelope(message_handle: MessageHandle) -> MessageEnvelope {
   }
 }
 
+// WrapEnvelopeWithHeader wraps the message like WrapEnvelope and sets `key` on a copy of its header,
+// so the header of the original envelope is left untouched
+pub fn wrap_envelope_with_header(message_handle: MessageHandle, key: &str, value: String) -> MessageEnvelope {
+  let envelope = wrap_envelope(message_handle);
+  let mut header = envelope
+    .get_header()
+    .map(|header| MessageHeaders::with_values(header.to_map()))
+    .unwrap_or_default();
+  header.set(key.to_string(), value);
+  envelope.with_header(header)
+}
+
 pub fn unwrap_envelope(message_handle: MessageHandle) -> (Option<MessageHeaders>, MessageHandle, Option<ExtendedPid>) {
   if let Some(envelope) = message_handle.to_typed::<MessageEnvelope>() {
     (
@@ -6,8 +6,9 @@ mod test {
   use crate::actor::actor::Props;
   use crate::actor::actor_system::ActorSystem;
   use crate::actor::context::{BasePart, MessagePart, SenderPart, SpawnerPart};
-  use crate::actor::message::message::Message;
   use crate::actor::message::message_handle::MessageHandle;
+  use crate::actor::message::message_or_envelope::MessageEnvelope;
+  use crate::actor::message::message_ttl::{with_ttl, SENT_AT_MILLIS_HEADER, TTL_MILLIS_HEADER};
   use crate::actor::message::readonly_message_headers::ReadonlyMessageHeaders;
   use crate::actor::message::response::ResponseHandle;
   use nexus_actor_message_derive_rs::Message;
@@ -50,4 +51,18 @@ mod test {
 
     let _ = f.result().await.unwrap();
   }
+
+  #[test]
+  fn test_with_ttl_leaves_the_original_header_untouched() {
+    let mut original = MessageEnvelope::new(MessageHandle::new(Length(1)));
+    original.set_header("trace-id".to_string(), "t-1".to_string());
+
+    let stamped = with_ttl(MessageHandle::new(original.clone()), Duration::from_secs(1));
+
+    assert_eq!(stamped.get_header_value("trace-id"), Some("t-1".to_string()));
+    assert_eq!(stamped.get_header_value(TTL_MILLIS_HEADER), Some("1000".to_string()));
+    assert!(stamped.get_header_value(SENT_AT_MILLIS_HEADER).is_some());
+    assert_eq!(original.get_header_value(TTL_MILLIS_HEADER), None);
+    assert_eq!(original.get_header_value(SENT_AT_MILLIS_HEADER), None);
+  }
 }
@@ -0,0 +1,48 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::actor::actor::ExtendedPid;
+use crate::actor::message::message_handle::MessageHandle;
+use nexus_actor_message_derive_rs::Message;
+
+// MessageSizer returns the serialized size of a message in bytes, None when the message cannot be serialized.
+// Actors spawned with Props::with_max_message_bytes use it to reject oversized messages
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+pub struct MessageSizer(Arc<dyn Fn(&MessageHandle) -> Option<usize> + Send + Sync + 'static>);
+
+impl MessageSizer {
+  pub fn new(f: impl Fn(&MessageHandle) -> Option<usize> + Send + Sync + 'static) -> Self {
+    MessageSizer(Arc::new(f))
+  }
+
+  pub fn run(&self, message_handle: &MessageHandle) -> Option<usize> {
+    self.0(message_handle)
+  }
+}
+
+impl Debug for MessageSizer {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "MessageSizer")
+  }
+}
+
+impl PartialEq for MessageSizer {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl Eq for MessageSizer {}
+
+static_assertions::assert_impl_all!(MessageSizer: Send, Sync);
+
+// MessageTooLargeEvent is published when an actor rejects a message larger than its max message bytes.
+// The message itself is sent to dead letters
+#[derive(Debug, Clone, PartialEq, Eq, Message)]
+pub struct MessageTooLargeEvent {
+  pub pid: Option<ExtendedPid>,
+  pub message_handle: MessageHandle,
+  pub size: usize,
+  pub max_bytes: usize,
+}
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+  use std::env;
+  use std::sync::Arc;
+  use std::time::Duration;
+
+  use crate::actor::actor::Props;
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{MessagePart, SenderPart, SpawnerPart};
+  use crate::actor::dispatch::DeadLetterEvent;
+  use crate::actor::message::{MessageHandle, MessageTooLargeEvent};
+  use crate::actor::ConfigOption;
+  use tokio::sync::{Mutex, Notify};
+  use tracing_subscriber::EnvFilter;
+
+  #[tokio::test]
+  async fn test_max_message_bytes_rejects_oversized_messages() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    // Only strings are serializable here, their size is their length
+    let system = ActorSystem::new_config_options([ConfigOption::with_message_sizer(|message_handle| {
+      message_handle.to_typed::<String>().map(|s| s.len())
+    })])
+    .await
+    .unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let received = Arc::new(Mutex::new(vec![]));
+    let received_notify = Arc::new(Notify::new());
+    let cloned_received = received.clone();
+    let cloned_received_notify = received_notify.clone();
+    let props = Props::from_async_actor_receiver_with_opts(
+      move |ctx| {
+        let received = cloned_received.clone();
+        let notify = cloned_received_notify.clone();
+        async move {
+          let message_handle = ctx.get_message_handle().await;
+          if let Some(s) = message_handle.to_typed::<String>() {
+            received.lock().await.push(s);
+            notify.notify_one();
+          } else if let Some(n) = message_handle.to_typed::<i32>() {
+            received.lock().await.push(n.to_string());
+            notify.notify_one();
+          }
+          Ok(())
+        }
+      },
+      [Props::with_max_message_bytes(8)],
+    )
+    .await;
+    let pid = root_context.spawn(props).await;
+
+    let too_large = Arc::new(Mutex::new(vec![]));
+    let dead_letters = Arc::new(Mutex::new(vec![]));
+    let rejected_notify = Arc::new(Notify::new());
+    let cloned_too_large = too_large.clone();
+    let cloned_dead_letters = dead_letters.clone();
+    let cloned_rejected_notify = rejected_notify.clone();
+    let cloned_pid = pid.clone();
+    system
+      .get_event_stream()
+      .await
+      .subscribe(move |msg| {
+        let too_large = cloned_too_large.clone();
+        let dead_letters = cloned_dead_letters.clone();
+        let rejected_notify = cloned_rejected_notify.clone();
+        let pid = cloned_pid.clone();
+        async move {
+          if let Some(event) = msg.to_typed::<MessageTooLargeEvent>() {
+            too_large.lock().await.push((event.pid, event.size, event.max_bytes));
+          } else if let Some(dead_letter) = msg.to_typed::<DeadLetterEvent>() {
+            if dead_letter.pid.as_ref() == Some(&pid) {
+              if let Some(s) = dead_letter.message_handle.to_typed::<String>() {
+                dead_letters.lock().await.push(s);
+                rejected_notify.notify_one();
+              }
+            }
+          }
+        }
+      })
+      .await;
+
+    root_context
+      .send(pid.clone(), MessageHandle::new("far too large".to_string()))
+      .await;
+    tokio::time::timeout(Duration::from_secs(3), rejected_notify.notified())
+      .await
+      .expect("oversized message was not dead-lettered");
+    assert_eq!(*too_large.lock().await, vec![(Some(pid.clone()), 13, 8)]);
+    assert_eq!(*dead_letters.lock().await, vec!["far too large".to_string()]);
+
+    // Small messages, and messages the sizer cannot serialize, are received
+    root_context
+      .send(pid.clone(), MessageHandle::new("fits".to_string()))
+      .await;
+    root_context.send(pid.clone(), MessageHandle::new(1_000_000_i32)).await;
+    tokio::time::timeout(Duration::from_secs(3), async {
+      while received.lock().await.len() < 2 {
+        received_notify.notified().await;
+      }
+    })
+    .await
+    .expect("messages within the limit were not received");
+    assert_eq!(*received.lock().await, vec!["fits".to_string(), "1000000".to_string()]);
+  }
+}
@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use crate::actor::message::message_handle::MessageHandle;
+use crate::actor::message::message_headers::MessageHeaders;
+use crate::actor::message::message_or_envelope::{wrap_envelope, MessageEnvelope};
+use nexus_actor_message_derive_rs::Message;
+
+// Header carrying how long, in milliseconds, a message stays worth processing after it was sent
+pub const TTL_MILLIS_HEADER: &str = "x-ttl-millis";
+// Header carrying the time the message was sent, in milliseconds since the Unix epoch
+pub const SENT_AT_MILLIS_HEADER: &str = "x-sent-at-millis";
+
+// Expired is sent to dead letters in place of a message that was dequeued after its TTL had elapsed
+#[derive(Debug, Clone, PartialEq, Message)]
+pub struct Expired {
+  pub message_handle: MessageHandle,
+  pub ttl: Duration,
+}
+
+// WithTtl wraps the message in an envelope stamped with the TTL and the current time. The mailbox drops
+// the message instead of processing it once the TTL has elapsed. The stamps go on a copy of the header,
+// so an envelope the caller still holds is left untouched
+pub fn with_ttl(message_handle: MessageHandle, ttl: Duration) -> MessageEnvelope {
+  let envelope = wrap_envelope(message_handle);
+  let mut header = envelope
+    .get_header()
+    .map(|header| MessageHeaders::with_values(header.to_map()))
+    .unwrap_or_default();
+  header.set(TTL_MILLIS_HEADER.to_string(), ttl.as_millis().to_string());
+  header.set(
+    SENT_AT_MILLIS_HEADER.to_string(),
+    chrono::Utc::now().timestamp_millis().to_string(),
+  );
+  envelope.with_header(header)
+}
+
+// ExpiredMessage returns the Expired notice for a message whose TTL has elapsed
+pub(crate) fn expired_message(message_handle: &MessageHandle) -> Option<Expired> {
+  let envelope = message_handle.to_typed::<MessageEnvelope>()?;
+  let ttl_millis = envelope.get_header_value(TTL_MILLIS_HEADER)?.parse::<i64>().ok()?;
+  let sent_at_millis = envelope.get_header_value(SENT_AT_MILLIS_HEADER)?.parse::<i64>().ok()?;
+  if chrono::Utc::now().timestamp_millis() - sent_at_millis <= ttl_millis {
+    return None;
+  }
+  Some(Expired {
+    message_handle: envelope.get_message_handle(),
+    ttl: Duration::from_millis(ttl_millis.max(0) as u64),
+  })
+}
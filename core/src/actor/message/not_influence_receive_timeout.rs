@@ -1,3 +1,4 @@
+use crate::actor::message::message::Message;
 use std::any::Any;
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -9,3 +10,24 @@ pub trait NotInfluenceReceiveTimeout: Debug + Send + Sync + 'static {
 
 #[derive(Debug, Clone)]
 pub struct NotInfluenceReceiveTimeoutHandle(pub Arc<dyn NotInfluenceReceiveTimeout>);
+
+impl Message for NotInfluenceReceiveTimeoutHandle {
+  fn eq_message(&self, other: &dyn Message) -> bool {
+    other
+      .as_any()
+      .downcast_ref::<NotInfluenceReceiveTimeoutHandle>()
+      .is_some_and(|other| Arc::ptr_eq(&self.0, &other.0))
+  }
+
+  fn as_any(&self) -> &(dyn Any + Send + Sync + 'static) {
+    self
+  }
+
+  fn get_type_name(&self) -> String {
+    std::any::type_name_of_val(self).to_string()
+  }
+
+  fn influences_receive_timeout(&self) -> bool {
+    false
+  }
+}
@@ -1,7 +1,10 @@
 use crate::actor::message::Message;
-use crate::generated::actor::PoisonPill;
 use std::any::Any;
 
+// PoisonPill is a user message: it stops the receiving actor once the user messages queued before it have
+// been processed, see StopperPart::poison
+pub use crate::generated::actor::PoisonPill;
+
 impl Message for PoisonPill {
   fn eq_message(&self, other: &dyn Message) -> bool {
     let msg = other.as_any().downcast_ref::<PoisonPill>();
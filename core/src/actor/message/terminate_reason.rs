@@ -3,6 +3,7 @@ pub enum TerminateReason {
   Stopped = 0,
   AddressTerminated = 1,
   NotFound = 2,
+  Failed = 3,
 }
 
 impl TerminateReason {
@@ -15,6 +16,7 @@ impl TerminateReason {
       TerminateReason::Stopped => "Stopped",
       TerminateReason::AddressTerminated => "AddressTerminated",
       TerminateReason::NotFound => "NotFound",
+      TerminateReason::Failed => "Failed",
     }
   }
 
@@ -24,6 +26,7 @@ impl TerminateReason {
       "Stopped" => Some(Self::Stopped),
       "AddressTerminated" => Some(Self::AddressTerminated),
       "NotFound" => Some(Self::NotFound),
+      "Failed" => Some(Self::Failed),
       _ => None,
     }
   }
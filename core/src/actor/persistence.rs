@@ -0,0 +1,6 @@
+mod in_memory_journal;
+mod journal;
+mod persistence_test;
+mod persistent_actor;
+
+pub use {self::in_memory_journal::*, self::journal::*, self::persistent_actor::*};
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::actor::message::MessageHandle;
+use crate::actor::persistence::journal::{Journal, JournalError, Snapshot};
+
+#[derive(Debug, Default)]
+struct EventLog {
+  deleted_to: u64,
+  events: Vec<MessageHandle>,
+}
+
+// InMemoryJournal keeps the events in process memory. They are lost with the process, so it is meant for tests
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryJournal {
+  events: Arc<RwLock<HashMap<String, EventLog>>>,
+  snapshots: Arc<RwLock<HashMap<String, Snapshot>>>,
+}
+
+impl InMemoryJournal {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub async fn len(&self, persistence_id: &str) -> usize {
+    let mg = self.events.read().await;
+    mg.get(persistence_id).map_or(0, |log| log.events.len())
+  }
+
+  pub async fn is_empty(&self, persistence_id: &str) -> bool {
+    self.len(persistence_id).await == 0
+  }
+}
+
+#[async_trait]
+impl Journal for InMemoryJournal {
+  async fn append(&self, persistence_id: &str, event: MessageHandle) -> Result<(), JournalError> {
+    let mut mg = self.events.write().await;
+    mg.entry(persistence_id.to_string()).or_default().events.push(event);
+    Ok(())
+  }
+
+  async fn read(
+    &self,
+    persistence_id: &str,
+    from_sequence_nr: u64,
+  ) -> Result<Vec<(u64, MessageHandle)>, JournalError> {
+    let mg = self.events.read().await;
+    Ok(
+      mg.get(persistence_id)
+        .map(|log| {
+          (log.deleted_to + 1..)
+            .zip(log.events.iter().cloned())
+            .skip_while(|(sequence_nr, _)| *sequence_nr < from_sequence_nr)
+            .collect()
+        })
+        .unwrap_or_default(),
+    )
+  }
+
+  async fn persist_snapshot(&self, persistence_id: &str, snapshot: Snapshot) -> Result<(), JournalError> {
+    let mut mg = self.snapshots.write().await;
+    mg.insert(persistence_id.to_string(), snapshot);
+    Ok(())
+  }
+
+  async fn load_snapshot(&self, persistence_id: &str) -> Result<Option<Snapshot>, JournalError> {
+    let mg = self.snapshots.read().await;
+    Ok(mg.get(persistence_id).cloned())
+  }
+
+  async fn delete_to(&self, persistence_id: &str, to_sequence_nr: u64) -> Result<(), JournalError> {
+    let mut mg = self.events.write().await;
+    if let Some(log) = mg.get_mut(persistence_id) {
+      let count = (to_sequence_nr.saturating_sub(log.deleted_to) as usize).min(log.events.len());
+      log.events.drain(..count);
+      log.deleted_to += count as u64;
+    }
+    Ok(())
+  }
+}
+
+static_assertions::assert_impl_all!(InMemoryJournal: Send, Sync);
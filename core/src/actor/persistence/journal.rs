@@ -0,0 +1,112 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::actor::message::MessageHandle;
+use nexus_actor_message_derive_rs::Message;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum JournalError {
+  #[error("No journal is configured for the actor")]
+  NotConfigured,
+  #[error("Journal storage failed: {0}")]
+  Storage(String),
+  #[error("The journal does not support snapshots")]
+  SnapshotsUnsupported,
+}
+
+static_assertions::assert_impl_all!(JournalError: Send, Sync);
+
+// Snapshot is the state of an actor once its first `sequence_nr` events have been applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+  pub sequence_nr: u64,
+  pub state: MessageHandle,
+}
+
+// RecoverSnapshot delivers the latest snapshot to a starting actor, before the events persisted after it
+#[derive(Debug, Clone, PartialEq, Eq, Message)]
+pub struct RecoverSnapshot(pub MessageHandle);
+
+// Journal stores the events persisted by actors, keyed by persistence id, in the order they were appended.
+// The first event of a persistence id has the sequence number 1
+#[async_trait]
+pub trait Journal: Debug + Send + Sync + 'static {
+  async fn append(&self, persistence_id: &str, event: MessageHandle) -> Result<(), JournalError>;
+
+  // Read returns the events of the persistence id from the sequence number `from_sequence_nr` on, oldest first,
+  // each with its sequence number
+  async fn read(
+    &self,
+    persistence_id: &str,
+    from_sequence_nr: u64,
+  ) -> Result<Vec<(u64, MessageHandle)>, JournalError>;
+
+  // PersistSnapshot replaces the snapshot of the persistence id. Journals without snapshot support fail with
+  // SnapshotsUnsupported, so the events a snapshot would cover are never deleted
+  async fn persist_snapshot(&self, _persistence_id: &str, _snapshot: Snapshot) -> Result<(), JournalError> {
+    Err(JournalError::SnapshotsUnsupported)
+  }
+
+  async fn load_snapshot(&self, _persistence_id: &str) -> Result<Option<Snapshot>, JournalError> {
+    Ok(None)
+  }
+
+  // DeleteTo removes the events of the persistence id up to and including `to_sequence_nr`. Sequence numbers
+  // of the remaining events are unchanged. Journals without deletion support keep the events
+  async fn delete_to(&self, _persistence_id: &str, _to_sequence_nr: u64) -> Result<(), JournalError> {
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct JournalHandle(Arc<dyn Journal>);
+
+impl JournalHandle {
+  pub fn new_arc(journal: Arc<dyn Journal>) -> Self {
+    Self(journal)
+  }
+
+  pub fn new(journal: impl Journal + 'static) -> Self {
+    Self(Arc::new(journal))
+  }
+}
+
+impl PartialEq for JournalHandle {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl Eq for JournalHandle {}
+
+#[async_trait]
+impl Journal for JournalHandle {
+  async fn append(&self, persistence_id: &str, event: MessageHandle) -> Result<(), JournalError> {
+    self.0.append(persistence_id, event).await
+  }
+
+  async fn read(
+    &self,
+    persistence_id: &str,
+    from_sequence_nr: u64,
+  ) -> Result<Vec<(u64, MessageHandle)>, JournalError> {
+    self.0.read(persistence_id, from_sequence_nr).await
+  }
+
+  async fn persist_snapshot(&self, persistence_id: &str, snapshot: Snapshot) -> Result<(), JournalError> {
+    self.0.persist_snapshot(persistence_id, snapshot).await
+  }
+
+  async fn load_snapshot(&self, persistence_id: &str) -> Result<Option<Snapshot>, JournalError> {
+    self.0.load_snapshot(persistence_id).await
+  }
+
+  async fn delete_to(&self, persistence_id: &str, to_sequence_nr: u64) -> Result<(), JournalError> {
+    self.0.delete_to(persistence_id, to_sequence_nr).await
+  }
+}
+
+static_assertions::assert_impl_all!(JournalHandle: Send, Sync);
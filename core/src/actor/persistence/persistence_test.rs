@@ -0,0 +1,397 @@
+#[cfg(test)]
+mod tests {
+  use std::env;
+  use std::time::Duration;
+
+  use async_trait::async_trait;
+  use nexus_actor_message_derive_rs::Message;
+  use tracing_subscriber::EnvFilter;
+
+  use crate::actor::actor::{Actor, ActorError, ErrorReason, Props};
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{
+    BasePart, ContextHandle, MessagePart, PersistencePart, RootContext, SenderPart, SpawnerPart,
+  };
+  use crate::actor::message::{MessageHandle, ResponseHandle};
+  use crate::actor::persistence::{
+    InMemoryJournal, Journal, JournalError, JournalHandle, PersistentActor, PersistentActorWrapper, Snapshot,
+  };
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Deposit(i32);
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Deposited(i32);
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Crash;
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct GetBalance;
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct Balance {
+    amount: i32,
+    replayed: usize,
+  }
+
+  #[derive(Debug, Default)]
+  struct LedgerActor {
+    balance: i32,
+    replayed: usize,
+  }
+
+  #[async_trait]
+  impl Actor for LedgerActor {
+    async fn receive(&mut self, mut ctx: ContextHandle) -> Result<(), ActorError> {
+      let message_handle = ctx.get_message_handle().await;
+      if let Some(Deposit(amount)) = message_handle.to_typed::<Deposit>() {
+        ctx
+          .persist(MessageHandle::new(Deposited(amount)))
+          .await
+          .map_err(|err| ActorError::ReceiveError(ErrorReason::new(err.to_string(), 0)))?;
+        self.balance += amount;
+      } else if let Some(Deposited(amount)) = message_handle.to_typed::<Deposited>() {
+        assert!(ctx.is_recovering().await);
+        self.balance += amount;
+        self.replayed += 1;
+      } else if message_handle.to_typed::<Crash>().is_some() {
+        return Err(ActorError::ReceiveError(ErrorReason::new("crash", 0)));
+      } else if message_handle.to_typed::<GetBalance>().is_some() {
+        ctx
+          .respond(ResponseHandle::new(Balance {
+            amount: self.balance,
+            replayed: self.replayed,
+          }))
+          .await;
+      }
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_persist_and_replay_rebuilds_state_after_crash() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let journal = InMemoryJournal::new();
+
+    let props = Props::from_async_actor_producer_with_opts(
+      |_| async { LedgerActor::default() },
+      [Props::with_journal(JournalHandle::new(journal.clone()))],
+    )
+    .await;
+    let pid = root_context.spawn_named(props, "ledger").await.unwrap();
+
+    let cloned_root_context = root_context.clone();
+    let get_balance = move |pid| {
+      let root_context = cloned_root_context.clone();
+      async move {
+        root_context
+          .request_future(pid, MessageHandle::new(GetBalance), Duration::from_secs(5))
+          .await
+          .result()
+          .await
+          .unwrap()
+          .to_typed::<Balance>()
+          .unwrap()
+      }
+    };
+
+    root_context.send(pid.clone(), MessageHandle::new(Deposit(10))).await;
+    root_context.send(pid.clone(), MessageHandle::new(Deposit(5))).await;
+    assert_eq!(
+      get_balance(pid.clone()).await,
+      Balance {
+        amount: 15,
+        replayed: 0
+      }
+    );
+    assert_eq!(journal.len("ledger").await, 2);
+
+    // The restarted actor starts from a blank state and gets it back from the journal
+    root_context.send(pid.clone(), MessageHandle::new(Crash)).await;
+    assert_eq!(
+      get_balance(pid.clone()).await,
+      Balance {
+        amount: 15,
+        replayed: 2
+      }
+    );
+    assert_eq!(journal.len("ledger").await, 2);
+
+    root_context.send(pid.clone(), MessageHandle::new(Deposit(1))).await;
+    assert_eq!(
+      get_balance(pid).await,
+      Balance {
+        amount: 16,
+        replayed: 2
+      }
+    );
+    assert_eq!(journal.len("ledger").await, 3);
+  }
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct TakeSnapshot;
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct AccountState(i32);
+
+  #[derive(Debug, Default)]
+  struct Account {
+    balance: i32,
+    applied: usize,
+  }
+
+  #[async_trait]
+  impl PersistentActor for Account {
+    async fn apply_event(&mut self, event: MessageHandle) {
+      if let Some(Deposited(amount)) = event.to_typed::<Deposited>() {
+        self.balance += amount;
+        self.applied += 1;
+      }
+    }
+
+    async fn apply_snapshot(&mut self, state: MessageHandle) {
+      if let Some(AccountState(balance)) = state.to_typed::<AccountState>() {
+        self.balance = balance;
+      }
+    }
+
+    fn snapshot(&self) -> Option<MessageHandle> {
+      Some(MessageHandle::new(AccountState(self.balance)))
+    }
+
+    async fn receive_command(&mut self, mut ctx: ContextHandle) -> Result<(), ActorError> {
+      let message_handle = ctx.get_message_handle().await;
+      if let Some(Deposit(amount)) = message_handle.to_typed::<Deposit>() {
+        self
+          .persist_event(&mut ctx, MessageHandle::new(Deposited(amount)))
+          .await?;
+      } else if message_handle.to_typed::<TakeSnapshot>().is_some() {
+        ctx
+          .persist_snapshot(MessageHandle::new(AccountState(self.balance)))
+          .await
+          .map_err(|err| ActorError::ReceiveError(ErrorReason::from_error(err, 0)))?;
+        ctx.respond(ResponseHandle::new(AccountState(self.balance))).await;
+      } else if message_handle.to_typed::<Crash>().is_some() {
+        return Err(ActorError::ReceiveError(ErrorReason::new("crash", 0)));
+      } else if message_handle.to_typed::<GetBalance>().is_some() {
+        ctx
+          .respond(ResponseHandle::new(Balance {
+            amount: self.balance,
+            replayed: self.applied,
+          }))
+          .await;
+      }
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_persistent_actor_recovers_from_snapshot_and_later_events() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let journal = InMemoryJournal::new();
+
+    let props = Props::from_async_actor_producer_with_opts(
+      |_| async { PersistentActorWrapper::new(Account::default()) },
+      [Props::with_journal(JournalHandle::new(journal.clone()))],
+    )
+    .await;
+    let pid = root_context.spawn_named(props, "account").await.unwrap();
+
+    let request = |root_context: RootContext, pid, message: MessageHandle| async move {
+      root_context
+        .request_future(pid, message, Duration::from_secs(5))
+        .await
+        .result()
+        .await
+        .unwrap()
+    };
+
+    root_context.send(pid.clone(), MessageHandle::new(Deposit(10))).await;
+    root_context.send(pid.clone(), MessageHandle::new(Deposit(5))).await;
+    let state = request(root_context.clone(), pid.clone(), MessageHandle::new(TakeSnapshot)).await;
+    assert_eq!(state.to_typed::<AccountState>(), Some(AccountState(15)));
+    assert_eq!(
+      journal.load_snapshot("account").await.unwrap(),
+      Some(Snapshot {
+        sequence_nr: 2,
+        state: MessageHandle::new(AccountState(15)),
+      })
+    );
+    root_context.send(pid.clone(), MessageHandle::new(Deposit(1))).await;
+
+    // Only the deposit made after the snapshot is replayed on top of it
+    root_context.send(pid.clone(), MessageHandle::new(Crash)).await;
+    let balance = request(root_context.clone(), pid.clone(), MessageHandle::new(GetBalance)).await;
+    assert_eq!(
+      balance.to_typed::<Balance>(),
+      Some(Balance {
+        amount: 16,
+        replayed: 1
+      })
+    );
+    assert_eq!(journal.len("account").await, 3);
+  }
+
+  #[tokio::test]
+  async fn test_in_memory_journal_reads_from_sequence_nr() {
+    let journal = InMemoryJournal::new();
+    for amount in 1..=3 {
+      journal
+        .append("account", MessageHandle::new(Deposited(amount)))
+        .await
+        .unwrap();
+    }
+    let events = journal.read("account", 2).await.unwrap();
+    assert_eq!(
+      events,
+      vec![
+        (2, MessageHandle::new(Deposited(2))),
+        (3, MessageHandle::new(Deposited(3)))
+      ]
+    );
+    assert!(journal.read("account", 4).await.unwrap().is_empty());
+    assert_eq!(journal.load_snapshot("account").await.unwrap(), None);
+  }
+
+  #[tokio::test]
+  async fn test_snapshot_every_n_events_bounds_replay() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let journal = InMemoryJournal::new();
+
+    let props = Props::from_async_actor_producer_with_opts(
+      |_| async { PersistentActorWrapper::new(Account::default()).snapshot_every_n_events(10) },
+      [Props::with_journal(JournalHandle::new(journal.clone()))],
+    )
+    .await;
+    let pid = root_context.spawn_named(props, "savings").await.unwrap();
+
+    for _ in 0..25 {
+      root_context.send(pid.clone(), MessageHandle::new(Deposit(1))).await;
+    }
+    root_context.send(pid.clone(), MessageHandle::new(Crash)).await;
+
+    let balance = root_context
+      .request_future(pid, MessageHandle::new(GetBalance), Duration::from_secs(5))
+      .await
+      .result()
+      .await
+      .unwrap();
+    assert_eq!(
+      balance.to_typed::<Balance>(),
+      Some(Balance {
+        amount: 25,
+        replayed: 5
+      })
+    );
+    assert_eq!(
+      journal.load_snapshot("savings").await.unwrap(),
+      Some(Snapshot {
+        sequence_nr: 20,
+        state: MessageHandle::new(AccountState(20)),
+      })
+    );
+    assert_eq!(journal.len("savings").await, 5);
+  }
+
+  #[tokio::test]
+  async fn test_in_memory_journal_keeps_sequence_nrs_after_delete() {
+    let journal = InMemoryJournal::new();
+    for amount in 1..=5 {
+      journal
+        .append("account", MessageHandle::new(Deposited(amount)))
+        .await
+        .unwrap();
+    }
+    journal.delete_to("account", 3).await.unwrap();
+    assert_eq!(journal.len("account").await, 2);
+    assert_eq!(
+      journal.read("account", 1).await.unwrap(),
+      vec![
+        (4, MessageHandle::new(Deposited(4))),
+        (5, MessageHandle::new(Deposited(5)))
+      ]
+    );
+    assert_eq!(
+      journal.read("account", 5).await.unwrap(),
+      vec![(5, MessageHandle::new(Deposited(5)))]
+    );
+  }
+
+  // EventsOnlyJournal stores events but keeps the default snapshot behavior of the Journal trait
+  #[derive(Debug, Clone, Default)]
+  struct EventsOnlyJournal(InMemoryJournal);
+
+  #[async_trait]
+  impl Journal for EventsOnlyJournal {
+    async fn append(&self, persistence_id: &str, event: MessageHandle) -> Result<(), JournalError> {
+      self.0.append(persistence_id, event).await
+    }
+
+    async fn read(
+      &self,
+      persistence_id: &str,
+      from_sequence_nr: u64,
+    ) -> Result<Vec<(u64, MessageHandle)>, JournalError> {
+      self.0.read(persistence_id, from_sequence_nr).await
+    }
+
+    async fn delete_to(&self, persistence_id: &str, to_sequence_nr: u64) -> Result<(), JournalError> {
+      self.0.delete_to(persistence_id, to_sequence_nr).await
+    }
+  }
+
+  #[tokio::test]
+  async fn test_snapshot_policy_keeps_events_without_snapshot_support() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let journal = EventsOnlyJournal::default();
+
+    let props = Props::from_async_actor_producer_with_opts(
+      |_| async { PersistentActorWrapper::new(Account::default()).snapshot_every_n_events(10) },
+      [Props::with_journal(JournalHandle::new(journal.clone()))],
+    )
+    .await;
+    let pid = root_context.spawn_named(props, "checking").await.unwrap();
+
+    for _ in 0..25 {
+      root_context.send(pid.clone(), MessageHandle::new(Deposit(1))).await;
+    }
+    root_context.send(pid.clone(), MessageHandle::new(Crash)).await;
+
+    let balance = root_context
+      .request_future(pid, MessageHandle::new(GetBalance), Duration::from_secs(5))
+      .await
+      .result()
+      .await
+      .unwrap();
+    assert_eq!(
+      balance.to_typed::<Balance>(),
+      Some(Balance {
+        amount: 25,
+        replayed: 25
+      })
+    );
+    assert_eq!(journal.0.len("checking").await, 25);
+    assert_eq!(
+      journal
+        .persist_snapshot(
+          "checking",
+          Snapshot {
+            sequence_nr: 25,
+            state: MessageHandle::new(AccountState(25)),
+          }
+        )
+        .await,
+      Err(JournalError::SnapshotsUnsupported)
+    );
+  }
+}
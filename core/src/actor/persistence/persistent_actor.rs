@@ -0,0 +1,110 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+
+use crate::actor::actor::{Actor, ActorError, ErrorReason};
+use crate::actor::context::{ContextHandle, MessagePart, PersistencePart};
+use crate::actor::message::MessageHandle;
+use crate::actor::persistence::{JournalError, RecoverSnapshot};
+
+// PersistentActor keeps its state as a sequence of events. While the actor is recovering, PersistentActorWrapper
+// feeds the latest snapshot to apply_snapshot and the journaled events to apply_event; every other message is a
+// command passed to receive_command
+#[async_trait]
+pub trait PersistentActor: Debug + Send + Sync + 'static {
+  async fn apply_event(&mut self, event: MessageHandle);
+
+  async fn apply_snapshot(&mut self, _: MessageHandle) {}
+
+  // Snapshot returns the current state for the wrapper's snapshot policy. Actors returning None are never
+  // snapshotted automatically
+  fn snapshot(&self) -> Option<MessageHandle> {
+    None
+  }
+
+  async fn receive_command(&mut self, context_handle: ContextHandle) -> Result<(), ActorError>;
+
+  // PersistEvent journals the event and then applies it to the actor's state
+  async fn persist_event(
+    &mut self,
+    context_handle: &mut ContextHandle,
+    event: MessageHandle,
+  ) -> Result<(), ActorError> {
+    context_handle
+      .persist(event.clone())
+      .await
+      .map_err(|err| ActorError::ReceiveError(ErrorReason::from_error(err, 0)))?;
+    self.apply_event(event).await;
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct PersistentActorWrapper<A: PersistentActor> {
+  actor: A,
+  snapshot_interval: Option<u64>,
+  last_snapshot_nr: u64,
+}
+
+impl<A: PersistentActor> PersistentActorWrapper<A> {
+  pub fn new(actor: A) -> Self {
+    Self {
+      actor,
+      snapshot_interval: None,
+      last_snapshot_nr: 0,
+    }
+  }
+
+  // SnapshotEveryNEvents snapshots the actor's state once n events have been persisted since the last snapshot,
+  // and deletes the journaled events the snapshot covers. The policy is turned off if the journal does not support
+  // snapshots, the events are then kept
+  pub fn snapshot_every_n_events(mut self, n: u64) -> Self {
+    self.snapshot_interval = Some(n.max(1));
+    self
+  }
+
+  async fn maybe_snapshot(&mut self, context_handle: &mut ContextHandle) -> Result<(), JournalError> {
+    let Some(interval) = self.snapshot_interval else {
+      return Ok(());
+    };
+    let sequence_nr = context_handle.sequence_nr().await;
+    if sequence_nr < self.last_snapshot_nr + interval {
+      return Ok(());
+    }
+    let Some(state) = self.actor.snapshot() else {
+      return Ok(());
+    };
+    match context_handle.persist_snapshot(state).await {
+      Err(JournalError::SnapshotsUnsupported) => {
+        tracing::warn!("Journal does not support snapshots, snapshot_every_n_events is turned off");
+        self.snapshot_interval = None;
+        return Ok(());
+      }
+      result => result?,
+    }
+    self.last_snapshot_nr = sequence_nr;
+    context_handle.delete_events_to(sequence_nr).await
+  }
+}
+
+#[async_trait]
+impl<A: PersistentActor> Actor for PersistentActorWrapper<A> {
+  async fn receive(&mut self, mut context_handle: ContextHandle) -> Result<(), ActorError> {
+    if !context_handle.is_recovering().await {
+      self.actor.receive_command(context_handle.clone()).await?;
+      return self
+        .maybe_snapshot(&mut context_handle)
+        .await
+        .map_err(|err| ActorError::ReceiveError(ErrorReason::from_error(err, 0)));
+    }
+    let message_handle = context_handle.get_message_handle().await;
+    match message_handle.to_typed::<RecoverSnapshot>() {
+      Some(RecoverSnapshot(state)) => {
+        self.last_snapshot_nr = context_handle.sequence_nr().await;
+        self.actor.apply_snapshot(state).await
+      }
+      None => self.actor.apply_event(message_handle).await,
+    }
+    Ok(())
+  }
+}
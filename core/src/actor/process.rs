@@ -21,6 +21,12 @@ pub trait Process: Debug + Send + Sync + 'static {
   fn as_any(&self) -> &dyn std::any::Any;
 }
 
+// Diagnostics reports the state behind a process, looked up by ProcessRegistry::get_process_diagnostics
+#[async_trait]
+pub trait Diagnostics: Debug + Send + Sync + 'static {
+  async fn get_diagnostics(&self) -> String;
+}
+
 #[derive(Debug, Clone)]
 pub struct ProcessHandle(Arc<dyn Process>);
 
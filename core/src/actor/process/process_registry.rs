@@ -1,17 +1,20 @@
+use std::collections::BTreeMap;
 use std::fmt::{Debug, Formatter};
 use std::future::Future;
 use std::hash::{Hash, Hasher};
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use dashmap::DashMap;
 use futures::future::BoxFuture;
+use regex::Regex;
 use siphasher::sip::SipHasher;
 use tokio::sync::RwLock;
 
 use crate::actor::actor::ActorProcess;
 use crate::actor::actor::ExtendedPid;
 use crate::actor::actor_system::ActorSystem;
+use crate::actor::dispatch::FuturePressureEvent;
 use crate::actor::process::{Process, ProcessHandle};
 use crate::generated::actor::Pid;
 
@@ -20,6 +23,9 @@ const LOCAL_ADDRESS: &str = "nonhost";
 #[derive(Debug, Clone)]
 pub struct ProcessRegistry {
   sequence_id: Arc<AtomicU64>,
+  pending_futures: Arc<AtomicUsize>,
+  process_count: Arc<AtomicUsize>,
+  future_pressure_high: Arc<AtomicBool>,
   actor_system: ActorSystem,
   address: Arc<RwLock<String>>,
   local_pids: SliceMap,
@@ -94,6 +100,9 @@ impl ProcessRegistry {
   pub fn new(actor_system: ActorSystem) -> Self {
     Self {
       sequence_id: Arc::new(AtomicU64::new(0)),
+      pending_futures: Arc::new(AtomicUsize::new(0)),
+      process_count: Arc::new(AtomicUsize::new(0)),
+      future_pressure_high: Arc::new(AtomicBool::new(false)),
       actor_system,
       address: Arc::new(RwLock::new(LOCAL_ADDRESS.to_string())),
       local_pids: SliceMap::new(),
@@ -121,6 +130,60 @@ impl ProcessRegistry {
     uint64_to_id(counter)
   }
 
+  // TryAcquireFutureSlot reserves a slot for a new future process.
+  // It returns false when `max` pending futures already exist; a `max` of 0 means unlimited.
+  pub(crate) fn try_acquire_future_slot(&self, max: usize) -> bool {
+    self
+      .pending_futures
+      .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |pending| {
+        (max == 0 || pending < max).then_some(pending + 1)
+      })
+      .is_ok()
+  }
+
+  pub(crate) fn release_future_slot(&self) {
+    self.pending_futures.fetch_sub(1, Ordering::SeqCst);
+  }
+
+  pub fn get_pending_futures(&self) -> usize {
+    self.pending_futures.load(Ordering::SeqCst)
+  }
+
+  // GetProcessCount returns the number of processes registered locally, including system processes
+  pub fn get_process_count(&self) -> usize {
+    self.process_count.load(Ordering::SeqCst)
+  }
+
+  // HasLocalProcess reports whether id is registered, without get_local_process's dead letter fallback
+  pub fn has_local_process(&self, id: &str) -> bool {
+    self.local_pids.get_bucket(id).contains_key(id)
+  }
+
+  // UpdateFuturePressure compares the pending futures with the high-water mark and returns the event to publish
+  // when the mark has just been reached or dropped below. A mark of 0 disables the check.
+  pub(crate) fn update_future_pressure(&self, high_water_mark: usize) -> Option<FuturePressureEvent> {
+    if high_water_mark == 0 {
+      return None;
+    }
+    let pending = self.get_pending_futures();
+    let high = pending >= high_water_mark;
+    self
+      .future_pressure_high
+      .compare_exchange(!high, high, Ordering::SeqCst, Ordering::SeqCst)
+      .ok()?;
+    if high {
+      Some(FuturePressureEvent::HighWaterMarkReached {
+        pending,
+        high_water_mark,
+      })
+    } else {
+      Some(FuturePressureEvent::Recovered {
+        pending,
+        high_water_mark,
+      })
+    }
+  }
+
   pub async fn add_process(&self, process: ProcessHandle, id: &str) -> (ExtendedPid, bool) {
     let bucket = self.local_pids.get_bucket(id);
     let pid = Pid {
@@ -130,20 +193,28 @@ impl ProcessRegistry {
     };
     let pid = ExtendedPid::new(pid);
     let inserted = bucket.insert(id.to_string(), process).is_none();
+    if inserted {
+      self.process_count.fetch_add(1, Ordering::SeqCst);
+    }
     (pid, inserted)
   }
 
   pub async fn remove_process(&self, pid: &ExtendedPid) {
     let bucket = self.local_pids.get_bucket(pid.id());
     if let Some((_, process)) = bucket.remove(pid.id()) {
+      self.process_count.fetch_sub(1, Ordering::SeqCst);
       if let Some(actor_process) = process.as_any().downcast_ref::<ActorProcess>() {
         actor_process.set_dead();
       }
     }
   }
 
+  pub async fn is_local_pid(&self, pid: &ExtendedPid) -> bool {
+    pid.address() == LOCAL_ADDRESS || pid.address() == self.get_address().await
+  }
+
   pub async fn get_process(&self, pid: &ExtendedPid) -> Option<ProcessHandle> {
-    let is_remote = pid.address() != LOCAL_ADDRESS && pid.address() != self.get_address().await;
+    let is_remote = !self.is_local_pid(pid).await;
     if is_remote {
       {
         let mg = self.remote_handlers.read().await;
@@ -158,6 +229,113 @@ impl ProcessRegistry {
     self.get_local_process(pid.id()).await
   }
 
+  // FindProcess behaves like GetProcess, but returns None instead of the dead letter process
+  // when no process is registered for the PID
+  pub async fn find_process(&self, pid: &ExtendedPid) -> Option<ProcessHandle> {
+    let is_remote = !self.is_local_pid(pid).await;
+    if is_remote {
+      let mg = self.remote_handlers.read().await;
+      for handler in mg.iter() {
+        if let Some(process) = handler.run(pid).await {
+          return Some(process);
+        }
+      }
+      return None;
+    }
+    let bucket = self.local_pids.get_bucket(pid.id());
+    let result = bucket.get(pid.id()).map(|r| r.clone());
+    result
+  }
+
+  // GetLocalProcesses returns every locally registered process, including system processes
+  pub(crate) fn get_local_processes(&self) -> Vec<ProcessHandle> {
+    self
+      .local_pids
+      .local_pids
+      .iter()
+      .flat_map(|bucket| bucket.iter().map(|entry| entry.value().clone()).collect::<Vec<_>>())
+      .collect()
+  }
+
+  // ListLocalPids returns the locally registered processes whose id matches the pattern, ordered by id.
+  // An invalid regex matches nothing.
+  pub async fn list_local_pids(&self, pattern: &str, match_type: ListProcessesMatchType) -> Vec<ExtendedPid> {
+    let regex = match match_type {
+      ListProcessesMatchType::MatchRegex => match Regex::new(pattern) {
+        Ok(regex) => Some(regex),
+        Err(err) => {
+          tracing::warn!("invalid process pattern: {}", err);
+          return Vec::new();
+        }
+      },
+      _ => None,
+    };
+    let address = self.get_address().await;
+    let mut ids = self
+      .local_pids
+      .local_pids
+      .iter()
+      .flat_map(|bucket| bucket.iter().map(|entry| entry.key().clone()).collect::<Vec<_>>())
+      .filter(|id| match match_type {
+        ListProcessesMatchType::MatchPartOfString => id.contains(pattern),
+        ListProcessesMatchType::MatchExactString => id == pattern,
+        ListProcessesMatchType::MatchRegex => regex.as_ref().unwrap().is_match(id),
+      })
+      .collect::<Vec<_>>();
+    ids.sort();
+    ids
+      .into_iter()
+      .map(|id| {
+        ExtendedPid::new(Pid {
+          address: address.clone(),
+          id,
+          request_id: 0,
+        })
+      })
+      .collect()
+  }
+
+  // NameSnapshot returns the name of every locally registered process mapped to its canonical PID, ordered by
+  // name so that snapshots taken before and after a scenario can be compared directly
+  pub async fn name_snapshot(&self) -> BTreeMap<String, String> {
+    let address = self.get_address().await;
+    self
+      .local_pids
+      .local_pids
+      .iter()
+      .flat_map(|bucket| bucket.iter().map(|entry| entry.key().clone()).collect::<Vec<_>>())
+      .map(|id| {
+        let pid = Pid {
+          address: address.clone(),
+          id: id.clone(),
+          request_id: 0,
+        };
+        (id, pid.to_string())
+      })
+      .collect()
+  }
+
+  // GetProcessDiagnostics returns the diagnostics of the local actor behind the PID, or a diagnostic with
+  // `"status": "not_found"` when no live actor is registered for it
+  pub async fn get_process_diagnostics(&self, pid: &ExtendedPid) -> String {
+    if self.is_local_pid(pid).await {
+      if let Some(process) = self.find_process(pid).await {
+        if let Some(actor_process) = process.as_any().downcast_ref::<ActorProcess>() {
+          if !actor_process.is_dead() {
+            if let Some(diagnostics) = actor_process.get_diagnostics().await {
+              return diagnostics;
+            }
+          }
+        }
+      }
+    }
+    serde_json::json!({
+      "pid": pid.to_string(),
+      "status": "not_found",
+    })
+    .to_string()
+  }
+
   pub async fn get_local_process(&self, id: &str) -> Option<ProcessHandle> {
     let bucket = self.local_pids.get_bucket(id);
     let result = bucket.get(id);
@@ -168,6 +346,15 @@ impl ProcessRegistry {
   }
 }
 
+// ListProcessesMatchType mirrors the remote ListProcessesMatchType, deciding how list_local_pids applies its pattern
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ListProcessesMatchType {
+  #[default]
+  MatchPartOfString,
+  MatchExactString,
+  MatchRegex,
+}
+
 const DIGITS: &[u8; 64] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ~+";
 
 pub fn uint64_to_id(u: u64) -> String {
@@ -1,6 +1,11 @@
 #[cfg(test)]
 mod tests {
-  use crate::actor::process::process_registry::uint64_to_id;
+  use crate::actor::actor::ExtendedPid;
+  use crate::actor::actor::Props;
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{SpawnerPart, StopperPart};
+  use crate::actor::process::process_registry::{uint64_to_id, ListProcessesMatchType};
+  use std::collections::BTreeMap;
   use std::time::Instant;
 
   const ITERATIONS: u32 = 1_000_000; // 適切な反復回数に調整してください
@@ -15,4 +20,103 @@ mod tests {
     let duration = start.elapsed();
     tracing::debug!("uint64_to_id: {:?}, last result: {}", duration, s);
   }
+
+  #[tokio::test]
+  async fn test_name_snapshot_differs_by_spawned_actors() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let before = system.get_process_registry().await.name_snapshot().await;
+
+    for name in ["worker-b", "worker-a"] {
+      root_context
+        .spawn_named(Props::from_async_actor_receiver(|_| async { Ok(()) }).await, name)
+        .await
+        .unwrap();
+    }
+
+    let after = system.get_process_registry().await.name_snapshot().await;
+    assert!(before.iter().all(|(name, pid)| after.get(name) == Some(pid)));
+    let added = after
+      .into_iter()
+      .filter(|(name, _)| !before.contains_key(name))
+      .collect::<BTreeMap<_, _>>();
+    assert_eq!(
+      added.into_iter().collect::<Vec<_>>(),
+      vec![
+        ("worker-a".to_string(), "nonhost-worker-a-0".to_string()),
+        ("worker-b".to_string(), "nonhost-worker-b-0".to_string()),
+      ]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_list_local_pids_honors_match_type() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    for name in ["orders", "orders-audit", "billing"] {
+      root_context
+        .spawn_named(Props::from_async_actor_receiver(|_| async { Ok(()) }).await, name)
+        .await
+        .unwrap();
+    }
+    let process_registry = system.get_process_registry().await;
+
+    let ids = |pids: Vec<ExtendedPid>| pids.into_iter().map(|pid| pid.id().to_string()).collect::<Vec<_>>();
+    assert_eq!(
+      ids(
+        process_registry
+          .list_local_pids("orders", ListProcessesMatchType::MatchPartOfString)
+          .await
+      ),
+      vec!["orders", "orders-audit"]
+    );
+    assert_eq!(
+      ids(
+        process_registry
+          .list_local_pids("orders", ListProcessesMatchType::MatchExactString)
+          .await
+      ),
+      vec!["orders"]
+    );
+    assert_eq!(
+      ids(
+        process_registry
+          .list_local_pids("^(billing|orders)$", ListProcessesMatchType::MatchRegex)
+          .await
+      ),
+      vec!["billing", "orders"]
+    );
+    assert!(process_registry
+      .list_local_pids("(", ListProcessesMatchType::MatchRegex)
+      .await
+      .is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_get_process_diagnostics_reports_actor_state() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let pid = root_context
+      .spawn_named(
+        Props::from_async_actor_receiver(|_| async { Ok(()) }).await,
+        "diagnosed",
+      )
+      .await
+      .unwrap();
+    let process_registry = system.get_process_registry().await;
+
+    let diagnostics: serde_json::Value =
+      serde_json::from_str(&process_registry.get_process_diagnostics(&pid).await).unwrap();
+    assert_eq!(diagnostics["pid"], pid.to_string());
+    assert!(diagnostics["actor_type"].is_string());
+    assert!(diagnostics["mailbox"]["user_messages"].is_number());
+    assert!(diagnostics["mailbox"]["system_messages"].is_number());
+    assert_eq!(diagnostics["children"], 0);
+    assert_eq!(diagnostics["restart_failures"], 0);
+
+    root_context.stop_future(&pid).await.result().await.unwrap();
+    let diagnostics: serde_json::Value =
+      serde_json::from_str(&process_registry.get_process_diagnostics(&pid).await).unwrap();
+    assert_eq!(diagnostics["status"], "not_found");
+  }
 }
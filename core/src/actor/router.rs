@@ -0,0 +1,6 @@
+mod routees;
+mod router_actor;
+mod router_props;
+mod router_test;
+
+pub use {self::routees::*, self::router_actor::*, self::router_props::*};
@@ -0,0 +1,12 @@
+use nexus_actor_message_derive_rs::Message;
+
+use crate::actor::actor::ExtendedPid;
+
+// GetRoutees asks a router for its current routees, it is answered with Routees instead of being routed
+#[derive(Debug, Clone, PartialEq, Eq, Message)]
+pub struct GetRoutees;
+
+#[derive(Debug, Clone, PartialEq, Message)]
+pub struct Routees {
+  pub pids: Vec<ExtendedPid>,
+}
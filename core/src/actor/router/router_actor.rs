@@ -0,0 +1,150 @@
+use async_trait::async_trait;
+use rand::Rng;
+
+use crate::actor::actor::{Actor, ActorError, ExtendedPid, Props};
+use crate::actor::context::{BasePart, ContextHandle, InfoPart, MessagePart, SenderPart, SpawnerPart};
+use crate::actor::message::{MessageHandle, ResponseHandle};
+use crate::actor::process::Process;
+use crate::actor::router::{GetRoutees, Routees};
+use crate::generated::actor::TerminatedReason;
+
+// RoutingStrategy decides which routee receives the next message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoutingStrategy {
+  RoundRobin,
+  Random,
+  Broadcast,
+}
+
+// RouterActor forwards every user message it receives, with its sender, to the routees picked by its strategy.
+// Pool routers spawn their routees as children: failed routees are restarted by the router's supervisor
+// strategy and stopped ones are replaced. Group routers route to existing actors and drop the ones that stop.
+#[derive(Debug)]
+pub(crate) struct RouterActor {
+  strategy: RoutingStrategy,
+  pool: Option<(usize, Props)>,
+  group: Vec<ExtendedPid>,
+  routees: Vec<ExtendedPid>,
+  next: usize,
+  stopping: bool,
+}
+
+impl RouterActor {
+  pub(crate) fn new_pool(strategy: RoutingStrategy, size: usize, props: Props) -> Self {
+    Self::new(strategy, Some((size, props)), vec![])
+  }
+
+  pub(crate) fn new_group(strategy: RoutingStrategy, pids: Vec<ExtendedPid>) -> Self {
+    Self::new(strategy, None, pids)
+  }
+
+  fn new(strategy: RoutingStrategy, pool: Option<(usize, Props)>, group: Vec<ExtendedPid>) -> Self {
+    Self {
+      strategy,
+      pool,
+      group,
+      routees: vec![],
+      next: 0,
+      stopping: false,
+    }
+  }
+
+  fn select_routees(&mut self) -> Vec<ExtendedPid> {
+    if self.routees.is_empty() {
+      return vec![];
+    }
+    let index = match self.strategy {
+      RoutingStrategy::RoundRobin => {
+        let index = self.next % self.routees.len();
+        self.next = index + 1;
+        index
+      }
+      RoutingStrategy::Random => rand::rng().random_range(0..self.routees.len()),
+      RoutingStrategy::Broadcast => return self.routees.clone(),
+    };
+    vec![self.routees[index].clone()]
+  }
+
+  // Each routee gets its own send, with the original sender, so a dead routee or a full mailbox only costs
+  // that routee's copy, which goes to dead letters
+  async fn route(&self, ctx: &mut ContextHandle, routees: Vec<ExtendedPid>) {
+    let message_handle = match ctx.get_message_envelope_opt().await {
+      Some(envelope) => MessageHandle::new(envelope),
+      None => ctx.get_message_handle().await,
+    };
+    for routee in &routees {
+      if ctx.try_send(routee.clone(), message_handle.clone()).await.is_err() {
+        ctx
+          .get_actor_system()
+          .await
+          .get_dead_letter()
+          .await
+          .send_user_message(Some(routee), message_handle.clone())
+          .await;
+      }
+    }
+  }
+}
+
+#[async_trait]
+impl Actor for RouterActor {
+  async fn receive(&mut self, mut ctx: ContextHandle) -> Result<(), ActorError> {
+    if ctx.get_message_handle().await.to_typed::<GetRoutees>().is_some() {
+      let routees = Routees {
+        pids: self.routees.clone(),
+      };
+      ctx.respond(ResponseHandle::new(routees)).await;
+      return Ok(());
+    }
+    let routees = self.select_routees();
+    if routees.is_empty() {
+      tracing::warn!("Router has no routees: pid = {}", ctx.get_self().await);
+      return Ok(());
+    }
+    self.route(&mut ctx, routees).await;
+    Ok(())
+  }
+
+  // Runs again after a restart, once the previous routees have been stopped
+  async fn pre_start(&mut self, mut ctx: ContextHandle) -> Result<(), ActorError> {
+    if let Some((size, props)) = self.pool.clone() {
+      for _ in 0..size {
+        let routee = ctx.spawn(props.clone()).await;
+        self.routees.push(routee);
+      }
+    }
+    for pid in self.group.clone() {
+      ctx.watch(&pid).await;
+      self.routees.push(pid);
+    }
+    Ok(())
+  }
+
+  async fn pre_restart(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+    self.stopping = true;
+    Ok(())
+  }
+
+  async fn pre_stop(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+    self.stopping = true;
+    Ok(())
+  }
+
+  async fn on_child_terminated(
+    &mut self,
+    mut ctx: ContextHandle,
+    pid: &ExtendedPid,
+    _: TerminatedReason,
+  ) -> Result<(), ActorError> {
+    self.routees.retain(|routee| routee != pid);
+    if self.stopping {
+      return Ok(());
+    }
+    if let Some((_, props)) = self.pool.clone() {
+      let routee = ctx.spawn(props).await;
+      tracing::debug!("Router replaced a stopped routee: {} -> {}", pid, routee);
+      self.routees.push(routee);
+    }
+    Ok(())
+  }
+}
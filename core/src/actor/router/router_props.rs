@@ -0,0 +1,39 @@
+use crate::actor::actor::{ExtendedPid, Props};
+use crate::actor::router::{RouterActor, RoutingStrategy};
+
+// NewRoundRobinPool returns the props of a router that spawns `size` routees from `props` and sends each
+// message to the next routee in turn
+pub async fn new_round_robin_pool(size: usize, props: Props) -> Props {
+  new_pool(RoutingStrategy::RoundRobin, size, props).await
+}
+
+// NewRandomPool returns the props of a router that spawns `size` routees from `props` and sends each message
+// to a routee picked at random
+pub async fn new_random_pool(size: usize, props: Props) -> Props {
+  new_pool(RoutingStrategy::Random, size, props).await
+}
+
+// NewBroadcastPool returns the props of a router that spawns `size` routees from `props` and sends every
+// message to all of them
+pub async fn new_broadcast_pool(size: usize, props: Props) -> Props {
+  new_pool(RoutingStrategy::Broadcast, size, props).await
+}
+
+// NewRoundRobinGroup returns the props of a router that sends each message to the next of the given, already
+// running, actors in turn
+pub async fn new_round_robin_group(pids: impl IntoIterator<Item = ExtendedPid>) -> Props {
+  let pids = pids.into_iter().collect::<Vec<_>>();
+  Props::from_async_actor_producer(move |_| {
+    let router = RouterActor::new_group(RoutingStrategy::RoundRobin, pids.clone());
+    async move { router }
+  })
+  .await
+}
+
+async fn new_pool(strategy: RoutingStrategy, size: usize, props: Props) -> Props {
+  Props::from_async_actor_producer(move |_| {
+    let router = RouterActor::new_pool(strategy, size, props.clone());
+    async move { router }
+  })
+  .await
+}
@@ -0,0 +1,358 @@
+#[cfg(test)]
+mod tests {
+  use std::collections::{HashMap, HashSet};
+  use std::env;
+  use std::sync::Arc;
+  use std::time::Duration;
+
+  use tokio::sync::{Mutex, Notify};
+  use tracing_subscriber::EnvFilter;
+
+  use crate::actor::actor::{ActorError, ErrorReason, ExtendedPid, Props};
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{InfoPart, MessagePart, RootContext, SenderPart, SpawnerPart, StopperPart};
+  use crate::actor::dispatch::{bounded_mailbox_creator, DeadLetterEvent};
+  use crate::actor::message::MessageHandle;
+  use crate::actor::router::{
+    new_broadcast_pool, new_random_pool, new_round_robin_group, new_round_robin_pool, GetRoutees, Routees, RouterActor,
+    RoutingStrategy,
+  };
+  use crate::generated::actor::Pid;
+
+  type Received = Arc<Mutex<Vec<(String, u32)>>>;
+
+  // Records which routee received each u32 message, and fails on 0
+  async fn worker_props(received: Received, notify: Arc<Notify>) -> Props {
+    Props::from_async_actor_receiver(move |ctx| {
+      let received = received.clone();
+      let notify = notify.clone();
+      async move {
+        if let Some(n) = ctx.get_message_handle().await.to_typed::<u32>() {
+          if n == 0 {
+            return Err(ActorError::ReceiveError(ErrorReason::new("crash", 0)));
+          }
+          received.lock().await.push((ctx.get_self().await.id().to_string(), n));
+          notify.notify_one();
+        }
+        Ok(())
+      }
+    })
+    .await
+  }
+
+  async fn wait_for(received: &Received, notify: &Notify, count: usize) -> Vec<(String, u32)> {
+    tokio::time::timeout(Duration::from_secs(3), async {
+      while received.lock().await.len() < count {
+        notify.notified().await;
+      }
+    })
+    .await
+    .expect("routees did not receive every message");
+    received.lock().await.clone()
+  }
+
+  fn counts_by_routee(received: &[(String, u32)]) -> HashMap<String, usize> {
+    received.iter().fold(HashMap::new(), |mut counts, (routee, _)| {
+      *counts.entry(routee.clone()).or_default() += 1;
+      counts
+    })
+  }
+
+  #[tokio::test]
+  async fn test_round_robin_pool_cycles_through_routees() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let received = Received::default();
+    let notify = Arc::new(Notify::new());
+
+    let props = new_round_robin_pool(3, worker_props(received.clone(), notify.clone()).await).await;
+    let router = root_context.spawn(props).await;
+    for n in 1..=6 {
+      root_context.send(router.clone(), MessageHandle::new(n as u32)).await;
+    }
+
+    let received = wait_for(&received, &notify, 6).await;
+    let routees = received.iter().map(|(routee, _)| routee.clone()).collect::<Vec<_>>();
+    assert_eq!(routees[..3].iter().collect::<HashSet<_>>().len(), 3);
+    assert_eq!(routees[..3], routees[3..]);
+    assert!(!routees.contains(&router.id().to_string()));
+  }
+
+  #[tokio::test]
+  async fn test_random_pool_routes_every_message_to_its_routees() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let received = Received::default();
+    let notify = Arc::new(Notify::new());
+
+    let props = new_random_pool(4, worker_props(received.clone(), notify.clone()).await).await;
+    let router = root_context.spawn(props).await;
+    for n in 1..=20 {
+      root_context.send(router.clone(), MessageHandle::new(n as u32)).await;
+    }
+
+    let received = wait_for(&received, &notify, 20).await;
+    let counts = counts_by_routee(&received);
+    assert!(counts.len() <= 4);
+    assert_eq!(counts.values().sum::<usize>(), 20);
+    assert!(!counts.contains_key(router.id()));
+  }
+
+  #[tokio::test]
+  async fn test_round_robin_group_routes_to_existing_actors() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let received = Received::default();
+    let notify = Arc::new(Notify::new());
+
+    let mut workers = vec![];
+    for _ in 0..2 {
+      let props = worker_props(received.clone(), notify.clone()).await;
+      workers.push(root_context.spawn(props).await);
+    }
+    let router = root_context.spawn(new_round_robin_group(workers.clone()).await).await;
+    for n in 1..=4 {
+      root_context.send(router.clone(), MessageHandle::new(n as u32)).await;
+    }
+
+    let received = wait_for(&received, &notify, 4).await;
+    let counts = counts_by_routee(&received);
+    let expected = workers
+      .iter()
+      .map(|pid| (pid.id().to_string(), 2))
+      .collect::<HashMap<_, _>>();
+    assert_eq!(counts, expected);
+  }
+
+  #[tokio::test]
+  async fn test_pool_restarts_failed_and_replaces_stopped_routees() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let received = Received::default();
+    let notify = Arc::new(Notify::new());
+
+    let props = new_round_robin_pool(1, worker_props(received.clone(), notify.clone()).await).await;
+    let router = root_context.spawn(props).await;
+
+    // A failed routee is restarted in place and keeps receiving
+    root_context.send(router.clone(), MessageHandle::new(0u32)).await;
+    root_context.send(router.clone(), MessageHandle::new(1u32)).await;
+    let first = wait_for(&received, &notify, 1).await[0].0.clone();
+
+    // A stopped routee is replaced by a new one
+    let routee = ExtendedPid::new(Pid {
+      address: router.address().to_string(),
+      id: first.clone(),
+      request_id: 0,
+    });
+    root_context.stop_future(&routee).await.result().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    root_context.send(router.clone(), MessageHandle::new(2u32)).await;
+
+    let received = wait_for(&received, &notify, 2).await;
+    assert_eq!(received[0], (first.clone(), 1));
+    assert_eq!(received[1].1, 2);
+    assert_ne!(received[1].0, first);
+  }
+
+  #[tokio::test]
+  async fn test_broadcast_pool_sends_every_message_to_all_routees() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let received = Received::default();
+    let notify = Arc::new(Notify::new());
+
+    let props = new_broadcast_pool(3, worker_props(received.clone(), notify.clone()).await).await;
+    let router = root_context.spawn(props).await;
+    for n in 1..=2 {
+      root_context.send(router.clone(), MessageHandle::new(n as u32)).await;
+    }
+
+    let received = wait_for(&received, &notify, 6).await;
+    let counts = counts_by_routee(&received);
+    assert_eq!(counts.len(), 3);
+    assert!(counts.values().all(|count| *count == 2));
+
+    let response = root_context
+      .request_future(router, MessageHandle::new(GetRoutees), Duration::from_secs(1))
+      .await
+      .result()
+      .await
+      .unwrap();
+    let routees = response
+      .to_typed::<Routees>()
+      .unwrap()
+      .pids
+      .iter()
+      .map(|pid| pid.id().to_string())
+      .collect::<HashSet<_>>();
+    assert_eq!(routees, counts.keys().cloned().collect::<HashSet<_>>());
+  }
+
+  async fn get_routees(root_context: &mut RootContext, router: &ExtendedPid) -> HashSet<String> {
+    let response = root_context
+      .request_future(router.clone(), MessageHandle::new(GetRoutees), Duration::from_secs(1))
+      .await
+      .result()
+      .await
+      .unwrap();
+    response
+      .to_typed::<Routees>()
+      .unwrap()
+      .pids
+      .iter()
+      .map(|pid| pid.id().to_string())
+      .collect()
+  }
+
+  async fn subscribe_dead_letters(system: &ActorSystem, target: ExtendedPid) -> Arc<Mutex<Vec<u32>>> {
+    let dead_letters = Arc::new(Mutex::new(Vec::new()));
+    let cloned_dead_letters = dead_letters.clone();
+    system
+      .get_event_stream()
+      .await
+      .subscribe(move |msg| {
+        let dead_letters = cloned_dead_letters.clone();
+        let target = target.clone();
+        async move {
+          if let Some(dead_letter) = msg.to_typed::<DeadLetterEvent>() {
+            if dead_letter.pid.as_ref() == Some(&target) {
+              if let Some(n) = dead_letter.message_handle.to_typed::<u32>() {
+                dead_letters.lock().await.push(n);
+              }
+            }
+          }
+        }
+      })
+      .await;
+    dead_letters
+  }
+
+  #[tokio::test]
+  async fn test_broadcast_group_drops_a_dead_routee() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let received = Received::default();
+    let notify = Arc::new(Notify::new());
+
+    let worker = root_context
+      .spawn(worker_props(received.clone(), notify.clone()).await)
+      .await;
+    let dead = ExtendedPid::new(Pid {
+      address: worker.address().to_string(),
+      id: "dead-routee".to_string(),
+      request_id: 0,
+    });
+
+    let routees = vec![dead, worker.clone()];
+    let router = root_context
+      .spawn(
+        Props::from_async_actor_producer(move |_| {
+          let router = RouterActor::new_group(RoutingStrategy::Broadcast, routees.clone());
+          async move { router }
+        })
+        .await,
+      )
+      .await;
+    root_context.send(router.clone(), MessageHandle::new(7u32)).await;
+
+    let received = wait_for(&received, &notify, 1).await;
+    assert_eq!(received, vec![(worker.id().to_string(), 7)]);
+
+    // The dead letter process answers the router's Watch with a Terminated for the dead routee
+    tokio::time::timeout(Duration::from_secs(3), async {
+      while get_routees(&mut root_context, &router).await != HashSet::from([worker.id().to_string()]) {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+      }
+    })
+    .await
+    .expect("the dead routee was not removed");
+  }
+
+  #[tokio::test]
+  async fn test_broadcast_to_a_full_routee_dead_letters_only_its_copy() {
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let received = Received::default();
+    let notify = Arc::new(Notify::new());
+
+    let entered = Arc::new(Notify::new());
+    let gate = Arc::new(Notify::new());
+    let cloned_received = received.clone();
+    let cloned_notify = notify.clone();
+    let cloned_entered = entered.clone();
+    let cloned_gate = gate.clone();
+    let full = root_context
+      .spawn(
+        Props::from_async_actor_receiver_with_opts(
+          move |ctx| {
+            let received = cloned_received.clone();
+            let notify = cloned_notify.clone();
+            let entered = cloned_entered.clone();
+            let gate = cloned_gate.clone();
+            async move {
+              if let Some(n) = ctx.get_message_handle().await.to_typed::<u32>() {
+                if n == 100 {
+                  entered.notify_one();
+                  gate.notified().await;
+                }
+                received.lock().await.push((ctx.get_self().await.id().to_string(), n));
+                notify.notify_one();
+              }
+              Ok(())
+            }
+          },
+          [Props::with_mailbox_producer(bounded_mailbox_creator(1, true))],
+        )
+        .await,
+      )
+      .await;
+    let worker = root_context
+      .spawn(worker_props(received.clone(), notify.clone()).await)
+      .await;
+    let dead_letters = subscribe_dead_letters(&system, full.clone()).await;
+
+    // The first message blocks the routee, the second fills its mailbox
+    root_context.send(full.clone(), MessageHandle::new(100u32)).await;
+    entered.notified().await;
+    root_context.send(full.clone(), MessageHandle::new(1u32)).await;
+
+    let routees = vec![full.clone(), worker.clone()];
+    let router = root_context
+      .spawn(
+        Props::from_async_actor_producer(move |_| {
+          let router = RouterActor::new_group(RoutingStrategy::Broadcast, routees.clone());
+          async move { router }
+        })
+        .await,
+      )
+      .await;
+    root_context.send(router, MessageHandle::new(7u32)).await;
+
+    assert_eq!(
+      wait_for(&received, &notify, 1).await,
+      vec![(worker.id().to_string(), 7)]
+    );
+    tokio::time::timeout(Duration::from_secs(3), async {
+      while dead_letters.lock().await.is_empty() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+      }
+    })
+    .await
+    .expect("the full routee's copy was not dead-lettered");
+    assert_eq!(*dead_letters.lock().await, vec![7]);
+
+    // The queued message was not evicted to make room for the broadcast
+    gate.notify_one();
+    let received = wait_for(&received, &notify, 3).await;
+    assert_eq!(
+      received[1..],
+      [(full.id().to_string(), 100), (full.id().to_string(), 1)]
+    );
+  }
+}
@@ -0,0 +1,116 @@
+use crate::actor::actor::{ActorProcess, ExtendedPid};
+use crate::actor::actor_system::ActorSystem;
+use crate::actor::message::{MessageEnvelope, MessageHandle};
+use crate::actor::process::Process;
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::{Instant, MissedTickBehavior};
+pub use tokio_util::sync::CancellationToken;
+
+// MIN_SCHEDULE_INTERVAL is the shortest interval schedule_repeated ticks at
+pub const MIN_SCHEDULE_INTERVAL: Duration = Duration::from_millis(1);
+
+// Scheduler sends messages after a delay or at a fixed interval. It also backs SenderContext::send_after.
+// Deliveries go through send_user_message, so the target receives them like any other message,
+// and messages to a dead pid end up in dead letters
+#[derive(Debug, Clone)]
+pub struct Scheduler {
+  actor_system: ActorSystem,
+}
+
+impl Scheduler {
+  pub fn new(actor_system: ActorSystem) -> Self {
+    Self { actor_system }
+  }
+
+  // ScheduleOnce sends the message to the target after delay, unless the returned token is cancelled first
+  pub fn schedule_once(
+    &self,
+    delay: Duration,
+    sender: Option<ExtendedPid>,
+    target: ExtendedPid,
+    message_handle: MessageHandle,
+  ) -> CancellationToken {
+    let actor_system = self.actor_system.clone();
+    self.schedule_once_fn(delay, move || async move {
+      Self::deliver(&actor_system, sender.as_ref(), &target, message_handle).await;
+    })
+  }
+
+  // ScheduleOnceFn runs f after delay, unless the returned token is cancelled first.
+  // The token is also cancelled once f has run, so holders can tell finished schedules apart
+  pub(crate) fn schedule_once_fn<F, Fut>(&self, delay: Duration, f: F) -> CancellationToken
+  where
+    F: FnOnce() -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static, {
+    let token = CancellationToken::new();
+    let cloned_token = token.clone();
+    tokio::spawn(async move {
+      let _guard = cloned_token.clone().drop_guard();
+      tokio::select! {
+        biased;
+        _ = cloned_token.cancelled() => {}
+        _ = tokio::time::sleep(delay) => f().await,
+      }
+    });
+    token
+  }
+
+  // ScheduleRepeated sends the message to the target after initial_delay and then every interval,
+  // until the returned token is cancelled or the target is found dead, which also cancels the token.
+  // Intervals shorter than MIN_SCHEDULE_INTERVAL are raised to it
+  pub fn schedule_repeated(
+    &self,
+    initial_delay: Duration,
+    interval: Duration,
+    sender: Option<ExtendedPid>,
+    target: ExtendedPid,
+    message_handle: MessageHandle,
+  ) -> CancellationToken {
+    let interval = interval.max(MIN_SCHEDULE_INTERVAL);
+    let token = CancellationToken::new();
+    let cloned_token = token.clone();
+    let actor_system = self.actor_system.clone();
+    tokio::spawn(async move {
+      let _guard = cloned_token.clone().drop_guard();
+      let mut ticker = tokio::time::interval_at(Instant::now() + initial_delay, interval);
+      ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+      loop {
+        tokio::select! {
+          biased;
+          _ = cloned_token.cancelled() => break,
+          _ = ticker.tick() => {
+            if Self::is_dead(&actor_system, &target).await {
+              break;
+            }
+            Self::deliver(&actor_system, sender.as_ref(), &target, message_handle.clone()).await;
+          }
+        }
+      }
+    });
+    token
+  }
+
+  async fn is_dead(actor_system: &ActorSystem, target: &ExtendedPid) -> bool {
+    match actor_system.get_process_registry().await.find_process(target).await {
+      Some(process) => process
+        .as_any()
+        .downcast_ref::<ActorProcess>()
+        .is_some_and(|actor_process| actor_process.is_dead()),
+      None => true,
+    }
+  }
+
+  async fn deliver(
+    actor_system: &ActorSystem,
+    sender: Option<&ExtendedPid>,
+    target: &ExtendedPid,
+    message_handle: MessageHandle,
+  ) {
+    let message_handle = match sender {
+      Some(sender) => MessageHandle::new(MessageEnvelope::new(message_handle).with_sender(sender.clone())),
+      None => message_handle,
+    };
+    target.send_user_message(actor_system.clone(), message_handle).await;
+  }
+}
@@ -0,0 +1,255 @@
+#[cfg(test)]
+mod tests {
+  use crate::actor::actor::{ActorError, ExtendedPid, Props};
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{MessagePart, SenderPart, SpawnerPart, StopperPart};
+  use crate::actor::dispatch::DeadLetterEvent;
+  use crate::actor::message::MessageHandle;
+  use nexus_actor_message_derive_rs::Message;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  use std::sync::Arc;
+  use std::time::Duration;
+  use tokio::sync::{Mutex, Notify};
+
+  #[derive(Debug, Clone, PartialEq, Message)]
+  struct Tick;
+
+  async fn spawn_tick_counter(
+    system: &ActorSystem,
+    ticks: Arc<AtomicUsize>,
+    senders: Arc<Mutex<Vec<Option<ExtendedPid>>>>,
+    notify: Arc<Notify>,
+  ) -> ExtendedPid {
+    let props = Props::from_async_actor_receiver(move |ctx| {
+      let ticks = ticks.clone();
+      let senders = senders.clone();
+      let notify = notify.clone();
+      async move {
+        if ctx.get_message_handle().await.to_typed::<Tick>().is_some() {
+          senders.lock().await.push(ctx.get_sender().await);
+          ticks.fetch_add(1, Ordering::SeqCst);
+          notify.notify_one();
+        }
+        Ok::<(), ActorError>(())
+      }
+    })
+    .await;
+    system.get_root_context().await.spawn(props).await
+  }
+
+  #[tokio::test]
+  async fn test_schedule_once_delivers_with_sender() {
+    let system = ActorSystem::new().await.unwrap();
+    let ticks = Arc::new(AtomicUsize::new(0));
+    let senders = Arc::new(Mutex::new(Vec::new()));
+    let notify = Arc::new(Notify::new());
+    let target = spawn_tick_counter(&system, ticks.clone(), senders.clone(), notify.clone()).await;
+    let sender = system.new_local_pid("scheduler-sender").await;
+
+    system.get_scheduler().schedule_once(
+      Duration::from_millis(50),
+      Some(sender.clone()),
+      target.clone(),
+      MessageHandle::new(Tick),
+    );
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(ticks.load(Ordering::SeqCst), 0);
+
+    tokio::time::timeout(Duration::from_secs(3), notify.notified())
+      .await
+      .expect("the scheduled message was not delivered");
+    assert_eq!(senders.lock().await.clone(), vec![Some(sender)]);
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(ticks.load(Ordering::SeqCst), 1);
+
+    let token = system.get_scheduler().schedule_once(
+      Duration::from_millis(50),
+      None,
+      target.clone(),
+      MessageHandle::new(Tick),
+    );
+    token.cancel();
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_eq!(ticks.load(Ordering::SeqCst), 1);
+
+    system
+      .get_root_context()
+      .await
+      .stop_future(&target)
+      .await
+      .result()
+      .await
+      .unwrap();
+  }
+
+  #[tokio::test]
+  async fn test_schedule_repeated_stops_when_cancelled() {
+    let system = ActorSystem::new().await.unwrap();
+    let ticks = Arc::new(AtomicUsize::new(0));
+    let notify = Arc::new(Notify::new());
+    let target = spawn_tick_counter(&system, ticks.clone(), Arc::new(Mutex::new(Vec::new())), notify.clone()).await;
+
+    let token = system.get_scheduler().schedule_repeated(
+      Duration::from_millis(10),
+      Duration::from_millis(20),
+      None,
+      target.clone(),
+      MessageHandle::new(Tick),
+    );
+    tokio::time::timeout(Duration::from_secs(3), async {
+      while ticks.load(Ordering::SeqCst) < 3 {
+        notify.notified().await;
+      }
+    })
+    .await
+    .expect("the repeated message was not delivered three times");
+
+    token.cancel();
+    // a delivery already in flight may still arrive
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let delivered = ticks.load(Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_eq!(ticks.load(Ordering::SeqCst), delivered);
+
+    system
+      .get_root_context()
+      .await
+      .stop_future(&target)
+      .await
+      .result()
+      .await
+      .unwrap();
+  }
+
+  #[tokio::test]
+  async fn test_schedule_repeated_with_zero_interval_ticks() {
+    let system = ActorSystem::new().await.unwrap();
+    let ticks = Arc::new(AtomicUsize::new(0));
+    let notify = Arc::new(Notify::new());
+    let target = spawn_tick_counter(&system, ticks.clone(), Arc::new(Mutex::new(Vec::new())), notify.clone()).await;
+
+    let token = system.get_scheduler().schedule_repeated(
+      Duration::ZERO,
+      Duration::ZERO,
+      None,
+      target.clone(),
+      MessageHandle::new(Tick),
+    );
+    tokio::time::timeout(Duration::from_secs(3), async {
+      while ticks.load(Ordering::SeqCst) < 3 {
+        notify.notified().await;
+      }
+    })
+    .await
+    .expect("the repeated message was not delivered three times");
+    token.cancel();
+
+    system
+      .get_root_context()
+      .await
+      .stop_future(&target)
+      .await
+      .result()
+      .await
+      .unwrap();
+  }
+
+  #[tokio::test]
+  async fn test_schedule_repeated_stops_when_target_is_dead() {
+    let system = ActorSystem::new().await.unwrap();
+    let ticks = Arc::new(AtomicUsize::new(0));
+    let notify = Arc::new(Notify::new());
+    let target = spawn_tick_counter(&system, ticks.clone(), Arc::new(Mutex::new(Vec::new())), notify.clone()).await;
+
+    let token = system.get_scheduler().schedule_repeated(
+      Duration::ZERO,
+      Duration::from_millis(10),
+      None,
+      target.clone(),
+      MessageHandle::new(Tick),
+    );
+    tokio::time::timeout(Duration::from_secs(3), notify.notified())
+      .await
+      .expect("the repeated message was not delivered");
+
+    system
+      .get_root_context()
+      .await
+      .stop_future(&target)
+      .await
+      .result()
+      .await
+      .unwrap();
+    tokio::time::timeout(Duration::from_secs(3), token.cancelled())
+      .await
+      .expect("the repeated schedule kept running after its target stopped");
+
+    let dead_letters = Arc::new(AtomicUsize::new(0));
+    let cloned_dead_letters = dead_letters.clone();
+    let cloned_target = target.clone();
+    system
+      .get_event_stream()
+      .await
+      .subscribe(move |msg| {
+        let dead_letters = cloned_dead_letters.clone();
+        let target = cloned_target.clone();
+        async move {
+          if let Some(dead_letter) = msg.to_typed::<DeadLetterEvent>() {
+            if dead_letter.pid.as_ref() == Some(&target) {
+              dead_letters.fetch_add(1, Ordering::SeqCst);
+            }
+          }
+        }
+      })
+      .await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(dead_letters.load(Ordering::SeqCst), 0);
+  }
+
+  #[tokio::test]
+  async fn test_schedule_once_to_dead_pid_goes_to_dead_letters() {
+    let system = ActorSystem::new().await.unwrap();
+    let target = spawn_tick_counter(
+      &system,
+      Arc::new(AtomicUsize::new(0)),
+      Arc::new(Mutex::new(Vec::new())),
+      Arc::new(Notify::new()),
+    )
+    .await;
+    system
+      .get_root_context()
+      .await
+      .stop_future(&target)
+      .await
+      .result()
+      .await
+      .unwrap();
+
+    let notify = Arc::new(Notify::new());
+    let cloned_notify = notify.clone();
+    let cloned_target = target.clone();
+    system
+      .get_event_stream()
+      .await
+      .subscribe(move |msg| {
+        let notify = cloned_notify.clone();
+        let target = cloned_target.clone();
+        async move {
+          if let Some(dead_letter) = msg.to_typed::<DeadLetterEvent>() {
+            if dead_letter.pid.as_ref() == Some(&target) && dead_letter.message_handle.to_typed::<Tick>().is_some() {
+              notify.notify_one();
+            }
+          }
+        }
+      })
+      .await;
+
+    system
+      .get_scheduler()
+      .schedule_once(Duration::from_millis(10), None, target, MessageHandle::new(Tick));
+    tokio::time::timeout(Duration::from_secs(3), notify.notified())
+      .await
+      .expect("the scheduled message did not reach dead letters");
+  }
+}
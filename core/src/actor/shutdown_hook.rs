@@ -0,0 +1,39 @@
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+
+// ShutdownHook is run by ActorSystem::shutdown with the time left before the shutdown deadline
+#[derive(Clone)]
+pub struct ShutdownHook(Arc<dyn Fn(Duration) -> BoxFuture<'static, ()> + Send + Sync + 'static>);
+
+impl ShutdownHook {
+  pub fn new<F, Fut>(f: F) -> Self
+  where
+    F: Fn(Duration) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static, {
+    ShutdownHook(Arc::new(move |remaining| Box::pin(f(remaining))))
+  }
+
+  pub async fn run(&self, remaining: Duration) {
+    (self.0)(remaining).await
+  }
+}
+
+impl Debug for ShutdownHook {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "ShutdownHook")
+  }
+}
+
+impl PartialEq for ShutdownHook {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl Eq for ShutdownHook {}
+
+static_assertions::assert_impl_all!(ShutdownHook: Send, Sync);
@@ -1,7 +1,9 @@
 mod directive;
 mod exponential_backoff_strategy;
 mod exponential_backoff_strategy_test;
+mod root_escalation_handler;
 mod strategy_all_for_one;
+mod strategy_all_for_one_test;
 mod strategy_one_for_one;
 mod strategy_one_for_one_test;
 mod strategy_restarting;
@@ -12,7 +14,7 @@ mod supervisor_strategy;
 mod supervisor_strategy_handle;
 
 pub use {
-  self::directive::*, self::exponential_backoff_strategy::*, self::strategy_all_for_one::*,
-  self::strategy_one_for_one::*, self::strategy_restarting::*, self::supervision_event::*,
-  self::supervisor_strategy::*, self::supervisor_strategy_handle::*,
+  self::directive::*, self::exponential_backoff_strategy::*, self::root_escalation_handler::*,
+  self::strategy_all_for_one::*, self::strategy_one_for_one::*, self::strategy_restarting::*,
+  self::supervision_event::*, self::supervisor_strategy::*, self::supervisor_strategy_handle::*,
 };
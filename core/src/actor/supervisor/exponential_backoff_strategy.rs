@@ -57,16 +57,19 @@ impl SupervisorStrategy for ExponentialBackoffStrategy {
     let noise = rand::thread_rng().gen_range(0..500);
     let dur = Duration::from_nanos(backoff + noise);
 
-    actor_system
+    let result = actor_system
       .get_config()
       .await
       .system_dispatcher
-      .schedule(Runnable::new(move || async move {
+      .schedule_with_backoff(Runnable::new(move || async move {
         tokio::time::sleep(dur).await;
         log_failure(actor_system.clone(), &child, reason.clone(), Directive::Restart).await;
         supervisor.restart_children(&[child]).await;
       }))
       .await;
+    if let Err(err) = result {
+      tracing::error!("Failed to schedule the delayed restart: {}", err);
+    }
   }
 
   fn as_any(&self) -> &dyn Any {
@@ -0,0 +1,66 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use crate::actor::actor::ErrorReason;
+use crate::actor::actor::ExtendedPid;
+use crate::actor::actor_system::ActorSystem;
+use crate::actor::dispatch::MailboxMessage;
+use crate::actor::message::MessageHandle;
+use crate::actor::message::SystemMessage;
+use crate::actor::supervisor::Directive;
+
+// RootEscalationHandler receives failures escalated by an actor that has nobody left to escalate to
+#[derive(Clone)]
+pub struct RootEscalationHandler(Arc<dyn Fn(ErrorReason, MessageHandle) + Send + Sync + 'static>);
+
+impl RootEscalationHandler {
+  pub fn new(f: impl Fn(ErrorReason, MessageHandle) + Send + Sync + 'static) -> Self {
+    RootEscalationHandler(Arc::new(f))
+  }
+
+  pub fn run(&self, reason: ErrorReason, message_handle: MessageHandle) {
+    self.0(reason, message_handle)
+  }
+}
+
+impl Debug for RootEscalationHandler {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "RootEscalationHandler")
+  }
+}
+
+impl PartialEq for RootEscalationHandler {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl Eq for RootEscalationHandler {}
+
+// Reports a failure escalated past the root and applies the configured root escalation directive to `who`,
+// whose mailbox is suspended at this point
+pub(crate) async fn handle_root_escalation(
+  actor_system: &ActorSystem,
+  who: &ExtendedPid,
+  reason: ErrorReason,
+  message_handle: MessageHandle,
+) {
+  let config = actor_system.get_config().await;
+  match config.root_escalation_handler {
+    Some(handler) => handler.run(reason, message_handle),
+    None => tracing::error!(
+      "[Supervision] Failure escalated past the root: actor = {}, message = {}, reason = {}",
+      who,
+      message_handle,
+      reason
+    ),
+  }
+  let message_handle = match config.root_escalation_directive {
+    Directive::Resume => MessageHandle::new(MailboxMessage::ResumeMailbox),
+    Directive::Restart => MessageHandle::new(SystemMessage::Restart),
+    Directive::Stop | Directive::Escalate => MessageHandle::new(SystemMessage::Stop),
+  };
+  who.send_system_message(actor_system.clone(), message_handle).await;
+}
+
+static_assertions::assert_impl_all!(RootEscalationHandler: Send, Sync);
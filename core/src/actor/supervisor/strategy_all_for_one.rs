@@ -38,7 +38,7 @@ impl AllForOneStrategy {
     self
   }
 
-  async fn should_stop(&self, rs: &mut RestartStatistics) -> bool {
+  pub(crate) async fn should_stop(&self, rs: &mut RestartStatistics) -> bool {
     if self.max_nr_of_retries == 0 {
       true
     } else {
@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod test {
+  use std::env;
+  use std::sync::Arc;
+  use std::time::{Duration, Instant};
+
+  use async_trait::async_trait;
+  use tokio::sync::{Mutex, Notify};
+  use tracing_subscriber::EnvFilter;
+
+  use crate::actor::actor::{Actor, ActorError, ErrorReason, Props, RestartStatistics};
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{ContextHandle, MessagePart, SenderPart, SpawnerPart};
+  use crate::actor::message::MessageHandle;
+  use crate::actor::supervisor::strategy_all_for_one::AllForOneStrategy;
+  use crate::actor::supervisor::supervisor_strategy_handle::SupervisorStrategyHandle;
+
+  #[tokio::test]
+  async fn test_all_for_one_strategy_request_restart_permission() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let cases = vec![
+      (
+        "no restart if max retries is 0",
+        AllForOneStrategy::new(0, Duration::from_secs(0)),
+        RestartStatistics::new(),
+        true,
+      ),
+      (
+        "restart when duration set and within window",
+        AllForOneStrategy::new(2, Duration::from_secs(10)),
+        RestartStatistics::with_values(vec![Instant::now() - Duration::from_secs(5)]),
+        false,
+      ),
+      (
+        "no restart when duration set, within window and exceeds max retries",
+        AllForOneStrategy::new(1, Duration::from_secs(10)),
+        RestartStatistics::with_values(vec![
+          Instant::now() - Duration::from_secs(5),
+          Instant::now() - Duration::from_secs(5),
+        ]),
+        true,
+      ),
+      (
+        "restart when duration set and outside window",
+        AllForOneStrategy::new(1, Duration::from_secs(10)),
+        RestartStatistics::with_values(vec![
+          Instant::now() - Duration::from_secs(11),
+          Instant::now() - Duration::from_secs(11),
+        ]),
+        false,
+      ),
+    ];
+
+    for (name, s, mut rs, expected_result) in cases {
+      assert_eq!(s.should_stop(&mut rs).await, expected_result, "{}", name);
+    }
+  }
+
+  #[derive(Debug)]
+  struct SiblingActor {
+    name: &'static str,
+    restarted: Arc<Mutex<Vec<&'static str>>>,
+    notify: Arc<Notify>,
+  }
+
+  #[async_trait]
+  impl Actor for SiblingActor {
+    async fn receive(&mut self, ctx: ContextHandle) -> Result<(), ActorError> {
+      if ctx.get_message_handle().await.to_typed::<String>().is_some() {
+        return Err(ActorError::ReceiveError(ErrorReason::new("Boom!".to_string(), 0)));
+      }
+      Ok(())
+    }
+
+    async fn post_restart(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+      let mut restarted = self.restarted.lock().await;
+      restarted.push(self.name);
+      if restarted.len() == 3 {
+        self.notify.notify_one();
+      }
+      Ok(())
+    }
+  }
+
+  #[derive(Debug)]
+  struct SiblingGroupActor {
+    restarted: Arc<Mutex<Vec<&'static str>>>,
+    notify: Arc<Notify>,
+  }
+
+  #[async_trait]
+  impl Actor for SiblingGroupActor {
+    async fn receive(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+      Ok(())
+    }
+
+    async fn post_start(&mut self, mut ctx: ContextHandle) -> Result<(), ActorError> {
+      for name in ["a", "b", "c"] {
+        let restarted = self.restarted.clone();
+        let notify = self.notify.clone();
+        let props = Props::from_async_actor_producer(move |_| {
+          let actor = SiblingActor {
+            name,
+            restarted: restarted.clone(),
+            notify: notify.clone(),
+          };
+          async move { actor }
+        })
+        .await;
+        ctx.spawn_named(props, name).await.unwrap();
+      }
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_all_for_one_strategy_restarts_every_sibling() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+
+    let restarted = Arc::new(Mutex::new(vec![]));
+    let notify = Arc::new(Notify::new());
+    let cloned_restarted = restarted.clone();
+    let cloned_notify = notify.clone();
+    let props = Props::from_async_actor_producer_with_opts(
+      move |_| {
+        let actor = SiblingGroupActor {
+          restarted: cloned_restarted.clone(),
+          notify: cloned_notify.clone(),
+        };
+        async move { actor }
+      },
+      [Props::with_supervisor_strategy(SupervisorStrategyHandle::new(
+        AllForOneStrategy::new(10, Duration::from_secs(10)),
+      ))],
+    )
+    .await;
+    let parent = root_context.spawn_named(props, "siblings").await.unwrap();
+    parent.started(system.clone()).await;
+
+    let child = system.new_local_pid(&format!("{}/b", parent.id())).await;
+    root_context.send(child, MessageHandle::new("fail".to_string())).await;
+
+    tokio::time::timeout(Duration::from_secs(3), notify.notified())
+      .await
+      .expect("siblings were not restarted");
+    let mut restarted = restarted.lock().await.clone();
+    restarted.sort();
+    assert_eq!(restarted, vec!["a", "b", "c"]);
+  }
+}
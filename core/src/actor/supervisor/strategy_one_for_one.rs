@@ -8,6 +8,7 @@ use crate::actor::actor::ExtendedPid;
 use crate::actor::actor::RestartStatistics;
 use crate::actor::actor_system::ActorSystem;
 use crate::actor::message::MessageHandle;
+use crate::actor::message::SystemMessage;
 use crate::actor::supervisor::directive::Directive;
 use crate::actor::supervisor::supervisor_strategy::{
   log_failure, Decider, Supervisor, SupervisorHandle, SupervisorStrategy,
@@ -22,6 +23,7 @@ pub struct OneForOneStrategy {
   max_nr_of_retries: u32,
   pub(crate) within_duration: Duration,
   decider: Arc<Decider>,
+  stop_on_start_failure: bool,
 }
 
 impl OneForOneStrategy {
@@ -30,6 +32,7 @@ impl OneForOneStrategy {
       max_nr_of_retries,
       within_duration,
       decider: Arc::new(Decider::new(default_decider)),
+      stop_on_start_failure: false,
     }
   }
 
@@ -41,6 +44,21 @@ impl OneForOneStrategy {
     self
   }
 
+  // WithStopOnStartFailure stops a child that fails while starting or restarting, whatever the decider says.
+  // Restarting it would run the same failing start again
+  pub fn with_stop_on_start_failure(mut self) -> Self {
+    self.stop_on_start_failure = true;
+    self
+  }
+
+  fn is_start_failure(&self, message_handle: &MessageHandle) -> bool {
+    self.stop_on_start_failure
+      && matches!(
+        message_handle.to_typed::<SystemMessage>(),
+        Some(SystemMessage::Start) | Some(SystemMessage::Restart)
+      )
+  }
+
   pub(crate) async fn should_stop(&self, rs: &mut RestartStatistics) -> bool {
     tracing::debug!(
       "OneForOneStrategy::should_stop: max_retries = {}, failure_count = {}",
@@ -69,6 +87,7 @@ impl PartialEq for OneForOneStrategy {
     self.max_nr_of_retries == other.max_nr_of_retries
       && self.within_duration == other.within_duration
       && self.decider == other.decider
+      && self.stop_on_start_failure == other.stop_on_start_failure
   }
 }
 
@@ -79,6 +98,7 @@ impl std::hash::Hash for OneForOneStrategy {
     self.max_nr_of_retries.hash(state);
     self.within_duration.hash(state);
     self.decider.hash(state);
+    self.stop_on_start_failure.hash(state);
   }
 }
 
@@ -99,7 +119,11 @@ impl SupervisorStrategy for OneForOneStrategy {
       rs,
       message_handle
     );
-    let directive = self.decider.run(reason.clone()).await;
+    let directive = if self.is_start_failure(&message_handle) {
+      Directive::Stop
+    } else {
+      self.decider.run(reason.clone()).await
+    };
     match directive {
       Directive::Resume => {
         // resume the failing child
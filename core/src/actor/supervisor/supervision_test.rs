@@ -15,14 +15,19 @@ mod test {
   use crate::actor::message::AutoReceiveMessage;
   use crate::actor::message::Message;
   use crate::actor::message::MessageHandle;
+  use crate::actor::supervisor::directive::Directive;
   use crate::actor::supervisor::strategy_one_for_one::OneForOneStrategy;
   use crate::actor::supervisor::supervisor_strategy::{SupervisorHandle, SupervisorStrategy};
   use crate::actor::supervisor::supervisor_strategy_handle::SupervisorStrategyHandle;
+  use crate::actor::ConfigOption;
+  use crate::generated::actor::Terminated;
+  use crate::testkit::SupervisorEventProbe;
   use async_trait::async_trait;
   use nexus_actor_message_derive_rs::Message;
   use std::any::Any;
   use std::collections::VecDeque;
   use std::env;
+  use std::sync::atomic::{AtomicUsize, Ordering};
   use std::sync::Arc;
   use std::time::Duration;
   use thiserror::Error;
@@ -127,11 +132,221 @@ mod test {
       .unwrap();
   }
 
+  #[tokio::test]
+  async fn test_root_escalation_handler_observes_failure_and_stops_actor() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let escalated = Arc::new(Mutex::new(Vec::new()));
+    let cloned_escalated = escalated.clone();
+    let system = ActorSystem::new_config_options([ConfigOption::with_root_escalation_handler(
+      move |reason, message_handle| {
+        cloned_escalated.try_lock().unwrap().push((reason, message_handle));
+      },
+    )])
+    .await
+    .unwrap();
+    let mut root = system.get_root_context().await;
+
+    let stopped = Arc::new(Notify::new());
+    let cloned_stopped = stopped.clone();
+    let props = Props::from_async_actor_producer_with_opts(
+      move |_| {
+        let cloned_stopped = cloned_stopped.clone();
+        async move {
+          EscalatingParentActor {
+            stopped: cloned_stopped,
+          }
+        }
+      },
+      [Props::with_supervisor_strategy(SupervisorStrategyHandle::new(
+        OneForOneStrategy::new(10, Duration::from_secs(10)).with_decider(|_| async { Directive::Escalate }),
+      ))],
+    )
+    .await;
+    root.spawn(props).await;
+
+    tokio::time::timeout(Duration::from_secs(3), stopped.notified())
+      .await
+      .expect("root actor was not stopped after escalating");
+
+    let escalated = escalated.lock().await;
+    assert_eq!(escalated.len(), 1);
+    let (reason, message_handle) = &escalated[0];
+    assert!(reason.is_type::<&str>());
+    assert_eq!(reason.code, 0);
+    assert_eq!(
+      message_handle.to_typed::<StringMessage>(),
+      Some(StringMessage("fail".to_string()))
+    );
+  }
+
+  #[tokio::test]
+  async fn test_decider_matches_actor_error_variant() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let decided = Arc::new(Mutex::new(Vec::new()));
+    let cloned_decided = decided.clone();
+
+    // Top-level actors are supervised by the system's default strategy
+    let system = ActorSystem::new_config_options([ConfigOption::with_default_supervisor_strategy(
+      SupervisorStrategyHandle::new(
+        OneForOneStrategy::new(10, Duration::from_secs(10)).with_decider(move |reason| {
+          let cloned_decided = cloned_decided.clone();
+          async move {
+            let directive = match reason.actor_error() {
+              Some(ActorError::ReceiveError(_)) => Directive::Resume,
+              _ => Directive::Stop,
+            };
+            cloned_decided
+              .lock()
+              .await
+              .push((directive, reason.message_snapshot().cloned()));
+            directive
+          }
+        }),
+      ),
+    )])
+    .await
+    .unwrap();
+    let mut root = system.get_root_context().await;
+
+    let starts = Arc::new(AtomicUsize::new(0));
+    let actor = StartCountingActor {
+      starts: starts.clone(),
+      fail_start: false,
+    };
+    let props = Props::from_async_actor_producer(move |_| {
+      let actor = actor.clone();
+      async move { actor }
+    })
+    .await;
+    let pid = root.spawn(props).await;
+    let fail = MessageHandle::new(StringMessage("fail".to_string()));
+    root.send(pid.clone(), fail.clone()).await;
+
+    let deadline = Instant::now() + Duration::from_secs(3);
+    while decided.lock().await.is_empty() {
+      assert!(Instant::now() < deadline, "decider was not called");
+      tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(*decided.lock().await, vec![(Directive::Resume, Some(fail))]);
+    assert_eq!(starts.load(Ordering::SeqCst), 1);
+  }
+
+  #[tokio::test]
+  async fn test_root_escalation_directive_restarts_top_level_actor() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let escalated = Arc::new(AtomicUsize::new(0));
+    let cloned_escalated = escalated.clone();
+    let system = ActorSystem::new_config_options([
+      ConfigOption::with_root_escalation_handler(move |_, _| {
+        cloned_escalated.fetch_add(1, Ordering::SeqCst);
+      }),
+      ConfigOption::with_root_escalation_directive(Directive::Restart),
+      ConfigOption::with_default_supervisor_strategy(SupervisorStrategyHandle::new(
+        OneForOneStrategy::new(10, Duration::from_secs(10)).with_decider(|_| async { Directive::Escalate }),
+      )),
+    ])
+    .await
+    .unwrap();
+    let mut root = system.get_root_context().await;
+
+    let starts = Arc::new(AtomicUsize::new(0));
+    let actor = StartCountingActor {
+      starts: starts.clone(),
+      fail_start: false,
+    };
+    let props = Props::from_async_actor_producer(move |_| {
+      let actor = actor.clone();
+      async move { actor }
+    })
+    .await;
+    let pid = root.spawn(props).await;
+
+    for expected in 2..=3 {
+      root
+        .send(pid.clone(), MessageHandle::new(StringMessage("fail".to_string())))
+        .await;
+      let deadline = Instant::now() + Duration::from_secs(3);
+      while starts.load(Ordering::SeqCst) < expected {
+        assert!(Instant::now() < deadline, "top-level actor was not restarted");
+        tokio::time::sleep(Duration::from_millis(10)).await;
+      }
+    }
+
+    assert_eq!(escalated.load(Ordering::SeqCst), 2);
+    assert!(system.get_process_registry().await.find_process(&pid).await.is_some());
+  }
+
+  #[tokio::test]
+  async fn test_strategy_less_actors_use_system_default_supervisor_strategy() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new_config_options([ConfigOption::with_default_supervisor_strategy(
+      SupervisorStrategyHandle::new(
+        OneForOneStrategy::new(10, Duration::from_secs(10)).with_decider(|_| async { Directive::Stop }),
+      ),
+    )])
+    .await
+    .unwrap();
+    let probe = SupervisorEventProbe::new(&system).await;
+    let mut root = system.get_root_context().await;
+
+    // The failing child of a parent without a strategy is stopped instead of restarted
+    let stopped = Arc::new(Notify::new());
+    let cloned_stopped = stopped.clone();
+    let props = Props::from_async_actor_producer(move |_| {
+      let cloned_stopped = cloned_stopped.clone();
+      async move {
+        EscalatingParentActor {
+          stopped: cloned_stopped,
+        }
+      }
+    })
+    .await;
+    root.spawn(props).await;
+    probe
+      .expect_directives(&[Directive::Stop], Duration::from_secs(3))
+      .await
+      .unwrap();
+
+    // Top-level actors are supervised by the default strategy as well
+    probe.clear();
+    let props = Props::from_async_actor_producer(|_| async { FailingChildActor }).await;
+    let pid = root.spawn(props).await;
+    root
+      .send(pid.clone(), MessageHandle::new(StringMessage("fail".to_string())))
+      .await;
+    probe
+      .expect_directives_for(&pid, &[Directive::Stop], Duration::from_secs(3))
+      .await
+      .unwrap();
+    probe.unsubscribe().await;
+  }
+
   #[derive(Debug, Clone)]
   struct ActorWithSupervisor {
     notify: Arc<Notify>,
   }
 
+  #[derive(Debug, Clone)]
+  struct EscalatingParentActor {
+    stopped: Arc<Notify>,
+  }
+
   #[derive(Debug, Clone)]
   struct FailingChildActor;
 
@@ -185,6 +400,27 @@ mod test {
     }
   }
 
+  #[async_trait]
+  impl Actor for EscalatingParentActor {
+    async fn post_start(&mut self, mut ctx: ContextHandle) -> Result<(), ActorError> {
+      let props = Props::from_async_actor_producer(|_| async { FailingChildActor }).await;
+      let child = ctx.spawn(props).await;
+      ctx
+        .send(child, MessageHandle::new(StringMessage("fail".to_string())))
+        .await;
+      Ok(())
+    }
+
+    async fn receive(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+      Ok(())
+    }
+
+    async fn post_stop(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+      self.stopped.notify_one();
+      Ok(())
+    }
+  }
+
   #[async_trait]
   impl Actor for FailingChildActor {
     async fn post_start(&mut self, _: ContextHandle) -> Result<(), ActorError> {
@@ -203,6 +439,79 @@ mod test {
       }
     }
   }
+  #[derive(Debug, Error)]
+  #[error("connection lost after {attempts} attempts")]
+  struct ConnectionLost {
+    attempts: u32,
+  }
+
+  #[derive(Debug)]
+  struct RestartReasonActor {
+    reasons: Arc<Mutex<Vec<String>>>,
+    notify: Arc<Notify>,
+  }
+
+  #[async_trait]
+  impl Actor for RestartReasonActor {
+    async fn receive(&mut self, ctx: ContextHandle) -> Result<(), ActorError> {
+      if ctx.get_message_handle().await.to_typed::<StringMessage>().is_some() {
+        return Err(ActorError::ReceiveError(ErrorReason::new(
+          ConnectionLost { attempts: 3 },
+          0,
+        )));
+      }
+      Ok(())
+    }
+
+    async fn post_restart_with_reason(
+      &mut self,
+      _: ContextHandle,
+      reason: Option<ErrorReason>,
+    ) -> Result<(), ActorError> {
+      let reason = reason.expect("restarted without a reason");
+      assert!(reason.is_type::<ConnectionLost>());
+      tracing::info!("Restarted because of {}", reason.kind());
+      self.reasons.lock().await.push(reason.kind().to_string());
+      self.notify.notify_one();
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_post_restart_receives_failure_reason() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let mut root_context = system.get_root_context().await;
+    let reasons = Arc::new(Mutex::new(vec![]));
+    let notify = Arc::new(Notify::new());
+
+    let cloned_reasons = reasons.clone();
+    let cloned_notify = notify.clone();
+    let props = Props::from_async_actor_producer(move |_| {
+      let reasons = cloned_reasons.clone();
+      let notify = cloned_notify.clone();
+      async move { RestartReasonActor { reasons, notify } }
+    })
+    .await;
+    let pid = root_context.spawn(props).await;
+
+    root_context
+      .send(pid, MessageHandle::new(StringMessage("fail".to_string())))
+      .await;
+    tokio::time::timeout(Duration::from_secs(5), notify.notified())
+      .await
+      .expect("actor was not restarted");
+
+    assert_eq!(
+      *reasons.lock().await,
+      vec![std::any::type_name::<ConnectionLost>().to_string()]
+    );
+  }
+
   #[derive(Debug, Error)]
   enum TestError {
     #[error("Timeout")]
@@ -250,4 +559,141 @@ mod test {
       Err(TestError::TimeoutError)
     }
   }
+
+  #[derive(Debug, Clone)]
+  struct StartCountingActor {
+    starts: Arc<AtomicUsize>,
+    fail_start: bool,
+  }
+
+  impl StartCountingActor {
+    fn start(&self) -> Result<(), ActorError> {
+      self.starts.fetch_add(1, Ordering::SeqCst);
+      if self.fail_start {
+        return Err(ActorError::StartError(ErrorReason::new("start failed", 0)));
+      }
+      Ok(())
+    }
+  }
+
+  #[async_trait]
+  impl Actor for StartCountingActor {
+    async fn receive(&mut self, ctx: ContextHandle) -> Result<(), ActorError> {
+      if ctx.get_message_handle().await.to_typed::<StringMessage>().is_some() {
+        return Err(ActorError::ReceiveError(ErrorReason::new("fail", 0)));
+      }
+      Ok(())
+    }
+
+    async fn post_start(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+      self.start()
+    }
+
+    async fn post_restart(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+      self.start()
+    }
+  }
+
+  #[derive(Debug, Clone)]
+  struct RestartPolicyParent {
+    child: StartCountingActor,
+    child_pid: Arc<Mutex<Option<ExtendedPid>>>,
+    terminated: Arc<Notify>,
+  }
+
+  #[async_trait]
+  impl Actor for RestartPolicyParent {
+    async fn receive(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+      Ok(())
+    }
+
+    async fn post_child_terminate(&mut self, _: ContextHandle, _: &Terminated) -> Result<(), ActorError> {
+      self.terminated.notify_one();
+      Ok(())
+    }
+
+    async fn post_start(&mut self, mut ctx: ContextHandle) -> Result<(), ActorError> {
+      let child = self.child.clone();
+      let props = Props::from_async_actor_producer(move |_| {
+        let child = child.clone();
+        async move { child }
+      })
+      .await;
+      *self.child_pid.lock().await = Some(ctx.spawn(props).await);
+      Ok(())
+    }
+  }
+
+  async fn spawn_restart_policy_parent(
+    system: &ActorSystem,
+    max_retries: u32,
+    child: StartCountingActor,
+  ) -> (ExtendedPid, Arc<Notify>) {
+    let child_pid = Arc::new(Mutex::new(None));
+    let terminated = Arc::new(Notify::new());
+    let parent = RestartPolicyParent {
+      child,
+      child_pid: child_pid.clone(),
+      terminated: terminated.clone(),
+    };
+    let props = Props::from_async_actor_producer_with_opts(
+      move |_| {
+        let parent = parent.clone();
+        async move { parent }
+      },
+      [Props::with_restart_policy(max_retries, Duration::from_secs(10))],
+    )
+    .await;
+    system.get_root_context().await.spawn(props).await;
+    let child_pid = tokio::time::timeout(Duration::from_secs(3), async {
+      loop {
+        if let Some(pid) = child_pid.lock().await.clone() {
+          return pid;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+      }
+    })
+    .await
+    .expect("the child was not spawned");
+    (child_pid, terminated)
+  }
+
+  #[tokio::test]
+  async fn test_restart_policy_stops_child_that_fails_to_start() {
+    let system = ActorSystem::new().await.unwrap();
+    let starts = Arc::new(AtomicUsize::new(0));
+    let child = StartCountingActor {
+      starts: starts.clone(),
+      fail_start: true,
+    };
+    let (_, terminated) = spawn_restart_policy_parent(&system, 3, child).await;
+
+    tokio::time::timeout(Duration::from_secs(3), terminated.notified())
+      .await
+      .expect("the child was not stopped");
+    assert_eq!(starts.load(Ordering::SeqCst), 1);
+  }
+
+  #[tokio::test]
+  async fn test_restart_policy_stops_child_after_max_retries() {
+    let system = ActorSystem::new().await.unwrap();
+    let starts = Arc::new(AtomicUsize::new(0));
+    let child = StartCountingActor {
+      starts: starts.clone(),
+      fail_start: false,
+    };
+    let (child_pid, terminated) = spawn_restart_policy_parent(&system, 2, child).await;
+
+    let mut root_context = system.get_root_context().await;
+    for _ in 0..3 {
+      root_context
+        .send(child_pid.clone(), MessageHandle::new(StringMessage("fail".to_string())))
+        .await;
+    }
+
+    tokio::time::timeout(Duration::from_secs(3), terminated.notified())
+      .await
+      .expect("the child was not stopped");
+    assert_eq!(starts.load(Ordering::SeqCst), 3);
+  }
 }
@@ -1,6 +1,6 @@
-use crate::actor::actor::{ActorError, ActorHandle, SpawnError, TypedExtendedPid, TypedProps};
+use crate::actor::actor::{ActorError, ActorHandle, ExtendedPid, SpawnError, TypedExtendedPid, TypedProps};
 use crate::actor::actor_system::ActorSystem;
-use crate::actor::context::{BasePart, ExtensionContext, ExtensionPart};
+use crate::actor::context::{BasePart, ExtensionContext, ExtensionPart, PersistencePart};
 use crate::actor::dispatch::future::ActorFuture;
 use crate::actor::message::{Message, MessageHandle, ReadonlyMessageHeadersHandle, TypedMessageEnvelope};
 use async_trait::async_trait;
@@ -14,14 +14,24 @@ pub trait TypedContext<M: Message>:
   + TypedSpawnerContext<M>
   + BasePart
   + TypedStopperPart<M>
+  + PersistencePart
   + Debug
   + Send
   + Sync
   + 'static {
 }
 
+#[async_trait]
 pub trait TypedSenderContext<M: Message>:
   TypedInfoPart<M> + TypedSenderPart<M> + TypedMessagePart<M> + Send + Sync + 'static {
+  // SendAfter sends a message to the given PID once the delay has elapsed
+  async fn send_after(&mut self, pid: ExtendedPid, message_handle: MessageHandle, delay: Duration) {
+    let actor_system = self.get_actor_system().await;
+    tokio::spawn(async move {
+      tokio::time::sleep(delay).await;
+      pid.send_user_message(actor_system, message_handle).await;
+    });
+  }
 }
 
 pub trait TypedReceiverContext<M: Message>:
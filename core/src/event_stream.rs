@@ -3,5 +3,11 @@ mod event_stream_impl;
 mod event_stream_test;
 mod predicate;
 mod subscription;
+mod topic;
+mod topic_registry;
+mod topic_test;
 
-pub use {self::event_handler::*, self::event_stream_impl::*, self::predicate::*, self::subscription::*};
+pub use {
+  self::event_handler::*, self::event_stream_impl::*, self::predicate::*, self::subscription::*, self::topic::*,
+  self::topic_registry::*,
+};
@@ -1,16 +1,21 @@
+use crate::actor::message::Message;
 use crate::actor::message::MessageHandle;
 use crate::event_stream::event_handler::EventHandler;
 use crate::event_stream::predicate::Predicate;
 use crate::event_stream::subscription::Subscription;
+use futures::future::join_all;
 use std::future::Future;
 use std::sync::atomic::{AtomicI32, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::{mpsc, RwLock};
 
 #[derive(Debug, Clone)]
 pub struct EventStream {
   subscriptions: Arc<RwLock<Vec<Subscription>>>,
   counter: Arc<AtomicI32>,
+  publish_queue: Option<mpsc::UnboundedSender<MessageHandle>>,
+  concurrent_handlers: bool,
 }
 
 impl EventStream {
@@ -18,9 +23,46 @@ impl EventStream {
     EventStream {
       subscriptions: Arc::new(RwLock::new(Vec::new())),
       counter: Arc::new(AtomicI32::new(0)),
+      publish_queue: None,
+      concurrent_handlers: false,
     }
   }
 
+  // NewConcurrent creates an event stream whose publish runs the handlers of all matching subscribers
+  // concurrently instead of one after another, so that a slow handler does not delay the others.
+  // Publish still returns once every handler has finished
+  pub fn new_concurrent() -> Self {
+    EventStream {
+      concurrent_handlers: true,
+      ..Self::new()
+    }
+  }
+
+  // NewSerialized creates an event stream that delivers events in the order they were published, even when
+  // published from concurrent tasks. Events are queued and delivered one at a time by a single task, so publish
+  // returns before subscribers have run. Must be called from within a tokio runtime
+  pub fn new_serialized() -> Self {
+    let subscriptions = Arc::new(RwLock::new(Vec::new()));
+    let (tx, mut rx) = mpsc::unbounded_channel::<MessageHandle>();
+    let cloned_subscriptions = subscriptions.clone();
+    tokio::spawn(async move {
+      // Ends once every clone of the event stream has been dropped
+      while let Some(evt) = rx.recv().await {
+        Self::deliver(&cloned_subscriptions, evt, false).await;
+      }
+    });
+    EventStream {
+      subscriptions,
+      counter: Arc::new(AtomicI32::new(0)),
+      publish_queue: Some(tx),
+      concurrent_handlers: false,
+    }
+  }
+
+  pub fn is_serialized(&self) -> bool {
+    self.publish_queue.is_some()
+  }
+
   pub async fn subscribe_handler(&self, handler: EventHandler) -> Subscription {
     let subscription = Subscription::new(self.counter.fetch_add(1, Ordering::SeqCst), Arc::new(handler), None);
     let mut subscriptions = self.subscriptions.write().await;
@@ -46,6 +88,24 @@ impl EventStream {
     subscription
   }
 
+  // SubscribeTyped subscribes to the events of type `T` only, passing them to the handler already downcast
+  pub async fn subscribe_typed<T, F, Fut>(&self, f: F) -> Subscription
+  where
+    T: Message,
+    F: Fn(&T) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static, {
+    let handler = EventHandler::new(move |evt: MessageHandle| {
+      let fut = evt.as_typed::<T>().map(&f);
+      async move {
+        if let Some(fut) = fut {
+          fut.await
+        }
+      }
+    });
+    let predicate = Predicate::new(|evt: MessageHandle| evt.is_typed::<T>());
+    self.subscribe_with_predicate(handler, predicate).await
+  }
+
   pub async fn unsubscribe(&self, sub: Subscription) {
     if sub.is_active() {
       let mut subscriptions = self.subscriptions.write().await;
@@ -59,14 +119,59 @@ impl EventStream {
   }
 
   pub async fn publish(&self, evt: MessageHandle) {
+    match &self.publish_queue {
+      Some(publish_queue) => {
+        if publish_queue.send(evt).is_err() {
+          tracing::error!("EventStream: serialized publish queue is closed");
+        }
+      }
+      None => Self::deliver(&self.subscriptions, evt, self.concurrent_handlers).await,
+    }
+  }
+
+  // PublishWithTimeout runs the handlers of all matching subscribers concurrently and drops those that do not
+  // finish within `per_handler_timeout`. Returns the indices, in subscription order, of the handlers that timed
+  // out. The event is delivered right away, also on a serialized event stream
+  pub async fn publish_with_timeout(&self, evt: MessageHandle, per_handler_timeout: Duration) -> Vec<usize> {
     let subscriptions = self.subscriptions.read().await;
-    for sub in &*subscriptions {
-      if let Some(predicate) = &sub.predicate {
-        if !predicate.run(evt.clone()) {
-          continue;
+    let deliveries = subscriptions
+      .iter()
+      .enumerate()
+      .filter(|(_, sub)| Self::accepts(sub, &evt))
+      .map(|(index, sub)| {
+        sub.record_delivery();
+        let evt = evt.clone();
+        async move {
+          match tokio::time::timeout(per_handler_timeout, sub.handler.run(evt)).await {
+            Ok(_) => None,
+            Err(_) => Some(index),
+          }
         }
+      });
+    join_all(deliveries).await.into_iter().flatten().collect()
+  }
+
+  fn accepts(sub: &Subscription, evt: &MessageHandle) -> bool {
+    sub
+      .predicate
+      .as_ref()
+      .is_none_or(|predicate| predicate.run(evt.clone()))
+  }
+
+  async fn deliver(subscriptions: &RwLock<Vec<Subscription>>, evt: MessageHandle, concurrent_handlers: bool) {
+    let subscriptions = subscriptions.read().await;
+    let matching = subscriptions.iter().filter(|sub| Self::accepts(sub, &evt));
+    if concurrent_handlers {
+      join_all(matching.map(|sub| {
+        sub.record_delivery();
+        sub.handler.run(evt.clone())
+      }))
+      .await;
+    } else {
+      for sub in matching {
+        sub.record_delivery();
+        sub.handler.run(evt.clone()).await;
       }
-      sub.handler.run(evt.clone()).await;
     }
   }
 
@@ -2,6 +2,7 @@
 mod tests {
   use std::sync::atomic::{AtomicI32, Ordering};
   use std::sync::Arc;
+  use std::time::Duration;
 
   use crate::actor::message::Message;
   use crate::actor::message::MessageHandle;
@@ -9,9 +10,9 @@ mod tests {
   use crate::event_stream::event_stream_impl::EventStream;
   use crate::event_stream::predicate::Predicate;
   use nexus_actor_message_derive_rs::Message;
-  use tokio::sync::Mutex;
+  use tokio::sync::{Mutex, Notify};
 
-  #[derive(Debug, PartialEq, Eq, Message)]
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
   pub struct TestString(pub String);
 
   #[tokio::test]
@@ -136,6 +137,118 @@ mod tests {
     assert!(!*called.lock().await);
   }
 
+  #[tokio::test]
+  async fn test_event_stream_subscription_delivered_count() {
+    let es = EventStream::new();
+    let s = es
+      .subscribe_with_predicate(
+        EventHandler::new(|_| async move {}),
+        Predicate::new(|evt: MessageHandle| evt.to_typed::<TestString>().is_some()),
+      )
+      .await;
+    assert_eq!(s.delivered_count(), 0);
+    assert!(s.last_delivered_at().is_none());
+
+    es.publish(MessageHandle::new(TestString("first".to_string()))).await;
+    assert_eq!(s.delivered_count(), 1);
+    let first_delivered_at = s.last_delivered_at().unwrap();
+
+    es.publish(MessageHandle::new(1)).await;
+    assert_eq!(s.delivered_count(), 1);
+
+    es.publish(MessageHandle::new(TestString("second".to_string()))).await;
+    assert_eq!(s.delivered_count(), 2);
+    assert!(s.last_delivered_at().unwrap() >= first_delivered_at);
+  }
+
+  #[tokio::test]
+  async fn test_event_stream_subscribe_typed() {
+    let es = EventStream::new();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let cloned_received = received.clone();
+    let s = es
+      .subscribe_typed::<TestString, _, _>(move |evt| {
+        let received = cloned_received.clone();
+        let value = evt.0.clone();
+        async move {
+          received.lock().await.push(value);
+        }
+      })
+      .await;
+    assert_eq!(es.length(), 1);
+
+    es.publish(MessageHandle::new(TestString("first".to_string()))).await;
+    es.publish(MessageHandle::new(1)).await;
+    es.publish(MessageHandle::new(TestString("second".to_string()))).await;
+    assert_eq!(*received.lock().await, vec!["first".to_string(), "second".to_string()]);
+    assert_eq!(s.delivered_count(), 2);
+
+    es.unsubscribe(s).await;
+    assert_eq!(es.length(), 0);
+    es.publish(MessageHandle::new(TestString("third".to_string()))).await;
+    assert_eq!(received.lock().await.len(), 2);
+  }
+
+  #[tokio::test]
+  async fn test_concurrent_event_stream_does_not_wait_for_slow_handlers() {
+    let es = EventStream::new_concurrent();
+    let fast_done = Arc::new(Notify::new());
+
+    // The first handler only finishes once the second one has run, which never happens if they run in sequence
+    let cloned_fast_done = fast_done.clone();
+    es.subscribe(move |_| {
+      let fast_done = cloned_fast_done.clone();
+      async move {
+        fast_done.notified().await;
+      }
+    })
+    .await;
+    let cloned_fast_done = fast_done.clone();
+    es.subscribe(move |_| {
+      let fast_done = cloned_fast_done.clone();
+      async move {
+        fast_done.notify_one();
+      }
+    })
+    .await;
+
+    tokio::time::timeout(Duration::from_secs(1), es.publish(MessageHandle::new(1)))
+      .await
+      .expect("handlers were not run concurrently");
+  }
+
+  #[tokio::test]
+  async fn test_event_stream_publish_with_timeout_skips_slow_handlers() {
+    let es = EventStream::new();
+    let calls = Arc::new(AtomicI32::new(0));
+
+    es.subscribe(|_| futures::future::pending::<()>()).await;
+    let cloned_calls = calls.clone();
+    es.subscribe(move |_| {
+      let calls = cloned_calls.clone();
+      async move {
+        calls.fetch_add(1, Ordering::SeqCst);
+      }
+    })
+    .await;
+    let blocked = Arc::new(Mutex::new(()));
+    let cloned_blocked = blocked.clone();
+    es.subscribe(move |_| {
+      let blocked = cloned_blocked.clone();
+      async move {
+        let _guard = blocked.lock().await;
+      }
+    })
+    .await;
+
+    let _guard = blocked.lock().await;
+    let timed_out = es
+      .publish_with_timeout(MessageHandle::new(1), Duration::from_millis(100))
+      .await;
+    assert_eq!(timed_out, vec![0, 2]);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+  }
+
   #[derive(Debug, PartialEq, Eq, Message)]
   struct Event {
     i: i32,
@@ -174,4 +287,62 @@ mod tests {
       }
     }
   }
+
+  #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+  async fn test_serialized_event_stream_delivers_concurrent_publishes_in_fifo_order() {
+    let es = EventStream::new_serialized();
+    assert!(es.is_serialized());
+
+    let mut receivers = Vec::new();
+    for _ in 0..2 {
+      let received = Arc::new(Mutex::new(Vec::new()));
+      let cloned_received = received.clone();
+      es.subscribe(move |evt| {
+        let received = cloned_received.clone();
+        async move {
+          if let Some(e) = evt.to_typed::<TestString>() {
+            tokio::task::yield_now().await;
+            received.lock().await.push(e.0);
+          }
+        }
+      })
+      .await;
+      receivers.push(received);
+    }
+
+    // Each publisher enqueues while holding the lock, so `published` is the order events entered the stream
+    let published = Arc::new(Mutex::new(Vec::new()));
+    let mut publishers = Vec::new();
+    for publisher in 0..8 {
+      let es = es.clone();
+      let published = published.clone();
+      publishers.push(tokio::spawn(async move {
+        for n in 0..50 {
+          let name = format!("{}-{}", publisher, n);
+          let mut published = published.lock().await;
+          es.publish(MessageHandle::new(TestString(name.clone()))).await;
+          published.push(name);
+          drop(published);
+          tokio::task::yield_now().await;
+        }
+      }));
+    }
+    for publisher in publishers {
+      publisher.await.unwrap();
+    }
+
+    let published = published.lock().await.clone();
+    tokio::time::timeout(Duration::from_secs(5), async {
+      for received in &receivers {
+        while received.lock().await.len() < published.len() {
+          tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+      }
+    })
+    .await
+    .expect("not all events were delivered");
+    for received in &receivers {
+      assert_eq!(*received.lock().await, published);
+    }
+  }
 }
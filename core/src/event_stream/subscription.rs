@@ -1,14 +1,19 @@
 use crate::event_stream::event_handler::EventHandler;
 use crate::event_stream::predicate::Predicate;
-use std::sync::atomic::{AtomicU32, Ordering};
+use chrono::{DateTime, Utc};
+use std::sync::atomic::{AtomicI64, AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
 
+const NEVER_DELIVERED: i64 = i64::MIN;
+
 #[derive(Debug, Clone)]
 pub struct Subscription {
   id: i32,
   pub(crate) handler: Arc<EventHandler>,
   pub(crate) predicate: Option<Predicate>,
   active: Arc<AtomicU32>,
+  delivered_count: Arc<AtomicU64>,
+  last_delivered_at_micros: Arc<AtomicI64>,
 }
 
 impl Subscription {
@@ -18,6 +23,8 @@ impl Subscription {
       handler,
       predicate,
       active: Arc::new(AtomicU32::new(1)),
+      delivered_count: Arc::new(AtomicU64::new(0)),
+      last_delivered_at_micros: Arc::new(AtomicI64::new(NEVER_DELIVERED)),
     }
   }
 
@@ -38,6 +45,25 @@ impl Subscription {
   pub fn is_active(&self) -> bool {
     self.active.load(Ordering::SeqCst) == 1
   }
+
+  // DeliveredCount returns how many published events passed the predicate and reached the handler
+  pub fn delivered_count(&self) -> u64 {
+    self.delivered_count.load(Ordering::SeqCst)
+  }
+
+  pub fn last_delivered_at(&self) -> Option<DateTime<Utc>> {
+    match self.last_delivered_at_micros.load(Ordering::SeqCst) {
+      NEVER_DELIVERED => None,
+      micros => DateTime::from_timestamp_micros(micros),
+    }
+  }
+
+  pub(crate) fn record_delivery(&self) {
+    self
+      .last_delivered_at_micros
+      .store(Utc::now().timestamp_micros(), Ordering::SeqCst);
+    self.delivered_count.fetch_add(1, Ordering::SeqCst);
+  }
 }
 
 static_assertions::assert_impl_all!(Subscription: Send, Sync);
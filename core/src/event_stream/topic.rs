@@ -0,0 +1,98 @@
+use crate::actor::message::Message;
+use crate::actor::message::MessageHandle;
+use crate::event_stream::event_handler::EventHandler;
+use crate::event_stream::event_stream_impl::EventStream;
+use crate::event_stream::predicate::Predicate;
+use crate::event_stream::subscription::Subscription;
+use nexus_actor_message_derive_rs::Message;
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+// TopicEvent is what a Topic publishes on the event stream, so that messages of the same type published
+// on different topics are not delivered across them
+#[derive(Debug, Clone, PartialEq, Eq, Message)]
+pub struct TopicEvent {
+  pub topic: String,
+  pub message_handle: MessageHandle,
+}
+
+// Topic is a named, typed channel on top of an untyped event stream
+pub struct Topic<T: Message + Clone> {
+  name: String,
+  event_stream: Arc<EventStream>,
+  phantom_data: PhantomData<T>,
+}
+
+impl<T: Message + Clone> Topic<T> {
+  pub fn new(event_stream: Arc<EventStream>, name: &str) -> Self {
+    Self {
+      name: name.to_string(),
+      event_stream,
+      phantom_data: PhantomData,
+    }
+  }
+
+  pub fn get_name(&self) -> &str {
+    &self.name
+  }
+
+  pub async fn publish(&self, message: T) {
+    self
+      .event_stream
+      .publish(MessageHandle::new(TopicEvent {
+        topic: self.name.clone(),
+        message_handle: MessageHandle::new(message),
+      }))
+      .await
+  }
+
+  // Subscribe registers `f` for the messages published on this topic
+  pub async fn subscribe<F, Fut>(&self, f: F) -> Subscription
+  where
+    F: Fn(T) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static, {
+    let name = self.name.clone();
+    let predicate = Predicate::new(move |evt| {
+      evt
+        .to_typed::<TopicEvent>()
+        .is_some_and(|event| event.topic == name && event.message_handle.to_typed::<T>().is_some())
+    });
+    let handler = EventHandler::new(move |evt: MessageHandle| {
+      let message = evt
+        .to_typed::<TopicEvent>()
+        .and_then(|event| event.message_handle.to_typed::<T>());
+      let fut = message.map(&f);
+      async move {
+        if let Some(fut) = fut {
+          fut.await;
+        }
+      }
+    });
+    self.event_stream.subscribe_with_predicate(handler, predicate).await
+  }
+
+  pub async fn unsubscribe(&self, subscription: Subscription) {
+    self.event_stream.unsubscribe(subscription).await
+  }
+}
+
+impl<T: Message + Clone> Clone for Topic<T> {
+  fn clone(&self) -> Self {
+    Self {
+      name: self.name.clone(),
+      event_stream: self.event_stream.clone(),
+      phantom_data: PhantomData,
+    }
+  }
+}
+
+impl<T: Message + Clone> Debug for Topic<T> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Topic")
+      .field("name", &self.name)
+      .field("type", &std::any::type_name::<T>())
+      .finish()
+  }
+}
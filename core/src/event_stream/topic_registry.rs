@@ -0,0 +1,52 @@
+use crate::actor::message::Message;
+use crate::event_stream::event_stream_impl::EventStream;
+use crate::event_stream::topic::Topic;
+use dashmap::DashMap;
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TopicError {
+  #[error("Topic {topic} carries {registered}, not {requested}")]
+  TypeMismatch {
+    topic: String,
+    registered: &'static str,
+    requested: &'static str,
+  },
+}
+
+// TopicRegistry hands out the topics of an event stream by name. The first lookup of a name fixes the
+// message type of the topic
+#[derive(Debug, Clone)]
+pub struct TopicRegistry {
+  event_stream: Arc<EventStream>,
+  topics: Arc<DashMap<String, &'static str>>,
+}
+
+impl TopicRegistry {
+  pub fn new(event_stream: Arc<EventStream>) -> Self {
+    Self {
+      event_stream,
+      topics: Arc::new(DashMap::new()),
+    }
+  }
+
+  pub fn get_topic<T: Message + Clone>(&self, name: &str) -> Result<Topic<T>, TopicError> {
+    let requested = std::any::type_name::<T>();
+    let registered = *self.topics.entry(name.to_string()).or_insert(requested);
+    if registered != requested {
+      return Err(TopicError::TypeMismatch {
+        topic: name.to_string(),
+        registered,
+        requested,
+      });
+    }
+    Ok(Topic::new(self.event_stream.clone(), name))
+  }
+
+  pub fn get_topic_names(&self) -> Vec<String> {
+    self.topics.iter().map(|entry| entry.key().clone()).collect()
+  }
+}
+
+static_assertions::assert_impl_all!(TopicRegistry: Send, Sync);
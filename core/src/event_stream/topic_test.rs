@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use crate::actor::actor_system::ActorSystem;
+  use crate::event_stream::TopicError;
+  use nexus_actor_message_derive_rs::Message;
+  use tokio::sync::Mutex;
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct OrderPlaced(u32);
+
+  #[derive(Debug, Clone, PartialEq, Eq, Message)]
+  struct PriceChanged(u32);
+
+  #[tokio::test]
+  async fn test_topics_of_different_types_do_not_cross_deliver() {
+    let system = ActorSystem::new().await.unwrap();
+    let registry = system.get_topic_registry().await;
+    let orders = registry.get_topic::<OrderPlaced>("orders").unwrap();
+    let prices = registry.get_topic::<PriceChanged>("prices").unwrap();
+
+    let received_orders = Arc::new(Mutex::new(Vec::new()));
+    let received_prices = Arc::new(Mutex::new(Vec::new()));
+    let cloned_orders = received_orders.clone();
+    let orders_subscription = orders
+      .subscribe(move |order| {
+        let received = cloned_orders.clone();
+        async move { received.lock().await.push(order) }
+      })
+      .await;
+    let cloned_prices = received_prices.clone();
+    prices
+      .subscribe(move |price| {
+        let received = cloned_prices.clone();
+        async move { received.lock().await.push(price) }
+      })
+      .await;
+
+    orders.publish(OrderPlaced(1)).await;
+    prices.publish(PriceChanged(100)).await;
+    orders.publish(OrderPlaced(2)).await;
+
+    assert_eq!(*received_orders.lock().await, vec![OrderPlaced(1), OrderPlaced(2)]);
+    assert_eq!(*received_prices.lock().await, vec![PriceChanged(100)]);
+
+    // Looking a topic up again hands out the same channel, with the type it was registered with
+    let same_orders = registry.get_topic::<OrderPlaced>("orders").unwrap();
+    same_orders.publish(OrderPlaced(3)).await;
+    assert_eq!(received_orders.lock().await.len(), 3);
+    assert_eq!(
+      registry.get_topic::<PriceChanged>("orders").unwrap_err(),
+      TopicError::TypeMismatch {
+        topic: "orders".to_string(),
+        registered: std::any::type_name::<OrderPlaced>(),
+        requested: std::any::type_name::<PriceChanged>(),
+      }
+    );
+
+    orders.unsubscribe(orders_subscription).await;
+    orders.publish(OrderPlaced(4)).await;
+    assert_eq!(received_orders.lock().await.len(), 3);
+  }
+}
@@ -2,4 +2,3 @@ pub mod actor {
   include!("../generated/actor.rs");
 }
 pub mod actor_impl;
-mod poison_pill;
@@ -1,5 +1,7 @@
 #![allow(dead_code)]
 extern crate nexus_actor_message_derive_rs;
+// Lets `#[derive(Message)]` expand to `::nexus_actor_core_rs::...` paths inside this crate as well
+extern crate self as nexus_actor_core_rs;
 
 pub mod actor;
 pub mod ctxext;
@@ -7,5 +9,6 @@ pub mod event_stream;
 pub mod extensions;
 pub mod generated;
 pub mod metrics;
+pub mod testkit;
 
 pub use nexus_actor_message_derive_rs::Message;
@@ -1,8 +1,10 @@
 use crate::actor::MetricsProvider;
 use opentelemetry::metrics::MeterProvider;
-use opentelemetry::metrics::{Counter, Histogram, Meter};
+use opentelemetry::metrics::{Counter, Histogram, Meter, ObservableGauge, UpDownCounter};
 use opentelemetry::KeyValue;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
 use tokio::sync::Mutex;
 
 pub const LIB_NAME: &str = "protoactor";
@@ -20,18 +22,43 @@ struct ActorMetricsInner {
   futures_started_count: Counter<u64>,
   futures_completed_count: Counter<u64>,
   futures_timed_out_count: Counter<u64>,
+  futures_pending_count: UpDownCounter<i64>,
   thread_pool_latency: Histogram<f64>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
+struct MailboxLengthObserver {
+  probe: Arc<dyn Fn() -> Option<u64> + Send + Sync>,
+  attributes: Vec<KeyValue>,
+}
+
+type MailboxLengthObservers = Arc<std::sync::Mutex<HashMap<u64, MailboxLengthObserver>>>;
+
+// MailboxLengthRegistration identifies a mailbox length observer so it can be removed again
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MailboxLengthRegistration(u64);
+
+#[derive(Clone)]
 pub struct ActorMetrics {
   inner: Arc<Mutex<ActorMetricsInner>>,
+  meter: Meter,
+  mailbox_length_gauge: Arc<OnceLock<ObservableGauge<u64>>>,
+  mailbox_length_observers: MailboxLengthObservers,
+  next_observer_id: Arc<AtomicU64>,
+}
+
+impl std::fmt::Debug for ActorMetrics {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ActorMetrics")
+      .field("inner", &self.inner)
+      .field("mailbox_length_observers", &self.get_mailbox_length_observer_count())
+      .finish()
+  }
 }
 
 impl ActorMetrics {
   pub fn new(meter_provider: Arc<MetricsProvider>) -> Result<Self, opentelemetry::metrics::MetricsError> {
     let meter = meter_provider.meter(LIB_NAME);
-
     Ok(ActorMetrics {
       inner: Arc::new(Mutex::new(ActorMetricsInner {
         meter: meter.clone(),
@@ -85,16 +112,65 @@ impl ActorMetrics {
           .with_description("Number of futures timed out")
           .with_unit("1")
           .try_init()?,
+        futures_pending_count: meter
+          .i64_up_down_counter("nexus_actor_futures_pending_count")
+          .with_description("Number of futures pending")
+          .with_unit("1")
+          .try_init()?,
         thread_pool_latency: meter
           .f64_histogram("nexus_actor_thread_pool_latency_duration_seconds")
           .with_description("History of latency in seconds")
           .with_unit("s")
           .try_init()?,
-        // mailbox_length,
       })),
+      meter,
+      mailbox_length_gauge: Arc::new(OnceLock::new()),
+      mailbox_length_observers: Arc::new(std::sync::Mutex::new(HashMap::new())),
+      next_observer_id: Arc::new(AtomicU64::new(0)),
     })
   }
 
+  // The probe is called from the gauge callback on every collection until the registration is removed.
+  // The gauge itself is only created once the first observer is registered
+  pub fn register_mailbox_length_observer<F>(&self, probe: F, attributes: Vec<KeyValue>) -> MailboxLengthRegistration
+  where
+    F: Fn() -> Option<u64> + Send + Sync + 'static, {
+    self.mailbox_length_gauge.get_or_init(|| {
+      let observers = self.mailbox_length_observers.clone();
+      self
+        .meter
+        .u64_observable_gauge("nexus_actor_actor_mailbox_length_gauge")
+        .with_description("Number of messages waiting in actor mailboxes")
+        .with_unit("1")
+        .with_callback(move |instrument| {
+          let observers = observers.lock().unwrap().values().cloned().collect::<Vec<_>>();
+          for observer in observers {
+            if let Some(length) = (observer.probe)() {
+              instrument.observe(length, &observer.attributes);
+            }
+          }
+        })
+        .init()
+    });
+    let id = self.next_observer_id.fetch_add(1, Ordering::SeqCst);
+    self.mailbox_length_observers.lock().unwrap().insert(
+      id,
+      MailboxLengthObserver {
+        probe: Arc::new(probe),
+        attributes,
+      },
+    );
+    MailboxLengthRegistration(id)
+  }
+
+  pub fn unregister_mailbox_length_observer(&self, registration: &MailboxLengthRegistration) {
+    self.mailbox_length_observers.lock().unwrap().remove(&registration.0);
+  }
+
+  pub fn get_mailbox_length_observer_count(&self) -> usize {
+    self.mailbox_length_observers.lock().unwrap().len()
+  }
+
   pub async fn increment_actor_failure_count(&self) {
     self.increment_actor_failure_count_with_opts(&[]).await;
   }
@@ -186,12 +262,68 @@ impl ActorMetrics {
     let inner_mg = self.inner.lock().await;
     inner_mg.futures_timed_out_count.add(1, attributes);
   }
+
+  pub async fn increment_futures_pending_count(&self) {
+    self.increment_futures_pending_count_with_opts(&[]).await;
+  }
+
+  pub async fn increment_futures_pending_count_with_opts(&self, attributes: &[KeyValue]) {
+    let inner_mg = self.inner.lock().await;
+    inner_mg.futures_pending_count.add(1, attributes);
+  }
+
+  pub async fn decrement_futures_pending_count(&self) {
+    self.decrement_futures_pending_count_with_opts(&[]).await;
+  }
+
+  pub async fn decrement_futures_pending_count_with_opts(&self, attributes: &[KeyValue]) {
+    let inner_mg = self.inner.lock().await;
+    inner_mg.futures_pending_count.add(-1, attributes);
+  }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
-  use opentelemetry_sdk::metrics::{ManualReader, MeterProviderBuilder};
+  use opentelemetry_sdk::metrics::data::{ResourceMetrics, Temporality};
+  use opentelemetry_sdk::metrics::reader::{AggregationSelector, MetricReader, TemporalitySelector};
+  use opentelemetry_sdk::metrics::{Aggregation, InstrumentKind, ManualReader, MeterProviderBuilder, Pipeline};
+  use opentelemetry_sdk::Resource;
+  use std::sync::atomic::AtomicUsize;
+  use std::sync::Weak;
+
+  #[derive(Debug, Clone)]
+  struct SharedReader(Arc<ManualReader>);
+
+  impl AggregationSelector for SharedReader {
+    fn aggregation(&self, kind: InstrumentKind) -> Aggregation {
+      self.0.aggregation(kind)
+    }
+  }
+
+  impl TemporalitySelector for SharedReader {
+    fn temporality(&self, kind: InstrumentKind) -> Temporality {
+      self.0.temporality(kind)
+    }
+  }
+
+  impl MetricReader for SharedReader {
+    fn register_pipeline(&self, pipeline: Weak<Pipeline>) {
+      self.0.register_pipeline(pipeline)
+    }
+
+    fn collect(&self, rm: &mut ResourceMetrics) -> opentelemetry::metrics::Result<()> {
+      self.0.collect(rm)
+    }
+
+    fn force_flush(&self) -> opentelemetry::metrics::Result<()> {
+      self.0.force_flush()
+    }
+
+    fn shutdown(&self) -> opentelemetry::metrics::Result<()> {
+      self.0.shutdown()
+    }
+  }
 
   #[tokio::test]
   async fn test_actor_metrics() {
@@ -210,4 +342,66 @@ mod tests {
     metrics.increment_futures_completed_count().await;
     metrics.increment_futures_timed_out_count().await;
   }
+
+  #[tokio::test]
+  async fn test_mailbox_length_observer_is_not_called_after_unregister() {
+    let reader = SharedReader(Arc::new(ManualReader::builder().build()));
+    let meter_provider = Arc::new(MetricsProvider::Sdk(
+      MeterProviderBuilder::default().with_reader(reader.clone()).build(),
+    ));
+    let metrics = ActorMetrics::new(meter_provider.clone()).unwrap();
+    let collect = || {
+      let mut rm = ResourceMetrics {
+        resource: Resource::empty(),
+        scope_metrics: vec![],
+      };
+      reader.collect(&mut rm).unwrap();
+    };
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let cloned_calls = calls.clone();
+    let registration = metrics.register_mailbox_length_observer(
+      move || {
+        cloned_calls.fetch_add(1, Ordering::SeqCst);
+        Some(1)
+      },
+      vec![],
+    );
+    assert_eq!(metrics.get_mailbox_length_observer_count(), 1);
+
+    collect();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    metrics.unregister_mailbox_length_observer(&registration);
+    assert_eq!(metrics.get_mailbox_length_observer_count(), 0);
+
+    collect();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+  }
+
+  #[tokio::test]
+  async fn test_mailbox_length_gauge_is_created_by_the_first_observer() {
+    let reader = SharedReader(Arc::new(ManualReader::builder().build()));
+    let meter_provider = Arc::new(MetricsProvider::Sdk(
+      MeterProviderBuilder::default().with_reader(reader.clone()).build(),
+    ));
+    let metrics = ActorMetrics::new(meter_provider.clone()).unwrap();
+    let has_gauge = || {
+      let mut rm = ResourceMetrics {
+        resource: Resource::empty(),
+        scope_metrics: vec![],
+      };
+      reader.collect(&mut rm).unwrap();
+      rm.scope_metrics
+        .iter()
+        .flat_map(|scope_metrics| scope_metrics.metrics.iter())
+        .any(|metric| metric.name == "nexus_actor_actor_mailbox_length_gauge")
+    };
+
+    assert!(!has_gauge());
+
+    metrics.register_mailbox_length_observer(|| Some(1), vec![]);
+    metrics.register_mailbox_length_observer(|| Some(2), vec![]);
+    assert!(has_gauge());
+  }
 }
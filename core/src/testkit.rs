@@ -0,0 +1,4 @@
+mod supervisor_event_probe;
+mod supervisor_event_probe_test;
+
+pub use self::supervisor_event_probe::*;
@@ -0,0 +1,145 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use thiserror::Error;
+use tokio::sync::Notify;
+
+use crate::actor::actor::ExtendedPid;
+use crate::actor::actor_system::ActorSystem;
+use crate::actor::supervisor::{Directive, SupervisorEvent};
+use crate::event_stream::{EventHandler, Predicate, Subscription};
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SupervisorEventProbeError {
+  #[error("Timed out waiting for directives: expected = {expected:?}, actual = {actual:?}")]
+  Timeout {
+    expected: Vec<Directive>,
+    actual: Vec<Directive>,
+  },
+  #[error("Unexpected directives: expected = {expected:?}, actual = {actual:?}")]
+  Mismatch {
+    expected: Vec<Directive>,
+    actual: Vec<Directive>,
+  },
+}
+
+// SupervisorEventProbe records the SupervisorEvents published to the event stream of an actor system,
+// so that tests can assert on supervision decisions instead of on their side effects
+#[derive(Debug, Clone)]
+pub struct SupervisorEventProbe {
+  actor_system: ActorSystem,
+  subscription: Subscription,
+  events: Arc<Mutex<Vec<SupervisorEvent>>>,
+  recorded: Arc<Notify>,
+}
+
+impl SupervisorEventProbe {
+  pub async fn new(actor_system: &ActorSystem) -> Self {
+    let events = Arc::new(Mutex::new(vec![]));
+    let recorded = Arc::new(Notify::new());
+    let cloned_events = events.clone();
+    let cloned_recorded = recorded.clone();
+    let subscription = actor_system
+      .get_event_stream()
+      .await
+      .subscribe_with_predicate(
+        EventHandler::new(move |evt| {
+          let events = cloned_events.clone();
+          let recorded = cloned_recorded.clone();
+          async move {
+            if let Some(event) = evt.to_typed::<SupervisorEvent>() {
+              events.lock().unwrap().push(event);
+              recorded.notify_waiters();
+            }
+          }
+        }),
+        Predicate::new(|evt| evt.is_typed::<SupervisorEvent>()),
+      )
+      .await;
+    Self {
+      actor_system: actor_system.clone(),
+      subscription,
+      events,
+      recorded,
+    }
+  }
+
+  pub fn get_events(&self) -> Vec<SupervisorEvent> {
+    self.events.lock().unwrap().clone()
+  }
+
+  pub fn get_directives(&self) -> Vec<Directive> {
+    self.get_events().iter().map(|event| event.directive).collect()
+  }
+
+  pub fn get_directives_for(&self, child: &ExtendedPid) -> Vec<Directive> {
+    self
+      .get_events()
+      .iter()
+      .filter(|event| event.child == *child)
+      .map(|event| event.directive)
+      .collect()
+  }
+
+  // Waits until as many directives as expected have been recorded, then compares them with the expected sequence
+  pub async fn expect_directives(
+    &self,
+    expected: &[Directive],
+    timeout: Duration,
+  ) -> Result<(), SupervisorEventProbeError> {
+    self.expect(expected, timeout, || self.get_directives()).await
+  }
+
+  // Like expect_directives, but only looks at the decisions taken for `child`
+  pub async fn expect_directives_for(
+    &self,
+    child: &ExtendedPid,
+    expected: &[Directive],
+    timeout: Duration,
+  ) -> Result<(), SupervisorEventProbeError> {
+    self.expect(expected, timeout, || self.get_directives_for(child)).await
+  }
+
+  async fn expect(
+    &self,
+    expected: &[Directive],
+    timeout: Duration,
+    directives: impl Fn() -> Vec<Directive>,
+  ) -> Result<(), SupervisorEventProbeError> {
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+      // Register for the next event before reading, so that an event recorded in between is not missed
+      let recorded = self.recorded.notified();
+      let actual = directives();
+      if actual.len() >= expected.len() {
+        return if actual == expected {
+          Ok(())
+        } else {
+          Err(SupervisorEventProbeError::Mismatch {
+            expected: expected.to_vec(),
+            actual,
+          })
+        };
+      }
+      if tokio::time::timeout_at(deadline, recorded).await.is_err() {
+        return Err(SupervisorEventProbeError::Timeout {
+          expected: expected.to_vec(),
+          actual: directives(),
+        });
+      }
+    }
+  }
+
+  pub fn clear(&self) {
+    self.events.lock().unwrap().clear();
+  }
+
+  pub async fn unsubscribe(&self) {
+    self
+      .actor_system
+      .get_event_stream()
+      .await
+      .unsubscribe(self.subscription.clone())
+      .await;
+  }
+}
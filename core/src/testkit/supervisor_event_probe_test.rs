@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+  use std::env;
+  use std::sync::{Arc, Mutex};
+  use std::time::Duration;
+
+  use async_trait::async_trait;
+  use tokio::sync::Notify;
+  use tracing_subscriber::EnvFilter;
+
+  use crate::actor::actor::{Actor, ActorError, ErrorReason, ExtendedPid, Props};
+  use crate::actor::actor_system::ActorSystem;
+  use crate::actor::context::{ContextHandle, MessagePart, SenderPart, SpawnerPart};
+  use crate::actor::message::MessageHandle;
+  use crate::actor::supervisor::{Directive, OneForOneStrategy, SupervisorStrategyHandle};
+  use crate::testkit::{SupervisorEventProbe, SupervisorEventProbeError};
+
+  #[derive(Debug, Clone)]
+  struct ParentActor {
+    child: Arc<Mutex<Option<ExtendedPid>>>,
+    spawned: Arc<Notify>,
+  }
+
+  #[derive(Debug, Clone)]
+  struct FailingChildActor;
+
+  #[async_trait]
+  impl Actor for ParentActor {
+    async fn post_start(&mut self, mut ctx: ContextHandle) -> Result<(), ActorError> {
+      let props = Props::from_async_actor_producer(|_| async { FailingChildActor }).await;
+      let child = ctx.spawn(props).await;
+      *self.child.lock().unwrap() = Some(child);
+      self.spawned.notify_one();
+      Ok(())
+    }
+
+    async fn receive(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+      Ok(())
+    }
+
+    async fn get_supervisor_strategy(&mut self) -> Option<SupervisorStrategyHandle> {
+      Some(SupervisorStrategyHandle::new(OneForOneStrategy::new(
+        2,
+        Duration::from_secs(10),
+      )))
+    }
+  }
+
+  #[async_trait]
+  impl Actor for FailingChildActor {
+    async fn receive(&mut self, ctx: ContextHandle) -> Result<(), ActorError> {
+      if ctx.get_message_handle().await.to_typed::<i32>().is_some() {
+        return Err(ActorError::ReceiveError(ErrorReason::new("Boom!", 0)));
+      }
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_supervisor_event_probe_asserts_decision_sequence() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let probe = SupervisorEventProbe::new(&system).await;
+
+    let parent = ParentActor {
+      child: Arc::new(Mutex::new(None)),
+      spawned: Arc::new(Notify::new()),
+    };
+    let cloned_parent = parent.clone();
+    let props = Props::from_async_actor_producer(move |_| {
+      let parent = cloned_parent.clone();
+      async move { parent }
+    })
+    .await;
+    let mut root_context = system.get_root_context().await;
+    root_context.spawn(props).await;
+    parent.spawned.notified().await;
+    let child = parent.child.lock().unwrap().clone().unwrap();
+
+    // Two restarts are allowed within the window, the third failure stops the child
+    for n in 0..3 {
+      root_context.send(child.clone(), MessageHandle::new(n)).await;
+    }
+
+    probe
+      .expect_directives_for(
+        &child,
+        &[Directive::Restart, Directive::Restart, Directive::Stop],
+        Duration::from_secs(5),
+      )
+      .await
+      .unwrap();
+
+    assert_eq!(
+      probe
+        .expect_directives(&[Directive::Restart, Directive::Stop], Duration::from_millis(100))
+        .await,
+      Err(SupervisorEventProbeError::Mismatch {
+        expected: vec![Directive::Restart, Directive::Stop],
+        actual: vec![Directive::Restart, Directive::Restart, Directive::Stop],
+      })
+    );
+
+    probe.clear();
+    assert_eq!(
+      probe
+        .expect_directives(&[Directive::Restart], Duration::from_millis(100))
+        .await,
+      Err(SupervisorEventProbeError::Timeout {
+        expected: vec![Directive::Restart],
+        actual: vec![],
+      })
+    );
+    probe.unsubscribe().await;
+  }
+}
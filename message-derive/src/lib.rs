@@ -1,15 +1,49 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Index, LitStr};
 
-#[proc_macro_derive(Message)]
+#[proc_macro_derive(Message, attributes(message))]
 pub fn derive_message(input: TokenStream) -> TokenStream {
   let input = parse_macro_input!(input as DeriveInput);
   let name = &input.ident;
 
+  let log_fields = match log_fields(&input) {
+    Ok(log_fields) => log_fields,
+    Err(error) => return error.to_compile_error().into(),
+  };
+
+  let options = match MessageOptions::parse(&input) {
+    Ok(options) => options,
+    Err(error) => return error.to_compile_error().into(),
+  };
+
+  let type_name = match &options.remote {
+    Some(remote) => quote! { #remote.to_string() },
+    None => quote! { std::any::type_name_of_val(self).to_string() },
+  };
+
+  let influences_receive_timeout = if options.not_influence_receive_timeout {
+    quote! {
+        fn influences_receive_timeout(&self) -> bool {
+            false
+        }
+    }
+  } else {
+    quote! {}
+  };
+
+  let remote_registration = match &options.remote {
+    Some(remote) => quote! {
+        ::nexus_actor_remote_rs::inventory::submit! {
+            ::nexus_actor_remote_rs::RemoteMessageRegistration::new::<#name>(#remote)
+        }
+    },
+    None => quote! {},
+  };
+
   let expanded = quote! {
-      impl Message for #name {
-          fn eq_message(&self, other: &dyn Message) -> bool {
+      impl ::nexus_actor_core_rs::actor::message::Message for #name {
+          fn eq_message(&self, other: &dyn ::nexus_actor_core_rs::actor::message::Message) -> bool {
               other.as_any().downcast_ref::<Self>()
                   .map_or(false, |other| self == other)
           }
@@ -19,10 +53,101 @@ pub fn derive_message(input: TokenStream) -> TokenStream {
           }
 
           fn get_type_name(&self) -> String {
-              std::any::type_name_of_val(self).to_string()
+              #type_name
           }
+
+          #log_fields
+
+          #influences_receive_timeout
       }
+
+      #remote_registration
   };
 
   TokenStream::from(expanded)
 }
+
+// MessageOptions are the `#[message(...)]` attributes of the type itself.
+// `remote = "my.pkg.Hello"` makes the declared name the type name of the message and registers its protobuf
+// serializers under that name when the remote starts, this requires nexus-actor-remote-rs.
+struct MessageOptions {
+  not_influence_receive_timeout: bool,
+  remote: Option<LitStr>,
+}
+
+impl MessageOptions {
+  fn parse(input: &DeriveInput) -> syn::Result<Self> {
+    let mut options = MessageOptions {
+      not_influence_receive_timeout: false,
+      remote: None,
+    };
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("message")) {
+      attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("not_influence_receive_timeout") {
+          options.not_influence_receive_timeout = true;
+          Ok(())
+        } else if meta.path.is_ident("remote") {
+          options.remote = Some(meta.value()?.parse()?);
+          Ok(())
+        } else {
+          Err(
+            meta.error("unsupported message attribute, expected `not_influence_receive_timeout` or `remote = \"...\"`"),
+          )
+        }
+      })?;
+    }
+    Ok(options)
+  }
+}
+
+// Generates `log_fields` from the struct fields annotated with `#[message(log)]`.
+// Nothing is generated when no field is annotated, so the default of the Message trait applies.
+fn log_fields(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+  let fields = match &input.data {
+    Data::Struct(data) => match &data.fields {
+      Fields::Named(fields) => fields.named.iter().collect::<Vec<_>>(),
+      Fields::Unnamed(fields) => fields.unnamed.iter().collect::<Vec<_>>(),
+      Fields::Unit => vec![],
+    },
+    _ => vec![],
+  };
+
+  let mut entries = vec![];
+  for (index, field) in fields.into_iter().enumerate() {
+    let mut log = false;
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("message")) {
+      attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("log") {
+          log = true;
+          Ok(())
+        } else {
+          Err(meta.error("unsupported message attribute, expected `log`"))
+        }
+      })?;
+    }
+    if !log {
+      continue;
+    }
+    let entry = match &field.ident {
+      Some(ident) => {
+        let key = ident.to_string();
+        quote! { ::nexus_actor_core_rs::actor::message::Field::stringer(#key, &self.#ident) }
+      }
+      None => {
+        let key = index.to_string();
+        let index = Index::from(index);
+        quote! { ::nexus_actor_core_rs::actor::message::Field::stringer(#key, &self.#index) }
+      }
+    };
+    entries.push(entry);
+  }
+
+  if entries.is_empty() {
+    return Ok(quote! {});
+  }
+  Ok(quote! {
+      fn log_fields(&self) -> Vec<::nexus_actor_core_rs::actor::message::Field> {
+          vec![#(#entries),*]
+      }
+  })
+}
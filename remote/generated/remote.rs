@@ -71,6 +71,17 @@ pub struct ActorPidResponse {
     pub status_code: i32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoteFailure {
+    #[prost(message, optional, tag = "1")]
+    pub who: ::core::option::Option<super::actor::Pid>,
+    #[prost(string, tag = "2")]
+    pub kind: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(int32, tag = "4")]
+    pub code: i32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConnectRequest {
     #[prost(oneof = "connect_request::ConnectionType", tags = "1, 2")]
     pub connection_type: ::core::option::Option<connect_request::ConnectionType>,
@@ -91,6 +102,8 @@ pub struct DisconnectRequest {}
 pub struct ClientConnection {
     #[prost(string, tag = "1")]
     pub system_id: ::prost::alloc::string::String,
+    #[prost(uint32, repeated, tag = "2")]
+    pub serializer_ids: ::prost::alloc::vec::Vec<u32>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ServerConnection {
@@ -98,6 +111,8 @@ pub struct ServerConnection {
     pub system_id: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub address: ::prost::alloc::string::String,
+    #[prost(uint32, repeated, tag = "3")]
+    pub serializer_ids: ::prost::alloc::vec::Vec<u32>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConnectResponse {
@@ -105,6 +120,8 @@ pub struct ConnectResponse {
     pub member_id: ::prost::alloc::string::String,
     #[prost(bool, tag = "3")]
     pub blocked: bool,
+    #[prost(uint32, tag = "4")]
+    pub serializer_id: u32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ListProcessesRequest {
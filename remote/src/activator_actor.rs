@@ -160,6 +160,15 @@ impl Actor for Activator {
                   .await;
                 Err(ActorError::ReceiveError(ErrorReason::new("Failed to spawn actor", 0)))
               }
+              SpawnError::ErrDispatcher(_) => {
+                context_handle
+                  .respond(ResponseHandle::new(ActorPidResponse {
+                    pid: None,
+                    status_code: ResponseStatusCode::Error as i32,
+                  }))
+                  .await;
+                Err(ActorError::ReceiveError(ErrorReason::new("Failed to spawn actor", 0)))
+              }
             },
           }
         }
@@ -1,5 +1,6 @@
 use crate::config::server_config::ServerConfig;
 use crate::config_option::ConfigOption;
+use crate::serializer::SerializerId;
 use dashmap::DashMap;
 use nexus_actor_core_rs::actor::actor::Props;
 use std::net::{IpAddr, SocketAddr};
@@ -21,7 +22,12 @@ struct ConfigInner {
   kinds: DashMap<String, Props>,
   max_retry_count: u32,
   retry_interval: Duration,
+  max_retry_interval: Duration,
+  max_reconnect_count: u32,
+  max_reconnect_interval: Duration,
+  endpoint_writer_pending_queue_size: usize,
   server_config: Option<ServerConfig>,
+  serializer_ids: Vec<SerializerId>,
 }
 
 #[derive(Debug, Clone)]
@@ -43,7 +49,12 @@ impl Default for Config {
         kinds: DashMap::new(),
         max_retry_count: 5,
         retry_interval: Duration::from_secs(2),
+        max_retry_interval: Duration::from_secs(30),
+        max_reconnect_count: 0,
+        max_reconnect_interval: Duration::from_secs(30),
+        endpoint_writer_pending_queue_size: 1000,
         server_config: None,
+        serializer_ids: vec![SerializerId::Proto, SerializerId::Json],
       })),
     }
   }
@@ -184,6 +195,46 @@ impl Config {
     mg.retry_interval = retry_interval;
   }
 
+  pub async fn get_max_retry_interval(&self) -> Duration {
+    let mg = self.inner.lock().await;
+    mg.max_retry_interval
+  }
+
+  pub async fn set_max_retry_interval(&mut self, max_retry_interval: Duration) {
+    let mut mg = self.inner.lock().await;
+    mg.max_retry_interval = max_retry_interval;
+  }
+
+  pub async fn get_max_reconnect_count(&self) -> u32 {
+    let mg = self.inner.lock().await;
+    mg.max_reconnect_count
+  }
+
+  pub async fn set_max_reconnect_count(&mut self, max_reconnect_count: u32) {
+    let mut mg = self.inner.lock().await;
+    mg.max_reconnect_count = max_reconnect_count;
+  }
+
+  pub async fn get_max_reconnect_interval(&self) -> Duration {
+    let mg = self.inner.lock().await;
+    mg.max_reconnect_interval
+  }
+
+  pub async fn set_max_reconnect_interval(&mut self, max_reconnect_interval: Duration) {
+    let mut mg = self.inner.lock().await;
+    mg.max_reconnect_interval = max_reconnect_interval;
+  }
+
+  pub async fn get_endpoint_writer_pending_queue_size(&self) -> usize {
+    let mg = self.inner.lock().await;
+    mg.endpoint_writer_pending_queue_size
+  }
+
+  pub async fn set_endpoint_writer_pending_queue_size(&mut self, endpoint_writer_pending_queue_size: usize) {
+    let mut mg = self.inner.lock().await;
+    mg.endpoint_writer_pending_queue_size = endpoint_writer_pending_queue_size;
+  }
+
   pub async fn get_server_config(&self) -> Option<ServerConfig> {
     let mg = self.inner.lock().await;
     mg.server_config.clone()
@@ -193,4 +244,14 @@ impl Config {
     let mut mg = self.inner.lock().await;
     mg.server_config = Some(server_config);
   }
+
+  pub async fn get_serializer_ids(&self) -> Vec<SerializerId> {
+    let mg = self.inner.lock().await;
+    mg.serializer_ids.clone()
+  }
+
+  pub async fn set_serializer_ids(&mut self, serializer_ids: Vec<SerializerId>) {
+    let mut mg = self.inner.lock().await;
+    mg.serializer_ids = serializer_ids;
+  }
 }
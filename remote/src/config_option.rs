@@ -1,5 +1,7 @@
 use crate::config::Config;
+use crate::serializer::SerializerId;
 use nexus_actor_core_rs::actor::actor::Props;
+use std::time::Duration;
 
 #[derive(Debug, Clone)]
 pub enum ConfigOption {
@@ -7,6 +9,13 @@ pub enum ConfigOption {
   SetPort(u16),
   SetAdvertisedHost(String),
   PutKind(String, Props),
+  SetSerializerIds(Vec<SerializerId>),
+  SetMaxRetryCount(u32),
+  SetRetryInterval(Duration),
+  SetMaxRetryInterval(Duration),
+  SetMaxReconnectCount(u32),
+  SetMaxReconnectInterval(Duration),
+  SetEndpointWriterPendingQueueSize(usize),
 }
 
 impl ConfigOption {
@@ -24,6 +33,27 @@ impl ConfigOption {
       ConfigOption::PutKind(kind, props) => {
         config.put_kind(kind, props.clone()).await;
       }
+      ConfigOption::SetSerializerIds(serializer_ids) => {
+        config.set_serializer_ids(serializer_ids.clone()).await;
+      }
+      ConfigOption::SetMaxRetryCount(max_retry_count) => {
+        config.set_max_retry_count(*max_retry_count).await;
+      }
+      ConfigOption::SetRetryInterval(retry_interval) => {
+        config.set_retry_interval(*retry_interval).await;
+      }
+      ConfigOption::SetMaxRetryInterval(max_retry_interval) => {
+        config.set_max_retry_interval(*max_retry_interval).await;
+      }
+      ConfigOption::SetMaxReconnectCount(max_reconnect_count) => {
+        config.set_max_reconnect_count(*max_reconnect_count).await;
+      }
+      ConfigOption::SetMaxReconnectInterval(max_reconnect_interval) => {
+        config.set_max_reconnect_interval(*max_reconnect_interval).await;
+      }
+      ConfigOption::SetEndpointWriterPendingQueueSize(size) => {
+        config.set_endpoint_writer_pending_queue_size(*size).await;
+      }
     }
   }
 
@@ -42,4 +72,37 @@ impl ConfigOption {
   pub fn with_kind(kind: &str, props: Props) -> ConfigOption {
     ConfigOption::PutKind(kind.to_string(), props)
   }
+
+  pub fn with_serializer_ids(serializer_ids: impl IntoIterator<Item = SerializerId>) -> ConfigOption {
+    ConfigOption::SetSerializerIds(serializer_ids.into_iter().collect())
+  }
+
+  // Attempts of the initial connect handshake. A peer answering that it blocks this system is not retried.
+  pub fn with_max_retry_count(max_retry_count: u32) -> ConfigOption {
+    ConfigOption::SetMaxRetryCount(max_retry_count)
+  }
+
+  // Wait before the first handshake retry, doubled for every further attempt up to the max retry interval.
+  pub fn with_retry_interval(retry_interval: Duration) -> ConfigOption {
+    ConfigOption::SetRetryInterval(retry_interval)
+  }
+
+  pub fn with_max_retry_interval(max_retry_interval: Duration) -> ConfigOption {
+    ConfigOption::SetMaxRetryInterval(max_retry_interval)
+  }
+
+  // Reconnects a dropped connection up to the given number of times, backing off exponentially from the
+  // retry interval. Zero, the default, terminates the endpoint as soon as the connection is lost.
+  pub fn with_max_reconnect_count(max_reconnect_count: u32) -> ConfigOption {
+    ConfigOption::SetMaxReconnectCount(max_reconnect_count)
+  }
+
+  pub fn with_max_reconnect_interval(max_reconnect_interval: Duration) -> ConfigOption {
+    ConfigOption::SetMaxReconnectInterval(max_reconnect_interval)
+  }
+
+  // Bounds the messages queued while reconnecting, the overflow is sent to dead letters.
+  pub fn with_endpoint_writer_pending_queue_size(size: usize) -> ConfigOption {
+    ConfigOption::SetEndpointWriterPendingQueueSize(size)
+  }
 }
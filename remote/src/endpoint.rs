@@ -1,4 +1,3 @@
-use nexus_actor_core_rs::actor::message::Message;
 use nexus_actor_core_rs::generated::actor::Pid;
 use nexus_actor_core_rs::Message;
 
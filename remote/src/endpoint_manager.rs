@@ -190,16 +190,24 @@ impl EndpointManager {
     }
     self.reset_endpoint_subscription().await;
     self.connections = Arc::new(DashMap::new());
-    for value_ref in self.endpoint_reader_connections.iter() {
-      let (key, value) = value_ref.pair();
+    let keys = self
+      .endpoint_reader_connections
+      .iter()
+      .map(|value_ref| value_ref.key().clone())
+      .collect::<Vec<_>>();
+    for key in keys {
+      let Some((_, value)) = self.endpoint_reader_connections.remove(&key) else {
+        continue;
+      };
       let sender = {
         let mg = value.lock().await;
-        mg.as_ref().unwrap().clone()
+        mg.as_ref().cloned()
       };
-      if let Err(err) = sender.send(true).await {
-        tracing::error!("Failed to send stop signal to endpoint reader: {:?}", err);
+      if let Some(sender) = sender {
+        if let Err(err) = sender.send(true).await {
+          tracing::error!("Failed to send stop signal to endpoint reader: {:?}", err);
+        }
       }
-      self.endpoint_reader_connections.remove(key);
     }
     tracing::info!("Stopped EndpointManager");
     Ok(())
@@ -308,6 +316,9 @@ impl EndpointManager {
           .send(pid, MessageHandle::new(endpoint_event))
           .await;
       }
+      EndpointEvent::EndpointDisconnected(ev) => {
+        tracing::debug!("EndpointManager received endpoint disconnected event: {}", ev.address);
+      }
     }
   }
 
@@ -400,8 +411,9 @@ impl EndpointManager {
   }
 
   async fn remove_endpoint(&self, message: &EndpointTerminatedEvent) {
-    if let Some(v) = self.connections.get(&message.address) {
-      let le = v.value();
+    // Clone the entry out so that no shard lock is held while removing it
+    let lazy = self.connections.get(&message.address).map(|v| v.value().clone());
+    if let Some(le) = lazy {
       if le
         .get_unloaded()
         .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
@@ -1,7 +1,8 @@
-use nexus_actor_core_rs::actor::actor::ExtendedPid;
+use nexus_actor_core_rs::actor::actor::{ErrorReason, ErrorReasonSummary, ExtendedPid, RestartStatistics};
 use nexus_actor_core_rs::actor::actor_system::ActorSystem;
 use nexus_actor_core_rs::actor::context::SenderPart;
-use nexus_actor_core_rs::actor::message::{MessageEnvelope, MessageHandle, MessageHeaders, SystemMessage};
+use nexus_actor_core_rs::actor::message::{Failure, MessageEnvelope, MessageHandle, MessageHeaders, SystemMessage};
+use nexus_actor_core_rs::actor::process::process_registry::ListProcessesMatchType;
 use nexus_actor_core_rs::actor::process::Process;
 use nexus_actor_core_rs::generated::actor::{Pid, Stop, Terminated, Unwatch, Watch};
 
@@ -11,12 +12,12 @@ use crate::generated::remote::connect_request::ConnectionType;
 use crate::generated::remote::remoting_server::Remoting;
 use crate::generated::remote::{
   ConnectRequest, GetProcessDiagnosticsRequest, GetProcessDiagnosticsResponse, ListProcessesRequest,
-  ListProcessesResponse, MessageBatch, RemoteMessage, ServerConnection,
+  ListProcessesResponse, MessageBatch, RemoteFailure, RemoteMessage, ServerConnection,
 };
 use crate::remote::Remote;
-use crate::serializer::{deserialize_any, deserialize_message, SerializerId};
+use crate::serializer::{deserialize_any, deserialize_message, negotiate_serializer_id, SerializerId};
 use std::pin::Pin;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Weak};
 use thiserror::Error;
 use tokio::sync::mpsc::{Receiver, Sender};
@@ -38,6 +39,8 @@ pub enum EndpointReaderError {
 #[derive(Debug, Clone)]
 pub(crate) struct EndpointReader {
   suspended: Arc<AtomicBool>,
+  accepting: Arc<AtomicBool>,
+  in_flight_batches: Arc<AtomicUsize>,
   remote: Weak<Remote>,
 }
 
@@ -45,6 +48,8 @@ impl EndpointReader {
   pub(crate) fn new(remote: Weak<Remote>) -> Self {
     EndpointReader {
       suspended: Arc::new(AtomicBool::new(false)),
+      accepting: Arc::new(AtomicBool::new(true)),
+      in_flight_batches: Arc::new(AtomicUsize::new(0)),
       remote,
     }
   }
@@ -80,14 +85,9 @@ impl EndpointReader {
   }
 
   async fn on_server_connection(&self, response_tx: &Sender<Result<RemoteMessage, Status>>, sc: &ServerConnection) {
-    if self
-      .remote
-      .upgrade()
-      .expect("Remote has been dropped")
-      .get_block_list()
-      .is_blocked(&sc.system_id)
-      .await
-    {
+    let remote = self.remote.upgrade().expect("Remote has been dropped");
+    let serializer_id = negotiate_serializer_id(&sc.serializer_ids, &remote.get_config().get_serializer_ids().await);
+    if remote.get_block_list().is_blocked(&sc.system_id).await {
       tracing::debug!("EndpointReader blocked connection from {}", sc.system_id);
       if let Err(e) = response_tx
         .send(Ok(RemoteMessage {
@@ -95,6 +95,7 @@ impl EndpointReader {
             remote::ConnectResponse {
               blocked: true,
               member_id: sc.system_id.clone(),
+              serializer_id: serializer_id.clone().into(),
             },
           )),
         }))
@@ -103,13 +104,18 @@ impl EndpointReader {
         tracing::error!("EndpointReader failed to send ConnectResponse message: {}", e);
       }
     } else {
-      tracing::debug!("EndpointReader accepted connection from {}", sc.system_id);
+      tracing::debug!(
+        "EndpointReader accepted connection from {}, serializer_id = {}",
+        sc.system_id,
+        serializer_id
+      );
       if let Err(e) = response_tx
         .send(Ok(RemoteMessage {
           message_type: Some(remote::remote_message::MessageType::ConnectResponse(
             remote::ConnectResponse {
               blocked: false,
               member_id: sc.system_id.clone(),
+              serializer_id: serializer_id.into(),
             },
           )),
         }))
@@ -238,6 +244,11 @@ impl EndpointReader {
           let msg_handle = MessageHandle::new_arc(data_arc.clone());
           tracing::info!("EndpointReader received message: {:?}", data_arc);
 
+          if let Some(remote_failure) = data_arc.as_any().downcast_ref::<RemoteFailure>() {
+            self.deliver_remote_failure(&target, remote_failure).await?;
+            continue;
+          }
+
           if sender_opt.is_none() && envelope.message_header.is_none() {
             tracing::info!("EndpointReader received message with no sender and no header");
             self
@@ -275,10 +286,51 @@ impl EndpointReader {
     Ok(())
   }
 
+  // deliver_remote_failure rebuilds a Failure from its wire form and hands it to the local supervisor.
+  async fn deliver_remote_failure(
+    &self,
+    target: &ExtendedPid,
+    remote_failure: &RemoteFailure,
+  ) -> Result<(), EndpointReaderError> {
+    let who = remote_failure.who.clone().ok_or(EndpointReaderError::UnknownSender)?;
+    let reason = ErrorReason::from_summary(ErrorReasonSummary::new(
+      remote_failure.kind.clone(),
+      remote_failure.message.clone(),
+      remote_failure.code,
+    ));
+    let failure = Failure::new(
+      ExtendedPid::new(who),
+      reason,
+      RestartStatistics::new(),
+      MessageHandle::new(remote_failure.clone()),
+    );
+    let ref_process = self
+      .get_actor_system()
+      .await
+      .get_process_registry()
+      .await
+      .get_local_process(target.id())
+      .await
+      .ok_or(EndpointReaderError::UnknownTarget)?;
+    ref_process
+      .send_system_message(target, MessageHandle::new(failure))
+      .await;
+    Ok(())
+  }
+
   pub fn set_suspend(&mut self, suspend: bool) {
     self.suspended.store(suspend, std::sync::atomic::Ordering::SeqCst);
   }
 
+  // Refuses new streams, while streams that are already open keep being served
+  pub fn stop_accepting(&self) {
+    self.accepting.store(false, Ordering::SeqCst);
+  }
+
+  pub fn get_in_flight_batches(&self) -> usize {
+    self.in_flight_batches.load(Ordering::SeqCst)
+  }
+
   async fn get_suspend(suspended: Arc<Mutex<bool>>) -> bool {
     *suspended.lock().await
   }
@@ -338,6 +390,9 @@ impl Remoting for EndpointReader {
   type ReceiveStream = Pin<Box<dyn Stream<Item = Result<RemoteMessage, Status>> + Send>>;
 
   async fn receive(&self, request: Request<Streaming<RemoteMessage>>) -> Result<Response<Self::ReceiveStream>, Status> {
+    if !self.accepting.load(Ordering::SeqCst) {
+      return Err(Status::unavailable("EndpointReader is shutting down"));
+    }
     tracing::info!("EndpointReader is starting");
     let suspended = self.suspended.clone();
 
@@ -359,7 +414,12 @@ impl Remoting for EndpointReader {
       let cloned_response_tx = response_tx.clone();
       let cloned_connection_key = connection_key.clone();
       async move {
-        if Self::get_disconnect_flg(cloned_disconnect_rx).await {
+        // A connected peer keeps listening on the response stream after its request stream has ended
+        let disconnect = tokio::select! {
+          disconnect = Self::get_disconnect_flg(cloned_disconnect_rx) => disconnect,
+          _ = cloned_response_tx.closed() => false,
+        };
+        if disconnect {
           tracing::debug!("EndpointReader is telling to remote that it's leaving");
           if let Err(e) = cloned_response_tx
             .send(Ok(RemoteMessage {
@@ -388,6 +448,7 @@ impl Remoting for EndpointReader {
       let cloned_response_tx = response_tx.clone();
       async move {
         let mut request_mg = cloned_request_arc.lock().await;
+        let mut connected = false;
         while let Some(msg) = request_mg.get_mut().next().await {
           match msg {
             Ok(remote_msg) => {
@@ -398,13 +459,19 @@ impl Remoting for EndpointReader {
               match remote_msg.message_type {
                 Some(message_type) => match message_type {
                   remote::remote_message::MessageType::ConnectRequest(connect_req) => {
-                    if let Err(e) = cloned_self.on_connect_request(&cloned_response_tx, &connect_req).await {
-                      tracing::error!("Failed to handle connect request, {}", e);
-                      break;
+                    match cloned_self.on_connect_request(&cloned_response_tx, &connect_req).await {
+                      Ok(unknown) => connected = !unknown,
+                      Err(e) => {
+                        tracing::error!("Failed to handle connect request, {}", e);
+                        break;
+                      }
                     }
                   }
                   remote::remote_message::MessageType::MessageBatch(message_batch) => {
-                    if let Err(e) = cloned_self.on_message_batch(&message_batch).await {
+                    cloned_self.in_flight_batches.fetch_add(1, Ordering::SeqCst);
+                    let result = cloned_self.on_message_batch(&message_batch).await;
+                    cloned_self.in_flight_batches.fetch_sub(1, Ordering::SeqCst);
+                    if let Err(e) = result {
                       tracing::error!("Failed to handle message batch, {}", e);
                       break;
                     }
@@ -425,8 +492,10 @@ impl Remoting for EndpointReader {
           }
         }
 
-        if let Some(tx) = disconnect_tx_arc.lock().await.take() {
-          let _ = tx.send(false).await;
+        if !connected {
+          if let Some(tx) = disconnect_tx_arc.lock().await.take() {
+            let _ = tx.send(false).await;
+          }
         }
         tracing::info!("EndpointReader stream closed");
       }
@@ -436,14 +505,43 @@ impl Remoting for EndpointReader {
     Ok(Response::new(Box::pin(output_stream) as Self::ReceiveStream))
   }
 
-  async fn list_processes(&self, _: Request<ListProcessesRequest>) -> Result<Response<ListProcessesResponse>, Status> {
-    Err(Status::unimplemented("Method not implemented"))
+  async fn list_processes(
+    &self,
+    request: Request<ListProcessesRequest>,
+  ) -> Result<Response<ListProcessesResponse>, Status> {
+    let request = request.into_inner();
+    let match_type = match request.r#type() {
+      remote::ListProcessesMatchType::MatchPartOfString => ListProcessesMatchType::MatchPartOfString,
+      remote::ListProcessesMatchType::MatchExactString => ListProcessesMatchType::MatchExactString,
+      remote::ListProcessesMatchType::MatchRegex => ListProcessesMatchType::MatchRegex,
+    };
+    let pids = self
+      .get_actor_system()
+      .await
+      .get_process_registry()
+      .await
+      .list_local_pids(&request.pattern, match_type)
+      .await
+      .into_iter()
+      .map(|pid| pid.inner_pid)
+      .collect();
+    Ok(Response::new(ListProcessesResponse { pids }))
   }
 
   async fn get_process_diagnostics(
     &self,
-    _: Request<GetProcessDiagnosticsRequest>,
+    request: Request<GetProcessDiagnosticsRequest>,
   ) -> Result<Response<GetProcessDiagnosticsResponse>, Status> {
-    Err(Status::unimplemented("Method not implemented"))
+    let Some(pid) = request.into_inner().pid else {
+      return Err(Status::invalid_argument("pid is required"));
+    };
+    let diagnostics_string = self
+      .get_actor_system()
+      .await
+      .get_process_registry()
+      .await
+      .get_process_diagnostics(&ExtendedPid::new(pid))
+      .await;
+    Ok(Response::new(GetProcessDiagnosticsResponse { diagnostics_string }))
   }
 }
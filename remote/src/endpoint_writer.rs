@@ -5,10 +5,12 @@ use crate::generated::remote::remoting_client::RemotingClient;
 use crate::generated::remote::{
   ConnectRequest, ConnectResponse, MessageBatch, MessageEnvelope, MessageHeader, RemoteMessage, ServerConnection,
 };
-use crate::messages::{EndpointConnectedEvent, EndpointEvent, EndpointTerminatedEvent, RemoteDeliver};
+use crate::messages::{
+  EndpointConnectedEvent, EndpointDisconnectedEvent, EndpointEvent, EndpointTerminatedEvent, RemoteDeliver,
+};
 use crate::remote::Remote;
 use crate::serializer::RootSerializable;
-use crate::serializer::{serialize_any, SerializerId};
+use crate::serializer::{find_custom_serializer_id, serialize_any, SerializerId};
 use async_trait::async_trait;
 use dashmap::DashMap;
 use futures::{StreamExt, TryFutureExt};
@@ -18,20 +20,153 @@ use nexus_actor_core_rs::actor::context::{BasePart, ContextHandle, InfoPart, Mes
 use nexus_actor_core_rs::actor::dispatch::DeadLetterEvent;
 use nexus_actor_core_rs::actor::message::{Message, MessageHandle, ReadonlyMessageHeaders};
 use nexus_actor_core_rs::generated::actor::{DeadLetterResponse, Pid};
+use nexus_actor_core_rs::Message;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Weak};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::sync::RwLock;
 use tonic::transport::Channel;
 use tonic::{Code, Response, Streaming};
 
+pub const PRIORITY_HEADER: &str = "x-priority";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MessagePriority {
+  High,
+  Low,
+}
+
+impl MessagePriority {
+  fn of(msg: &MessageHandle) -> Self {
+    let priority = msg
+      .to_typed::<RemoteDeliver>()
+      .and_then(|rd| rd.header)
+      .and_then(|header| header.get(PRIORITY_HEADER));
+    match priority {
+      Some(value) if value.eq_ignore_ascii_case("high") => MessagePriority::High,
+      _ => MessagePriority::Low,
+    }
+  }
+}
+
+// PriorityBatches keeps one batch buffer per priority.
+// High priority messages are released right away in their own batch, low priority ones are coalesced
+// until the batch is full or flush is called.
+#[derive(Debug, Default)]
+struct PriorityBatches {
+  high: Vec<MessageHandle>,
+  low: Vec<MessageHandle>,
+}
+
+impl PriorityBatches {
+  fn push(&mut self, msg: MessageHandle, batch_size: usize) -> Vec<Vec<MessageHandle>> {
+    match MessagePriority::of(&msg) {
+      MessagePriority::High => {
+        self.high.push(msg);
+        vec![std::mem::take(&mut self.high)]
+      }
+      MessagePriority::Low => {
+        self.low.push(msg);
+        if self.low.len() >= batch_size {
+          vec![std::mem::take(&mut self.low)]
+        } else {
+          vec![]
+        }
+      }
+    }
+  }
+
+  fn flush(&mut self) -> Vec<Vec<MessageHandle>> {
+    [std::mem::take(&mut self.high), std::mem::take(&mut self.low)]
+      .into_iter()
+      .filter(|batch| !batch.is_empty())
+      .collect()
+  }
+
+  fn has_pending(&self) -> bool {
+    !self.high.is_empty() || !self.low.is_empty()
+  }
+}
+
+// PendingEnvelopes holds the messages received while the connection is being re-established.
+// Messages beyond the capacity are handed back to the caller.
+#[derive(Debug, Default)]
+struct PendingEnvelopes {
+  messages: VecDeque<MessageHandle>,
+}
+
+impl PendingEnvelopes {
+  fn push(&mut self, msg: MessageHandle, capacity: usize) -> Result<(), MessageHandle> {
+    if self.messages.len() >= capacity {
+      return Err(msg);
+    }
+    self.messages.push_back(msg);
+    Ok(())
+  }
+
+  fn drain(&mut self) -> Vec<MessageHandle> {
+    self.messages.drain(..).collect()
+  }
+}
+
+fn retry_backoff(retry_interval: Duration, max_interval: Duration, attempt: u32) -> Duration {
+  retry_interval
+    .checked_mul(2u32.saturating_pow(attempt))
+    .map_or(max_interval, |interval| interval.min(max_interval))
+}
+
+// Runs the connect handshake up to max_retry_count times, backing off between attempts. A blocked peer is not
+// retried
+async fn retry_handshake<F, Fut>(
+  max_retry_count: u32,
+  retry_interval: Duration,
+  max_interval: Duration,
+  mut handshake: F,
+) -> Result<(), EndpointWriterError>
+where
+  F: FnMut(u32) -> Fut,
+  Fut: Future<Output = Result<(), EndpointWriterError>>, {
+  let mut result = Err(EndpointWriterError::NoResponse);
+  for attempt in 0..max_retry_count {
+    if attempt > 0 {
+      tokio::time::sleep(retry_backoff(retry_interval, max_interval, attempt - 1)).await;
+    }
+    result = handshake(attempt).await;
+    if matches!(result, Ok(_) | Err(EndpointWriterError::Blocked(_))) {
+      break;
+    }
+  }
+  result
+}
+
+// Sent by the writer to itself, so it arrives after the messages already queued in the mailbox
+#[derive(Debug, Clone, PartialEq, Message)]
+struct FlushBatches;
+
+// Sent by the stream reader when the connection is lost
+#[derive(Debug, Clone, PartialEq, Message)]
+struct Reconnect;
+
+// Sent by the reconnect task once it has finished, successfully or not
+#[derive(Debug, Clone, PartialEq, Message)]
+struct FlushPending;
+
 #[derive(Debug, Clone)]
 pub struct EndpointWriter {
   config: Config,
   address: String,
   conn: Arc<RwLock<Option<Channel>>>,
   stream: Arc<RwLock<Option<RemotingClient<Channel>>>>,
+  serializer_id: Arc<RwLock<SerializerId>>,
   remote: Weak<Remote>,
+  batches: Arc<RwLock<PriorityBatches>>,
+  flush_scheduled: Arc<AtomicBool>,
+  pending: Arc<RwLock<PendingEnvelopes>>,
+  reconnecting: Arc<AtomicBool>,
+  stopped: Arc<AtomicBool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
@@ -46,6 +181,8 @@ pub enum EndpointWriterError {
   NoResponse,
   #[error("No field")]
   NoField,
+  #[error("Connection blocked by remote: {0}")]
+  Blocked(String),
 }
 
 impl EndpointWriter {
@@ -55,7 +192,13 @@ impl EndpointWriter {
       address,
       conn: Arc::new(RwLock::new(None)),
       stream: Arc::new(RwLock::new(None)),
+      serializer_id: Arc::new(RwLock::new(SerializerId::Proto)),
       remote,
+      batches: Arc::new(RwLock::new(PriorityBatches::default())),
+      flush_scheduled: Arc::new(AtomicBool::new(false)),
+      pending: Arc::new(RwLock::new(PendingEnvelopes::default())),
+      reconnecting: Arc::new(AtomicBool::new(false)),
+      stopped: Arc::new(AtomicBool::new(false)),
     }
   }
 
@@ -89,6 +232,16 @@ impl EndpointWriter {
     *mg = Some(stream);
   }
 
+  async fn get_serializer_id(&self) -> SerializerId {
+    let mg = self.serializer_id.read().await;
+    mg.clone()
+  }
+
+  async fn set_serializer_id(&self, serializer_id: SerializerId) {
+    let mut mg = self.serializer_id.write().await;
+    *mg = serializer_id;
+  }
+
   async fn get_actor_system(&self) -> ActorSystem {
     self
       .remote
@@ -98,30 +251,35 @@ impl EndpointWriter {
       .clone()
   }
 
-  pub async fn initialize(&mut self, _: ContextHandle) {
+  pub async fn initialize(&mut self, ctx: ContextHandle) {
     let now = Instant::now();
+    let self_pid = ctx.get_self().await;
     tracing::info!("Started EndpointWriter. connecting: to {}", self.address);
-    let _i = 0;
-    let mut error: Option<EndpointWriterError> = None;
-    for i in 0..self.config.get_max_retry_count().await {
-      match self.initialize_internal().await {
-        Ok(_) => {
-          break;
-        }
-        Err(e) => {
-          error = Some(e.clone());
-          tracing::error!(
-            "Failed to connect to remote: address = {}, retrying... {}",
-            self.address,
-            i
-          );
-          tokio::time::sleep(self.config.get_retry_interval().await).await;
-          continue;
+    let result = retry_handshake(
+      self.config.get_max_retry_count().await,
+      self.config.get_retry_interval().await,
+      self.config.get_max_retry_interval().await,
+      |attempt| {
+        let mut cloned_self = self.clone();
+        let self_pid = self_pid.clone();
+        async move {
+          let result = cloned_self.initialize_internal(self_pid).await;
+          if let Err(e) = &result {
+            tracing::error!(
+              "Failed to connect to remote: address = {}, attempt = {}, error = {}",
+              cloned_self.address,
+              attempt,
+              e
+            );
+            cloned_self.close_client_conn().await;
+          }
+          result
         }
-      }
-    }
+      },
+    )
+    .await;
 
-    if error.is_some() {
+    if result.is_err() {
       let terminated = EndpointEvent::EndpointTerminated(EndpointTerminatedEvent {
         address: self.address.clone(),
       });
@@ -160,6 +318,13 @@ impl EndpointWriter {
         connection_type: Some(ConnectionType::ServerConnection(ServerConnection {
           system_id: self.get_actor_system().await.get_id().await,
           address: self.get_actor_system().await.get_address().await,
+          serializer_ids: self
+            .config
+            .get_serializer_ids()
+            .await
+            .into_iter()
+            .map(u32::from)
+            .collect(),
         })),
       })),
     };
@@ -190,7 +355,7 @@ impl EndpointWriter {
     }
   }
 
-  async fn initialize_internal(&mut self) -> Result<(), EndpointWriterError> {
+  async fn initialize_internal(&mut self, self_pid: ExtendedPid) -> Result<(), EndpointWriterError> {
     let cloned_self = self.clone();
 
     let channel = self.create_channel().await?;
@@ -205,12 +370,21 @@ impl EndpointWriter {
     let remote_message = Self::get_remote_message_in_response(&mut streaming_response).await?;
     // FIXME
     let connect_response = Self::get_connect_response(remote_message)?;
+    if connect_response.blocked {
+      return Err(EndpointWriterError::Blocked(self.address.clone()));
+    }
     tracing::info!(
       "Connected to remote: address = {}, connect_response = {:?}",
       self.address,
       connect_response
     );
+    let serializer_id = match SerializerId::try_from(connect_response.serializer_id) {
+      Ok(SerializerId::None) | Err(_) => SerializerId::Proto,
+      Ok(serializer_id) => serializer_id,
+    };
+    self.set_serializer_id(serializer_id).await;
 
+    let reconnect_enabled = self.config.get_max_reconnect_count().await > 0;
     tokio::spawn(async move {
       let mut cloned_self = cloned_self.clone();
       let mut streaming = streaming_response.into_inner();
@@ -219,6 +393,10 @@ impl EndpointWriter {
         let terminated_event = match result {
           Err(e) => {
             tracing::error!("EndpointWriter failed to receive message: {}", e);
+            if reconnect_enabled {
+              cloned_self.request_reconnect(self_pid).await;
+              return;
+            }
             Some(EndpointEvent::EndpointTerminated(EndpointTerminatedEvent {
               address: cloned_self.address.clone(),
             }))
@@ -239,6 +417,10 @@ impl EndpointWriter {
           return;
         }
       }
+
+      if reconnect_enabled {
+        cloned_self.request_reconnect(self_pid).await;
+      }
     });
 
     let connected = EndpointEvent::EndpointConnected(EndpointConnectedEvent {
@@ -249,6 +431,92 @@ impl EndpointWriter {
     Ok(())
   }
 
+  async fn request_reconnect(&self, self_pid: ExtendedPid) {
+    if self.stopped.load(Ordering::SeqCst) {
+      return;
+    }
+    self
+      .get_actor_system()
+      .await
+      .get_root_context()
+      .await
+      .send(self_pid, MessageHandle::new(Reconnect))
+      .await;
+  }
+
+  async fn start_reconnect(&mut self, ctx: &ContextHandle) {
+    if self.reconnecting.swap(true, Ordering::SeqCst) {
+      return;
+    }
+    tracing::warn!(
+      "EndpointWriter lost connection, reconnecting: address = {}",
+      self.address
+    );
+    let disconnected = EndpointEvent::EndpointDisconnected(EndpointDisconnectedEvent {
+      address: self.address.clone(),
+    });
+    self.publish_stream(MessageHandle::new(disconnected)).await;
+    self.close_client_conn().await;
+
+    let mut cloned_self = self.clone();
+    let self_pid = ctx.get_self().await;
+    tokio::spawn(async move {
+      cloned_self.reconnect(self_pid).await;
+    });
+  }
+
+  async fn reconnect(&mut self, self_pid: ExtendedPid) {
+    let retry_interval = self.config.get_retry_interval().await;
+    let max_interval = self.config.get_max_reconnect_interval().await;
+    let max_reconnect_count = self.config.get_max_reconnect_count().await;
+    let mut root_context = self.get_actor_system().await.get_root_context().await;
+
+    for attempt in 0..max_reconnect_count {
+      tokio::time::sleep(retry_backoff(retry_interval, max_interval, attempt)).await;
+      if self.stopped.load(Ordering::SeqCst) {
+        return;
+      }
+      match self.initialize_internal(self_pid.clone()).await {
+        Ok(_) => {
+          tracing::info!("EndpointWriter reconnected: address = {}", self.address);
+          root_context.send(self_pid, MessageHandle::new(FlushPending)).await;
+          return;
+        }
+        Err(e) => {
+          tracing::error!(
+            "Failed to reconnect to remote: address = {}, attempt = {}, error = {}",
+            self.address,
+            attempt,
+            e
+          );
+          self.close_client_conn().await;
+          if matches!(e, EndpointWriterError::Blocked(_)) {
+            break;
+          }
+        }
+      }
+    }
+
+    // Hand the pending messages to dead letters before the endpoint goes away
+    root_context.send(self_pid, MessageHandle::new(FlushPending)).await;
+    let terminated = EndpointEvent::EndpointTerminated(EndpointTerminatedEvent {
+      address: self.address.clone(),
+    });
+    self.publish_stream(MessageHandle::new(terminated)).await;
+  }
+
+  async fn flush_pending(&mut self, ctx: &mut ContextHandle) -> Result<(), ActorError> {
+    self.reconnecting.store(false, Ordering::SeqCst);
+    let pending = {
+      let mut mg = self.pending.write().await;
+      mg.drain()
+    };
+    if pending.is_empty() {
+      return Ok(());
+    }
+    self.send_envelopes(pending, ctx).await
+  }
+
   async fn publish_stream(&mut self, msg: MessageHandle) {
     self
       .get_actor_system()
@@ -292,7 +560,7 @@ impl EndpointWriter {
     let mut sender_names = DashMap::new();
     let mut sender_names_arr = vec![];
 
-    let serializer_id = SerializerId::None;
+    let serializer_id = self.get_serializer_id().await;
 
     for msg in msg_list {
       let typed_msg = msg.to_typed::<EndpointEvent>();
@@ -302,6 +570,17 @@ impl EndpointWriter {
         return Ok(());
       }
 
+      let msg = if self.reconnecting.load(Ordering::SeqCst) {
+        let pending_queue_size = self.config.get_endpoint_writer_pending_queue_size().await;
+        let mut mg = self.pending.write().await;
+        match mg.push(msg, pending_queue_size) {
+          Ok(()) => continue,
+          Err(msg) => msg,
+        }
+      } else {
+        msg
+      };
+
       let rd = msg
         .to_typed::<RemoteDeliver>()
         .expect("Failed to convert to RemoteDeliver");
@@ -349,9 +628,6 @@ impl EndpointWriter {
 
       tracing::info!("message = {:?}", message);
 
-      let s_id = u32::from(serializer_id.clone());
-      tracing::info!("EndpointWriter: serializer_id = {:?}", s_id);
-
       let request_opt = message.to_typed::<Arc<dyn RootSerializable>>();
       let v = request_opt.map(|request| request.serialize());
 
@@ -375,9 +651,18 @@ impl EndpointWriter {
         continue;
       }
 
+      let serialized_type_name = match &v {
+        Some(Ok(msg)) => msg.get_type_name(),
+        _ => message.get_type_name(),
+      };
+      let message_serializer_id =
+        find_custom_serializer_id(&serialized_type_name).unwrap_or_else(|| serializer_id.clone());
+      let s_id = u32::from(message_serializer_id.clone());
+      tracing::info!("EndpointWriter: serializer_id = {:?}", s_id);
+
       let result = if let Some(Ok(msg)) = v {
         tracing::info!("EndpointWriter: serialize message");
-        let result = serialize_any(msg.as_any(), &serializer_id, &msg.get_type_name());
+        let result = serialize_any(msg.as_any(), &message_serializer_id, &serialized_type_name);
         if let Err(e) = &result {
           tracing::error!("Failed to serialize message: {:?}", e);
           continue;
@@ -387,8 +672,8 @@ impl EndpointWriter {
         tracing::info!("EndpointWriter: serialize_any message");
         Some(serialize_any(
           message.as_any(),
-          &serializer_id,
-          &message.get_type_name(),
+          &message_serializer_id,
+          &serialized_type_name,
         ))
       };
 
@@ -459,6 +744,37 @@ impl EndpointWriter {
     Ok(())
   }
 
+  async fn enqueue_envelope(&mut self, msg: MessageHandle, ctx: &mut ContextHandle) -> Result<(), ActorError> {
+    let batch_size = self.config.get_endpoint_writer_batch_size().await;
+    let ready = {
+      let mut mg = self.batches.write().await;
+      mg.push(msg, batch_size)
+    };
+    for batch in ready {
+      self.send_envelopes(batch, ctx).await?;
+    }
+    let has_pending = {
+      let mg = self.batches.read().await;
+      mg.has_pending()
+    };
+    if has_pending && !self.flush_scheduled.swap(true, Ordering::SeqCst) {
+      ctx.send(ctx.get_self().await, MessageHandle::new(FlushBatches)).await;
+    }
+    Ok(())
+  }
+
+  async fn flush_batches(&mut self, ctx: &mut ContextHandle) -> Result<(), ActorError> {
+    self.flush_scheduled.store(false, Ordering::SeqCst);
+    let batches = {
+      let mut mg = self.batches.write().await;
+      mg.flush()
+    };
+    for batch in batches {
+      self.send_envelopes(batch, ctx).await?;
+    }
+    Ok(())
+  }
+
   async fn close_client_conn(&mut self) {
     if self.get_stream().await.is_some() {
       let Some(s) = self.take_stream().await else {
@@ -544,6 +860,18 @@ impl Actor for EndpointWriter {
       Some(EndpointEvent::EndpointTerminated(_)) => {
         context_handle.stop(&context_handle.get_self().await).await;
       }
+      _ if msg.to_typed::<FlushBatches>().is_some() => {
+        self.flush_batches(&mut context_handle).await?;
+      }
+      _ if msg.to_typed::<Reconnect>().is_some() => {
+        self.start_reconnect(&context_handle).await;
+      }
+      _ if msg.to_typed::<FlushPending>().is_some() => {
+        self.flush_pending(&mut context_handle).await?;
+      }
+      _ if msg.to_typed::<RemoteDeliver>().is_some() => {
+        self.enqueue_envelope(msg, &mut context_handle).await?;
+      }
       _ => {
         let _ = self.send_envelopes(vec![msg], &mut context_handle).await?;
       }
@@ -556,13 +884,542 @@ impl Actor for EndpointWriter {
     Ok(())
   }
 
-  async fn pre_restart(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+  async fn pre_restart(&mut self, mut ctx: ContextHandle) -> Result<(), ActorError> {
+    self.stopped.store(true, Ordering::SeqCst);
+    let _ = self.flush_batches(&mut ctx).await;
     self.close_client_conn().await;
     Ok(())
   }
 
-  async fn pre_stop(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+  async fn pre_stop(&mut self, mut ctx: ContextHandle) -> Result<(), ActorError> {
+    self.stopped.store(true, Ordering::SeqCst);
+    let _ = self.flush_pending(&mut ctx).await;
+    let _ = self.flush_batches(&mut ctx).await;
     self.close_client_conn().await;
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::config_option::ConfigOption;
+  use crate::generated::remote::remoting_server::{Remoting, RemotingServer};
+  use crate::generated::remote::{
+    GetProcessDiagnosticsRequest, GetProcessDiagnosticsResponse, ListProcessesRequest, ListProcessesResponse,
+  };
+  use crate::serializer::{initialize_proto_serializers, register_serializer_any, SerializerAny, SerializerError};
+  use nexus_actor_core_rs::actor::actor::Props;
+  use nexus_actor_core_rs::actor::context::SpawnerPart;
+  use nexus_actor_core_rs::actor::message::{MessageHeaders, ReadonlyMessageHeadersHandle};
+  use std::any::Any;
+  use std::pin::Pin;
+  use std::sync::atomic::AtomicUsize;
+  use tokio::sync::{mpsc, Mutex, Notify};
+  use tonic::codegen::tokio_stream::wrappers::ReceiverStream;
+  use tonic::codegen::tokio_stream::Stream;
+  use tonic::transport::Server;
+  use tonic::{Request, Status};
+
+  #[derive(Clone, PartialEq, Message, ::prost::Message)]
+  struct Ping {
+    #[prost(string, tag = "1")]
+    id: String,
+  }
+
+  // How the scripted peer answers a connect request
+  #[derive(Debug, Clone)]
+  enum Handshake {
+    Accept,
+    // Accepts, then ends the stream as if the connection was lost
+    AcceptThenDrop,
+    AcceptWhenReleased(Arc<Notify>),
+    // Answers with a blocked ConnectResponse
+    Block,
+  }
+
+  type ResponseSender = mpsc::Sender<Result<RemoteMessage, Status>>;
+
+  // ScriptedRemoting answers connect requests with its script, the last handshake repeats, and records the
+  // targets of every delivered envelope
+  #[derive(Debug, Clone)]
+  struct ScriptedRemoting {
+    script: Arc<Mutex<VecDeque<Handshake>>>,
+    connects: Arc<AtomicUsize>,
+    delivered: Arc<Mutex<Vec<String>>>,
+    envelopes: Arc<Mutex<Vec<MessageEnvelope>>>,
+    open: Arc<Mutex<Vec<ResponseSender>>>,
+  }
+
+  impl ScriptedRemoting {
+    fn new(script: impl IntoIterator<Item = Handshake>) -> Self {
+      Self {
+        script: Arc::new(Mutex::new(script.into_iter().collect())),
+        connects: Arc::new(AtomicUsize::new(0)),
+        delivered: Arc::new(Mutex::new(vec![])),
+        envelopes: Arc::new(Mutex::new(vec![])),
+        open: Arc::new(Mutex::new(vec![])),
+      }
+    }
+
+    async fn serve(&self, address: &str) {
+      let service = RemotingServer::new(self.clone());
+      let address = address.parse().unwrap();
+      tokio::spawn(async move {
+        Server::builder()
+          .add_service(service)
+          .serve(address)
+          .await
+          .expect("Failed to start scripted server");
+      });
+      tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+
+    async fn next_handshake(&self) -> Handshake {
+      let mut script = self.script.lock().await;
+      if script.len() > 1 {
+        script.pop_front().unwrap()
+      } else {
+        script.front().cloned().unwrap_or(Handshake::Accept)
+      }
+    }
+  }
+
+  #[tonic::async_trait]
+  impl Remoting for ScriptedRemoting {
+    type ReceiveStream = Pin<Box<dyn Stream<Item = Result<RemoteMessage, Status>> + Send>>;
+
+    async fn receive(
+      &self,
+      request: Request<Streaming<RemoteMessage>>,
+    ) -> Result<tonic::Response<Self::ReceiveStream>, Status> {
+      let mut inbound = request.into_inner();
+      let (tx, rx) = mpsc::channel(1);
+      match inbound.next().await.and_then(|msg| msg.ok()).and_then(|msg| msg.message_type) {
+        Some(MessageType::ConnectRequest(_)) => {
+          self.connects.fetch_add(1, Ordering::SeqCst);
+          let (blocked, keep_open) = match self.next_handshake().await {
+            Handshake::Accept => (false, true),
+            Handshake::AcceptThenDrop => (false, false),
+            Handshake::AcceptWhenReleased(release) => {
+              release.notified().await;
+              (false, true)
+            }
+            Handshake::Block => (true, false),
+          };
+          let response = RemoteMessage {
+            message_type: Some(MessageType::ConnectResponse(ConnectResponse {
+              member_id: "".to_string(),
+              blocked,
+              serializer_id: u32::from(SerializerId::Proto),
+            })),
+          };
+          tx.send(Ok(response)).await.unwrap();
+          if keep_open {
+            self.open.lock().await.push(tx);
+          }
+        }
+        Some(MessageType::MessageBatch(batch)) => {
+          let mut delivered = self.delivered.lock().await;
+          for envelope in batch.envelopes {
+            delivered.push(batch.targets[envelope.target as usize].id.clone());
+            self.envelopes.lock().await.push(envelope);
+          }
+        }
+        _ => return Err(Status::unimplemented("Message not scripted")),
+      }
+      Ok(tonic::Response::new(Box::pin(ReceiverStream::new(rx))))
+    }
+
+    async fn list_processes(
+      &self,
+      _: Request<ListProcessesRequest>,
+    ) -> Result<tonic::Response<ListProcessesResponse>, Status> {
+      Err(Status::unimplemented("Method not implemented"))
+    }
+
+    async fn get_process_diagnostics(
+      &self,
+      _: Request<GetProcessDiagnosticsRequest>,
+    ) -> Result<tonic::Response<GetProcessDiagnosticsResponse>, Status> {
+      Err(Status::unimplemented("Method not implemented"))
+    }
+  }
+
+  type EndpointEvents = Arc<Mutex<Vec<EndpointEvent>>>;
+
+  // Spawns a writer to address and returns a clone sharing its state, with the endpoint events it publishes
+  async fn spawn_writer(
+    system: &ActorSystem,
+    address: &str,
+    config: Config,
+  ) -> (Arc<Remote>, EndpointWriter, ExtendedPid, EndpointEvents) {
+    let events = Arc::new(Mutex::new(vec![]));
+    let cloned_events = events.clone();
+    system
+      .get_event_stream()
+      .await
+      .subscribe(move |msg| {
+        let events = cloned_events.clone();
+        async move {
+          if let Some(event) = msg.to_typed::<EndpointEvent>() {
+            events.lock().await.push(event);
+          }
+        }
+      })
+      .await;
+
+    let remote = Arc::new(Remote::new(system.clone(), config.clone()).await);
+    let writer = EndpointWriter::new(Arc::downgrade(&remote), address.to_string(), config);
+    let cloned_writer = writer.clone();
+    let pid = system
+      .get_root_context()
+      .await
+      .spawn(
+        Props::from_async_actor_producer(move |_| {
+          let writer = cloned_writer.clone();
+          async move { writer }
+        })
+        .await,
+      )
+      .await;
+    (remote, writer, pid, events)
+  }
+
+  async fn wait_until<F, Fut>(what: &str, mut condition: F)
+  where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = bool>, {
+    tokio::time::timeout(Duration::from_secs(5), async {
+      while !condition().await {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+      }
+    })
+    .await
+    .unwrap_or_else(|_| panic!("timed out waiting for {}", what));
+  }
+
+  fn ping_deliver(address: &str, id: &str) -> MessageHandle {
+    MessageHandle::new(RemoteDeliver {
+      header: None,
+      message: MessageHandle::new(Ping { id: id.to_string() }),
+      target: Pid {
+        address: address.to_string(),
+        id: id.to_string(),
+        request_id: 0,
+      },
+      sender: None,
+      serializer_id: 0,
+    })
+  }
+
+  fn remote_deliver(id: &str, priority: Option<&str>) -> MessageHandle {
+    let header = priority.map(|priority| {
+      let mut headers = MessageHeaders::new();
+      headers.set(PRIORITY_HEADER.to_string(), priority.to_string());
+      ReadonlyMessageHeadersHandle::new(headers)
+    });
+    MessageHandle::new(RemoteDeliver {
+      header,
+      message: MessageHandle::new(id.to_string()),
+      target: Pid {
+        address: "localhost:8090".to_string(),
+        id: id.to_string(),
+        request_id: 0,
+      },
+      sender: None,
+      serializer_id: 0,
+    })
+  }
+
+  fn target_ids(batch: &[MessageHandle]) -> Vec<String> {
+    batch
+      .iter()
+      .map(|msg| msg.to_typed::<RemoteDeliver>().unwrap().target.id)
+      .collect()
+  }
+
+  #[test]
+  fn test_high_priority_is_sent_ahead_of_buffered_low_priority() {
+    let mut batches = PriorityBatches::default();
+
+    assert!(batches.push(remote_deliver("low-1", None), 10).is_empty());
+    assert!(batches.push(remote_deliver("low-2", Some("low")), 10).is_empty());
+
+    let ready = batches.push(remote_deliver("high-1", Some("high")), 10);
+    assert_eq!(ready.len(), 1);
+    assert_eq!(target_ids(&ready[0]), vec!["high-1"]);
+    assert!(batches.has_pending());
+
+    let flushed = batches.flush();
+    assert_eq!(flushed.len(), 1);
+    assert_eq!(target_ids(&flushed[0]), vec!["low-1", "low-2"]);
+    assert!(!batches.has_pending());
+  }
+
+  #[test]
+  fn test_low_priority_batch_is_released_when_full() {
+    let mut batches = PriorityBatches::default();
+
+    assert!(batches.push(remote_deliver("low-1", None), 2).is_empty());
+    let ready = batches.push(remote_deliver("low-2", None), 2);
+    assert_eq!(ready.len(), 1);
+    assert_eq!(target_ids(&ready[0]), vec!["low-1", "low-2"]);
+    assert!(!batches.has_pending());
+  }
+
+  #[test]
+  fn test_retry_backoff_doubles_up_to_the_max_interval() {
+    let base = Duration::from_millis(100);
+    let max = Duration::from_secs(1);
+
+    assert_eq!(retry_backoff(base, max, 0), Duration::from_millis(100));
+    assert_eq!(retry_backoff(base, max, 1), Duration::from_millis(200));
+    assert_eq!(retry_backoff(base, max, 3), Duration::from_millis(800));
+    assert_eq!(retry_backoff(base, max, 4), max);
+    assert_eq!(retry_backoff(base, max, 40), max);
+  }
+
+  #[tokio::test]
+  async fn test_handshake_is_retried_until_the_peer_accepts() {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let cloned_attempts = attempts.clone();
+    let result = retry_handshake(5, Duration::from_millis(1), Duration::from_millis(10), move |_| {
+      let attempt = cloned_attempts.fetch_add(1, Ordering::SeqCst) + 1;
+      async move {
+        if attempt < 3 {
+          Err(EndpointWriterError::Connection("peer not ready".to_string()))
+        } else {
+          Ok(())
+        }
+      }
+    })
+    .await;
+
+    assert!(result.is_ok());
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+  }
+
+  #[tokio::test]
+  async fn test_blocked_handshake_is_not_retried() {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let cloned_attempts = attempts.clone();
+    let result = retry_handshake(5, Duration::from_millis(1), Duration::from_millis(10), move |_| {
+      cloned_attempts.fetch_add(1, Ordering::SeqCst);
+      async { Err(EndpointWriterError::Blocked("localhost:8090".to_string())) }
+    })
+    .await;
+
+    assert!(matches!(result, Err(EndpointWriterError::Blocked(_))));
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+  }
+
+  #[tokio::test]
+  async fn test_handshake_gives_up_after_max_retry_count() {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let cloned_attempts = attempts.clone();
+    let result = retry_handshake(3, Duration::from_millis(1), Duration::from_millis(10), move |_| {
+      cloned_attempts.fetch_add(1, Ordering::SeqCst);
+      async { Err(EndpointWriterError::NoResponse) }
+    })
+    .await;
+
+    assert!(matches!(result, Err(EndpointWriterError::NoResponse)));
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+  }
+
+  #[test]
+  fn test_pending_envelopes_are_bounded_and_drained_in_order() {
+    let mut pending = PendingEnvelopes::default();
+
+    assert!(pending.push(remote_deliver("a", None), 2).is_ok());
+    assert!(pending.push(remote_deliver("b", None), 2).is_ok());
+    let rejected = pending.push(remote_deliver("c", None), 2).unwrap_err();
+    assert_eq!(target_ids(&[rejected]), vec!["c"]);
+
+    assert_eq!(target_ids(&pending.drain()), vec!["a", "b"]);
+    assert!(pending.drain().is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_envelopes_sent_while_reconnecting_are_flushed_in_order() {
+    initialize_proto_serializers::<Ping>().expect("Failed to register serializer");
+    let address = "127.0.0.1:8110";
+    let release = Arc::new(Notify::new());
+    let server = ScriptedRemoting::new([
+      Handshake::AcceptThenDrop,
+      Handshake::AcceptWhenReleased(release.clone()),
+    ]);
+    server.serve(address).await;
+
+    let system = ActorSystem::new().await.unwrap();
+    let config = Config::from([
+      ConfigOption::with_max_reconnect_count(3),
+      ConfigOption::with_retry_interval(Duration::from_millis(10)),
+      ConfigOption::with_max_reconnect_interval(Duration::from_millis(50)),
+    ])
+    .await;
+    let (_remote, writer, pid, events) = spawn_writer(&system, address, config).await;
+
+    wait_until("the connection to drop", || async {
+      events
+        .lock()
+        .await
+        .iter()
+        .any(|event| matches!(event, EndpointEvent::EndpointDisconnected(_)))
+    })
+    .await;
+    let mut root_context = system.get_root_context().await;
+    for id in ["a", "b", "c"] {
+      root_context.send(pid.clone(), ping_deliver(address, id)).await;
+    }
+    wait_until("the envelopes to be buffered", || async {
+      writer.pending.read().await.messages.len() == 3
+    })
+    .await;
+    assert!(server.delivered.lock().await.is_empty());
+
+    release.notify_one();
+    wait_until("the buffered envelopes to be delivered", || async {
+      server.delivered.lock().await.len() == 3
+    })
+    .await;
+    assert_eq!(*server.delivered.lock().await, vec!["a", "b", "c"]);
+    assert_eq!(server.connects.load(Ordering::SeqCst), 2);
+    assert!(writer.pending.read().await.messages.is_empty());
+  }
+
+  fn is_terminated(events: &[EndpointEvent]) -> bool {
+    events
+      .iter()
+      .any(|event| matches!(event, EndpointEvent::EndpointTerminated(_)))
+  }
+
+  #[tokio::test]
+  async fn test_blocked_connect_response_is_not_retried() {
+    let address = "127.0.0.1:8111";
+    let server = ScriptedRemoting::new([Handshake::Block]);
+    server.serve(address).await;
+
+    let system = ActorSystem::new().await.unwrap();
+    let config = Config::from([
+      ConfigOption::with_max_retry_count(5),
+      ConfigOption::with_retry_interval(Duration::from_millis(10)),
+    ])
+    .await;
+    let (_remote, _writer, _pid, events) = spawn_writer(&system, address, config).await;
+
+    wait_until("the endpoint to terminate", || async {
+      is_terminated(&events.lock().await)
+    })
+    .await;
+    assert_eq!(server.connects.load(Ordering::SeqCst), 1);
+    assert!(!events
+      .lock()
+      .await
+      .iter()
+      .any(|event| matches!(event, EndpointEvent::EndpointConnected(_))));
+  }
+
+  #[tokio::test]
+  async fn test_reconnect_stops_at_a_blocked_connect_response() {
+    let address = "127.0.0.1:8112";
+    let server = ScriptedRemoting::new([Handshake::AcceptThenDrop, Handshake::Block]);
+    server.serve(address).await;
+
+    let system = ActorSystem::new().await.unwrap();
+    let config = Config::from([
+      ConfigOption::with_max_reconnect_count(5),
+      ConfigOption::with_retry_interval(Duration::from_millis(10)),
+      ConfigOption::with_max_reconnect_interval(Duration::from_millis(50)),
+    ])
+    .await;
+    let (_remote, _writer, _pid, events) = spawn_writer(&system, address, config).await;
+
+    wait_until("the endpoint to terminate", || async {
+      is_terminated(&events.lock().await)
+    })
+    .await;
+    assert_eq!(server.connects.load(Ordering::SeqCst), 2);
+  }
+
+  #[derive(Debug, Clone, PartialEq, Message)]
+  struct Shout {
+    text: String,
+  }
+
+  struct ShoutSerializer;
+
+  impl SerializerAny for ShoutSerializer {
+    fn serialize_any(&self, msg: &dyn Any) -> Result<Vec<u8>, SerializerError> {
+      msg
+        .downcast_ref::<Shout>()
+        .map(|shout| shout.text.to_uppercase().into_bytes())
+        .ok_or(SerializerError::SerializationError("Invalid type".to_string()))
+    }
+
+    fn deserialize_any(&self, _: &[u8]) -> Result<Arc<dyn Any + Send + Sync>, SerializerError> {
+      Err(SerializerError::DeserializationError("Not supported".to_string()))
+    }
+
+    fn deserialize_message(&self, _: &[u8]) -> Result<Arc<dyn Message>, SerializerError> {
+      Err(SerializerError::DeserializationError("Not supported".to_string()))
+    }
+
+    fn type_name(&self) -> String {
+      std::any::type_name::<Shout>().to_string()
+    }
+  }
+
+  #[tokio::test]
+  async fn test_custom_serializer_is_used_for_outbound_envelopes() {
+    initialize_proto_serializers::<Ping>().expect("Failed to register serializer");
+    register_serializer_any(SerializerId::of_custom(102), Arc::new(ShoutSerializer))
+      .expect("Failed to register serializer");
+    let address = "127.0.0.1:8113";
+    let server = ScriptedRemoting::new([Handshake::Accept]);
+    server.serve(address).await;
+
+    let system = ActorSystem::new().await.unwrap();
+    let (_remote, _writer, pid, events) = spawn_writer(&system, address, Config::default()).await;
+    wait_until("the endpoint to connect", || async {
+      events
+        .lock()
+        .await
+        .iter()
+        .any(|event| matches!(event, EndpointEvent::EndpointConnected(_)))
+    })
+    .await;
+
+    let mut root_context = system.get_root_context().await;
+    root_context
+      .send(
+        pid.clone(),
+        MessageHandle::new(RemoteDeliver {
+          header: None,
+          message: MessageHandle::new(Shout {
+            text: "hello".to_string(),
+          }),
+          target: Pid {
+            address: address.to_string(),
+            id: "shout".to_string(),
+            request_id: 0,
+          },
+          sender: None,
+          serializer_id: 0,
+        }),
+      )
+      .await;
+    root_context.send(pid, ping_deliver(address, "ping")).await;
+
+    wait_until("the envelopes to be delivered", || async {
+      server.envelopes.lock().await.len() == 2
+    })
+    .await;
+    let envelopes = server.envelopes.lock().await;
+    assert_eq!(*server.delivered.lock().await, vec!["shout", "ping"]);
+    assert_eq!(envelopes[0].serializer_id, 102);
+    assert_eq!(envelopes[0].message_data, b"HELLO".to_vec());
+    assert_eq!(envelopes[1].serializer_id, u32::from(SerializerId::Proto));
+  }
+}
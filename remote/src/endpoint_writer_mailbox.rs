@@ -18,6 +18,7 @@ pub struct EndpointWriterMailbox {
   has_more_messages: Arc<AtomicI32>,
   batch_size: Arc<AtomicUsize>,
   suspended: Arc<AtomicBool>,
+  externally_suspended: Arc<AtomicBool>,
   invoker_opt: Arc<RwLock<Option<MessageInvokerHandle>>>,
   dispatcher_opt: Arc<RwLock<Option<DispatcherHandle>>>,
 }
@@ -33,6 +34,7 @@ impl EndpointWriterMailbox {
       has_more_messages: Arc::new(AtomicI32::new(0)),
       batch_size: Arc::new(AtomicUsize::new(batch_size)),
       suspended: Arc::new(AtomicBool::new(false)),
+      externally_suspended: Arc::new(AtomicBool::new(false)),
       invoker_opt: Arc::new(RwLock::new(None)),
       dispatcher_opt: Arc::new(RwLock::new(None)),
     }
@@ -49,7 +51,7 @@ impl EndpointWriterMailbox {
   }
 
   fn is_suspended(&self) -> bool {
-    self.suspended.load(Ordering::SeqCst)
+    self.suspended.load(Ordering::SeqCst) || self.externally_suspended.load(Ordering::SeqCst)
   }
 
   fn set_suspended(&self, value: bool) {
@@ -80,14 +82,19 @@ impl EndpointWriterMailbox {
     {
       let dispatcher = self.get_dispatcher_opt().await.expect("Dispatcher is not set");
       let self_clone = self.to_handle().await;
-      dispatcher
-        .schedule(Runnable::new(move || {
+      let result = dispatcher
+        .schedule_with_backoff(Runnable::new(move || {
           let self_clone = self_clone.clone();
           async move {
             self_clone.process_messages().await;
           }
         }))
         .await;
+      if let Err(err) = result {
+        // The messages stay queued, the next post schedules the mailbox again
+        tracing::error!("Failed to schedule endpoint writer mailbox: {}", err);
+        self.scheduler_status.store(false, Ordering::SeqCst);
+      }
     }
   }
 
@@ -112,6 +119,12 @@ impl EndpointWriterMailbox {
           Some(MailboxMessage::ResumeMailbox) => {
             self.set_suspended(false);
           }
+          Some(MailboxMessage::SuspendMailboxExternally) => {
+            self.externally_suspended.store(true, Ordering::SeqCst);
+          }
+          Some(MailboxMessage::ResumeMailboxExternally) => {
+            self.externally_suspended.store(false, Ordering::SeqCst);
+          }
           _ => {
             if let Err(err) = message_invoker.invoke_system_message(msg.clone()).await {
               message_invoker
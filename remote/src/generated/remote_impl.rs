@@ -1,4 +1,4 @@
-use crate::generated::remote::{ActorPidRequest, ActorPidResponse};
+use crate::generated::remote::{ActorPidRequest, ActorPidResponse, RemoteFailure};
 use nexus_actor_core_rs::actor::message::Message;
 use std::any::Any;
 
@@ -35,3 +35,20 @@ impl Message for ActorPidResponse {
     std::any::type_name_of_val(self).to_string()
   }
 }
+
+impl Message for RemoteFailure {
+  fn eq_message(&self, other: &dyn Message) -> bool {
+    match other.as_any().downcast_ref::<RemoteFailure>() {
+      Some(a) => self == a,
+      None => false,
+    }
+  }
+
+  fn as_any(&self) -> &(dyn Any + Send + Sync + 'static) {
+    self
+  }
+
+  fn get_type_name(&self) -> String {
+    std::any::type_name_of_val(self).to_string()
+  }
+}
@@ -17,3 +17,10 @@ mod remote;
 mod remote_process;
 mod response_status_code;
 mod serializer;
+
+// used by the code `#[derive(Message)]` generates for `#[message(remote = "...")]`
+extern crate self as nexus_actor_remote_rs;
+#[doc(hidden)]
+pub use {self::serializer::RemoteMessageRegistration, inventory};
+
+pub use self::serializer::{register_remote_messages, serialized_size};
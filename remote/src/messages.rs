@@ -4,8 +4,8 @@ use crate::generated::remote::{
   ClientConnection, ConnectRequest, ConnectResponse, DisconnectRequest, MessageBatch, MessageEnvelope, MessageHeader,
   RemoteMessage, ServerConnection,
 };
+use nexus_actor_core_rs::actor::message::MessageHandle;
 use nexus_actor_core_rs::actor::message::ReadonlyMessageHeadersHandle;
-use nexus_actor_core_rs::actor::message::{Message, MessageHandle};
 use nexus_actor_core_rs::generated::actor::Pid;
 use nexus_actor_core_rs::Message;
 use std::collections::HashMap;
@@ -21,10 +21,17 @@ pub struct EndpointConnectedEvent {
   pub address: String,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointDisconnectedEvent {
+  pub address: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Message)]
+#[allow(clippy::enum_variant_names)]
 pub enum EndpointEvent {
   EndpointTerminated(EndpointTerminatedEvent),
   EndpointConnected(EndpointConnectedEvent),
+  EndpointDisconnected(EndpointDisconnectedEvent),
 }
 
 impl EndpointEvent {
@@ -35,6 +42,10 @@ impl EndpointEvent {
   pub fn is_terminated(&self) -> bool {
     matches!(self, EndpointEvent::EndpointTerminated(_))
   }
+
+  pub fn is_disconnected(&self) -> bool {
+    matches!(self, EndpointEvent::EndpointDisconnected(_))
+  }
 }
 
 #[derive(Debug, Clone, PartialEq, Message)]
@@ -87,6 +98,7 @@ impl Hash for RemoteMessage {
 impl Hash for ClientConnection {
   fn hash<H: Hasher>(&self, state: &mut H) {
     self.system_id.hash(state);
+    self.serializer_ids.hash(state);
   }
 }
 
@@ -94,6 +106,7 @@ impl Hash for ServerConnection {
   fn hash<H: Hasher>(&self, state: &mut H) {
     self.system_id.hash(state);
     self.address.hash(state);
+    self.serializer_ids.hash(state);
   }
 }
 
@@ -162,6 +175,7 @@ impl Hash for ConnectResponse {
   fn hash<H: Hasher>(&self, state: &mut H) {
     self.member_id.hash(state);
     self.blocked.hash(state);
+    self.serializer_id.hash(state);
   }
 }
 
@@ -3,32 +3,81 @@ use crate::config::server_config::ServerConfig;
 use crate::config::Config;
 use crate::endpoint_manager::EndpointManager;
 use crate::endpoint_reader::EndpointReader;
+use crate::generated::remote::remoting_client::RemotingClient;
 use crate::generated::remote::remoting_server::RemotingServer;
+use crate::generated::remote::{
+  GetProcessDiagnosticsRequest, GetProcessDiagnosticsResponse, ListProcessesRequest, ListProcessesResponse,
+  RemoteFailure,
+};
 use crate::messages::RemoteDeliver;
 use crate::remote_process::RemoteProcess;
-use crate::serializer::SerializerId;
+use crate::serializer::{initialize_proto_serializers, register_remote_messages, SerializerError, SerializerId};
 use dashmap::DashMap;
 use nexus_actor_core_rs::actor::actor::Props;
 use nexus_actor_core_rs::actor::actor_system::ActorSystem;
 use nexus_actor_core_rs::actor::message::{MessageHandle, ReadonlyMessageHeadersHandle};
 use nexus_actor_core_rs::actor::process::process_registry::AddressResolver;
 use nexus_actor_core_rs::actor::process::ProcessHandle;
+use nexus_actor_core_rs::actor::ShutdownHook;
 use nexus_actor_core_rs::extensions::{next_extension_id, Extension, ExtensionId};
 use nexus_actor_core_rs::generated::actor::Pid;
 use once_cell::sync::Lazy;
 use std::any::Any;
 use std::future::Future;
 use std::net::ToSocketAddrs;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
 use tokio::sync::oneshot;
 use tokio::sync::Mutex;
-use tonic::transport::Server;
+use tonic::transport::{Channel, Server};
+use tonic::{Code, Request, Status};
 
-#[derive(Debug, Clone, Error)]
+// RemoteError is what the remoting API returns, so callers never have to match on transport types
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
 pub enum RemoteError {
   #[error("Server error")]
   ServerError,
+  #[error("Failed to connect to remote: {0}")]
+  Connect(String),
+  #[error("Request timed out")]
+  Timeout,
+  #[error("Request was cancelled")]
+  Cancelled,
+  #[error("Remote responded with status: {0:?}")]
+  Status(Code),
+  #[error("Failed to serialize message: {0}")]
+  Serialization(String),
+  #[error("Unknown message type")]
+  UnknownType,
+}
+
+impl From<Status> for RemoteError {
+  fn from(status: Status) -> Self {
+    match status.code() {
+      Code::DeadlineExceeded => RemoteError::Timeout,
+      Code::Cancelled => RemoteError::Cancelled,
+      code => RemoteError::Status(code),
+    }
+  }
+}
+
+impl From<tonic::transport::Error> for RemoteError {
+  fn from(error: tonic::transport::Error) -> Self {
+    RemoteError::Connect(error.to_string())
+  }
+}
+
+impl From<SerializerError> for RemoteError {
+  fn from(error: SerializerError) -> Self {
+    match error {
+      SerializerError::SerializationError(message) | SerializerError::DeserializationError(message) => {
+        RemoteError::Serialization(message)
+      }
+      SerializerError::UnknownType => RemoteError::UnknownType,
+    }
+  }
 }
 
 pub static EXTENSION_ID: Lazy<ExtensionId> = Lazy::new(next_extension_id);
@@ -64,7 +113,8 @@ pub struct Remote {
   config: Config,
   kinds: Arc<DashMap<String, Props>>,
   block_list: BlockList,
-  shutdown: Option<Shutdown>,
+  shutdown: Arc<Mutex<Option<Shutdown>>>,
+  shutdown_hook_registered: Arc<AtomicBool>,
 }
 
 impl Remote {
@@ -77,7 +127,8 @@ impl Remote {
       config: config.clone(),
       kinds: Arc::new(DashMap::new()),
       block_list,
-      shutdown: None,
+      shutdown: Arc::new(Mutex::new(None)),
+      shutdown_hook_registered: Arc::new(AtomicBool::new(false)),
     };
     for (k, v) in config.get_kinds().await {
       r.register(&k, v);
@@ -90,9 +141,9 @@ impl Remote {
     r
   }
 
-  async fn get_endpoint_reader(&self) -> EndpointReader {
+  async fn get_endpoint_reader_opt(&self) -> Option<EndpointReader> {
     let mg = self.endpoint_reader.lock().await;
-    mg.as_ref().expect("EndpointReader is not found").clone()
+    mg.clone()
   }
 
   async fn set_endpoint_reader(&self, endpoint_reader: EndpointReader) {
@@ -152,7 +203,11 @@ impl Remote {
     F: FnOnce() -> Fut,
     Fut: Future<Output = ()> + Send + Sync, {
     let (shutdown, rx) = Shutdown::new();
-    self.shutdown = Some(shutdown);
+    *self.shutdown.lock().await = Some(shutdown);
+
+    // failures escalated to a parent on another node travel as RemoteFailure
+    initialize_proto_serializers::<RemoteFailure>()?;
+    register_remote_messages()?;
 
     let my_self = Arc::new(self.clone());
     let cloned_self = my_self.clone();
@@ -200,9 +255,28 @@ impl Remote {
     })?;
     self.set_endpoint_manager(endpoint_manager.clone()).await;
 
-    let endpoint_reader = EndpointReader::new(self_weak);
+    let endpoint_reader = EndpointReader::new(self_weak.clone());
     self.set_endpoint_reader(endpoint_reader.clone()).await;
 
+    // the hook is registered by the first start only and does not keep the remote alive
+    if !self.shutdown_hook_registered.swap(true, Ordering::SeqCst) {
+      let hook_remote = self_weak;
+      self
+        .actor_system
+        .register_shutdown_hook(ShutdownHook::new(move |remaining| {
+          let remote = hook_remote.upgrade();
+          async move {
+            let Some(remote) = remote else {
+              return;
+            };
+            if let Err(e) = remote.shutdown(remaining).await {
+              tracing::error!("Failed to shut down remote: {:?}", e);
+            }
+          }
+        }))
+        .await;
+    }
+
     let router = server.add_service(RemotingServer::new(endpoint_reader));
     let shutdown_future = async {
       tracing::info!("Server started: {}", socket_addr);
@@ -242,21 +316,94 @@ impl Remote {
     (ProcessHandle::new(ref_process.clone()), true)
   }
 
-  pub async fn shutdown(&mut self, graceful: bool) -> Result<(), RemoteError> {
-    if graceful {
-      tracing::debug!("Shutting down gracefully");
-      if let Some(mut endpoint_manager) = self.get_endpoint_manager_opt().await {
-        endpoint_manager.stop().await.map_err(|e| {
-          tracing::error!("Failed to stop EndpointManager: {:?}", e);
-          RemoteError::ServerError
-        })?;
-      }
-      self.get_endpoint_reader().await.set_suspend(true);
+  // Shuts remoting down in order: new streams are refused, message batches being delivered to local actors are
+  // drained, connected peers are sent a DisconnectRequest and finally the server is closed.
+  // Returns RemoteError::Timeout if the drain did not finish in time, the remaining steps are still taken
+  pub async fn shutdown(&self, timeout: Duration) -> Result<(), RemoteError> {
+    tracing::debug!("Shutting down remote");
+    let deadline = tokio::time::Instant::now() + timeout;
+    let endpoint_reader = self.get_endpoint_reader_opt().await;
+    let mut drained = true;
+    if let Some(endpoint_reader) = &endpoint_reader {
+      endpoint_reader.stop_accepting();
+      drained = tokio::time::timeout_at(deadline, async {
+        while endpoint_reader.get_in_flight_batches() > 0 {
+          tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+      })
+      .await
+      .is_ok();
+    }
+    if let Some(mut endpoint_manager) = self.get_endpoint_manager_opt().await {
+      endpoint_manager.stop().await.map_err(|e| {
+        tracing::error!("Failed to stop EndpointManager: {:?}", e);
+        RemoteError::ServerError
+      })?;
+    }
+    if let Some(mut endpoint_reader) = endpoint_reader {
+      endpoint_reader.set_suspend(true);
     }
-    if let Some(shutdown) = self.shutdown.take() {
+    if let Some(shutdown) = self.shutdown.lock().await.take() {
       shutdown.shutdown().await;
     }
-    Ok(())
+    if drained {
+      Ok(())
+    } else {
+      Err(RemoteError::Timeout)
+    }
+  }
+
+  pub async fn list_processes(
+    &self,
+    address: &str,
+    pattern: &str,
+    timeout: Duration,
+  ) -> Result<ListProcessesResponse, RemoteError> {
+    let mut client = Self::connect_client(address, timeout).await?;
+    let mut request = Request::new(ListProcessesRequest {
+      pattern: pattern.to_string(),
+      r#type: Default::default(),
+    });
+    request.set_timeout(timeout);
+    let response = Self::call_with_timeout(timeout, client.list_processes(request)).await?;
+    Ok(response.into_inner())
+  }
+
+  pub async fn get_process_diagnostics(
+    &self,
+    pid: Pid,
+    timeout: Duration,
+  ) -> Result<GetProcessDiagnosticsResponse, RemoteError> {
+    let mut client = Self::connect_client(&pid.address, timeout).await?;
+    let mut request = Request::new(GetProcessDiagnosticsRequest { pid: Some(pid) });
+    request.set_timeout(timeout);
+    let response = Self::call_with_timeout(timeout, client.get_process_diagnostics(request)).await?;
+    Ok(response.into_inner())
+  }
+
+  // The server enforces the request's grpc-timeout by cancelling the call, so a cancellation that
+  // arrives once the deadline has passed is reported as a timeout
+  async fn call_with_timeout<T>(
+    timeout: Duration,
+    call: impl std::future::Future<Output = Result<T, Status>>,
+  ) -> Result<T, RemoteError> {
+    let deadline = tokio::time::Instant::now() + timeout;
+    match tokio::time::timeout_at(deadline, call).await {
+      Err(_) => Err(RemoteError::Timeout),
+      Ok(Err(status)) if status.code() == Code::Cancelled && tokio::time::Instant::now() >= deadline => {
+        Err(RemoteError::Timeout)
+      }
+      Ok(result) => Ok(result?),
+    }
+  }
+
+  async fn connect_client(address: &str, timeout: Duration) -> Result<RemotingClient<Channel>, RemoteError> {
+    let channel = Channel::from_shared(format!("http://{}", address))
+      .map_err(|e| RemoteError::Connect(e.to_string()))?
+      .connect_timeout(timeout)
+      .connect()
+      .await?;
+    Ok(RemotingClient::new(channel))
   }
 
   pub async fn send_message(
@@ -294,20 +441,37 @@ impl Extension for Remote {
 
 #[cfg(test)]
 mod tests {
-  use nexus_actor_core_rs::actor::actor::{Actor, ActorError, Props};
+  use nexus_actor_core_rs::actor::actor::{
+    Actor, ActorError, ErrorReason, ErrorReasonSummary, ExtendedPid, Props, RestartStatistics,
+  };
   use nexus_actor_core_rs::actor::actor_system::ActorSystem;
   use nexus_actor_core_rs::actor::context::{BasePart, ContextHandle, MessagePart, SenderPart, SpawnerPart};
-  use nexus_actor_core_rs::actor::message::Message;
-  use nexus_actor_core_rs::actor::message::{MessageHandle, ResponseHandle};
+  use nexus_actor_core_rs::actor::message::{Failure, MessageHandle, ResponseHandle};
+  use nexus_actor_core_rs::actor::supervisor::{Directive, OneForOneStrategy, SupervisorStrategyHandle};
 
   use crate::config::Config;
   use crate::config_option::ConfigOption;
 
-  use crate::remote::Remote;
-  use crate::serializer::initialize_proto_serializers;
+  use crate::generated::remote::remoting_server::{Remoting, RemotingServer};
+  use crate::generated::remote::{
+    GetProcessDiagnosticsRequest, GetProcessDiagnosticsResponse, ListProcessesRequest, ListProcessesResponse,
+    RemoteMessage,
+  };
+  use crate::messages::EndpointEvent;
+  use crate::remote::{Remote, RemoteError};
+  use crate::serializer::{initialize_json_serializers, initialize_proto_serializers, SerializerId};
+  use nexus_actor_core_rs::generated::actor::Pid;
   use nexus_actor_message_derive_rs::Message;
+  use serde::{Deserialize, Serialize};
   use std::env;
+  use std::pin::Pin;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  use std::sync::Arc;
   use std::time::Duration;
+  use tokio::sync::{Mutex, Notify};
+  use tonic::codegen::tokio_stream::Stream;
+  use tonic::transport::Server;
+  use tonic::{Request, Response, Status, Streaming};
 
   use tokio::time::sleep;
 
@@ -325,7 +489,7 @@ mod tests {
     let config = Config::from([ConfigOption::with_host("127.0.0.1"), ConfigOption::with_port(8080)]).await;
 
     tracing::debug!("config: {:?}", config);
-    let mut remote = Remote::new(system.clone(), config).await;
+    let remote = Remote::new(system.clone(), config).await;
     let mut cloned_remote = remote.clone();
     tokio::spawn(async move {
       let result = cloned_remote.start().await;
@@ -333,7 +497,7 @@ mod tests {
     });
     sleep(Duration::from_secs(3)).await;
     assert_eq!("127.0.0.1:8080", system.get_address().await);
-    let result = remote.shutdown(true).await;
+    let result = remote.shutdown(Duration::from_secs(3)).await;
     assert!(result.is_ok());
     sleep(Duration::from_secs(1)).await;
   }
@@ -353,7 +517,7 @@ mod tests {
     .await;
 
     tracing::debug!("config: {:?}", config);
-    let mut remote = Remote::new(system.clone(), config).await;
+    let remote = Remote::new(system.clone(), config).await;
     let mut cloned_remote = remote.clone();
     tokio::spawn(async move {
       let result = cloned_remote.start().await;
@@ -361,7 +525,7 @@ mod tests {
     });
     sleep(Duration::from_secs(3)).await;
     assert_eq!("localhost:8080", system.get_address().await);
-    let result = remote.shutdown(true).await;
+    let result = remote.shutdown(Duration::from_secs(3)).await;
     assert!(result.is_ok());
     sleep(Duration::from_secs(1)).await;
   }
@@ -493,4 +657,461 @@ mod tests {
       panic!("Unexpected response type");
     }
   }
+
+  #[derive(Debug, Clone)]
+  struct SupervisingActor;
+
+  #[async_trait::async_trait]
+  impl Actor for SupervisingActor {
+    async fn receive(&mut self, _: ContextHandle) -> Result<(), ActorError> {
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn test_remote_failure_escalates_with_reconstructed_reason() {
+    let _ = env::set_var("RUST_LOG", "nexus_actor_core_rs=info");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let parent_wait_group = WaitGroup::with_count(1);
+    let parent_system = ActorSystem::new().await.unwrap();
+    let parent_config = Config::from([ConfigOption::with_host("127.0.0.1"), ConfigOption::with_port(8097)]).await;
+    let mut parent_remote = Remote::new(parent_system.clone(), parent_config).await;
+    let cloned_parent_wait_group = parent_wait_group.clone();
+    tokio::spawn(async move {
+      parent_remote
+        .start_with_callback(|| async {
+          cloned_parent_wait_group.done().await;
+        })
+        .await
+        .expect("Failed to start parent node");
+    });
+    parent_wait_group.wait().await;
+
+    let received = Arc::new(Mutex::new(None));
+    let notify = Arc::new(Notify::new());
+    let cloned_received = received.clone();
+    let cloned_notify = notify.clone();
+    let parent_props = Props::from_async_actor_producer_with_opts(
+      |_| async { SupervisingActor },
+      [Props::with_supervisor_strategy(SupervisorStrategyHandle::new(
+        OneForOneStrategy::new(10, Duration::from_secs(10)).with_decider(move |reason: ErrorReason| {
+          let received = cloned_received.clone();
+          let notify = cloned_notify.clone();
+          async move {
+            *received.lock().await = Some(reason);
+            notify.notify_one();
+            Directive::Resume
+          }
+        }),
+      ))],
+    )
+    .await;
+    let parent_pid = parent_system
+      .get_root_context()
+      .await
+      .spawn_named(parent_props, "parent")
+      .await
+      .unwrap();
+
+    let child_wait_group = WaitGroup::with_count(1);
+    let child_system = ActorSystem::new().await.unwrap();
+    let child_config = Config::from([ConfigOption::with_host("127.0.0.1"), ConfigOption::with_port(8098)]).await;
+    let mut child_remote = Remote::new(child_system.clone(), child_config).await;
+    let cloned_child_wait_group = child_wait_group.clone();
+    tokio::spawn(async move {
+      child_remote
+        .start_with_callback(|| async {
+          cloned_child_wait_group.done().await;
+        })
+        .await
+        .expect("Failed to start child node");
+    });
+    child_wait_group.wait().await;
+
+    let child_pid = child_system
+      .get_root_context()
+      .await
+      .spawn(Props::from_async_actor_producer(|_| async { SupervisingActor }).await)
+      .await;
+
+    // this is what a child does when its parent lives on another node
+    let failure = Failure::new(
+      child_pid.clone(),
+      ErrorReason::new_serializable(std::io::Error::other("disk full"), 42),
+      RestartStatistics::new(),
+      MessageHandle::new(EchoMessage::new("in flight".to_string())),
+    );
+    parent_pid
+      .send_system_message(child_system.clone(), MessageHandle::new(failure))
+      .await;
+
+    tokio::time::timeout(Duration::from_secs(10), notify.notified())
+      .await
+      .expect("remote failure was not escalated");
+
+    let reason = received.lock().await.clone().unwrap();
+    assert_eq!(reason.kind(), "std::io::error::Error");
+    assert_eq!(reason.code, 42);
+    assert_eq!(
+      reason.to_summary(),
+      ErrorReasonSummary::new("std::io::error::Error", "disk full", 42)
+    );
+  }
+
+  #[derive(Debug, Clone, PartialEq, Message, Serialize, Deserialize)]
+  pub struct JsonEchoMessage {
+    pub message: String,
+  }
+
+  #[tokio::test]
+  async fn test_remote_communication_with_negotiated_json() {
+    let _ = env::set_var("RUST_LOG", "nexus_actor_core_rs=info");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    // JsonEchoMessage has no protobuf serializer, so delivery only succeeds if both sides agreed on JSON
+    initialize_json_serializers::<JsonEchoMessage>().expect("Failed to register serializer");
+
+    let server_wait_group = WaitGroup::with_count(1);
+    let server_system = ActorSystem::new().await.unwrap();
+    let server_config = Config::from([
+      ConfigOption::with_host("127.0.0.1"),
+      ConfigOption::with_port(8092),
+      ConfigOption::with_serializer_ids([SerializerId::Json, SerializerId::Proto]),
+    ])
+    .await;
+    let mut server_remote = Remote::new(server_system.clone(), server_config).await;
+    let cloned_server_wait_group = server_wait_group.clone();
+    tokio::spawn(async move {
+      server_remote
+        .start_with_callback(|| async {
+          cloned_server_wait_group.done().await;
+        })
+        .await
+        .expect("Failed to start server");
+    });
+
+    server_wait_group.wait().await;
+
+    let echo_props = Props::from_async_actor_receiver(|ctx| async move {
+      if let Some(msg) = ctx.get_message_handle().await.to_typed::<JsonEchoMessage>() {
+        ctx
+          .respond(ResponseHandle::new(JsonEchoMessage {
+            message: format!("Echo: {}", msg.message),
+          }))
+          .await;
+      }
+      Ok(())
+    })
+    .await;
+    let echo_pid = server_system
+      .get_root_context()
+      .await
+      .spawn_named(echo_props, "json_echo")
+      .await
+      .unwrap();
+
+    let client_wait_group = WaitGroup::with_count(1);
+    let client_system = ActorSystem::new().await.unwrap();
+    let client_config = Config::from([
+      ConfigOption::with_host("127.0.0.1"),
+      ConfigOption::with_port(8093),
+      ConfigOption::with_serializer_ids([SerializerId::Json]),
+    ])
+    .await;
+    let mut client_remote = Remote::new(client_system.clone(), client_config).await;
+    let cloned_client_wait_group = client_wait_group.clone();
+    tokio::spawn(async move {
+      client_remote
+        .start_with_callback(|| async {
+          cloned_client_wait_group.done().await;
+        })
+        .await
+        .expect("Failed to start client");
+    });
+
+    client_wait_group.wait().await;
+
+    let response = client_system
+      .get_root_context()
+      .await
+      .request_future(
+        echo_pid,
+        MessageHandle::new(JsonEchoMessage {
+          message: "Hello, JSON!".to_string(),
+        }),
+        Duration::from_secs(10),
+      )
+      .await
+      .result()
+      .await
+      .unwrap();
+
+    assert_eq!(
+      response.to_typed::<JsonEchoMessage>(),
+      Some(JsonEchoMessage {
+        message: "Echo: Hello, JSON!".to_string(),
+      })
+    );
+  }
+
+  #[tokio::test]
+  async fn test_shutdown_drains_batches_and_disconnects_peers() {
+    let _ = env::set_var("RUST_LOG", "nexus_actor_core_rs=info");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    initialize_proto_serializers::<EchoMessage>().expect("Failed to register serializer");
+    const MESSAGES: usize = 20;
+
+    let server_wait_group = WaitGroup::with_count(1);
+    let server_system = ActorSystem::new().await.unwrap();
+    let server_config = Config::from([ConfigOption::with_host("127.0.0.1"), ConfigOption::with_port(8095)]).await;
+    let mut server_remote = Remote::new(server_system.clone(), server_config).await;
+    let cloned_server_wait_group = server_wait_group.clone();
+    let server_handle = tokio::spawn(async move {
+      server_remote
+        .start_with_callback(|| async {
+          cloned_server_wait_group.done().await;
+        })
+        .await
+    });
+
+    server_wait_group.wait().await;
+
+    let received = Arc::new(AtomicUsize::new(0));
+    let cloned_received = received.clone();
+    let counter_props = Props::from_async_actor_receiver(move |ctx| {
+      let received = cloned_received.clone();
+      async move {
+        if ctx.get_message_handle().await.to_typed::<EchoMessage>().is_some() {
+          received.fetch_add(1, Ordering::SeqCst);
+        }
+        Ok(())
+      }
+    })
+    .await;
+    let counter_pid = server_system
+      .get_root_context()
+      .await
+      .spawn_named(counter_props, "counter")
+      .await
+      .unwrap();
+
+    let client_wait_group = WaitGroup::with_count(1);
+    let client_system = ActorSystem::new().await.unwrap();
+    let client_config = Config::from([ConfigOption::with_host("127.0.0.1"), ConfigOption::with_port(8096)]).await;
+    let mut client_remote = Remote::new(client_system.clone(), client_config).await;
+    let cloned_client_wait_group = client_wait_group.clone();
+    tokio::spawn(async move {
+      client_remote
+        .start_with_callback(|| async {
+          cloned_client_wait_group.done().await;
+        })
+        .await
+        .expect("Failed to start client");
+    });
+
+    client_wait_group.wait().await;
+
+    let disconnected = Arc::new(Notify::new());
+    let cloned_disconnected = disconnected.clone();
+    client_system
+      .get_event_stream()
+      .await
+      .subscribe(move |msg| {
+        let disconnected = cloned_disconnected.clone();
+        async move {
+          if let Some(event) = msg.to_typed::<EndpointEvent>() {
+            if event.is_terminated() {
+              disconnected.notify_one();
+            }
+          }
+        }
+      })
+      .await;
+
+    // A pid without the cached local process, so that the client has to go through remoting
+    let counter_pid = ExtendedPid::new(counter_pid.inner_pid.clone());
+    let mut root_context = client_system.get_root_context().await;
+    for n in 0..MESSAGES {
+      root_context
+        .send(counter_pid.clone(), MessageHandle::new(EchoMessage::new(n.to_string())))
+        .await;
+    }
+    while received.load(Ordering::SeqCst) == 0 {
+      sleep(Duration::from_millis(1)).await;
+    }
+
+    server_system.shutdown(Duration::from_secs(5)).await;
+
+    let server_result = tokio::time::timeout(Duration::from_secs(5), server_handle)
+      .await
+      .expect("server did not stop")
+      .unwrap();
+    assert!(server_result.is_ok());
+    tokio::time::timeout(Duration::from_secs(5), disconnected.notified())
+      .await
+      .expect("client was not asked to disconnect");
+    tokio::time::timeout(Duration::from_secs(5), async {
+      while received.load(Ordering::SeqCst) < MESSAGES {
+        sleep(Duration::from_millis(1)).await;
+      }
+    })
+    .await
+    .expect("not all messages were delivered");
+    assert_eq!(received.load(Ordering::SeqCst), MESSAGES);
+  }
+
+  #[derive(Debug, Clone)]
+  struct SlowRemoting {
+    delay: Duration,
+  }
+
+  #[tonic::async_trait]
+  impl Remoting for SlowRemoting {
+    type ReceiveStream = Pin<Box<dyn Stream<Item = Result<RemoteMessage, Status>> + Send>>;
+
+    async fn receive(&self, _: Request<Streaming<RemoteMessage>>) -> Result<Response<Self::ReceiveStream>, Status> {
+      Err(Status::unimplemented("Method not implemented"))
+    }
+
+    async fn list_processes(
+      &self,
+      _: Request<ListProcessesRequest>,
+    ) -> Result<Response<ListProcessesResponse>, Status> {
+      sleep(self.delay).await;
+      Ok(Response::new(ListProcessesResponse { pids: vec![] }))
+    }
+
+    async fn get_process_diagnostics(
+      &self,
+      _: Request<GetProcessDiagnosticsRequest>,
+    ) -> Result<Response<GetProcessDiagnosticsResponse>, Status> {
+      sleep(self.delay).await;
+      Ok(Response::new(GetProcessDiagnosticsResponse {
+        diagnostics_string: "".to_string(),
+      }))
+    }
+  }
+
+  #[tokio::test]
+  async fn test_list_processes_timeout() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    tokio::spawn(async move {
+      Server::builder()
+        .add_service(RemotingServer::new(SlowRemoting {
+          delay: Duration::from_secs(10),
+        }))
+        .serve("127.0.0.1:8094".parse().unwrap())
+        .await
+        .expect("Failed to start slow server");
+    });
+    sleep(Duration::from_millis(500)).await;
+
+    let system = ActorSystem::new().await.unwrap();
+    let remote = Remote::new(system, Config::default()).await;
+
+    let started_at = tokio::time::Instant::now();
+    let result = remote
+      .list_processes("127.0.0.1:8094", "", Duration::from_millis(300))
+      .await;
+    assert!(matches!(result, Err(RemoteError::Timeout)), "{:?}", result);
+    assert!(started_at.elapsed() < Duration::from_secs(5));
+
+    let pid = Pid {
+      address: "127.0.0.1:8094".to_string(),
+      id: "some".to_string(),
+      request_id: 0,
+    };
+    let result = remote.get_process_diagnostics(pid, Duration::from_millis(300)).await;
+    assert!(matches!(result, Err(RemoteError::Timeout)), "{:?}", result);
+  }
+
+  #[tokio::test]
+  async fn test_list_processes_and_diagnostics_from_local_registry() {
+    let wait_group = WaitGroup::with_count(1);
+    let system = ActorSystem::new().await.unwrap();
+    let config = Config::from([ConfigOption::with_host("127.0.0.1"), ConfigOption::with_port(8099)]).await;
+    let mut server_remote = Remote::new(system.clone(), config).await;
+    let cloned_wait_group = wait_group.clone();
+    tokio::spawn(async move {
+      server_remote
+        .start_with_callback(|| async {
+          cloned_wait_group.done().await;
+        })
+        .await
+    });
+    wait_group.wait().await;
+
+    let pid = system
+      .get_root_context()
+      .await
+      .spawn_named(
+        Props::from_async_actor_receiver(|_| async { Ok(()) }).await,
+        "listed-worker",
+      )
+      .await
+      .unwrap();
+
+    let remote = Remote::new(ActorSystem::new().await.unwrap(), Config::default()).await;
+    let response = remote
+      .list_processes("127.0.0.1:8099", "listed-", Duration::from_secs(3))
+      .await
+      .unwrap();
+    assert_eq!(response.pids, vec![pid.inner_pid.clone()]);
+
+    let response = remote
+      .get_process_diagnostics(pid.inner_pid.clone(), Duration::from_secs(3))
+      .await
+      .unwrap();
+    let diagnostics: serde_json::Value = serde_json::from_str(&response.diagnostics_string).unwrap();
+    assert_eq!(diagnostics["pid"], pid.to_string());
+    assert!(diagnostics["actor_type"].is_string());
+
+    let unknown = Pid {
+      address: "127.0.0.1:8099".to_string(),
+      id: "missing".to_string(),
+      request_id: 0,
+    };
+    let response = remote
+      .get_process_diagnostics(unknown, Duration::from_secs(3))
+      .await
+      .unwrap();
+    let diagnostics: serde_json::Value = serde_json::from_str(&response.diagnostics_string).unwrap();
+    assert_eq!(diagnostics["status"], "not_found");
+  }
+
+  #[tokio::test]
+  async fn test_connect_failure_maps_to_remote_error_connect() {
+    let _ = env::set_var("RUST_LOG", "debug");
+    let _ = tracing_subscriber::fmt()
+      .with_env_filter(EnvFilter::from_default_env())
+      .try_init();
+
+    let system = ActorSystem::new().await.unwrap();
+    let remote = Remote::new(system, Config::default()).await;
+
+    // Nothing listens on port 1, so the connection is refused
+    let result = remote.list_processes("127.0.0.1:1", "", Duration::from_secs(1)).await;
+    assert!(matches!(result, Err(RemoteError::Connect(_))), "{:?}", result);
+  }
+
+  #[test]
+  fn test_cancelled_status_is_not_a_timeout() {
+    assert_eq!(
+      RemoteError::from(Status::deadline_exceeded("deadline")),
+      RemoteError::Timeout
+    );
+    assert_eq!(RemoteError::from(Status::cancelled("cancelled")), RemoteError::Cancelled);
+  }
 }
@@ -1,10 +1,11 @@
+use crate::generated::remote::RemoteFailure;
 use crate::messages::{RemoteUnwatch, RemoteWatch};
 use crate::remote::Remote;
 use crate::serializer::SerializerId;
 use async_trait::async_trait;
 use nexus_actor_core_rs::actor::actor::ExtendedPid;
 use nexus_actor_core_rs::actor::message::{
-  unwrap_envelope, MessageHandle, ReadonlyMessageHeadersHandle, SystemMessage,
+  unwrap_envelope, Failure, MessageHandle, ReadonlyMessageHeadersHandle, SystemMessage,
 };
 use nexus_actor_core_rs::actor::process::Process;
 use nexus_actor_core_rs::generated::actor::{Pid, Unwatch, Watch};
@@ -55,6 +56,27 @@ impl Process for RemoteProcess {
 
   async fn send_system_message(&self, pid: &ExtendedPid, message_handle: MessageHandle) {
     tracing::debug!("Sending system message to remote process");
+    if let Some(failure) = message_handle.to_typed::<Failure>() {
+      // the reason itself cannot cross the wire, so only its summary is sent
+      let summary = failure.reason.to_summary();
+      let remote_failure = RemoteFailure {
+        who: Some(failure.who.inner_pid.clone()),
+        kind: summary.kind,
+        message: summary.message,
+        code: summary.code,
+      };
+      self
+        .remote
+        .send_message(
+          pid.inner_pid.clone(),
+          None,
+          MessageHandle::new(remote_failure),
+          None,
+          SerializerId::None,
+        )
+        .await;
+      return;
+    }
     let watch_opt = message_handle.to_typed::<Watch>();
     let unwatch_opt = message_handle.to_typed::<Unwatch>();
     match (watch_opt, unwatch_opt) {
@@ -1,5 +1,5 @@
 use dashmap::DashMap;
-use nexus_actor_core_rs::actor::message::Message;
+use nexus_actor_core_rs::actor::message::{Message, MessageHandle};
 use once_cell::sync::Lazy;
 use prost::Message as ProstMessage;
 use serde::{Deserialize, Serialize};
@@ -34,12 +34,22 @@ pub trait SerializerAny: Send + Sync {
 }
 
 struct ProtoSerializer<T: ProstMessage> {
+  type_name: String,
   _phantom: PhantomData<T>,
 }
 
+impl<T: ProstMessage + Default> ProtoSerializer<T> {
+  fn with_type_name(type_name: &str) -> Self {
+    Self {
+      type_name: type_name.to_string(),
+      _phantom: PhantomData,
+    }
+  }
+}
+
 impl<T: ProstMessage + Default> Default for ProtoSerializer<T> {
   fn default() -> Self {
-    Self { _phantom: PhantomData }
+    Self::with_type_name(std::any::type_name::<T>())
   }
 }
 
@@ -66,7 +76,7 @@ impl<T: Message + ProstMessage + Default + 'static> SerializerAny for ProtoSeria
   }
 
   fn type_name(&self) -> String {
-    std::any::type_name::<T>().to_string()
+    self.type_name.clone()
   }
 }
 
@@ -80,7 +90,7 @@ impl<T: Message + ProstMessage + Default> Serializer<T> for ProtoSerializer<T> {
   }
 
   fn get_type_name(&self) -> String {
-    std::any::type_name::<T>().to_string()
+    self.type_name.clone()
   }
 }
 
@@ -231,6 +241,8 @@ impl Hash for SerializerKey {
 }
 
 static SERIALIZERS: Lazy<DashMap<SerializerKey, Arc<dyn Any + Send + Sync>>> = Lazy::new(DashMap::new);
+// Custom serializer ids by type name, so outbound messages find theirs without scanning SERIALIZERS
+static CUSTOM_SERIALIZER_IDS: Lazy<DashMap<String, SerializerId>> = Lazy::new(DashMap::new);
 
 pub fn register_serializer<T: 'static>(
   serializer_id: SerializerId,
@@ -258,6 +270,9 @@ pub fn register_serializer_any(
     serializer_id.to_string(),
     serializer.type_name()
   );
+  if serializer_id.is_custom() {
+    CUSTOM_SERIALIZER_IDS.insert(serializer.type_name(), serializer_id.clone());
+  }
   let key = SerializerKey::new(serializer_id, serializer.type_name(), true);
   let mut h = DefaultHasher::new();
   key.hash(&mut h);
@@ -318,6 +333,22 @@ pub fn find_serializer_any_all(type_name: &str) -> Option<Arc<dyn SerializerAny>
   None
 }
 
+// Returns the id of a custom serializer registered for the type, which takes precedence over the
+// serializer negotiated for the connection.
+pub fn find_custom_serializer_id(type_name: &str) -> Option<SerializerId> {
+  CUSTOM_SERIALIZER_IDS.get(type_name).map(|entry| entry.value().clone())
+}
+
+// Picks the first serializer of the requester's preference list that is also supported locally,
+// falling back to protobuf when there is no common format.
+pub fn negotiate_serializer_id(requested: &[u32], supported: &[SerializerId]) -> SerializerId {
+  requested
+    .iter()
+    .filter_map(|id| SerializerId::try_from(*id).ok())
+    .find(|id| *id != SerializerId::None && supported.contains(id))
+    .unwrap_or(SerializerId::Proto)
+}
+
 pub fn serialize<T: 'static>(msg: &T, serializer_id: &SerializerId) -> Result<Vec<u8>, SerializerError> {
   let serializer =
     find_serializer::<T>(serializer_id, std::any::type_name::<T>()).ok_or(SerializerError::UnknownType)?;
@@ -339,6 +370,17 @@ pub fn serialize_any(msg: &dyn Any, serializer_id: &SerializerId, type_name: &st
   serializer.serialize_any(msg)
 }
 
+// Returns the size of the message as it would be serialized for a remote peer, preferring a custom serializer
+// registered for its type like EndpointWriter does, None when no serializer is registered for it.
+// Pass it to ConfigOption::with_message_sizer so Props::with_max_message_bytes measures messages with the registry
+pub fn serialized_size(message_handle: &MessageHandle) -> Option<usize> {
+  let type_name = message_handle.get_type_name();
+  let serializer_id = find_custom_serializer_id(&type_name).unwrap_or(SerializerId::None);
+  serialize_any(message_handle.as_any(), &serializer_id, &type_name)
+    .ok()
+    .map(|bytes| bytes.len())
+}
+
 pub fn deserialize<T: 'static>(bytes: &[u8], serializer_id: &SerializerId) -> Result<T, SerializerError> {
   let serializer =
     find_serializer::<T>(serializer_id, std::any::type_name::<T>()).ok_or(SerializerError::UnknownType)?;
@@ -393,6 +435,48 @@ pub fn initialize_proto_serializers<T: Message + Default + ProstMessage + Send +
   Ok(())
 }
 
+// Registers the protobuf serializers of T under the given type name, which is the name the message
+// carries on the wire when its get_type_name is overridden
+pub fn initialize_proto_serializers_with_type_name<T: Message + Default + ProstMessage + Send + Sync + 'static>(
+  type_name: &str,
+) -> Result<(), SerializerError> {
+  register_serializer(SerializerId::Proto, Arc::new(ProtoSerializer::<T>::default()))?;
+  register_serializer_any(
+    SerializerId::Proto,
+    Arc::new(ProtoSerializer::<T>::with_type_name(type_name)),
+  )?;
+  Ok(())
+}
+
+// RemoteMessageRegistration is submitted by `#[derive(Message)]` for the types annotated with
+// `#[message(remote = "...")]`, register_remote_messages registers their protobuf serializers
+pub struct RemoteMessageRegistration {
+  type_name: &'static str,
+  register: fn(&str) -> Result<(), SerializerError>,
+}
+
+impl RemoteMessageRegistration {
+  pub const fn new<T: Message + Default + ProstMessage + Send + Sync + 'static>(type_name: &'static str) -> Self {
+    Self {
+      type_name,
+      register: initialize_proto_serializers_with_type_name::<T>,
+    }
+  }
+
+  pub fn get_type_name(&self) -> &'static str {
+    self.type_name
+  }
+}
+
+inventory::collect!(RemoteMessageRegistration);
+
+pub fn register_remote_messages() -> Result<(), SerializerError> {
+  for registration in inventory::iter::<RemoteMessageRegistration> {
+    (registration.register)(registration.type_name)?;
+  }
+  Ok(())
+}
+
 pub trait RootSerializable: Message {
   fn serialize(&self) -> Result<Arc<dyn RootSerialized>, SerializerError>;
 }
@@ -429,6 +513,21 @@ mod tests {
     assert_eq!(msg, deserialized);
   }
 
+  #[test]
+  fn test_serialized_size_uses_the_registered_serializer() {
+    #[derive(Debug, Clone, PartialEq, Message)]
+    struct Unregistered;
+
+    initialize_serializers::<TestMessage>().expect("Failed to register serializer");
+    let msg = TestMessage {
+      a: 42,
+      b: "world".to_string(),
+    };
+    let expected = serialize(&msg, &SerializerId::Proto).unwrap().len();
+    assert_eq!(serialized_size(&MessageHandle::new(msg)), Some(expected));
+    assert_eq!(serialized_size(&MessageHandle::new(Unregistered)), None);
+  }
+
   #[test]
   fn test_proto_serialization_any() {
     let _ = env::set_var("RUST_LOG", "nexus_actor_core_rs=info");
@@ -458,4 +557,95 @@ mod tests {
     let deserialized = deserialize::<TestMessage>(&bytes, &SerializerId::Json).unwrap();
     assert_eq!(msg, deserialized);
   }
+
+  #[derive(Debug, Clone, PartialEq, Message)]
+  pub struct CustomMessage {
+    pub text: String,
+  }
+
+  struct CustomSerializer;
+
+  impl SerializerAny for CustomSerializer {
+    fn serialize_any(&self, msg: &dyn Any) -> Result<Vec<u8>, SerializerError> {
+      msg
+        .downcast_ref::<CustomMessage>()
+        .map(|m| m.text.to_uppercase().into_bytes())
+        .ok_or(SerializerError::SerializationError("Invalid type".to_string()))
+    }
+
+    fn deserialize_any(&self, bytes: &[u8]) -> Result<Arc<dyn Any + Send + Sync>, SerializerError> {
+      self
+        .deserialize_message(bytes)
+        .map(|m| Arc::new(m) as Arc<dyn Any + Send + Sync>)
+    }
+
+    fn deserialize_message(&self, bytes: &[u8]) -> Result<Arc<dyn Message>, SerializerError> {
+      String::from_utf8(bytes.to_vec())
+        .map(|text| Arc::new(CustomMessage { text }) as Arc<dyn Message>)
+        .map_err(|e| SerializerError::DeserializationError(e.to_string()))
+    }
+
+    fn type_name(&self) -> String {
+      std::any::type_name::<CustomMessage>().to_string()
+    }
+  }
+
+  #[test]
+  fn test_custom_serializer_is_found_by_type_name() {
+    let type_name = std::any::type_name::<CustomMessage>();
+    assert_eq!(find_custom_serializer_id(type_name), None);
+
+    let serializer_id = SerializerId::of_custom(101);
+    register_serializer_any(serializer_id.clone(), Arc::new(CustomSerializer)).expect("Failed to register serializer");
+    assert_eq!(find_custom_serializer_id(type_name), Some(serializer_id.clone()));
+    assert_eq!(find_custom_serializer_id(std::any::type_name::<TestMessage>()), None);
+
+    let msg = CustomMessage {
+      text: "hello".to_string(),
+    };
+    let bytes = serialize_any(&msg, &serializer_id, type_name).unwrap();
+    assert_eq!(bytes, b"HELLO".to_vec());
+    let deserialized = deserialize_message(&bytes, &serializer_id, type_name).unwrap();
+    assert_eq!(
+      deserialized.as_any().downcast_ref::<CustomMessage>(),
+      Some(&CustomMessage {
+        text: "HELLO".to_string()
+      })
+    );
+  }
+
+  #[derive(Clone, PartialEq, Message, ::prost::Message)]
+  #[message(remote = "test.pkg.RemoteHello")]
+  pub struct RemoteHello {
+    #[prost(string, tag = "1")]
+    pub who: String,
+  }
+
+  #[test]
+  fn test_remote_message_is_registered_under_declared_type_name() {
+    register_remote_messages().expect("Failed to register remote messages");
+    let msg = RemoteHello {
+      who: "world".to_string(),
+    };
+    assert_eq!(msg.get_type_name(), "test.pkg.RemoteHello");
+
+    let bytes = serialize_any(msg.as_any(), &SerializerId::Proto, &msg.get_type_name()).unwrap();
+    let deserialized = deserialize_message(&bytes, &SerializerId::Proto, "test.pkg.RemoteHello").unwrap();
+    assert_eq!(deserialized.as_any().downcast_ref::<RemoteHello>(), Some(&msg));
+  }
+
+  #[test]
+  fn test_negotiate_serializer_id() {
+    let json = u32::from(SerializerId::Json);
+    let proto = u32::from(SerializerId::Proto);
+    assert_eq!(
+      negotiate_serializer_id(&[json, proto], &[SerializerId::Proto, SerializerId::Json]),
+      SerializerId::Json
+    );
+    assert_eq!(
+      negotiate_serializer_id(&[json], &[SerializerId::Proto]),
+      SerializerId::Proto
+    );
+    assert_eq!(negotiate_serializer_id(&[], &[SerializerId::Json]), SerializerId::Proto);
+  }
 }